@@ -0,0 +1,119 @@
+//! Scanner/cleaner hot paths over synthetic fixtures: `cargo bench --bench hot_paths`.
+//!
+//! Unix only, like tests/end_to_end.rs: the fixtures redirect `HOME`, which
+//! `dirs` ignores on Windows.
+
+#[cfg(unix)]
+#[path = "../tests/fixtures/mod.rs"]
+mod fixtures;
+
+#[cfg(unix)]
+mod benches {
+    use super::fixtures::{self, FakeConversation, Fixture};
+    use app_lib::{backup, cleaner, conversation, ide_detector, scanner};
+    use criterion::{black_box, BatchSize, Criterion, Throughput};
+    use std::time::{Duration, Instant};
+
+    const CACHE_DIRS: usize = 50;
+    const FILES_PER_DIR: usize = 40;
+    const FILE_SIZE: usize = 4 * 1024;
+    const CONVERSATION_COUNT: usize = 200;
+
+    fn cursor(ides: Vec<ide_detector::IdeInfo>) -> ide_detector::IdeInfo {
+        ides.into_iter().find(|i| i.id == "cursor").expect("fixture Cursor not detected")
+    }
+
+    /// Cursor with a cache of CACHE_DIRS × FILES_PER_DIR files and no conversations.
+    fn busy_cursor(fx: &Fixture) -> ide_detector::IdeInfo {
+        let dir = fx.cursor(&[]);
+        fixtures::file_tree(&dir.join("Cache"), CACHE_DIRS, FILES_PER_DIR, FILE_SIZE);
+        cursor(ide_detector::detect_all_ides())
+    }
+
+    pub fn detect_and_scan(c: &mut Criterion) {
+        let fx = Fixture::new("bench-scan");
+        let ide = busy_cursor(&fx);
+        fx.jetbrains("IntelliJIdea", &["2023.3", "2024.1", "2024.2"]);
+
+        let mut group = c.benchmark_group("scan");
+        group.bench_function("detect_all_ides", |b| b.iter(ide_detector::detect_all_ides));
+        group.throughput(Throughput::Elements((CACHE_DIRS * FILES_PER_DIR) as u64));
+        group.bench_function("scan_ide/cursor", |b| b.iter(|| scanner::scan_ide(black_box(&ide))));
+        group.finish();
+    }
+
+    pub fn conversations(c: &mut Criterion) {
+        let fx = Fixture::new("bench-conversations");
+        // FakeConversation borrows 'static strings; leaking a few hundred is fine for a bench
+        let fakes: Vec<FakeConversation> = (0..CONVERSATION_COUNT)
+            .map(|i| FakeConversation {
+                id: Box::leak(format!("{:08x}-bench", i).into_boxed_str()),
+                title: Box::leak(format!("Conversation number {}", i).into_boxed_str()),
+                messages: 20,
+            })
+            .collect();
+        fx.cursor(&fakes);
+        let ide = cursor(ide_detector::detect_all_ides());
+
+        let mut group = c.benchmark_group("conversations");
+        group.throughput(Throughput::Elements(CONVERSATION_COUNT as u64));
+        group.bench_function("scan/cursor_sqlite", |b| {
+            b.iter(|| conversation::scan_conversations(black_box(&ide), conversation::ConversationSort::default()))
+        });
+        group.finish();
+    }
+
+    pub fn backup_zip(c: &mut Criterion) {
+        let fx = Fixture::new("bench-backup");
+        let ide = busy_cursor(&fx);
+        let cache = ide.cache_paths.clone();
+
+        let mut group = c.benchmark_group("backup");
+        group.sample_size(10);
+        group.throughput(Throughput::Bytes((CACHE_DIRS * FILES_PER_DIR * FILE_SIZE) as u64));
+        group.bench_function("create_backup/cache", |b| {
+            // Only the zip is timed; each backup is deleted again so the manifest stays small
+            b.iter_custom(|iters| {
+                let mut total = Duration::ZERO;
+                for _ in 0..iters {
+                    let start = Instant::now();
+                    let info = backup::create_backup(&ide, &cache).unwrap();
+                    total += start.elapsed();
+                    backup::delete_backup(&info.id).unwrap();
+                }
+                total
+            })
+        });
+        group.finish();
+    }
+
+    pub fn clean(c: &mut Criterion) {
+        let fx = Fixture::new("bench-clean");
+        let target = fx.root.join("clean-target");
+
+        let mut group = c.benchmark_group("clean");
+        group.sample_size(10);
+        group.throughput(Throughput::Elements((CACHE_DIRS * FILES_PER_DIR) as u64));
+        for (name, clean) in [
+            ("clean_directory", cleaner::clean_directory as fn(&std::path::Path) -> (u64, u64, Vec<String>)),
+            ("clean_directory_safe", cleaner::clean_directory_safe),
+        ] {
+            group.bench_function(name, |b| {
+                b.iter_batched(
+                    || fixtures::file_tree(&target, CACHE_DIRS, FILES_PER_DIR, FILE_SIZE),
+                    |()| clean(&target),
+                    BatchSize::PerIteration,
+                )
+            });
+        }
+        group.finish();
+    }
+}
+
+#[cfg(unix)]
+criterion::criterion_group!(hot_paths, benches::detect_and_scan, benches::conversations, benches::backup_zip, benches::clean);
+#[cfg(unix)]
+criterion::criterion_main!(hot_paths);
+
+#[cfg(not(unix))]
+fn main() {}
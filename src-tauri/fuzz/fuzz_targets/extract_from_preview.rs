@@ -0,0 +1,14 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// Input is "<key>\n<value>"; without a newline the whole input is a composer value.
+fuzz_target!(|data: &str| {
+    let (key, value) = data.split_once('\n').unwrap_or(("composerData:fuzz", data));
+    if key.is_empty() {
+        return;
+    }
+    if let Some(conv) = app_lib::conversation::fuzzing::extract_from_preview(key, value) {
+        assert_eq!(conv.source_key, key);
+    }
+});
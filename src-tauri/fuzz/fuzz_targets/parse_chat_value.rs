@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|json: &str| {
+    for conv in app_lib::conversation::fuzzing::parse_chat_value(json) {
+        assert!(!conv.title.is_empty());
+    }
+});
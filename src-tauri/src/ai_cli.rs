@@ -0,0 +1,846 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs;
+use std::io::{BufRead, BufReader};
+use std::path::{Component, Path, PathBuf};
+use walkdir::WalkDir;
+
+use crate::batch::{BatchResult, ItemResult};
+
+/// Lines of a Claude Code transcript read for its title while listing.
+const TITLE_SCAN_LINES: usize = 64;
+/// Gemini chat files larger than this aren't parsed just for a title.
+const MAX_TITLE_PARSE_BYTES: u64 = 8 * 1024 * 1024;
+const TITLE_MAX_CHARS: usize = 80;
+const MS_PER_DAY: i64 = 86_400_000;
+/// Aider appends every chat in a project to one Markdown file in its root.
+const AIDER_HISTORY_FILE: &str = ".aider.chat.history.md";
+/// Repo map cache aider keeps next to the history.
+const AIDER_TAGS_CACHE: &str = ".aider.tags.cache.v3";
+const AIDER_SESSION_MARKER: &str = "# aider chat started at ";
+/// User prompts are level-4 headings; tool output is quoted with `>`.
+const AIDER_PROMPT_PREFIX: &str = "####";
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum AiCliTool {
+    ClaudeCode,
+    GeminiCli,
+    CodexCli,
+    Aider,
+}
+
+/// A tool's whole state folder, caches and settings included.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AiCliRoot {
+    pub tool: AiCliTool,
+    pub path: PathBuf,
+    pub size: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AiCliSession {
+    pub id: String,
+    pub path: PathBuf,
+    /// Transcript plus the per-session folder Claude Code keeps next to it.
+    pub size: u64,
+    /// UTC epoch milliseconds.
+    pub last_modified: Option<i64>,
+    pub title: String,
+    /// Untouched for longer than the report's `stale_days`.
+    pub stale: bool,
+}
+
+/// One project's sessions for one tool.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AiCliProject {
+    pub tool: AiCliTool,
+    /// Working directory the sessions ran in, or the tool's folder name for it
+    /// when that isn't recorded.
+    pub project: String,
+    pub storage_path: PathBuf,
+    /// Newest first.
+    pub sessions: Vec<AiCliSession>,
+    pub size: u64,
+    pub last_modified: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AiCliReport {
+    pub roots: Vec<AiCliRoot>,
+    /// Largest first.
+    pub projects: Vec<AiCliProject>,
+    pub total_size: u64,
+    pub stale_count: usize,
+    pub stale_size: u64,
+    pub stale_days: u32,
+}
+
+/// What a session contains, read from the whole transcript.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AiCliSessionSummary {
+    pub tool: AiCliTool,
+    pub id: String,
+    pub path: PathBuf,
+    pub project: Option<String>,
+    pub title: String,
+    /// Summaries the tool wrote when compacting the conversation.
+    pub summaries: Vec<String>,
+    pub first_prompt: Option<String>,
+    pub last_prompt: Option<String>,
+    pub user_messages: usize,
+    pub assistant_messages: usize,
+    pub models: Vec<String>,
+    /// UTC epoch milliseconds.
+    pub started: Option<i64>,
+    pub last_active: Option<i64>,
+    pub size: u64,
+}
+
+// ── Locations ──
+
+/// `$CLAUDE_CONFIG_DIR`, else ~/.claude.
+fn claude_dir() -> Option<PathBuf> {
+    std::env::var_os("CLAUDE_CONFIG_DIR")
+        .map(PathBuf::from)
+        .or_else(|| dirs::home_dir().map(|h| h.join(".claude")))
+        .filter(|p| p.is_dir())
+}
+
+fn gemini_dir() -> Option<PathBuf> {
+    dirs::home_dir().map(|h| h.join(".gemini")).filter(|p| p.is_dir())
+}
+
+/// `$CODEX_HOME`, else ~/.codex.
+fn codex_dir() -> Option<PathBuf> {
+    std::env::var_os("CODEX_HOME")
+        .map(PathBuf::from)
+        .or_else(|| dirs::home_dir().map(|h| h.join(".codex")))
+        .filter(|p| p.is_dir())
+}
+
+/// `path` relative to `root`, only if every component below `root` is a plain
+/// name; `..` would pass a lexical prefix check and climb back out.
+fn relative_to<'a>(path: &'a Path, root: &Path) -> Option<&'a Path> {
+    let rel = path.strip_prefix(root).ok()?;
+    rel.components().all(|c| matches!(c, Component::Normal(_))).then_some(rel)
+}
+
+/// Which tool a session file belongs to. Only transcripts in the tools' own
+/// session folders qualify, so nothing else can be listed or pruned.
+fn session_tool(path: &Path) -> Option<AiCliTool> {
+    let name = path.file_name()?.to_string_lossy().to_string();
+    if let Some(projects) = claude_dir().map(|d| d.join("projects")) {
+        let in_project = relative_to(path, &projects).is_some_and(|rel| rel.components().count() == 2);
+        if name.ends_with(".jsonl") && in_project {
+            return Some(AiCliTool::ClaudeCode);
+        }
+    }
+    if let Some(tmp) = gemini_dir().map(|d| d.join("tmp")) {
+        // tmp/<project hash>/chats/session-*.json or tmp/<project hash>/checkpoint-*.json
+        let rel: Vec<_> = relative_to(path, &tmp).map(|rel| rel.iter().collect()).unwrap_or_default();
+        let in_chats = rel.len() == 3 && rel[1] == "chats";
+        let checkpoint = rel.len() == 2 && name.starts_with("checkpoint-");
+        if name.ends_with(".json") && (in_chats || checkpoint) {
+            return Some(AiCliTool::GeminiCli);
+        }
+    }
+    if let Some(sessions) = codex_dir().map(|d| d.join("sessions")) {
+        if name.starts_with("rollout-") && name.ends_with(".jsonl") && relative_to(path, &sessions).is_some() {
+            return Some(AiCliTool::CodexCli);
+        }
+    }
+    if name == AIDER_HISTORY_FILE {
+        return Some(AiCliTool::Aider);
+    }
+    None
+}
+
+/// Claude Code keeps subagent transcripts and tool output in `<session id>/`;
+/// aider's tags cache goes with its history.
+fn companion_dir(tool: AiCliTool, path: &Path) -> Option<PathBuf> {
+    let dir = match tool {
+        AiCliTool::ClaudeCode => path.with_extension(""),
+        AiCliTool::Aider => path.parent()?.join(AIDER_TAGS_CACHE),
+        _ => return None,
+    };
+    dir.is_dir().then_some(dir)
+}
+
+fn dir_size(dir: &Path) -> u64 {
+    WalkDir::new(dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| e.metadata().ok())
+        .map(|m| m.len())
+        .sum()
+}
+
+fn modified_ms(path: &Path) -> Option<i64> {
+    fs::metadata(path)
+        .ok()
+        .and_then(|m| m.modified().ok())
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_millis() as i64)
+}
+
+fn rfc3339_ms(v: &Value) -> Option<i64> {
+    chrono::DateTime::parse_from_rfc3339(v.as_str()?).ok().map(|t| t.timestamp_millis())
+}
+
+// ── Transcript parsing ──
+
+/// Plain text of a message body: a string, or the text parts of a content array.
+fn message_text(content: &Value) -> String {
+    match content {
+        Value::String(s) => s.clone(),
+        Value::Array(parts) => parts
+            .iter()
+            .filter_map(|p| p.as_str().or_else(|| p.get("text").and_then(|t| t.as_str())))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        _ => String::new(),
+    }
+}
+
+/// A prompt the user typed, as opposed to slash-command markup or tool results.
+fn is_typed_prompt(text: &str) -> bool {
+    let text = text.trim_start();
+    !text.is_empty() && !text.starts_with('<')
+}
+
+fn short_title(text: &str) -> String {
+    let line = text.lines().map(str::trim).find(|l| !l.is_empty()).unwrap_or("");
+    match line.char_indices().nth(TITLE_MAX_CHARS) {
+        Some((i, _)) => format!("{}…", &line[..i]),
+        None => line.to_string(),
+    }
+}
+
+/// One Claude Code JSONL record → (type, text, record).
+fn claude_record(line: &str) -> Option<(String, String, Value)> {
+    let record: Value = serde_json::from_str(line).ok()?;
+    let kind = record.get("type")?.as_str()?.to_string();
+    let text = match kind.as_str() {
+        "summary" => record.get("summary").and_then(|s| s.as_str()).unwrap_or("").to_string(),
+        _ => record.get("message").and_then(|m| m.get("content")).map(message_text).unwrap_or_default(),
+    };
+    Some((kind, text, record))
+}
+
+/// The typed prompts and assistant replies of a Claude Code transcript, in order.
+/// Replies that only ran tools show the tool names.
+fn claude_messages(path: &Path) -> Result<Vec<crate::conversation::ConversationMessage>, String> {
+    let file = fs::File::open(path).map_err(|e| format!("Failed to open session: {}", e))?;
+    let mut messages = Vec::new();
+    for line in BufReader::new(file).lines().map_while(Result::ok) {
+        let Some((kind, text, record)) = claude_record(&line) else { continue };
+        let content = match kind.as_str() {
+            "user" if is_typed_prompt(&text) => text,
+            "assistant" if !text.trim().is_empty() => text,
+            "assistant" => {
+                let blocks = record.get("message").and_then(|m| m.get("content")).and_then(|c| c.as_array());
+                let tools: Vec<String> = blocks
+                    .into_iter()
+                    .flatten()
+                    .filter(|b| b.get("type").and_then(|t| t.as_str()) == Some("tool_use"))
+                    .map(|b| format!("[{}]", b.get("name").and_then(|n| n.as_str()).unwrap_or("tool")))
+                    .collect();
+                if tools.is_empty() { continue; }
+                tools.join(" ")
+            }
+            _ => continue,
+        };
+        messages.push(crate::conversation::ConversationMessage {
+            role: kind,
+            content,
+            timestamp: record.get("timestamp").and_then(rfc3339_ms),
+        });
+    }
+    Ok(messages)
+}
+
+/// A summary line if the head has one, else the first typed prompt.
+fn claude_title(path: &Path) -> String {
+    let Ok(file) = fs::File::open(path) else { return String::new() };
+    let mut first_prompt = None;
+    for line in BufReader::new(file).lines().take(TITLE_SCAN_LINES).map_while(Result::ok) {
+        let Some((kind, text, _)) = claude_record(&line) else { continue };
+        match kind.as_str() {
+            "summary" if !text.is_empty() => return short_title(&text),
+            "user" if first_prompt.is_none() && is_typed_prompt(&text) => first_prompt = Some(text),
+            _ => {}
+        }
+    }
+    first_prompt.map(|t| short_title(&t)).unwrap_or_default()
+}
+
+/// The `cwd` Claude Code records on its transcript lines.
+fn claude_project_cwd(dir: &Path) -> Option<String> {
+    fs::read_dir(dir).ok()?.filter_map(|e| e.ok()).map(|e| e.path()).filter(|p| p.extension().is_some_and(|e| e == "jsonl")).find_map(
+        |path| {
+            let file = fs::File::open(&path).ok()?;
+            BufReader::new(file).lines().take(TITLE_SCAN_LINES).map_while(Result::ok).find_map(|line| {
+                let record: Value = serde_json::from_str(&line).ok()?;
+                record.get("cwd")?.as_str().map(str::to_string)
+            })
+        },
+    )
+}
+
+fn gemini_messages(parsed: &Value) -> Vec<Value> {
+    // chats/session-*.json: { messages: [...] }; checkpoint-*.json: a bare history array
+    parsed.get("messages").or(Some(parsed)).and_then(|m| m.as_array()).cloned().unwrap_or_default()
+}
+
+fn gemini_role(message: &Value) -> &str {
+    message.get("type").or_else(|| message.get("role")).and_then(|r| r.as_str()).unwrap_or("")
+}
+
+fn gemini_text(message: &Value) -> String {
+    message.get("content").or_else(|| message.get("parts")).map(message_text).unwrap_or_default()
+}
+
+fn gemini_title(path: &Path) -> String {
+    if fs::metadata(path).map(|m| m.len() > MAX_TITLE_PARSE_BYTES).unwrap_or(true) {
+        return String::new();
+    }
+    let Some(parsed) = fs::read_to_string(path).ok().and_then(|d| serde_json::from_str::<Value>(&d).ok()) else {
+        return String::new();
+    };
+    gemini_messages(&parsed)
+        .iter()
+        .filter(|m| gemini_role(m) == "user")
+        .map(gemini_text)
+        .find(|t| is_typed_prompt(t))
+        .map(|t| short_title(&t))
+        .unwrap_or_default()
+}
+
+/// One Codex rollout line → (role, or "tool" for tool calls, text, record).
+/// Newer rollouts wrap items as `{type: "response_item", payload}`, older ones
+/// write them bare; session metadata and UI events yield None.
+fn codex_item(line: &str) -> Option<(String, String, Value)> {
+    let record: Value = serde_json::from_str(line).ok()?;
+    let item = match record.get("type").and_then(|t| t.as_str()) {
+        Some("response_item") => record.get("payload")?,
+        _ => &record,
+    };
+    let (kind, text) = match item.get("type").and_then(|t| t.as_str())? {
+        "message" => (item.get("role")?.as_str()?.to_string(), item.get("content").map(message_text).unwrap_or_default()),
+        "function_call" | "custom_tool_call" | "local_shell_call" => {
+            ("tool".to_string(), item.get("name").and_then(|n| n.as_str()).unwrap_or("shell").to_string())
+        }
+        _ => return None,
+    };
+    Some((kind, text, record))
+}
+
+/// `cwd` (or `model`) from a rollout's `session_meta` / `turn_context` records.
+fn codex_context<'a>(record: &'a Value, field: &str) -> Option<&'a str> {
+    match record.get("type").and_then(|t| t.as_str()) {
+        Some("session_meta") | Some("turn_context") => record.get("payload")?.get(field)?.as_str(),
+        _ => None,
+    }
+}
+
+fn codex_head(path: &Path) -> Vec<String> {
+    let Ok(file) = fs::File::open(path) else { return Vec::new() };
+    BufReader::new(file).lines().take(TITLE_SCAN_LINES).map_while(Result::ok).collect()
+}
+
+/// The first typed prompt; the environment and instruction preamble is tagged markup.
+fn codex_title(path: &Path) -> String {
+    codex_head(path)
+        .iter()
+        .filter_map(|line| codex_item(line))
+        .find(|(kind, text, _)| kind == "user" && is_typed_prompt(text))
+        .map(|(_, text, _)| short_title(&text))
+        .unwrap_or_default()
+}
+
+fn codex_messages(path: &Path) -> Result<Vec<crate::conversation::ConversationMessage>, String> {
+    let file = fs::File::open(path).map_err(|e| format!("Failed to open session: {}", e))?;
+    let mut messages = Vec::new();
+    for line in BufReader::new(file).lines().map_while(Result::ok) {
+        let Some((kind, text, record)) = codex_item(&line) else { continue };
+        let (role, content) = match kind.as_str() {
+            "user" if is_typed_prompt(&text) => ("user", text),
+            "assistant" if !text.trim().is_empty() => ("assistant", text),
+            "tool" => ("assistant", format!("[{}]", text)),
+            _ => continue,
+        };
+        messages.push(crate::conversation::ConversationMessage {
+            role: role.to_string(),
+            content,
+            timestamp: record.get("timestamp").and_then(rfc3339_ms),
+        });
+    }
+    Ok(messages)
+}
+
+fn gemini_transcript(path: &Path) -> Result<Vec<crate::conversation::ConversationMessage>, String> {
+    let data = fs::read_to_string(path).map_err(|e| format!("Failed to read session: {}", e))?;
+    let parsed: Value = serde_json::from_str(&data).map_err(|e| format!("Failed to parse session: {}", e))?;
+    Ok(gemini_messages(&parsed)
+        .iter()
+        .filter_map(|message| {
+            let role = match gemini_role(message) {
+                "user" => "user",
+                "gemini" | "model" => "assistant",
+                _ => return None,
+            };
+            let content = gemini_text(message);
+            (!content.trim().is_empty()).then(|| crate::conversation::ConversationMessage {
+                role: role.to_string(),
+                content,
+                timestamp: message.get("timestamp").and_then(rfc3339_ms),
+            })
+        })
+        .collect())
+}
+
+/// Local time of an `# aider chat started at YYYY-MM-DD HH:MM:SS` line.
+fn aider_session_start(line: &str) -> Option<i64> {
+    let stamp = line.strip_prefix(AIDER_SESSION_MARKER)?.trim();
+    use chrono::TimeZone;
+    let local = chrono::NaiveDateTime::parse_from_str(stamp, "%Y-%m-%d %H:%M:%S").ok()?;
+    chrono::Local.from_local_datetime(&local).single().map(|t| t.timestamp_millis())
+}
+
+fn aider_prompt(line: &str) -> Option<&str> {
+    line.strip_prefix(AIDER_PROMPT_PREFIX).map(|p| p.strip_prefix(' ').unwrap_or(p))
+}
+
+fn aider_title(path: &Path) -> String {
+    let Ok(file) = fs::File::open(path) else { return String::new() };
+    BufReader::new(file)
+        .lines()
+        .map_while(Result::ok)
+        .find_map(|line| aider_prompt(&line).filter(|p| is_typed_prompt(p)).map(short_title))
+        .unwrap_or_default()
+}
+
+/// Every chat in the history file, in order: consecutive prompt lines form one
+/// user message, the unquoted text after them the reply. Messages carry the
+/// start time of the chat they belong to.
+fn aider_messages(path: &Path) -> Result<Vec<crate::conversation::ConversationMessage>, String> {
+    let data = fs::read_to_string(path).map_err(|e| format!("Failed to read chat history: {}", e))?;
+    let mut messages: Vec<crate::conversation::ConversationMessage> = Vec::new();
+    let mut current: Option<&str> = None;
+    let mut started = None;
+    for line in data.lines() {
+        if line.starts_with(AIDER_SESSION_MARKER) {
+            started = aider_session_start(line);
+            current = None;
+            continue;
+        }
+        if line.starts_with('>') {
+            continue;
+        }
+        let (role, text) = match aider_prompt(line) {
+            Some(prompt) => ("user", prompt),
+            None => ("assistant", line),
+        };
+        match messages.last_mut() {
+            Some(last) if current == Some(role) => {
+                last.content.push('\n');
+                last.content.push_str(text);
+            }
+            _ if text.trim().is_empty() => continue,
+            _ => messages.push(crate::conversation::ConversationMessage {
+                role: role.to_string(),
+                content: text.to_string(),
+                timestamp: started,
+            }),
+        }
+        current = Some(role);
+    }
+    for message in &mut messages {
+        message.content = message.content.trim_end().to_string();
+    }
+    Ok(messages)
+}
+
+/// The transcript of any tool's session file, for the conversations page.
+pub(crate) fn session_messages(path: &Path) -> Result<Vec<crate::conversation::ConversationMessage>, String> {
+    match session_tool(path).ok_or_else(|| format!("{}: not an AI CLI session file", path.display()))? {
+        AiCliTool::ClaudeCode => claude_messages(path),
+        AiCliTool::GeminiCli => gemini_transcript(path),
+        AiCliTool::CodexCli => codex_messages(path),
+        AiCliTool::Aider => aider_messages(path),
+    }
+}
+
+// ── Listing ──
+
+fn read_session(tool: AiCliTool, path: PathBuf, cutoff: i64) -> AiCliSession {
+    let last_modified = modified_ms(&path);
+    let size = fs::metadata(&path).map(|m| m.len()).unwrap_or(0)
+        + companion_dir(tool, &path).map(|d| dir_size(&d)).unwrap_or(0);
+    let title = match tool {
+        AiCliTool::ClaudeCode => claude_title(&path),
+        AiCliTool::GeminiCli => gemini_title(&path),
+        AiCliTool::CodexCli => codex_title(&path),
+        AiCliTool::Aider => aider_title(&path),
+    };
+    AiCliSession {
+        id: path.file_stem().unwrap_or_default().to_string_lossy().to_string(),
+        stale: last_modified.map(|m| m < cutoff).unwrap_or(false),
+        path,
+        size,
+        last_modified,
+        title,
+    }
+}
+
+fn project_from(tool: AiCliTool, project: String, storage_path: PathBuf, files: Vec<PathBuf>, cutoff: i64) -> Option<AiCliProject> {
+    let mut sessions: Vec<AiCliSession> = files.into_iter().map(|p| read_session(tool, p, cutoff)).collect();
+    if sessions.is_empty() {
+        return None;
+    }
+    sessions.sort_by(|a, b| b.last_modified.cmp(&a.last_modified));
+    Some(AiCliProject {
+        tool,
+        project,
+        storage_path,
+        size: sessions.iter().map(|s| s.size).sum(),
+        last_modified: sessions.first().and_then(|s| s.last_modified),
+        sessions,
+    })
+}
+
+fn files_matching(dir: &Path, matches: impl Fn(&str) -> bool) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else { return Vec::new() };
+    entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.is_file() && p.file_name().map(|n| matches(&n.to_string_lossy())).unwrap_or(false))
+        .collect()
+}
+
+fn subdirs(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else { return Vec::new() };
+    entries.filter_map(|e| e.ok()).map(|e| e.path()).filter(|p| p.is_dir()).collect()
+}
+
+/// `~/.claude/projects/<cwd with separators replaced>/<session id>.jsonl`
+fn claude_projects(root: &Path, cutoff: i64) -> Vec<AiCliProject> {
+    subdirs(&root.join("projects"))
+        .into_iter()
+        .filter_map(|dir| {
+            let project = claude_project_cwd(&dir).unwrap_or_else(|| dir.file_name().unwrap_or_default().to_string_lossy().to_string());
+            let files = files_matching(&dir, |n| n.ends_with(".jsonl"));
+            project_from(AiCliTool::ClaudeCode, project, dir, files, cutoff)
+        })
+        .collect()
+}
+
+/// `~/.gemini/tmp/<project hash>/chats/*.json` plus saved `checkpoint-*.json` chats.
+/// Gemini only records a hash of the project path, unless `.project_root` is there.
+fn gemini_projects(root: &Path, cutoff: i64) -> Vec<AiCliProject> {
+    subdirs(&root.join("tmp"))
+        .into_iter()
+        .filter_map(|dir| {
+            let project = fs::read_to_string(dir.join(".project_root"))
+                .ok()
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .unwrap_or_else(|| dir.file_name().unwrap_or_default().to_string_lossy().to_string());
+            let mut files = files_matching(&dir.join("chats"), |n| n.ends_with(".json"));
+            files.extend(files_matching(&dir, |n| n.starts_with("checkpoint-") && n.ends_with(".json")));
+            project_from(AiCliTool::GeminiCli, project, dir, files, cutoff)
+        })
+        .collect()
+}
+
+/// `~/.codex/sessions/YYYY/MM/DD/rollout-*.jsonl`, grouped by the `cwd` each
+/// rollout records.
+fn codex_projects(root: &Path, cutoff: i64) -> Vec<AiCliProject> {
+    let sessions_dir = root.join("sessions");
+    let mut by_cwd: std::collections::BTreeMap<String, Vec<PathBuf>> = std::collections::BTreeMap::new();
+    for entry in WalkDir::new(&sessions_dir).into_iter().filter_map(|e| e.ok()).filter(|e| e.file_type().is_file()) {
+        let name = entry.file_name().to_string_lossy();
+        if !name.starts_with("rollout-") || !name.ends_with(".jsonl") {
+            continue;
+        }
+        let cwd = codex_head(entry.path()).iter().find_map(|line| {
+            let record: Value = serde_json::from_str(line).ok()?;
+            codex_context(&record, "cwd").map(str::to_string)
+        });
+        by_cwd.entry(cwd.unwrap_or_else(|| "sessions".to_string())).or_default().push(entry.into_path());
+    }
+    by_cwd
+        .into_iter()
+        .filter_map(|(project, files)| project_from(AiCliTool::CodexCli, project, sessions_dir.clone(), files, cutoff))
+        .collect()
+}
+
+/// Aider histories in the configured project roots; each project is its own session.
+fn aider_projects(cutoff: i64) -> Vec<AiCliProject> {
+    let mut projects = Vec::new();
+    for root in crate::config::load_config().project_roots {
+        let mut walker = WalkDir::new(&root).max_depth(crate::project_artifacts::MAX_SEARCH_DEPTH).into_iter();
+        while let Some(entry) = walker.next() {
+            let Ok(entry) = entry else { continue };
+            let name = entry.file_name().to_string_lossy();
+            if entry.file_type().is_dir() {
+                if crate::project_artifacts::SKIPPED_DIRS.contains(&name.as_ref()) || crate::cleaner::has_ignore_marker(entry.path()) {
+                    walker.skip_current_dir();
+                }
+                continue;
+            }
+            if name != AIDER_HISTORY_FILE {
+                continue;
+            }
+            let history = entry.into_path();
+            let Some(dir) = history.parent().map(Path::to_path_buf) else { continue };
+            if projects.iter().any(|p: &AiCliProject| p.storage_path == dir) {
+                continue;
+            }
+            projects.extend(project_from(AiCliTool::Aider, dir.display().to_string(), dir, vec![history], cutoff));
+        }
+    }
+    projects
+}
+
+/// Projects whose sessions the conversations page lists as CLI agent conversations.
+pub(crate) fn conversation_projects() -> Vec<AiCliProject> {
+    let mut projects = Vec::new();
+    if let Some(root) = claude_dir() {
+        projects.extend(claude_projects(&root, i64::MIN));
+    }
+    if let Some(root) = gemini_dir() {
+        projects.extend(gemini_projects(&root, i64::MIN));
+    }
+    if let Some(root) = codex_dir() {
+        projects.extend(codex_projects(&root, i64::MIN));
+    }
+    projects.extend(aider_projects(i64::MIN));
+    projects
+}
+
+/// Whether `path` is a session transcript in one of the tools' session folders.
+pub(crate) fn is_session_file(path: &Path) -> bool {
+    session_tool(path).is_some()
+}
+
+/// Claude Code, Gemini CLI, Codex CLI and aider session transcripts per project. Sessions untouched
+/// for `stale_days` are marked stale.
+pub fn analyze_ai_cli(stale_days: u32) -> AiCliReport {
+    let cutoff = chrono::Utc::now().timestamp_millis() - stale_days as i64 * MS_PER_DAY;
+    let mut roots = Vec::new();
+    let mut projects = Vec::new();
+    if let Some(root) = claude_dir() {
+        projects.extend(claude_projects(&root, cutoff));
+        roots.push(AiCliRoot { tool: AiCliTool::ClaudeCode, size: dir_size(&root), path: root });
+    }
+    if let Some(root) = gemini_dir() {
+        projects.extend(gemini_projects(&root, cutoff));
+        roots.push(AiCliRoot { tool: AiCliTool::GeminiCli, size: dir_size(&root), path: root });
+    }
+    // The root's size includes Codex's log/ and cache folders next to sessions/
+    if let Some(root) = codex_dir() {
+        projects.extend(codex_projects(&root, cutoff));
+        roots.push(AiCliRoot { tool: AiCliTool::CodexCli, size: dir_size(&root), path: root });
+    }
+    // Aider has no state folder of its own, only files in each project
+    projects.extend(aider_projects(cutoff));
+    projects.sort_by(|a, b| b.size.cmp(&a.size));
+    let stale: Vec<&AiCliSession> = projects.iter().flat_map(|p| &p.sessions).filter(|s| s.stale).collect();
+    AiCliReport {
+        total_size: roots.iter().map(|r| r.size).sum(),
+        stale_count: stale.len(),
+        stale_size: stale.iter().map(|s| s.size).sum(),
+        stale_days,
+        roots,
+        projects,
+    }
+}
+
+// ── Session summary ──
+
+fn claude_summary(path: &Path, summary: &mut AiCliSessionSummary) -> Result<(), String> {
+    let file = fs::File::open(path).map_err(|e| format!("Failed to open session: {}", e))?;
+    for line in BufReader::new(file).lines().map_while(Result::ok) {
+        let Some((kind, text, record)) = claude_record(&line) else { continue };
+        if let Some(ts) = record.get("timestamp").and_then(rfc3339_ms) {
+            summary.started = Some(summary.started.map_or(ts, |s| s.min(ts)));
+            summary.last_active = Some(summary.last_active.map_or(ts, |s| s.max(ts)));
+        }
+        if summary.project.is_none() {
+            summary.project = record.get("cwd").and_then(|c| c.as_str()).map(str::to_string);
+        }
+        match kind.as_str() {
+            "summary" if !text.is_empty() => summary.summaries.push(text),
+            "user" if is_typed_prompt(&text) => {
+                summary.user_messages += 1;
+                summary.first_prompt.get_or_insert_with(|| text.clone());
+                summary.last_prompt = Some(text);
+            }
+            "assistant" => {
+                summary.assistant_messages += 1;
+                if let Some(model) = record.get("message").and_then(|m| m.get("model")).and_then(|m| m.as_str()) {
+                    if !summary.models.iter().any(|m| m == model) {
+                        summary.models.push(model.to_string());
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn gemini_summary(path: &Path, summary: &mut AiCliSessionSummary) -> Result<(), String> {
+    let data = fs::read_to_string(path).map_err(|e| format!("Failed to read session: {}", e))?;
+    let parsed: Value = serde_json::from_str(&data).map_err(|e| format!("Failed to parse session: {}", e))?;
+    summary.started = parsed.get("startTime").and_then(rfc3339_ms);
+    summary.last_active = parsed.get("lastUpdated").and_then(rfc3339_ms);
+    for message in gemini_messages(&parsed) {
+        let text = gemini_text(&message);
+        match gemini_role(&message) {
+            "user" if is_typed_prompt(&text) => {
+                summary.user_messages += 1;
+                summary.first_prompt.get_or_insert_with(|| text.clone());
+                summary.last_prompt = Some(text);
+            }
+            "gemini" | "model" => summary.assistant_messages += 1,
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn codex_summary(path: &Path, summary: &mut AiCliSessionSummary) -> Result<(), String> {
+    let file = fs::File::open(path).map_err(|e| format!("Failed to open session: {}", e))?;
+    for line in BufReader::new(file).lines().map_while(Result::ok) {
+        let Ok(record) = serde_json::from_str::<Value>(&line) else { continue };
+        if let Some(ts) = record.get("timestamp").and_then(rfc3339_ms) {
+            summary.started = Some(summary.started.map_or(ts, |s| s.min(ts)));
+            summary.last_active = Some(summary.last_active.map_or(ts, |s| s.max(ts)));
+        }
+        if summary.project.is_none() {
+            summary.project = codex_context(&record, "cwd").map(str::to_string);
+        }
+        if let Some(model) = codex_context(&record, "model") {
+            if !summary.models.iter().any(|m| m == model) {
+                summary.models.push(model.to_string());
+            }
+        }
+        let Some((kind, text, _)) = codex_item(&line) else { continue };
+        match kind.as_str() {
+            "user" if is_typed_prompt(&text) => {
+                summary.user_messages += 1;
+                summary.first_prompt.get_or_insert_with(|| text.clone());
+                summary.last_prompt = Some(text);
+            }
+            "assistant" => summary.assistant_messages += 1,
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn aider_summary(path: &Path, summary: &mut AiCliSessionSummary) -> Result<(), String> {
+    summary.project = path.parent().map(|p| p.display().to_string());
+    for message in aider_messages(path)? {
+        summary.started = summary.started.or(message.timestamp);
+        summary.last_active = message.timestamp.or(summary.last_active);
+        if message.role == "user" {
+            summary.user_messages += 1;
+            summary.first_prompt.get_or_insert_with(|| message.content.clone());
+            summary.last_prompt = Some(message.content);
+        } else {
+            summary.assistant_messages += 1;
+        }
+    }
+    // Chat start times only; the file's own time is closer to the last activity
+    summary.last_active = modified_ms(path).or(summary.last_active);
+    Ok(())
+}
+
+/// Read a whole session transcript into a summary for review before pruning.
+pub fn session_summary(path: &Path) -> Result<AiCliSessionSummary, String> {
+    let tool = session_tool(path).ok_or_else(|| format!("{}: not an AI CLI session file", path.display()))?;
+    let mut summary = AiCliSessionSummary {
+        tool,
+        id: path.file_stem().unwrap_or_default().to_string_lossy().to_string(),
+        path: path.to_path_buf(),
+        project: None,
+        title: String::new(),
+        summaries: Vec::new(),
+        first_prompt: None,
+        last_prompt: None,
+        user_messages: 0,
+        assistant_messages: 0,
+        models: Vec::new(),
+        started: None,
+        last_active: None,
+        size: fs::metadata(path).map(|m| m.len()).unwrap_or(0) + companion_dir(tool, path).map(|d| dir_size(&d)).unwrap_or(0),
+    };
+    match tool {
+        AiCliTool::ClaudeCode => claude_summary(path, &mut summary)?,
+        AiCliTool::GeminiCli => gemini_summary(path, &mut summary)?,
+        AiCliTool::CodexCli => codex_summary(path, &mut summary)?,
+        AiCliTool::Aider => aider_summary(path, &mut summary)?,
+    }
+    summary.title = summary.summaries.first().or(summary.first_prompt.as_ref()).map(|t| short_title(t)).unwrap_or_default();
+    summary.last_active = summary.last_active.or_else(|| modified_ms(path));
+    Ok(summary)
+}
+
+// ── Pruning ──
+
+/// Delete the given session transcripts (and Claude Code's per-session folders).
+/// Paths outside the tools' session folders are refused.
+pub fn prune_sessions(paths: &[PathBuf]) -> BatchResult {
+    let protected = crate::config::load_config().protected_conversations;
+    let mut failed_paths = Vec::new();
+    let items = paths
+        .iter()
+        .map(|path| {
+            let id = path.display().to_string();
+            let Some(tool) = session_tool(path).filter(|_| path.is_file()) else {
+                return if path.exists() {
+                    ItemResult::skipped(id, "Not an AI CLI session file")
+                } else {
+                    ItemResult::not_found(id)
+                };
+            };
+            if crate::conversation::protected_conversation_under(&protected, path).is_some() {
+                return ItemResult::skipped(id, "Protected conversation");
+            }
+            let mut freed = 0;
+            let mut errors = Vec::new();
+            if let Some(dir) = companion_dir(tool, path) {
+                let (dir_freed, _, dir_errors) = crate::cleaner::clean_directory(&dir);
+                freed += dir_freed;
+                if let Err(e) = crate::cleaner::remove_tree(&dir) {
+                    if dir.exists() {
+                        errors.extend(dir_errors);
+                        errors.push(format!("{}: {}", dir.display(), e));
+                        failed_paths.push(dir);
+                    }
+                }
+            }
+            let size = fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+            match crate::cleaner::remove_file(path) {
+                Ok(()) => freed += size,
+                Err(e) => {
+                    errors.push(format!("{}: {}", path.display(), e));
+                    failed_paths.push(path.clone());
+                }
+            }
+            // Drop the project folder once its last session is gone (fails harmlessly otherwise);
+            // aider's history lives in the user's own project folder, which stays
+            if let Some(parent) = path.parent().filter(|_| tool != AiCliTool::Aider) {
+                let _ = fs::remove_dir(parent);
+            }
+            ItemResult::from_errors(id, freed, errors)
+        })
+        .collect();
+    let mut result = BatchResult::from_items(items);
+    result.failed_paths = failed_paths;
+    result
+}
@@ -14,6 +14,14 @@ pub struct BackupInfo {
     pub file_path: PathBuf,
     pub size: u64,
     pub file_count: u64,
+    /// Registry keys exported into the zip (one .reg file each, under `registry/`).
+    #[serde(default)]
+    pub registry_keys: Vec<String>,
+    #[serde(default)]
+    pub registry_files: Vec<String>,
+    /// Credential Manager targets saved into the zip's credentials.json.
+    #[serde(default)]
+    pub credential_targets: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -59,9 +67,83 @@ fn save_manifest(list: &[BackupInfo]) {
 }
 
 pub fn create_backup(ide: &crate::ide_detector::IdeInfo, paths: &[PathBuf]) -> Result<BackupInfo, String> {
+    create_backup_with_extras(&ide.id, &ide.name, paths, &[], &[])
+}
+
+/// Export a registry key to .reg text using `reg export`.
+#[cfg(target_os = "windows")]
+fn export_registry_key(key: &str) -> Result<Vec<u8>, String> {
+    let tmp = std::env::temp_dir().join(format!(
+        "idecleaner_reg_{}_{}.reg",
+        std::process::id(),
+        chrono::Local::now().format("%H%M%S%f")
+    ));
+    let output = crate::process::hidden_command("reg")
+        .args(["export", key, &tmp.to_string_lossy(), "/y"])
+        .output()
+        .map_err(|e| format!("Failed to run reg export: {}", e))?;
+    if !output.status.success() {
+        let _ = fs::remove_file(&tmp);
+        return Err(format!("reg export failed for {}", key));
+    }
+    let data = fs::read(&tmp).map_err(|e| format!("Failed to read exported key: {}", e));
+    let _ = fs::remove_file(&tmp);
+    data
+}
+
+#[cfg(not(target_os = "windows"))]
+fn export_registry_key(key: &str) -> Result<Vec<u8>, String> {
+    Err(format!("Registry export is only supported on Windows ({})", key))
+}
+
+/// Import exported .reg text back with `reg import`.
+#[cfg(target_os = "windows")]
+fn import_registry_file(data: &[u8]) -> Result<(), String> {
+    let tmp = std::env::temp_dir().join(format!(
+        "idecleaner_reg_{}_{}.reg",
+        std::process::id(),
+        chrono::Local::now().format("%H%M%S%f")
+    ));
+    fs::write(&tmp, data).map_err(|e| format!("Failed to write registry file: {}", e))?;
+    let output = crate::process::hidden_command("reg")
+        .args(["import", &tmp.to_string_lossy()])
+        .output();
+    let _ = fs::remove_file(&tmp);
+    let output = output.map_err(|e| format!("Failed to run reg import: {}", e))?;
+    if !output.status.success() {
+        return Err(format!("reg import failed: {}", String::from_utf8_lossy(&output.stderr).trim()));
+    }
+    Ok(())
+}
+
+#[cfg(not(target_os = "windows"))]
+fn import_registry_file(_data: &[u8]) -> Result<(), String> {
+    Err("Registry import is only supported on Windows".into())
+}
+
+/// Turn a registry key into a file name usable inside the zip.
+fn registry_file_name(index: usize, key: &str) -> String {
+    let sanitized: String = key
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '.' || c == '-' { c } else { '_' })
+        .collect();
+    format!("registry/{:02}_{}.reg", index, sanitized)
+}
+
+const CREDENTIALS_FILE: &str = "credentials.json";
+
+/// Back up files, registry keys and credentials into one zip. Fails if any
+/// registry key can't be exported, so callers never delete a key that wasn't saved.
+pub fn create_backup_with_extras(
+    ide_id: &str,
+    ide_name: &str,
+    paths: &[PathBuf],
+    registry_keys: &[String],
+    credentials: &[crate::credentials::SavedCredential],
+) -> Result<BackupInfo, String> {
     let backup_dir = get_backup_dir();
     let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S").to_string();
-    let backup_id = format!("{}_{}", ide.id, timestamp);
+    let backup_id = format!("{}_{}", ide_id, timestamp);
     let zip_path = backup_dir.join(format!("{}.zip", backup_id));
 
     let file = fs::File::create(&zip_path).map_err(|e| format!("Failed to create backup file: {}", e))?;
@@ -81,11 +163,13 @@ pub fn create_backup(ide: &crate::ide_detector::IdeInfo, paths: &[PathBuf]) -> R
                     .path()
                     .strip_prefix(base_path)
                     .unwrap_or(entry.path());
-                let archive_name = format!(
-                    "{}/{}",
-                    base_path.file_name().unwrap_or_default().to_string_lossy(),
-                    rel.to_string_lossy().replace('\\', "/")
-                );
+                let base_name = base_path.file_name().unwrap_or_default().to_string_lossy();
+                // Categories can list single files (crash logs), which have no relative part
+                let archive_name = if rel.as_os_str().is_empty() {
+                    base_name.to_string()
+                } else {
+                    format!("{}/{}", base_name, rel.to_string_lossy().replace('\\', "/"))
+                };
 
                 if let Ok(mut src) = fs::File::open(entry.path()) {
                     if zip.start_file(&archive_name, options).is_ok() {
@@ -110,18 +194,46 @@ pub fn create_backup(ide: &crate::ide_detector::IdeInfo, paths: &[PathBuf]) -> R
         }
     }
 
+    let mut registry_files = Vec::new();
+    for (i, key) in registry_keys.iter().enumerate() {
+        let data = match export_registry_key(key) {
+            Ok(d) => d,
+            Err(e) => {
+                drop(zip);
+                let _ = fs::remove_file(&zip_path);
+                return Err(e);
+            }
+        };
+        let name = registry_file_name(i, key);
+        zip.start_file(name.as_str(), options)
+            .and_then(|_| zip.write_all(&data).map_err(|e| e.into()))
+            .map_err(|e| format!("Failed to add {} to backup: {}", key, e))?;
+        registry_files.push(name);
+    }
+
+    if !credentials.is_empty() {
+        let data = serde_json::to_vec_pretty(credentials)
+            .map_err(|e| format!("Failed to serialize credentials: {}", e))?;
+        zip.start_file(CREDENTIALS_FILE, options)
+            .and_then(|_| zip.write_all(&data).map_err(|e| e.into()))
+            .map_err(|e| format!("Failed to add credentials to backup: {}", e))?;
+    }
+
     zip.finish().map_err(|e| format!("Failed to finalize zip: {}", e))?;
 
     let zip_size = fs::metadata(&zip_path).map(|m| m.len()).unwrap_or(0);
 
     let info = BackupInfo {
         id: backup_id,
-        ide_id: ide.id.clone(),
-        ide_name: ide.name.clone(),
+        ide_id: ide_id.to_string(),
+        ide_name: ide_name.to_string(),
         timestamp,
         file_path: zip_path,
         size: zip_size,
         file_count,
+        registry_keys: registry_keys.to_vec(),
+        registry_files,
+        credential_targets: credentials.iter().map(|c| c.target_name.clone()).collect(),
     };
 
     let mut manifest = load_manifest();
@@ -143,7 +255,7 @@ pub fn clear_all_backups() -> Result<u64, String> {
             if let Ok(meta) = fs::metadata(&info.file_path) {
                 freed += meta.len();
             }
-            let _ = fs::remove_file(&info.file_path);
+            let _ = crate::cleaner::remove_file(&info.file_path);
         }
     }
     save_manifest(&[]);
@@ -166,7 +278,7 @@ pub fn delete_backup(backup_id: &str) -> Result<(), String> {
     if let Some(idx) = pos {
         let info = manifest.remove(idx);
         if info.file_path.exists() {
-            fs::remove_file(&info.file_path)
+            crate::cleaner::remove_file(&info.file_path)
                 .map_err(|e| format!("Failed to delete backup file: {}", e))?;
         }
         save_manifest(&manifest);
@@ -175,3 +287,71 @@ pub fn delete_backup(backup_id: &str) -> Result<(), String> {
         Err(format!("Backup '{}' not found", backup_id))
     }
 }
+
+/// Write the credentials saved in a backup back into Credential Manager.
+/// Returns the restored target names.
+pub fn restore_credentials(backup_id: &str) -> Result<Vec<String>, String> {
+    let manifest = load_manifest();
+    let info = manifest
+        .iter()
+        .find(|b| b.id == backup_id)
+        .ok_or_else(|| format!("Backup '{}' not found", backup_id))?;
+    if info.credential_targets.is_empty() {
+        return Err("Backup contains no credentials".into());
+    }
+
+    let file = fs::File::open(&info.file_path).map_err(|e| format!("Failed to open backup: {}", e))?;
+    let mut archive = zip::ZipArchive::new(file).map_err(|e| format!("Failed to read backup: {}", e))?;
+    let mut data = String::new();
+    archive
+        .by_name(CREDENTIALS_FILE)
+        .map_err(|e| format!("Failed to read {}: {}", CREDENTIALS_FILE, e))
+        .and_then(|mut f| std::io::Read::read_to_string(&mut f, &mut data).map_err(|e| e.to_string()))?;
+    let saved: Vec<crate::credentials::SavedCredential> =
+        serde_json::from_str(&data).map_err(|e| format!("Invalid {}: {}", CREDENTIALS_FILE, e))?;
+
+    let mut restored = Vec::new();
+    let mut errors = Vec::new();
+    for cred in &saved {
+        match crate::credentials::write_credential(cred) {
+            Ok(()) => restored.push(cred.target_name.clone()),
+            Err(e) => errors.push(e),
+        }
+    }
+    if restored.is_empty() && !errors.is_empty() {
+        return Err(errors.join("; "));
+    }
+    Ok(restored)
+}
+
+/// Import the registry keys saved in a backup. Returns the restored key names.
+pub fn restore_registry(backup_id: &str) -> Result<Vec<String>, String> {
+    let manifest = load_manifest();
+    let info = manifest
+        .iter()
+        .find(|b| b.id == backup_id)
+        .ok_or_else(|| format!("Backup '{}' not found", backup_id))?;
+    if info.registry_files.is_empty() {
+        return Err("Backup contains no registry keys".into());
+    }
+
+    let file = fs::File::open(&info.file_path).map_err(|e| format!("Failed to open backup: {}", e))?;
+    let mut archive = zip::ZipArchive::new(file).map_err(|e| format!("Failed to read backup: {}", e))?;
+    let mut restored = Vec::new();
+    let mut errors = Vec::new();
+    for (key, name) in info.registry_keys.iter().zip(&info.registry_files) {
+        let mut data = Vec::new();
+        let read = archive
+            .by_name(name)
+            .map_err(|e| format!("Failed to read {}: {}", name, e))
+            .and_then(|mut f| std::io::Read::read_to_end(&mut f, &mut data).map_err(|e| e.to_string()));
+        match read.and_then(|_| import_registry_file(&data)) {
+            Ok(()) => restored.push(key.clone()),
+            Err(e) => errors.push(format!("{}: {}", key, e)),
+        }
+    }
+    if restored.is_empty() && !errors.is_empty() {
+        return Err(errors.join("; "));
+    }
+    Ok(restored)
+}
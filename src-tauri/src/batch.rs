@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Outcome of one item of a batch command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ItemStatus {
+    Done,
+    /// Already gone; nothing to do, and nothing to retry.
+    NotFound,
+    Failed,
+    /// Left alone on purpose (a protected conversation, a path the command
+    /// doesn't clean); nothing to retry.
+    Skipped,
+}
+
+/// Per-item result, so the UI can mark exactly which rows failed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemResult {
+    /// The id the caller sent for this item.
+    pub id: String,
+    pub status: ItemStatus,
+    pub bytes_freed: u64,
+    pub error: Option<String>,
+}
+
+impl ItemResult {
+    pub fn done(id: impl Into<String>, bytes_freed: u64) -> ItemResult {
+        ItemResult { id: id.into(), status: ItemStatus::Done, bytes_freed, error: None }
+    }
+
+    pub fn not_found(id: impl Into<String>) -> ItemResult {
+        ItemResult { id: id.into(), status: ItemStatus::NotFound, bytes_freed: 0, error: None }
+    }
+
+    pub fn failed(id: impl Into<String>, error: impl Into<String>) -> ItemResult {
+        ItemResult { id: id.into(), status: ItemStatus::Failed, bytes_freed: 0, error: Some(error.into()) }
+    }
+
+    pub fn skipped(id: impl Into<String>, reason: impl Into<String>) -> ItemResult {
+        ItemResult { id: id.into(), status: ItemStatus::Skipped, bytes_freed: 0, error: Some(reason.into()) }
+    }
+
+    /// Done when nothing went wrong, else failed with every error; what was
+    /// freed before the failure still counts.
+    pub fn from_errors(id: impl Into<String>, bytes_freed: u64, errors: Vec<String>) -> ItemResult {
+        if errors.is_empty() {
+            ItemResult::done(id, bytes_freed)
+        } else {
+            ItemResult { bytes_freed, ..ItemResult::failed(id, errors.join("; ")) }
+        }
+    }
+}
+
+/// Result of a batch command: one entry per requested item, in request order,
+/// plus the totals the UI shows in its summary.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchResult {
+    pub items: Vec<ItemResult>,
+    pub freed_bytes: u64,
+    pub succeeded: usize,
+    pub failed: usize,
+    /// Set when failed items were recorded for `retry::retry_failed`.
+    #[serde(default)]
+    pub operation_id: Option<String>,
+    /// What failed items left on disk, for `retry::record`. Empty for batches
+    /// that aren't of paths.
+    #[serde(default)]
+    pub failed_paths: Vec<PathBuf>,
+}
+
+impl BatchResult {
+    pub fn from_items(items: Vec<ItemResult>) -> BatchResult {
+        BatchResult {
+            freed_bytes: items.iter().map(|i| i.bytes_freed).sum(),
+            succeeded: items.iter().filter(|i| i.status == ItemStatus::Done).count(),
+            failed: items.iter().filter(|i| i.status == ItemStatus::Failed).count(),
+            items,
+            operation_id: None,
+            failed_paths: Vec::new(),
+        }
+    }
+}
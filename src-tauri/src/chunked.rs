@@ -0,0 +1,103 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+/// Results kept waiting for the frontend; the oldest is dropped past this.
+const MAX_PENDING: usize = 8;
+
+/// Handle returned instead of a large result. The result arrives as `shell`
+/// with its item list emptied; the frontend pulls the items with `fetch_chunk`
+/// and can render each chunk as it lands.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChunkedHandle {
+    pub handle: String,
+    pub shell: serde_json::Value,
+    /// Field of `shell` the items go into; `None` when the result is the list itself.
+    pub items_field: Option<String>,
+    pub item_count: usize,
+    pub chunk_count: usize,
+}
+
+struct PendingResult {
+    seq: u64,
+    chunks: Vec<Vec<serde_json::Value>>,
+    fetched: Vec<bool>,
+}
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+static PENDING: Mutex<Option<HashMap<String, PendingResult>>> = Mutex::new(None);
+
+/// Keep a result until every chunk has been fetched or it's released. The
+/// list in `items_field` (or the result itself) is handed out
+/// `items_per_chunk` items at a time; everything else goes in the handle.
+pub fn store<T: Serialize>(value: &T, items_field: Option<&str>, items_per_chunk: usize) -> Result<ChunkedHandle, String> {
+    let mut shell = serde_json::to_value(value).map_err(|e| format!("Failed to serialize result: {}", e))?;
+    let list = match items_field {
+        Some(field) => shell.get_mut(field),
+        None => Some(&mut shell),
+    };
+    let items = match list {
+        Some(serde_json::Value::Array(items)) => std::mem::take(items),
+        _ => return Err(format!("Result has no list to split at {:?}", items_field)),
+    };
+    let item_count = items.len();
+    let mut chunks: Vec<Vec<serde_json::Value>> = Vec::new();
+    let mut items = items.into_iter().peekable();
+    while items.peek().is_some() {
+        chunks.push(items.by_ref().take(items_per_chunk.max(1)).collect());
+    }
+
+    let seq = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    let handle = format!("chunked_{}", seq);
+    let result = ChunkedHandle {
+        handle: handle.clone(),
+        shell,
+        items_field: items_field.map(str::to_string),
+        item_count,
+        chunk_count: chunks.len(),
+    };
+    if chunks.is_empty() {
+        return Ok(result);
+    }
+
+    let mut guard = PENDING.lock().unwrap_or_else(|e| e.into_inner());
+    let pending = guard.get_or_insert_with(HashMap::new);
+    while pending.len() >= MAX_PENDING {
+        let oldest = pending.iter().min_by_key(|(_, p)| p.seq).map(|(k, _)| k.clone());
+        match oldest {
+            Some(k) => pending.remove(&k),
+            None => break,
+        };
+    }
+    let fetched = vec![false; chunks.len()];
+    pending.insert(handle, PendingResult { seq, chunks, fetched });
+    Ok(result)
+}
+
+/// One chunk of a stored result. The result is freed once all its chunks were fetched.
+pub fn fetch_chunk(handle: &str, index: usize) -> Result<Vec<serde_json::Value>, String> {
+    let mut guard = PENDING.lock().unwrap_or_else(|e| e.into_inner());
+    let pending = guard.get_or_insert_with(HashMap::new);
+    let entry = pending
+        .get_mut(handle)
+        .ok_or_else(|| format!("Result '{}' not found or expired", handle))?;
+    let chunk = entry
+        .chunks
+        .get(index)
+        .cloned()
+        .ok_or_else(|| format!("Chunk {} out of range for '{}'", index, handle))?;
+    entry.fetched[index] = true;
+    if entry.fetched.iter().all(|f| *f) {
+        pending.remove(handle);
+    }
+    Ok(chunk)
+}
+
+/// Drop a stored result the frontend no longer needs (e.g. an abandoned scan).
+pub fn release(handle: &str) {
+    let mut guard = PENDING.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(pending) = guard.as_mut() {
+        pending.remove(handle);
+    }
+}
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
 use walkdir::WalkDir;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,6 +24,38 @@ pub struct CleanResult {
     pub freed_bytes: u64,
     pub deleted_files: u64,
     pub errors: Vec<String>,
+    /// Files that couldn't be removed.
+    #[serde(default)]
+    pub failed_paths: Vec<PathBuf>,
+    /// Set when `failed_paths` were recorded for `retry::retry_failed`.
+    #[serde(default)]
+    pub operation_id: Option<String>,
+}
+
+pub const FAMILY_CLEAN_PROGRESS_EVENT: &str = "family-clean-progress";
+
+/// Sent before each IDE of a family clean starts, and once more when all are done.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FamilyCleanProgress {
+    /// The IDE being cleaned now; None once the family is finished.
+    pub ide_id: Option<String>,
+    pub ide_name: Option<String>,
+    /// IDEs finished so far.
+    pub done: usize,
+    pub total: usize,
+    /// Freed across the finished IDEs.
+    pub freed_bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FamilyCleanResult {
+    pub ide_type: crate::ide_detector::IdeType,
+    pub results: Vec<CleanResult>,
+    pub freed_bytes: u64,
+    pub deleted_files: u64,
+    pub errors: Vec<String>,
+    #[serde(default)]
+    pub operation_id: Option<String>,
 }
 
 /// Protected file names that should never be deleted regardless of user selection.
@@ -48,8 +81,192 @@ fn is_protected(path: &Path) -> bool {
     false
 }
 
+// ── Do-not-touch markers ──
+
+/// A file with this name protects the folder it's in, and everything below it,
+/// from every cleaner regardless of what the UI selected.
+pub const IGNORE_MARKER: &str = ".devcleaner-ignore";
+
+pub fn has_ignore_marker(dir: &Path) -> bool {
+    dir.join(IGNORE_MARKER).is_file()
+}
+
+/// `path` (if a folder) or one of its ancestors carries the marker.
+pub fn is_marked(path: &Path) -> bool {
+    let start = if path.is_dir() { Some(path) } else { path.parent() };
+    start.map(|p| p.ancestors().any(has_ignore_marker)).unwrap_or(false)
+}
+
+/// Marked folders inside `dir`, outermost only.
+pub fn marked_dirs_under(dir: &Path) -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    let mut walker = WalkDir::new(dir).into_iter();
+    while let Some(entry) = walker.next() {
+        let Ok(entry) = entry else { continue };
+        if entry.file_type().is_dir() && has_ignore_marker(entry.path()) {
+            found.push(entry.into_path());
+            walker.skip_current_dir();
+        }
+    }
+    found
+}
+
+fn marker_error(path: &Path) -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::PermissionDenied,
+        format!("{} is protected by {}", path.display(), IGNORE_MARKER),
+    )
+}
+
+/// Walk `dir` without descending into marked folders.
+fn unmarked_entries(dir: &Path) -> impl Iterator<Item = walkdir::DirEntry> {
+    WalkDir::new(dir)
+        .into_iter()
+        .filter_entry(|e| !(e.file_type().is_dir() && has_ignore_marker(e.path())))
+        .filter_map(|e| e.ok())
+}
+
+/// `fs::remove_dir_all` that refuses when the folder is marked, sits in a marked
+/// folder or contains one.
+pub fn remove_tree(path: &Path) -> std::io::Result<()> {
+    if is_marked(path) || !marked_dirs_under(path).is_empty() {
+        return Err(marker_error(path));
+    }
+    if secure_delete_enabled() {
+        for entry in WalkDir::new(path).into_iter().filter_map(|e| e.ok()) {
+            if entry.file_type().is_file() && is_wipe_target(entry.path()) {
+                overwrite(entry.path());
+            }
+        }
+    }
+    fs::remove_dir_all(path)
+}
+
+/// `fs::remove_file` that refuses inside a marked folder. A SQLite database
+/// takes its `-wal`/`-shm`/`-journal` files along, which would otherwise be
+/// left behind as strays.
+pub fn remove_file(path: &Path) -> std::io::Result<()> {
+    if is_marked(path) {
+        return Err(marker_error(path));
+    }
+    let secure = secure_delete_enabled();
+    let is_db = crate::db_pool::is_sqlite_file(path);
+    unlink(path, secure)?;
+    if is_db {
+        for suffix in crate::db_pool::COMPANION_SUFFIXES {
+            let companion = crate::db_pool::companion_path(path, suffix);
+            if companion.is_file() {
+                let _ = unlink(&companion, secure);
+            }
+        }
+    }
+    Ok(())
+}
+
+// ── Secure deletion ──
+
+const WIPE_CHUNK: usize = 1 << 20;
+
+/// What secure mode overwrites: conversation files (Windsurf `.pb`, chat and
+/// CLI transcripts), logs and exports. Caches and build output are only unlinked.
+const WIPE_EXTENSIONS: &[&str] = &["pb", "json", "jsonl", "log", "md", "txt", "xml", "html"];
+
+pub fn secure_delete_enabled() -> bool {
+    crate::config::secure_delete_enabled()
+}
+
+fn is_wipe_target(path: &Path) -> bool {
+    path.extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .is_some_and(|e| WIPE_EXTENSIONS.contains(&e.as_str()))
+}
+
+/// Overwrite a file's contents with zeros and flush them to disk, clearing a
+/// read-only bit first. Symlinks (whose target isn't being deleted) and files
+/// that may have other hard links (whose contents live on under another name)
+/// are left as they are. Best effort: a file that can't be overwritten is still
+/// unlinked by the caller.
+fn overwrite(path: &Path) {
+    use std::io::Write;
+    let Ok(meta) = fs::symlink_metadata(path) else { return };
+    if !meta.file_type().is_file() || may_have_other_links(path, &meta) {
+        return;
+    }
+    let mut perms = meta.permissions();
+    if perms.readonly() {
+        // The file is unlinked right after, so widening its mode doesn't matter
+        #[allow(clippy::permissions_set_readonly_false)]
+        perms.set_readonly(false);
+        let _ = fs::set_permissions(path, perms);
+    }
+    let Ok(mut file) = fs::OpenOptions::new().write(true).open(path) else { return };
+    let zeros = vec![0u8; WIPE_CHUNK];
+    let mut left = meta.len();
+    while left > 0 {
+        let n = left.min(WIPE_CHUNK as u64) as usize;
+        if file.write_all(&zeros[..n]).is_err() {
+            return;
+        }
+        left -= n as u64;
+    }
+    let _ = file.sync_all();
+}
+
+#[cfg(unix)]
+fn may_have_other_links(_path: &Path, meta: &fs::Metadata) -> bool {
+    use std::os::unix::fs::MetadataExt;
+    meta.nlink() > 1
+}
+
+/// Hard link count from `GetFileInformationByHandle`; unknown counts as linked.
+#[cfg(target_os = "windows")]
+fn may_have_other_links(path: &Path, _meta: &fs::Metadata) -> bool {
+    use std::os::windows::io::AsRawHandle;
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct ByHandleFileInformation {
+        file_attributes: u32,
+        creation_time: [u32; 2],
+        last_access_time: [u32; 2],
+        last_write_time: [u32; 2],
+        volume_serial_number: u32,
+        file_size_high: u32,
+        file_size_low: u32,
+        number_of_links: u32,
+        file_index_high: u32,
+        file_index_low: u32,
+    }
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetFileInformationByHandle(file: *mut std::ffi::c_void, info: *mut ByHandleFileInformation) -> i32;
+    }
+
+    let Ok(file) = fs::File::open(path) else { return true };
+    let mut info = ByHandleFileInformation::default();
+    if unsafe { GetFileInformationByHandle(file.as_raw_handle() as *mut std::ffi::c_void, &mut info) } == 0 {
+        return true;
+    }
+    info.number_of_links > 1
+}
+
+#[cfg(not(any(unix, target_os = "windows")))]
+fn may_have_other_links(_path: &Path, _meta: &fs::Metadata) -> bool {
+    true
+}
+
+/// Remove a file, overwriting it first when `secure` and it's a wipe target.
+fn unlink(path: &Path, secure: bool) -> std::io::Result<()> {
+    if secure && is_wipe_target(path) {
+        overwrite(path);
+    }
+    fs::remove_file(path)
+}
+
 /// Clean directory contents, skipping protected user files.
 pub fn clean_directory_safe(dir: &Path) -> (u64, u64, Vec<String>) {
+    crate::db_pool::close_under(dir);
     let mut freed: u64 = 0;
     let mut deleted: u64 = 0;
     let mut errors = Vec::new();
@@ -57,26 +274,26 @@ pub fn clean_directory_safe(dir: &Path) -> (u64, u64, Vec<String>) {
     if !dir.exists() {
         return (0, 0, vec![]);
     }
+    if is_marked(dir) {
+        return (0, 0, vec![marker_error(dir).to_string()]);
+    }
 
-    let files: Vec<_> = WalkDir::new(dir)
-        .into_iter()
-        .filter_map(|e| e.ok())
+    let files: Vec<_> = unmarked_entries(dir)
         .filter(|e| e.file_type().is_file())
         .collect();
+    let secure = secure_delete_enabled();
 
     for entry in &files {
         if is_protected(entry.path()) { continue; }
         let size = entry.metadata().map(|m| m.len()).unwrap_or(0);
-        match fs::remove_file(entry.path()) {
+        match unlink(entry.path(), secure) {
             Ok(()) => { freed += size; deleted += 1; }
             Err(e) => errors.push(format!("{}: {}", entry.path().display(), e)),
         }
     }
 
     // Remove empty directories (bottom-up)
-    let mut dirs: Vec<_> = WalkDir::new(dir)
-        .into_iter()
-        .filter_map(|e| e.ok())
+    let mut dirs: Vec<_> = unmarked_entries(dir)
         .filter(|e| e.file_type().is_dir())
         .map(|e| e.into_path())
         .collect();
@@ -89,6 +306,7 @@ pub fn clean_directory_safe(dir: &Path) -> (u64, u64, Vec<String>) {
 }
 
 pub fn clean_directory(dir: &Path) -> (u64, u64, Vec<String>) {
+    crate::db_pool::close_under(dir);
     let mut freed: u64 = 0;
     let mut deleted: u64 = 0;
     let mut errors = Vec::new();
@@ -96,17 +314,19 @@ pub fn clean_directory(dir: &Path) -> (u64, u64, Vec<String>) {
     if !dir.exists() {
         return (0, 0, vec![]);
     }
+    if is_marked(dir) {
+        return (0, 0, vec![marker_error(dir).to_string()]);
+    }
 
     // Collect files first, then delete (avoid iterator invalidation)
-    let files: Vec<_> = WalkDir::new(dir)
-        .into_iter()
-        .filter_map(|e| e.ok())
+    let files: Vec<_> = unmarked_entries(dir)
         .filter(|e| e.file_type().is_file())
         .collect();
+    let secure = secure_delete_enabled();
 
     for entry in &files {
         let size = entry.metadata().map(|m| m.len()).unwrap_or(0);
-        match fs::remove_file(entry.path()) {
+        match unlink(entry.path(), secure) {
             Ok(()) => {
                 freed += size;
                 deleted += 1;
@@ -118,9 +338,7 @@ pub fn clean_directory(dir: &Path) -> (u64, u64, Vec<String>) {
     }
 
     // Try to remove empty directories (bottom-up)
-    let mut dirs: Vec<_> = WalkDir::new(dir)
-        .into_iter()
-        .filter_map(|e| e.ok())
+    let mut dirs: Vec<_> = unmarked_entries(dir)
         .filter(|e| e.file_type().is_dir())
         .map(|e| e.into_path())
         .collect();
@@ -135,16 +353,98 @@ pub fn clean_directory(dir: &Path) -> (u64, u64, Vec<String>) {
     (freed, deleted, errors)
 }
 
+/// Delete files not modified in the last `older_than_days` days, keeping recent ones.
+pub fn clean_directory_older_than(dir: &Path, older_than_days: u32) -> (u64, u64, Vec<String>) {
+    crate::db_pool::close_under(dir);
+    let mut freed: u64 = 0;
+    let mut deleted: u64 = 0;
+    let mut errors = Vec::new();
+
+    let cutoff = match SystemTime::now().checked_sub(Duration::from_secs(older_than_days as u64 * 86_400)) {
+        Some(c) => c,
+        None => return (0, 0, vec![]),
+    };
+    if is_marked(dir) {
+        return (0, 0, vec![marker_error(dir).to_string()]);
+    }
+
+    let files: Vec<_> = unmarked_entries(dir)
+        .filter(|e| e.file_type().is_file())
+        .collect();
+    let secure = secure_delete_enabled();
+
+    for entry in &files {
+        let meta = match entry.metadata() {
+            Ok(m) => m,
+            Err(_) => continue,
+        };
+        if meta.modified().map(|m| m >= cutoff).unwrap_or(true) { continue; }
+        match unlink(entry.path(), secure) {
+            Ok(()) => { freed += meta.len(); deleted += 1; }
+            Err(e) => errors.push(format!("{}: {}", entry.path().display(), e)),
+        }
+    }
+
+    (freed, deleted, errors)
+}
+
+/// Age-based purge of one category (e.g. JetBrains "Local History"): only files
+/// older than `older_than_days` are removed.
+pub fn purge_category_older_than(
+    ide: &crate::ide_detector::IdeInfo,
+    category: &str,
+    older_than_days: u32,
+) -> CleanResult {
+    let mut result = CleanResult {
+        ide_id: ide.id.clone(),
+        freed_bytes: 0,
+        deleted_files: 0,
+        errors: Vec::new(),
+        failed_paths: Vec::new(),
+        operation_id: None,
+    };
+
+    let protected = crate::config::load_config().protected_conversations;
+    let scan = crate::scanner::scan_ide(ide);
+    for cat in scan.categories.iter().filter(|c| c.name == category) {
+        for path in &cat.paths {
+            if let Some(conv) = crate::conversation::protected_conversation_under(&protected, path) {
+                result.errors.push(format!("{}: holds protected conversation {}, skipped", path.display(), conv.id));
+                continue;
+            }
+            let (freed, deleted, errors) = clean_directory_older_than(path, older_than_days);
+            result.freed_bytes += freed;
+            result.deleted_files += deleted;
+            if !errors.is_empty() {
+                result.failed_paths.extend(remaining_files_older_than(path, older_than_days));
+            }
+            result.errors.extend(errors);
+        }
+    }
+
+    result
+}
+
 pub fn clean_ide(
     ide: &crate::ide_detector::IdeInfo,
     categories: &[String],
     _mode: &CleanMode,
+) -> CleanResult {
+    clean_scanned(ide, &crate::scanner::scan_ide(ide), categories)
+}
+
+/// `clean_ide` over a scan of `ide` the caller already has.
+pub fn clean_scanned(
+    ide: &crate::ide_detector::IdeInfo,
+    scan: &crate::scanner::IdeScanResult,
+    categories: &[String],
 ) -> CleanResult {
     let mut total_freed: u64 = 0;
     let mut total_deleted: u64 = 0;
     let mut all_errors = Vec::new();
+    let mut failed_paths = Vec::new();
 
-    let scan = crate::scanner::scan_ide(ide);
+    let protected = crate::config::load_config().protected_conversations;
 
     for cat in &scan.categories {
         if !categories.contains(&cat.name) {
@@ -152,11 +452,20 @@ pub fn clean_ide(
         }
 
         for path in &cat.paths {
+            // A category path holding a protected conversation (e.g. a workspace storage folder
+            // with its chat database) is left alone entirely.
+            if let Some(conv) = crate::conversation::protected_conversation_under(&protected, path) {
+                all_errors.push(format!("{}: holds protected conversation {}, skipped", path.display(), conv.id));
+                continue;
+            }
             // Trust the user's explicit selection — mode only controls auto-selection in the frontend.
             // Protected files (settings.json, keybindings.json, snippets) are still preserved.
             let (freed, deleted, errors) = clean_directory_safe(path);
             total_freed += freed;
             total_deleted += deleted;
+            if !errors.is_empty() {
+                failed_paths.extend(remaining_files(path));
+            }
             all_errors.extend(errors);
         }
     }
@@ -166,5 +475,67 @@ pub fn clean_ide(
         freed_bytes: total_freed,
         deleted_files: total_deleted,
         errors: all_errors,
+        failed_paths,
+        operation_id: None,
+    }
+}
+
+/// Unprotected files still under `dir` after a clean, i.e. the ones that failed.
+pub(crate) fn remaining_files(dir: &Path) -> Vec<PathBuf> {
+    unmarked_entries(dir)
+        .filter(|e| e.file_type().is_file() && !is_protected(e.path()))
+        .map(|e| e.into_path())
+        .collect()
+}
+
+/// The files `clean_directory_older_than` should have removed but couldn't;
+/// recent files it was told to keep are not among them.
+fn remaining_files_older_than(dir: &Path, older_than_days: u32) -> Vec<PathBuf> {
+    let Some(cutoff) = SystemTime::now().checked_sub(Duration::from_secs(older_than_days as u64 * 86_400)) else {
+        return Vec::new();
+    };
+    remaining_files(dir)
+        .into_iter()
+        .filter(|p| fs::metadata(p).and_then(|m| m.modified()).map(|m| m < cutoff).unwrap_or(false))
+        .collect()
+}
+
+/// Clean the given categories in every installed IDE of one family.
+/// Categories an IDE doesn't have are simply skipped for that IDE.
+pub fn clean_family(
+    ides: &[crate::ide_detector::IdeInfo],
+    ide_type: &crate::ide_detector::IdeType,
+    categories: &[String],
+    mode: &CleanMode,
+    on_progress: impl Fn(&FamilyCleanProgress),
+) -> FamilyCleanResult {
+    let family: Vec<&crate::ide_detector::IdeInfo> =
+        ides.iter().filter(|ide| ide.installed && &ide.ide_type == ide_type).collect();
+    let mut results: Vec<CleanResult> = Vec::with_capacity(family.len());
+    let mut freed_bytes = 0;
+    for ide in &family {
+        on_progress(&FamilyCleanProgress {
+            ide_id: Some(ide.id.clone()),
+            ide_name: Some(ide.name.clone()),
+            done: results.len(),
+            total: family.len(),
+            freed_bytes,
+        });
+        let result = clean_ide(ide, categories, mode);
+        freed_bytes += result.freed_bytes;
+        results.push(result);
+    }
+    on_progress(&FamilyCleanProgress { ide_id: None, ide_name: None, done: results.len(), total: family.len(), freed_bytes });
+
+    FamilyCleanResult {
+        ide_type: ide_type.clone(),
+        freed_bytes,
+        deleted_files: results.iter().map(|r| r.deleted_files).sum(),
+        errors: results
+            .iter()
+            .flat_map(|r| r.errors.iter().map(move |e| format!("[{}] {}", r.ide_id, e)))
+            .collect(),
+        results,
+        operation_id: None,
     }
 }
@@ -1,10 +1,40 @@
+use crate::ai_cli;
 use crate::backup;
+use crate::batch;
+use crate::chunked;
 use crate::cleaner;
+use crate::config;
 use crate::conversation;
+use crate::conversation_archive;
+use crate::conversation_export;
+use crate::conversation_notes;
+use crate::conversation_search;
+use crate::credentials;
+use crate::dev_caches;
+use crate::devcontainers;
+use crate::extensions;
+use crate::external_profile;
+use crate::footprint;
 use crate::ide_detector;
+use crate::local_models;
+use crate::maven_repo;
+use crate::migration;
+use crate::orphaned_storage;
+use crate::policy;
+use crate::program_snapshots;
+use crate::project_artifacts;
+use crate::retry;
+use crate::rules;
+use crate::scan_snapshot;
 use crate::scanner;
+use crate::secret_scan;
+use crate::ssh_remote;
+use crate::stale_versions;
+use crate::tray;
 use crate::uninstaller;
+use crate::wsl;
 use std::process::Command;
+use tauri::{AppHandle, Emitter};
 
 #[tauri::command]
 pub fn detect_ides() -> Vec<ide_detector::IdeInfo> {
@@ -30,11 +60,11 @@ pub fn check_ide_running(ide_id: String) -> Vec<String> {
     let process_names = ide_detector::get_process_names(&ide_id);
 
     let mut running = Vec::new();
-    if let Ok(output) = Command::new("tasklist").output() {
+    if let Ok(output) = crate::process::hidden_command("tasklist").output() {
         let list = String::from_utf8_lossy(&output.stdout).to_lowercase();
         for name in process_names {
             if list.contains(&name.to_lowercase()) {
-                running.push(name.to_string());
+                running.push(name);
             }
         }
     }
@@ -48,6 +78,7 @@ pub fn clean_ide(
     mode: cleaner::CleanMode,
     create_backup: bool,
 ) -> Result<cleaner::CleanResult, String> {
+    policy::ensure_destructive_allowed()?;
     let ides = ide_detector::detect_installed_ides();
     let ide = ides
         .iter()
@@ -55,20 +86,84 @@ pub fn clean_ide(
         .ok_or_else(|| format!("IDE '{}' not found or not installed", ide_id))?;
 
     if create_backup {
-        let scan = scanner::scan_ide(ide);
-        let paths_to_backup: Vec<_> = scan
-            .categories
-            .iter()
-            .filter(|c| categories.contains(&c.name))
-            .flat_map(|c| c.paths.clone())
-            .collect();
-
-        if !paths_to_backup.is_empty() {
-            backup::create_backup(ide, &paths_to_backup)?;
+        backup_categories(ide, &categories)?;
+    }
+
+    let mut result = cleaner::clean_ide(ide, &categories, &mode);
+    result.operation_id = retry::record(retry::OperationKind::Clean, vec![ide_id], retry::path_items(&result.failed_paths));
+    Ok(result)
+}
+
+#[tauri::command]
+pub fn purge_category_older_than(
+    ide_id: String,
+    category: String,
+    older_than_days: u32,
+    create_backup: bool,
+) -> Result<cleaner::CleanResult, String> {
+    policy::ensure_destructive_allowed()?;
+    let ides = ide_detector::detect_installed_ides();
+    let ide = ides
+        .iter()
+        .find(|i| i.id == ide_id && i.installed)
+        .ok_or_else(|| format!("IDE '{}' not found or not installed", ide_id))?;
+
+    if create_backup {
+        backup_categories(ide, std::slice::from_ref(&category))?;
+    }
+
+    let mut result = cleaner::purge_category_older_than(ide, &category, older_than_days);
+    result.operation_id = retry::record(retry::OperationKind::Clean, vec![ide_id], retry::path_items(&result.failed_paths));
+    Ok(result)
+}
+
+fn backup_categories(ide: &ide_detector::IdeInfo, categories: &[String]) -> Result<(), String> {
+    let scan = scanner::scan_ide(ide);
+    let paths_to_backup: Vec<_> = scan
+        .categories
+        .iter()
+        .filter(|c| categories.contains(&c.name))
+        .flat_map(|c| c.paths.clone())
+        .collect();
+
+    if !paths_to_backup.is_empty() {
+        backup::create_backup(ide, &paths_to_backup)?;
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn scan_family(ide_type: ide_detector::IdeType) -> scanner::ScanSummary {
+    let ides = ide_detector::detect_installed_ides();
+    scanner::scan_family(&ides, &ide_type)
+}
+
+#[tauri::command(async)]
+pub fn clean_family(
+    app: AppHandle,
+    ide_type: ide_detector::IdeType,
+    categories: Vec<String>,
+    mode: cleaner::CleanMode,
+    create_backup: bool,
+) -> Result<cleaner::FamilyCleanResult, String> {
+    policy::ensure_destructive_allowed()?;
+    let ides = ide_detector::detect_installed_ides();
+
+    // Back up every IDE first so a failed backup aborts before anything is deleted
+    if create_backup {
+        for ide in ides.iter().filter(|i| i.installed && i.ide_type == ide_type) {
+            backup_categories(ide, &categories)?;
         }
     }
 
-    Ok(cleaner::clean_ide(ide, &categories, &mode))
+    let mut result = cleaner::clean_family(&ides, &ide_type, &categories, &mode, |progress| {
+        let _ = app.emit(cleaner::FAMILY_CLEAN_PROGRESS_EVENT, progress);
+    });
+    let failed: Vec<&cleaner::CleanResult> = result.results.iter().filter(|r| !r.failed_paths.is_empty()).collect();
+    let paths: Vec<std::path::PathBuf> = failed.iter().flat_map(|r| r.failed_paths.iter().cloned()).collect();
+    let ide_ids = failed.iter().map(|r| r.ide_id.clone()).collect();
+    result.operation_id = retry::record(retry::OperationKind::Clean, ide_ids, retry::path_items(&paths));
+    Ok(result)
 }
 
 #[tauri::command]
@@ -78,6 +173,7 @@ pub fn list_backups() -> backup::BackupListResult {
 
 #[tauri::command]
 pub fn delete_backup(backup_id: String) -> Result<(), String> {
+    policy::ensure_destructive_allowed()?;
     backup::delete_backup(&backup_id)
 }
 
@@ -88,6 +184,7 @@ pub fn get_backup_dir_path() -> String {
 
 #[tauri::command]
 pub fn clear_all_backups() -> Result<u64, String> {
+    policy::ensure_destructive_allowed()?;
     backup::clear_all_backups()
 }
 
@@ -149,27 +246,136 @@ pub fn get_conversation_content(
 }
 
 #[tauri::command]
-pub fn delete_conversation(source_db: String, source_key: String) -> Result<u64, String> {
-    conversation::delete_conversation(&source_db, &source_key)
+pub fn export_conversation(
+    source_db: String,
+    source_key: String,
+    conversation_id: String,
+    format: conversation_export::ExportFormat,
+    path: String,
+    redact: Option<bool>,
+) -> Result<(), String> {
+    conversation_export::export_conversation(&source_db, &source_key, &conversation_id, format, std::path::Path::new(&path), redact.unwrap_or(false))
+}
+
+#[tauri::command]
+pub fn archive_conversations(ide_id: String, conversations: Vec<conversation::ConversationInfo>) -> Result<batch::BatchResult, String> {
+    policy::ensure_destructive_allowed()?;
+    conversation_archive::archive_conversations(&ide_id, &conversations)
+}
+
+#[tauri::command]
+pub fn list_archived_conversations() -> Result<Vec<conversation_archive::ArchivedConversation>, String> {
+    conversation_archive::list_archived_conversations()
+}
+
+#[tauri::command]
+pub fn get_archived_conversation(id: i64) -> Result<conversation::ConversationContent, String> {
+    conversation_archive::get_archived_conversation(id)
+}
+
+#[tauri::command]
+pub fn restore_conversation(archive_id: i64) -> Result<conversation_archive::ArchivedConversation, String> {
+    policy::ensure_destructive_allowed()?;
+    conversation_archive::restore_conversation(archive_id)
+}
+
+#[tauri::command]
+pub fn delete_archived_conversations(ids: Vec<i64>) -> Result<batch::BatchResult, String> {
+    policy::ensure_destructive_allowed()?;
+    conversation_archive::delete_archived_conversations(&ids)
+}
+
+#[tauri::command]
+pub fn update_search_index() -> Result<conversation_search::SearchIndexStats, String> {
+    conversation_search::update_search_index()
+}
+
+#[tauri::command]
+pub fn search_conversations(query: String, ide_filter: Option<String>) -> Result<Vec<conversation_search::SearchHit>, String> {
+    conversation_search::search_conversations(&query, ide_filter.as_deref())
+}
+
+#[tauri::command]
+pub fn export_conversations_batch(
+    items: Vec<conversation::BatchDeleteRequest>,
+    format: conversation_export::ExportFormat,
+    target_zip: String,
+    redact: Option<bool>,
+) -> Result<batch::BatchResult, String> {
+    conversation_export::export_conversations_batch(&items, format, std::path::Path::new(&target_zip), redact.unwrap_or(false))
+}
+
+#[tauri::command]
+pub fn scan_conversation_secrets(ide_id: String) -> Result<Vec<secret_scan::ConversationSecrets>, String> {
+    secret_scan::scan_source(&ide_id)
 }
 
 #[tauri::command]
-pub fn delete_conversations_batch(items: Vec<conversation::BatchDeleteRequest>) -> Result<u64, String> {
-    conversation::delete_conversations_batch(&items)
+pub fn delete_conversation(source_db: String, source_key: String, vacuum: Option<bool>) -> Result<u64, String> {
+    policy::ensure_destructive_allowed()?;
+    conversation::delete_conversation(&source_db, &source_key, vacuum.unwrap_or(true))
 }
 
 #[tauri::command]
-pub fn scan_conversations(ide_id: String) -> conversation::ConversationListResult {
+pub fn delete_conversations_batch(
+    items: Vec<conversation::BatchDeleteRequest>,
+    ide_id: Option<String>,
+    vacuum: Option<bool>,
+) -> Result<batch::BatchResult, String> {
+    policy::ensure_destructive_allowed()?;
+    let mut result = conversation::delete_conversations_batch(&items, vacuum.unwrap_or(true));
+    let failed = items
+        .into_iter()
+        .zip(&result.items)
+        .filter(|(_, r)| r.status == batch::ItemStatus::Failed)
+        .map(|(request, _)| retry::FailedItem::Conversation { request })
+        .collect();
+    result.operation_id = retry::record(retry::OperationKind::DeleteConversations, ide_id.into_iter().collect(), failed);
+    Ok(result)
+}
+
+#[tauri::command]
+pub fn set_conversation_protected(conversation: conversation::ConversationInfo, protected: bool) -> Result<(), String> {
+    conversation::set_conversation_protected(&conversation, protected)
+}
+
+#[tauri::command]
+pub fn annotate_conversation(id: String, tags: Vec<String>, note: String) -> Result<conversation_notes::ConversationAnnotation, String> {
+    conversation::annotate_conversation(&id, &tags, &note)
+}
+
+#[tauri::command]
+pub fn delete_duplicate_conversations(ide_id: String) -> Result<batch::BatchResult, String> {
+    policy::ensure_destructive_allowed()?;
+    conversation::delete_redundant_copies(&ide_id)
+}
+
+#[tauri::command]
+pub fn delete_stray_database_files(ide_id: String) -> Result<batch::BatchResult, String> {
+    policy::ensure_destructive_allowed()?;
+    conversation::delete_stray_files(&ide_id)
+}
+
+#[tauri::command(async)]
+pub fn vacuum_database(app: AppHandle, ide_id: String, path: String) -> Result<conversation::VacuumResult, String> {
+    policy::ensure_destructive_allowed()?;
+    conversation::vacuum_database(&ide_id, std::path::Path::new(&path), |progress| {
+        let _ = app.emit(conversation::VACUUM_PROGRESS_EVENT, progress);
+    })
+}
+
+#[tauri::command]
+pub fn query_conversations(ide_id: String, query: conversation::ConversationQuery, rescan: Option<bool>) -> conversation::ConversationPage {
+    if ide_id == conversation::CLI_AGENTS_ID {
+        return conversation::query_cli_agents(&query, rescan.unwrap_or(false));
+    }
     let ides = ide_detector::detect_installed_ides();
-    if let Some(ide) = ides.iter().find(|i| i.id == ide_id && i.installed) {
-        conversation::scan_conversations(ide)
-    } else {
-        conversation::ConversationListResult {
-            ide_id,
-            conversations: vec![],
-            db_files: vec![],
-            total_size: 0,
-        }
+    match ides.iter().find(|i| i.id == ide_id && i.installed) {
+        Some(ide) => conversation::query_conversations(ide, &query, rescan.unwrap_or(false)),
+        None => conversation::page_conversations(
+            &conversation::ConversationListResult { ide_id, conversations: vec![], db_files: vec![], total_size: 0, stray_files: vec![] },
+            &query,
+        ),
     }
 }
 
@@ -195,16 +401,30 @@ pub fn find_residual_data(
 pub fn uninstall_program(
     program: uninstaller::InstalledProgram,
     options: uninstaller::UninstallOptions,
-) -> uninstaller::UninstallResult {
-    uninstaller::uninstall_program(&program, &options)
+) -> Result<uninstaller::UninstallResult, String> {
+    policy::ensure_destructive_allowed()?;
+    // Only Windows has a Programs and Features list to compare
+    let before = if cfg!(target_os = "windows") { program_snapshots::take_snapshot(&program.display_name).ok() } else { None };
+    let mut result = uninstaller::uninstall_program(&program, &options);
+    result.programs_diff = before.and_then(|b| program_snapshots::diff_snapshots(&b.id, None).ok());
+    result.operation_id = retry::record(
+        retry::OperationKind::Uninstall,
+        program.ide_id.into_iter().collect(),
+        retry::path_items(&result.failed_paths),
+    );
+    Ok(result)
 }
 
 #[tauri::command]
 pub fn delete_storage_entry(path: String) -> Result<u64, String> {
+    policy::ensure_destructive_allowed()?;
     let p = std::path::Path::new(&path);
     if !p.exists() {
         return Err("Path does not exist".into());
     }
+    if let Some(conv) = conversation::protected_conversation_under(&config::load_config().protected_conversations, p) {
+        return Err(format!("Holds protected conversation {}; remove its protection first", conv.id));
+    }
     let size = walkdir::WalkDir::new(p)
         .into_iter()
         .filter_map(|e| e.ok())
@@ -212,7 +432,8 @@ pub fn delete_storage_entry(path: String) -> Result<u64, String> {
         .filter_map(|e| e.metadata().ok())
         .map(|m| m.len())
         .sum::<u64>();
-    std::fs::remove_dir_all(p).map_err(|e| format!("Failed to delete: {}", e))?;
+    crate::db_pool::close_under(p);
+    crate::cleaner::remove_tree(p).map_err(|e| format!("Failed to delete: {}", e))?;
     Ok(size)
 }
 
@@ -232,3 +453,481 @@ pub fn format_bytes(bytes: u64) -> String {
         format!("{} B", bytes)
     }
 }
+
+#[tauri::command]
+pub fn get_portable_paths() -> Vec<std::path::PathBuf> {
+    config::load_config().portable_paths
+}
+
+#[tauri::command]
+pub fn add_portable_path(path: String) -> Result<Vec<std::path::PathBuf>, String> {
+    config::add_portable_path(&path)
+}
+
+#[tauri::command]
+pub fn remove_portable_path(path: String) -> Result<Vec<std::path::PathBuf>, String> {
+    config::remove_portable_path(&path)
+}
+
+#[tauri::command]
+pub fn get_project_roots() -> Vec<std::path::PathBuf> {
+    config::load_config().project_roots
+}
+
+#[tauri::command]
+pub fn add_project_root(path: String) -> Result<Vec<std::path::PathBuf>, String> {
+    config::add_project_root(&path)
+}
+
+#[tauri::command]
+pub fn remove_project_root(path: String) -> Result<Vec<std::path::PathBuf>, String> {
+    config::remove_project_root(&path)
+}
+
+#[tauri::command]
+pub fn find_rust_targets() -> Vec<project_artifacts::RustTarget> {
+    project_artifacts::find_rust_targets(&config::load_config().project_roots)
+}
+
+#[tauri::command]
+pub fn clean_rust_targets(paths: Vec<std::path::PathBuf>) -> Result<batch::BatchResult, String> {
+    policy::ensure_destructive_allowed()?;
+    let mut result = project_artifacts::clean_rust_targets(&paths);
+    result.operation_id = retry::record(retry::OperationKind::Clean, Vec::new(), retry::path_items(&result.failed_paths));
+    Ok(result)
+}
+
+#[tauri::command]
+pub fn clean_stale_rust_targets(older_than_days: u32) -> Result<batch::BatchResult, String> {
+    policy::ensure_destructive_allowed()?;
+    let stale = project_artifacts::stale_rust_targets(&config::load_config().project_roots, older_than_days);
+    let paths: Vec<std::path::PathBuf> = stale.into_iter().map(|t| t.target_path).collect();
+    let mut result = project_artifacts::clean_rust_targets(&paths);
+    result.operation_id = retry::record(retry::OperationKind::Clean, Vec::new(), retry::path_items(&result.failed_paths));
+    Ok(result)
+}
+
+#[tauri::command]
+pub fn find_python_artifacts(stale_days: u32) -> Vec<project_artifacts::PythonProject> {
+    project_artifacts::find_python_artifacts(&config::load_config().project_roots, stale_days)
+}
+
+#[tauri::command]
+pub fn clean_python_artifacts(paths: Vec<std::path::PathBuf>) -> Result<batch::BatchResult, String> {
+    policy::ensure_destructive_allowed()?;
+    let mut result = project_artifacts::clean_python_artifacts(&paths);
+    result.operation_id = retry::record(retry::OperationKind::Clean, Vec::new(), retry::path_items(&result.failed_paths));
+    Ok(result)
+}
+
+#[tauri::command]
+pub fn analyze_maven_repo(stale_months: u32) -> Option<maven_repo::MavenRepoReport> {
+    maven_repo::analyze_maven_repo(stale_months)
+}
+
+#[tauri::command]
+pub fn clean_maven_artifacts(paths: Vec<std::path::PathBuf>) -> Result<batch::BatchResult, String> {
+    policy::ensure_destructive_allowed()?;
+    let mut result = maven_repo::clean_maven_artifacts(&paths);
+    result.operation_id = retry::record(retry::OperationKind::Clean, Vec::new(), retry::path_items(&result.failed_paths));
+    Ok(result)
+}
+
+#[tauri::command]
+pub fn analyze_local_models() -> local_models::LocalModelReport {
+    local_models::analyze_local_models()
+}
+
+#[tauri::command]
+pub fn delete_local_models(paths: Vec<std::path::PathBuf>) -> Result<batch::BatchResult, String> {
+    policy::ensure_destructive_allowed()?;
+    let mut result = local_models::delete_local_models(&paths);
+    result.operation_id = retry::record(retry::OperationKind::Clean, Vec::new(), retry::path_items(&result.failed_paths));
+    Ok(result)
+}
+
+#[tauri::command]
+pub fn prune_model_revisions(paths: Vec<std::path::PathBuf>) -> Result<batch::BatchResult, String> {
+    policy::ensure_destructive_allowed()?;
+    let mut result = local_models::prune_model_revisions(&paths);
+    result.operation_id = retry::record(retry::OperationKind::Clean, Vec::new(), retry::path_items(&result.failed_paths));
+    Ok(result)
+}
+
+#[tauri::command]
+pub fn list_failed_operations() -> Vec<retry::FailedOperation> {
+    retry::list_failed_operations()
+}
+
+#[tauri::command]
+pub fn retry_failed(operation_id: String, close_ide: bool) -> Result<batch::BatchResult, String> {
+    policy::ensure_destructive_allowed()?;
+    retry::retry_failed(&operation_id, close_ide)
+}
+
+#[tauri::command]
+pub fn analyze_extensions(ide_id: String) -> Result<extensions::ExtensionReport, String> {
+    let ides = ide_detector::detect_installed_ides();
+    let ide = ides
+        .iter()
+        .find(|i| i.id == ide_id && i.installed)
+        .ok_or_else(|| format!("IDE '{}' not found or not installed", ide_id))?;
+    extensions::analyze_extensions(ide)
+}
+
+#[tauri::command]
+pub fn clean_stale_extensions(ide_id: String, paths: Vec<std::path::PathBuf>) -> Result<batch::BatchResult, String> {
+    policy::ensure_destructive_allowed()?;
+    let ides = ide_detector::detect_installed_ides();
+    let ide = ides
+        .iter()
+        .find(|i| i.id == ide_id && i.installed)
+        .ok_or_else(|| format!("IDE '{}' not found or not installed", ide_id))?;
+    let mut result = extensions::clean_stale_extensions(ide, &paths)?;
+    result.operation_id = retry::record(retry::OperationKind::Clean, vec![ide_id], retry::path_items(&result.failed_paths));
+    Ok(result)
+}
+
+#[tauri::command]
+pub fn uninstall_extension(ide_id: String, extension_id: String, clear_global_storage: bool) -> Result<extensions::ExtensionUninstallResult, String> {
+    policy::ensure_destructive_allowed()?;
+    let ides = ide_detector::detect_installed_ides();
+    let ide = ides
+        .iter()
+        .find(|i| i.id == ide_id && i.installed)
+        .ok_or_else(|| format!("IDE '{}' not found or not installed", ide_id))?;
+    let mut result = extensions::uninstall_extension(ide, &extension_id, clear_global_storage)?;
+    result.operation_id = retry::record(retry::OperationKind::Uninstall, vec![ide_id], retry::path_items(&result.failed_paths));
+    Ok(result)
+}
+
+#[tauri::command]
+pub fn export_extension_list(ide_id: String, path: String) -> Result<extensions::ExtensionList, String> {
+    let ides = ide_detector::detect_installed_ides();
+    let ide = ides
+        .iter()
+        .find(|i| i.id == ide_id && i.installed)
+        .ok_or_else(|| format!("IDE '{}' not found or not installed", ide_id))?;
+    extensions::export_extension_list(ide, std::path::Path::new(&path))
+}
+
+#[tauri::command]
+pub fn read_extension_list(path: String) -> Result<extensions::ExtensionList, String> {
+    extensions::read_extension_list(std::path::Path::new(&path))
+}
+
+#[tauri::command]
+pub fn import_extension_list(ide_id: String, path: String, exact_versions: bool) -> Result<extensions::ExtensionInstallResult, String> {
+    policy::ensure_destructive_allowed()?;
+    let ides = ide_detector::detect_installed_ides();
+    let ide = ides
+        .iter()
+        .find(|i| i.id == ide_id && i.installed)
+        .ok_or_else(|| format!("IDE '{}' not found or not installed", ide_id))?;
+    extensions::import_extension_list(ide, std::path::Path::new(&path), exact_versions)
+}
+
+#[tauri::command]
+pub fn scan_wsl_servers() -> wsl::WslReport {
+    wsl::scan_wsl_servers()
+}
+
+#[tauri::command]
+pub fn clean_wsl_items(paths: Vec<std::path::PathBuf>) -> Result<batch::BatchResult, String> {
+    policy::ensure_destructive_allowed()?;
+    let mut result = wsl::clean_wsl_items(&paths);
+    result.operation_id = retry::record(retry::OperationKind::Clean, Vec::new(), retry::path_items(&result.failed_paths));
+    Ok(result)
+}
+
+#[tauri::command]
+pub fn scan_remote_servers(target: ssh_remote::SshTarget) -> Result<ssh_remote::RemoteServerReport, String> {
+    ssh_remote::scan_remote_servers(&target)
+}
+
+#[tauri::command]
+pub fn clean_remote_servers(target: ssh_remote::SshTarget, paths: Vec<String>) -> Result<batch::BatchResult, String> {
+    policy::ensure_destructive_allowed()?;
+    ssh_remote::clean_remote_servers(&target, &paths)
+}
+
+#[tauri::command]
+pub fn analyze_external_profile(path: String) -> Result<external_profile::ExternalProfileReport, String> {
+    external_profile::analyze_external_profile(std::path::Path::new(&path))
+}
+
+#[tauri::command]
+pub fn get_conversation_rules() -> rules::RulesDiagnostics {
+    rules::get_rules_diagnostics()
+}
+
+#[tauri::command]
+pub fn get_conversation_read_limits() -> conversation::ReadLimits {
+    conversation::read_limits()
+}
+
+#[tauri::command]
+pub fn set_conversation_read_limits(limits: Option<conversation::ReadLimits>) -> Result<conversation::ReadLimits, String> {
+    conversation::set_read_limits(limits)
+}
+
+#[tauri::command]
+pub fn get_ide_definitions() -> ide_detector::IdeDefinitions {
+    ide_detector::load_ide_definitions()
+}
+
+#[tauri::command]
+pub fn get_ide_credentials() -> Vec<credentials::CredentialEntry> {
+    let ides = ide_detector::detect_installed_ides();
+    credentials::find_ide_credentials(&ides)
+}
+
+#[tauri::command]
+pub fn restore_credentials(backup_id: String) -> Result<Vec<String>, String> {
+    policy::ensure_destructive_allowed()?;
+    backup::restore_credentials(&backup_id)
+}
+
+#[tauri::command]
+pub fn restore_registry(backup_id: String) -> Result<Vec<String>, String> {
+    policy::ensure_destructive_allowed()?;
+    backup::restore_registry(&backup_id)
+}
+
+#[tauri::command]
+pub fn detect_all_ides() -> Vec<ide_detector::IdeInfo> {
+    ide_detector::detect_all_ides()
+}
+
+#[tauri::command]
+pub fn get_ignored_ides() -> Vec<String> {
+    config::load_config().ignored_ides
+}
+
+#[tauri::command]
+pub fn set_ide_ignored(ide_id: String, ignored: bool) -> Result<Vec<String>, String> {
+    config::set_ide_ignored(&ide_id, ignored)
+}
+
+#[tauri::command]
+pub fn get_secure_delete() -> bool {
+    config::secure_delete_enabled()
+}
+
+#[tauri::command]
+pub fn set_secure_delete(enabled: bool) -> Result<bool, String> {
+    config::set_secure_delete(enabled)
+}
+
+#[tauri::command]
+pub fn snapshot_installed_programs(label: String) -> Result<program_snapshots::ProgramsSnapshot, String> {
+    program_snapshots::take_snapshot(&label)
+}
+
+#[tauri::command]
+pub fn list_program_snapshots() -> Vec<program_snapshots::ProgramsSnapshotInfo> {
+    program_snapshots::list_snapshots()
+}
+
+#[tauri::command]
+pub fn delete_program_snapshot(snapshot_id: String) -> Result<(), String> {
+    policy::ensure_destructive_allowed()?;
+    program_snapshots::delete_snapshot(&snapshot_id)
+}
+
+#[tauri::command]
+pub fn diff_program_snapshots(
+    before_id: String,
+    after_id: Option<String>,
+) -> Result<program_snapshots::ProgramsDiff, String> {
+    program_snapshots::diff_snapshots(&before_id, after_id.as_deref())
+}
+
+#[tauri::command]
+pub fn find_stale_versions() -> Vec<stale_versions::StaleVersion> {
+    let ides = ide_detector::detect_installed_ides();
+    stale_versions::find_stale_versions(&ides)
+}
+
+#[tauri::command]
+pub fn clean_stale_versions(
+    versions: Vec<stale_versions::StaleVersionRequest>,
+    create_backup: bool,
+) -> Result<batch::BatchResult, String> {
+    policy::ensure_destructive_allowed()?;
+    let ides = ide_detector::detect_installed_ides();
+    let mut result = stale_versions::clean_stale_versions(&ides, &versions, create_backup);
+    result.operation_id = retry::record(retry::OperationKind::Clean, Vec::new(), retry::path_items(&result.failed_paths));
+    Ok(result)
+}
+
+#[tauri::command]
+pub fn find_toolbox_leftovers() -> Vec<stale_versions::ToolboxLeftover> {
+    stale_versions::find_toolbox_leftovers()
+}
+
+#[tauri::command]
+pub fn clean_toolbox_leftovers(ids: Vec<String>) -> Result<batch::BatchResult, String> {
+    policy::ensure_destructive_allowed()?;
+    let mut result = stale_versions::clean_toolbox_leftovers(&ids);
+    result.operation_id = retry::record(retry::OperationKind::Clean, Vec::new(), retry::path_items(&result.failed_paths));
+    Ok(result)
+}
+
+#[tauri::command]
+pub fn find_orphaned_storage() -> Vec<orphaned_storage::OrphanedStorage> {
+    let ides = ide_detector::detect_installed_ides();
+    orphaned_storage::find_orphaned_storage(&ides)
+}
+
+#[tauri::command]
+pub fn clean_orphaned_storage(
+    paths: Vec<std::path::PathBuf>,
+    create_backup: bool,
+) -> Result<batch::BatchResult, String> {
+    policy::ensure_destructive_allowed()?;
+    let ides = ide_detector::detect_installed_ides();
+    let mut result = orphaned_storage::clean_orphaned_storage(&ides, &paths, create_backup);
+    let ide_ids = ides
+        .iter()
+        .filter(|i| {
+            let storage = [&i.global_storage_path, &i.workspace_storage_path];
+            result.failed_paths.iter().any(|p| storage.iter().filter_map(|s| s.as_ref()).any(|s| p.starts_with(s)))
+        })
+        .map(|i| i.id.clone())
+        .collect();
+    result.operation_id = retry::record(retry::OperationKind::Clean, ide_ids, retry::path_items(&result.failed_paths));
+    Ok(result)
+}
+
+#[tauri::command]
+pub fn get_policy_status() -> policy::PolicyStatus {
+    policy::current_policy()
+}
+
+#[tauri::command]
+pub fn set_tray_labels(app: AppHandle, labels: tray::TrayLabels) {
+    tray::set_labels(&app, labels);
+}
+
+#[tauri::command]
+pub fn scan_dev_caches() -> Vec<dev_caches::DevCache> {
+    dev_caches::scan_dev_caches()
+}
+
+#[tauri::command]
+pub fn clean_dev_cache(
+    id: String,
+    use_tool: bool,
+    only: Option<std::path::PathBuf>,
+) -> Result<batch::BatchResult, String> {
+    policy::ensure_destructive_allowed()?;
+    let mut result = dev_caches::clean_dev_cache(&id, use_tool, only.as_deref())?;
+    result.operation_id = retry::record(retry::OperationKind::Clean, Vec::new(), retry::path_items(&result.failed_paths));
+    Ok(result)
+}
+
+#[tauri::command]
+pub fn scan_devcontainers() -> devcontainers::DevContainerReport {
+    devcontainers::scan_devcontainers()
+}
+
+#[tauri::command]
+pub fn remove_devcontainer_items(items: Vec<devcontainers::DevContainerRef>) -> Result<batch::BatchResult, String> {
+    policy::ensure_destructive_allowed()?;
+    let mut result = devcontainers::remove_devcontainer_items(&items);
+    result.operation_id = retry::record(retry::OperationKind::Clean, Vec::new(), retry::path_items(&result.failed_paths));
+    Ok(result)
+}
+
+#[tauri::command]
+pub fn get_developer_footprint() -> footprint::DeveloperFootprint {
+    footprint::get_developer_footprint()
+}
+
+#[tauri::command]
+pub fn export_curation(path: String) -> Result<(), String> {
+    config::export_curation(std::path::Path::new(&path))
+}
+
+#[tauri::command]
+pub fn import_curation(path: String) -> Result<config::CurationImportSummary, String> {
+    config::import_curation(std::path::Path::new(&path))
+}
+
+#[tauri::command]
+pub fn export_migration(path: String, ide_ids: Vec<String>, include_conversations: bool) -> Result<migration::MigrationExportSummary, String> {
+    migration::export_migration(std::path::Path::new(&path), &ide_ids, include_conversations)
+}
+
+#[tauri::command]
+pub fn read_migration_manifest(path: String) -> Result<migration::MigrationManifest, String> {
+    migration::read_migration_manifest(std::path::Path::new(&path))
+}
+
+#[tauri::command]
+pub fn import_migration(path: String, options: migration::MigrationImportOptions) -> Result<migration::MigrationImportSummary, String> {
+    policy::ensure_destructive_allowed()?;
+    migration::import_migration(std::path::Path::new(&path), &options)
+}
+
+#[tauri::command]
+pub fn preview_settings_transfer(from_id: String, to_id: String) -> Result<migration::SettingsTransferPreview, String> {
+    migration::preview_settings_transfer(&from_id, &to_id)
+}
+
+#[tauri::command]
+pub fn transfer_settings(from_id: String, to_id: String, options: migration::SettingsTransferOptions) -> Result<migration::SettingsTransferResult, String> {
+    policy::ensure_destructive_allowed()?;
+    migration::transfer_settings(&from_id, &to_id, &options)
+}
+
+#[tauri::command]
+pub fn save_scan_snapshot(path: String) -> Result<(), String> {
+    scan_snapshot::save_scan_snapshot(std::path::Path::new(&path)).map(|_| ())
+}
+
+#[tauri::command]
+pub fn load_scan_snapshot(path: String) -> Result<scan_snapshot::ScanSnapshot, String> {
+    scan_snapshot::load_scan_snapshot(std::path::Path::new(&path))
+}
+
+#[tauri::command]
+pub fn analyze_ai_cli(stale_days: u32) -> ai_cli::AiCliReport {
+    ai_cli::analyze_ai_cli(stale_days)
+}
+
+#[tauri::command]
+pub fn get_ai_cli_session_summary(path: std::path::PathBuf) -> Result<ai_cli::AiCliSessionSummary, String> {
+    ai_cli::session_summary(&path)
+}
+
+#[tauri::command]
+pub fn prune_ai_cli_sessions(paths: Vec<std::path::PathBuf>) -> Result<batch::BatchResult, String> {
+    policy::ensure_destructive_allowed()?;
+    let mut result = ai_cli::prune_sessions(&paths);
+    result.operation_id = retry::record(retry::OperationKind::Clean, Vec::new(), retry::path_items(&result.failed_paths));
+    Ok(result)
+}
+
+// ── Chunked results (large payloads pulled piecewise by the frontend) ──
+
+#[tauri::command]
+pub fn scan_all_ides_chunked() -> Result<chunked::ChunkedHandle, String> {
+    chunked::store(&scan_all_ides(), Some("results"), 1)
+}
+
+#[tauri::command]
+pub fn list_storage_entries_chunked(path: String) -> Result<chunked::ChunkedHandle, String> {
+    chunked::store(&list_storage_entries(path), None, 500)
+}
+
+#[tauri::command]
+pub fn fetch_chunk(handle: String, index: usize) -> Result<Vec<serde_json::Value>, String> {
+    chunked::fetch_chunk(&handle, index)
+}
+
+#[tauri::command]
+pub fn release_chunked(handle: String) {
+    chunked::release(&handle)
+}
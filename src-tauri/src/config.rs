@@ -0,0 +1,265 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Backend-side settings that Rust code needs to read (the UI preferences
+/// live in the webview's localStorage and never reach the scanners).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AppConfig {
+    /// Extra roots to probe for portable installs (the folder holding the exe, or its `data` folder).
+    pub portable_paths: Vec<PathBuf>,
+    /// IDE ids hidden from detection, scans and cleaning (e.g. a work-managed install).
+    pub ignored_ides: Vec<String>,
+    /// Roots searched for project build artifacts (Rust `target/`, ...).
+    pub project_roots: Vec<PathBuf>,
+    /// Conversation scan: characters read per key for the title/count preview (None = built-in).
+    pub preview_len: Option<usize>,
+    /// Conversation scan: values up to this size are read and parsed whole (None = built-in).
+    pub max_full_read: Option<u64>,
+    /// Conversations kept out of batch deletes and bulk cleanup.
+    pub protected_conversations: Vec<ProtectedConversation>,
+    /// Overwrite files before unlinking them and zero freed SQLite pages.
+    pub secure_delete: bool,
+}
+
+/// DevCleaner's own data directory (shared with the backup manifest).
+pub fn get_app_data_dir() -> PathBuf {
+    let base = dirs::data_local_dir().unwrap_or_else(|| PathBuf::from("."));
+    base.join("IDECleaner")
+}
+
+fn get_config_path() -> PathBuf {
+    get_app_data_dir().join("config.json")
+}
+
+pub fn load_config() -> AppConfig {
+    let path = get_config_path();
+    if path.exists() {
+        if let Ok(data) = fs::read_to_string(&path) {
+            if let Ok(cfg) = serde_json::from_str::<AppConfig>(&data) {
+                return cfg;
+            }
+        }
+    }
+    AppConfig::default()
+}
+
+pub fn save_config(cfg: &AppConfig) -> Result<(), String> {
+    let path = get_config_path();
+    if let Some(parent) = path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    let data = serde_json::to_string_pretty(cfg).map_err(|e| format!("Failed to serialize config: {}", e))?;
+    fs::write(&path, data).map_err(|e| format!("Failed to write config: {}", e))
+}
+
+// ── Portable install paths ──
+
+pub fn add_portable_path(path: &str) -> Result<Vec<PathBuf>, String> {
+    let p = PathBuf::from(path);
+    if !p.is_dir() {
+        return Err(format!("'{}' is not a directory", path));
+    }
+    let mut cfg = load_config();
+    if !cfg.portable_paths.contains(&p) {
+        cfg.portable_paths.push(p);
+        save_config(&cfg)?;
+    }
+    Ok(cfg.portable_paths)
+}
+
+pub fn remove_portable_path(path: &str) -> Result<Vec<PathBuf>, String> {
+    let p = PathBuf::from(path);
+    let mut cfg = load_config();
+    cfg.portable_paths.retain(|x| x != &p);
+    save_config(&cfg)?;
+    Ok(cfg.portable_paths)
+}
+
+// ── Project roots ──
+
+pub fn add_project_root(path: &str) -> Result<Vec<PathBuf>, String> {
+    let p = PathBuf::from(path);
+    if !p.is_dir() {
+        return Err(format!("'{}' is not a directory", path));
+    }
+    let mut cfg = load_config();
+    if !cfg.project_roots.contains(&p) {
+        cfg.project_roots.push(p);
+        save_config(&cfg)?;
+    }
+    Ok(cfg.project_roots)
+}
+
+pub fn remove_project_root(path: &str) -> Result<Vec<PathBuf>, String> {
+    let p = PathBuf::from(path);
+    let mut cfg = load_config();
+    cfg.project_roots.retain(|x| x != &p);
+    save_config(&cfg)?;
+    Ok(cfg.project_roots)
+}
+
+// ── Ignored IDEs ──
+
+pub fn set_ide_ignored(ide_id: &str, ignored: bool) -> Result<Vec<String>, String> {
+    let mut cfg = load_config();
+    let present = cfg.ignored_ides.iter().any(|id| id == ide_id);
+    if ignored && !present {
+        cfg.ignored_ides.push(ide_id.to_string());
+    } else if !ignored && present {
+        cfg.ignored_ides.retain(|id| id != ide_id);
+    } else {
+        return Ok(cfg.ignored_ides);
+    }
+    save_config(&cfg)?;
+    Ok(cfg.ignored_ides)
+}
+
+// ── Secure deletion ──
+
+/// `secure_delete` of the config file at the given path, so removals in a loop
+/// don't reparse the config for every file.
+static SECURE_DELETE: std::sync::Mutex<Option<(PathBuf, bool)>> = std::sync::Mutex::new(None);
+
+pub fn secure_delete_enabled() -> bool {
+    let path = get_config_path();
+    let mut cached = SECURE_DELETE.lock().unwrap_or_else(|e| e.into_inner());
+    match cached.as_ref() {
+        Some((p, enabled)) if *p == path => *enabled,
+        _ => {
+            let enabled = load_config().secure_delete;
+            *cached = Some((path, enabled));
+            enabled
+        }
+    }
+}
+
+pub fn set_secure_delete(enabled: bool) -> Result<bool, String> {
+    let mut cfg = load_config();
+    if cfg.secure_delete != enabled {
+        cfg.secure_delete = enabled;
+        save_config(&cfg)?;
+    }
+    *SECURE_DELETE.lock().unwrap_or_else(|e| e.into_inner()) = Some((get_config_path(), cfg.secure_delete));
+    Ok(cfg.secure_delete)
+}
+
+// ── Protected conversations ──
+
+/// A pinned conversation, keyed by `ConversationInfo::id`. Its location is kept
+/// too, so deleting the whole key or file it lives in is refused as well.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProtectedConversation {
+    pub id: String,
+    pub source_db: String,
+    pub source_key: String,
+}
+
+pub fn set_conversation_protected(entry: ProtectedConversation, protected: bool) -> Result<Vec<ProtectedConversation>, String> {
+    let mut cfg = load_config();
+    let present = cfg.protected_conversations.iter().any(|p| p.id == entry.id);
+    if protected && !present {
+        cfg.protected_conversations.push(entry);
+    } else if !protected && present {
+        cfg.protected_conversations.retain(|p| p.id != entry.id);
+    } else {
+        return Ok(cfg.protected_conversations);
+    }
+    save_config(&cfg)?;
+    Ok(cfg.protected_conversations)
+}
+
+// ── Curation export/import ──
+
+const CURATION_FORMAT_VERSION: u32 = 1;
+
+/// The user's curation, carried to a new machine or across a reinstall:
+/// excluded IDEs, portable roots, protected conversations and the conversation
+/// rules pack (whose `ignored_keys` are the conversation exclusion rules).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CurationExport {
+    pub format_version: u32,
+    pub exported_at: i64,
+    pub ignored_ides: Vec<String>,
+    pub portable_paths: Vec<PathBuf>,
+    pub protected_conversations: Vec<ProtectedConversation>,
+    pub rules_pack: crate::rules::RulesPackFile,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CurationImportSummary {
+    pub added_ignored_ides: Vec<String>,
+    pub added_portable_paths: Vec<PathBuf>,
+    /// Portable roots that don't exist on this machine.
+    pub skipped_portable_paths: Vec<PathBuf>,
+    pub added_protected: usize,
+    pub added_rules: usize,
+}
+
+pub fn current_curation() -> Result<CurationExport, String> {
+    let cfg = load_config();
+    Ok(CurationExport {
+        format_version: CURATION_FORMAT_VERSION,
+        exported_at: chrono::Local::now().timestamp(),
+        ignored_ides: cfg.ignored_ides,
+        portable_paths: cfg.portable_paths,
+        protected_conversations: cfg.protected_conversations,
+        rules_pack: crate::rules::read_rules_pack()?,
+    })
+}
+
+pub fn export_curation(path: &Path) -> Result<(), String> {
+    let data = serde_json::to_string_pretty(&current_curation()?).map_err(|e| format!("Failed to serialize export: {}", e))?;
+    fs::write(path, data).map_err(|e| format!("Failed to write export: {}", e))
+}
+
+pub fn import_curation(path: &Path) -> Result<CurationImportSummary, String> {
+    let data = fs::read_to_string(path).map_err(|e| format!("Failed to read export: {}", e))?;
+    let export: CurationExport = serde_json::from_str(&data).map_err(|e| format!("Failed to parse export: {}", e))?;
+    merge_curation(export)
+}
+
+/// Merge an export into the current settings: entries are only ever added,
+/// nothing already configured here is removed or overwritten.
+pub fn merge_curation(export: CurationExport) -> Result<CurationImportSummary, String> {
+    if export.format_version > CURATION_FORMAT_VERSION {
+        return Err(format!("Export format {} is newer than this version supports", export.format_version));
+    }
+
+    let mut cfg = load_config();
+    let mut summary = CurationImportSummary {
+        added_ignored_ides: Vec::new(),
+        added_portable_paths: Vec::new(),
+        skipped_portable_paths: Vec::new(),
+        added_protected: 0,
+        added_rules: 0,
+    };
+    for id in export.ignored_ides {
+        if !cfg.ignored_ides.contains(&id) {
+            cfg.ignored_ides.push(id.clone());
+            summary.added_ignored_ides.push(id);
+        }
+    }
+    for p in export.portable_paths {
+        if cfg.portable_paths.contains(&p) { continue; }
+        if p.is_dir() {
+            cfg.portable_paths.push(p.clone());
+            summary.added_portable_paths.push(p);
+        } else {
+            summary.skipped_portable_paths.push(p);
+        }
+    }
+    for entry in export.protected_conversations {
+        if !cfg.protected_conversations.iter().any(|p| p.id == entry.id) {
+            cfg.protected_conversations.push(entry);
+            summary.added_protected += 1;
+        }
+    }
+    if !summary.added_ignored_ides.is_empty() || !summary.added_portable_paths.is_empty() || summary.added_protected > 0 {
+        save_config(&cfg)?;
+    }
+    summary.added_rules = crate::rules::merge_into_rules_pack(&export.rules_pack)?;
+    Ok(summary)
+}
@@ -1,9 +1,14 @@
 use rusqlite::Connection;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
+use std::sync::Mutex;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+use crate::batch::{BatchResult, ItemResult, ItemStatus};
+use crate::protowire;
+use crate::rules::ConversationRules;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ConversationInfo {
     pub id: String,
     pub title: String,
@@ -11,7 +16,24 @@ pub struct ConversationInfo {
     pub source_key: String,
     pub message_count: usize,
     pub size_bytes: u64,
+    /// UTC epoch milliseconds.
     pub last_modified: Option<i64>,
+    /// `last_modified` in the machine's local time zone, "YYYY-MM-DD HH:MM".
+    pub last_modified_local: Option<String>,
+    /// Dominant script of the title: "zh", "ja", "ko", "ru", "latin", or "" if undetermined.
+    pub language: String,
+    /// Other places the same conversation is stored, merged into this entry.
+    /// Defaulted so search index rows written before it still load.
+    #[serde(default)]
+    pub duplicates: Vec<ConversationCopy>,
+    /// Pinned by the user; batch deletes and bulk cleanup skip it.
+    #[serde(default)]
+    pub protected: bool,
+    /// The user's tags and note from DevCleaner's own notes database.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub note: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -19,6 +41,7 @@ pub struct DbFileInfo {
     pub path: String,
     pub size: u64,
     pub name: String,
+    /// UTC epoch milliseconds.
     pub modified: Option<i64>,
 }
 
@@ -28,38 +51,14 @@ pub struct ConversationListResult {
     pub conversations: Vec<ConversationInfo>,
     pub db_files: Vec<DbFileInfo>,
     pub total_size: u64,
+    /// `-wal`/`-shm`/`-journal` files whose database is gone.
+    #[serde(default)]
+    pub stray_files: Vec<DbFileInfo>,
 }
 
 // ── Key patterns ──
 
-// Exact keys for aggregated chat data (need full read)
-const CHAT_DATA_KEYS: &[&str] = &[
-    "workbench.panel.aichat.view.aichat.chatdata",
-    "workbench.panel.chat.view.chatView.chatdata",
-    "aiChat.chatdata",
-    "chat.data",
-    "cascade.chatdata",
-    "cascade.conversations",
-    "composer.composerData",
-    "interactive.sessions",
-];
-
-// LIKE patterns for individual conversations in ItemTable
-const ITEM_TABLE_LIKE: &[&str] = &[
-    "composerData:%",
-    "cascade.%",
-    "chat.%",
-    "aichat.%",
-    "aiChat.%",
-    "copilot.%",
-    "trae.%",
-    "marscode.%",
-    "kiro.%",
-    "memento/icube-ai-agent-storage",
-    "memento/interactive-session%",
-    "jetskiStateSync.agentManagerInitState",
-    "antigravityUnifiedStateSync.trajectorySummaries",
-];
+// CHAT_DATA_KEYS / ITEM_TABLE_LIKE / IGNORED_KEYS live in `rules` so a rules pack can extend them.
 
 // Discovery LIKE patterns for unknown key schemas
 const DISCOVERY_LIKE: &[&str] = &[
@@ -69,39 +68,74 @@ const DISCOVERY_LIKE: &[&str] = &[
     "%Conversation%",
 ];
 
-// Keys that look like conversations but are actually metadata/config
-const IGNORED_KEYS: &[&str] = &[
-    "chat.participantNameRegistry",
-    "chat.ChatSessionStore.index",
-    "chat.workspaceTransfer",
-    "chat.customModes",
-    "chat.setupContext",
-    "composer.planRegistry",
-];
+const DEFAULT_MAX_FULL_READ: u64 = 50_000_000;
+const DEFAULT_PREVIEW_LEN: usize = 8000;
+const PREVIEW_LEN_RANGE: std::ops::RangeInclusive<usize> = 1_000..=1_000_000;
+const MAX_FULL_READ_RANGE: std::ops::RangeInclusive<u64> = 1_000_000..=1_000_000_000;
+/// Chunk size for values too big for the preview but not worth reading whole.
+const WINDOW_LEN: usize = 256 * 1024;
+/// Tail of the previous window re-scanned with the next, so a title or count
+/// marker cut at a window edge is still found.
+const WINDOW_OVERLAP: usize = 256;
+
+// ── Read limits (user-configurable, see config.rs) ──
+
+/// How much of each value the scanner reads: `preview_len` characters for the
+/// quick title/count pass, whole values up to `max_full_read` bytes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadLimits {
+    pub preview_len: usize,
+    pub max_full_read: u64,
+}
+
+fn validate_read_limits(preview_len: usize, max_full_read: u64) -> Result<(), String> {
+    if !PREVIEW_LEN_RANGE.contains(&preview_len) {
+        return Err(format!(
+            "Preview length must be between {} and {} characters",
+            PREVIEW_LEN_RANGE.start(), PREVIEW_LEN_RANGE.end()
+        ));
+    }
+    if !MAX_FULL_READ_RANGE.contains(&max_full_read) {
+        return Err(format!(
+            "Full-read ceiling must be between {} and {} bytes",
+            MAX_FULL_READ_RANGE.start(), MAX_FULL_READ_RANGE.end()
+        ));
+    }
+    Ok(())
+}
+
+/// Configured limits, clamped into range in case config.json was edited by hand.
+pub fn read_limits() -> ReadLimits {
+    let cfg = crate::config::load_config();
+    ReadLimits {
+        preview_len: cfg
+            .preview_len
+            .unwrap_or(DEFAULT_PREVIEW_LEN)
+            .clamp(*PREVIEW_LEN_RANGE.start(), *PREVIEW_LEN_RANGE.end()),
+        max_full_read: cfg
+            .max_full_read
+            .unwrap_or(DEFAULT_MAX_FULL_READ)
+            .clamp(*MAX_FULL_READ_RANGE.start(), *MAX_FULL_READ_RANGE.end()),
+    }
+}
 
-fn is_ignored_key(key: &str) -> bool {
-    IGNORED_KEYS.contains(&key)
-        || key.starts_with("workbench.panel.composerChatViewPane.")
-        || key.starts_with("windsurf.cascadeViewContainerId.")
-        || key.starts_with("workbench.panel.icube.")
-        || key.starts_with("workbench.panel.chat")
-        || key.starts_with("workbench.panel.chatSidebar")
-        || key.starts_with("workbench.panel.chatEditing")
-        || key.starts_with("workbench.view.trae.")
-        || key.contains("AI.agent.model")
-        || key.contains("AI.agent.modeList")
-        || key.contains("sessionRelation:")
-        || key.starts_with("currentAgentData_")
-        || key.starts_with("icube_session_agent_map")
-        || key.starts_with("icube-ai-agent-storage-input-history")
-        || key.starts_with("chatHistoryNeedToBeMigrated")
-        || key.starts_with("hasAutoNewSession")
-        || key.ends_with(".hidden")
-        || key.ends_with(".state")
-}
-
-const MAX_FULL_READ: u64 = 50_000_000;
-const PREVIEW_LEN: usize = 8000;
+/// Save new limits; `None` for both restores the built-in defaults.
+pub fn set_read_limits(limits: Option<ReadLimits>) -> Result<ReadLimits, String> {
+    let mut cfg = crate::config::load_config();
+    match limits {
+        Some(l) => {
+            validate_read_limits(l.preview_len, l.max_full_read)?;
+            cfg.preview_len = Some(l.preview_len);
+            cfg.max_full_read = Some(l.max_full_read);
+        }
+        None => {
+            cfg.preview_len = None;
+            cfg.max_full_read = None;
+        }
+    }
+    crate::config::save_config(&cfg)?;
+    Ok(read_limits())
+}
 
 // ── DB helpers ──
 
@@ -133,10 +167,10 @@ struct KeyEntry {
     size: u64,
 }
 
-fn scan_keys_preview(conn: &Connection, table: &str, pattern: &str) -> Vec<KeyEntry> {
+fn scan_keys_preview(conn: &Connection, table: &str, pattern: &str, preview_len: usize) -> Vec<KeyEntry> {
     let sql = format!(
         "SELECT key, substr(value, 1, {}), length(value) FROM [{}] WHERE key LIKE ?1",
-        PREVIEW_LEN, table
+        preview_len, table
     );
     conn.prepare(&sql)
         .ok()
@@ -267,16 +301,24 @@ fn try_parse_conversation_item(
         message_count: msg_count,
         size_bytes: size,
         last_modified: modified,
+        ..Default::default()
     })
 }
 
-fn extract_from_preview(entry: &KeyEntry, db_path: &str, modified: Option<i64>) -> Option<ConversationInfo> {
+fn extract_from_preview(
+    conn: &Connection,
+    table: &str,
+    entry: &KeyEntry,
+    db_path: &str,
+    modified: Option<i64>,
+    limits: &ReadLimits,
+) -> Option<ConversationInfo> {
     // Try special format handlers first
     if entry.key.starts_with("memento/interactive-session") {
         return extract_interactive_session(entry, db_path, modified);
     }
-    if entry.key.starts_with("jetskiStateSync.") || entry.key.starts_with("antigravityUnifiedStateSync.") {
-        return extract_antigravity_protobuf(entry, db_path, modified);
+    if is_antigravity_key(&entry.key) {
+        return extract_antigravity_protobuf(conn, table, entry, db_path, modified, limits);
     }
 
     // Try full JSON parsing first (works for small values that fit in preview)
@@ -286,8 +328,11 @@ fn extract_from_preview(entry: &KeyEntry, db_path: &str, modified: Option<i64>)
     }
 
     // Fallback: string-based title extraction for truncated JSON
-    let title = extract_title_from_text(&entry.preview);
-    let msg_count = count_messages_from_text(&entry.preview);
+    let (title, msg_count) = if entry.size > limits.preview_len as u64 && entry.size < limits.max_full_read {
+        scan_value_windows(conn, table, entry)
+    } else {
+        (extract_title_from_text(&entry.preview), count_messages_from_text(&entry.preview))
+    };
 
     if title.is_empty() && entry.size < 100 { return None; }
 
@@ -303,6 +348,7 @@ fn extract_from_preview(entry: &KeyEntry, db_path: &str, modified: Option<i64>)
         message_count: msg_count,
         size_bytes: entry.size,
         last_modified: modified,
+        ..Default::default()
     })
 }
 
@@ -350,55 +396,10 @@ fn extract_interactive_session(entry: &KeyEntry, db_path: &str, modified: Option
         message_count: total_count,
         size_bytes: entry.size,
         last_modified: modified,
+        ..Default::default()
     })
 }
 
-/// Extract conversation info from Antigravity's protobuf-encoded keys.
-/// These contain base64-encoded protobuf with conversation titles as UTF-8 strings.
-fn extract_antigravity_protobuf(entry: &KeyEntry, db_path: &str, modified: Option<i64>) -> Option<ConversationInfo> {
-    // The value is raw protobuf bytes (not base64 in the preview, since SQLite stores as TEXT)
-    // Try to extract readable strings from the raw bytes
-    let bytes = entry.preview.as_bytes();
-    let titles = extract_readable_strings(bytes, 10);
-
-    if titles.is_empty() { return None; }
-
-    // First long readable string is likely the conversation title
-    let title = titles.into_iter()
-        .find(|s| s.len() >= 10 && s.chars().all(|c| c.is_alphanumeric() || c.is_whitespace() || ".,;:!?-_'\"()".contains(c)))
-        .unwrap_or_else(|| "Antigravity Session".to_string());
-
-    Some(ConversationInfo {
-        id: format!("{}:{}", db_path, entry.key),
-        title,
-        source_db: db_path.to_string(),
-        source_key: entry.key.clone(),
-        message_count: 0,
-        size_bytes: entry.size,
-        last_modified: modified,
-    })
-}
-
-/// Extract readable ASCII/UTF-8 strings from binary data.
-fn extract_readable_strings(data: &[u8], min_len: usize) -> Vec<String> {
-    let mut strings = Vec::new();
-    let mut current = String::new();
-    for &b in data {
-        if b >= 0x20 && b < 0x7F {
-            current.push(b as char);
-        } else {
-            if current.len() >= min_len {
-                strings.push(current.clone());
-            }
-            current.clear();
-        }
-    }
-    if current.len() >= min_len {
-        strings.push(current);
-    }
-    strings
-}
-
 /// Clean up a raw DB key to produce a human-readable title.
 fn clean_key_title(key: &str) -> String {
     let cleaned = key
@@ -408,7 +409,7 @@ fn clean_key_title(key: &str) -> String {
         .replace("interactive-session", "Copilot Chat");
     // For keys like "composerData:UUID", show just the UUID short form
     if let Some(uuid) = cleaned.strip_prefix("composerData:") {
-        return format!("Composer {}", &uuid[..8.min(uuid.len())]);
+        return format!("Composer {}", uuid.chars().take(8).collect::<String>());
     }
     cleaned
 }
@@ -425,8 +426,12 @@ fn extract_title_from_text(text: &str) -> String {
                 if bytes[end] == b'"' { break; }
                 end += 1;
             }
+            // A trailing backslash steps past the end of a cut-off preview
+            let end = end.min(bytes.len());
             if end > 0 && end < 200 {
-                return remaining[..end].to_string();
+                let raw = &remaining[..end];
+                // Undo JSON escapes (\", \uXXXX); keep the raw text if they don't parse
+                return serde_json::from_str::<String>(&format!("\"{}\"", raw)).unwrap_or_else(|_| raw.to_string());
             }
         }
     }
@@ -439,12 +444,93 @@ fn count_messages_from_text(text: &str) -> usize {
         .max(text.matches("\"type\":\"user\"").count())
 }
 
+/// Title and message count of a value too large for the preview, streamed
+/// through an incremental blob handle in WINDOW_LEN chunks so the whole value
+/// is never held in memory and each chunk is read once.
+fn scan_value_windows(conn: &Connection, table: &str, entry: &KeyEntry) -> (String, usize) {
+    use std::io::Read;
+
+    let fallback = || (extract_title_from_text(&entry.preview), count_messages_from_text(&entry.preview));
+    let sql = format!("SELECT rowid FROM [{}] WHERE key = ?1", table);
+    let Ok(rowid) = conn.query_row(&sql, [&entry.key], |row| row.get::<_, i64>(0)) else { return fallback() };
+    let Ok(mut blob) = conn.blob_open(rusqlite::DatabaseName::Main, table, "value", rowid, true) else { return fallback() };
+
+    let mut title = String::new();
+    let mut roles = 0;
+    let mut user_turns = 0;
+    let mut carry = String::new();
+    let mut buf = vec![0u8; WINDOW_LEN];
+    // Bytes of a UTF-8 character split across two chunks
+    let mut pending: Vec<u8> = Vec::new();
+    loop {
+        let n = match blob.read(&mut buf) {
+            Ok(0) | Err(_) => break,
+            Ok(n) => n,
+        };
+        pending.extend_from_slice(&buf[..n]);
+        let complete = match std::str::from_utf8(&pending) {
+            Err(e) if e.error_len().is_none() => e.valid_up_to(),
+            _ => pending.len(),
+        };
+        let window = String::from_utf8_lossy(&pending[..complete]).into_owned();
+        pending.drain(..complete);
+
+        let text = format!("{}{}", carry, window);
+        if title.is_empty() {
+            title = extract_title_from_text(&text);
+        }
+        // Matches inside the carried tail were already counted with the previous window
+        roles += text.matches("\"role\"").count() - carry.matches("\"role\"").count();
+        user_turns += text.matches("\"type\":\"user\"").count() - carry.matches("\"type\":\"user\"").count();
+        let tail_start = text.char_indices().rev().nth(WINDOW_OVERLAP - 1).map(|(i, _)| i).unwrap_or(0);
+        carry = text[tail_start..].to_string();
+    }
+    // Same estimate as count_messages_from_text, over the whole value
+    (title, roles.max(user_turns))
+}
+
+// ── Timestamps: everything is normalized to UTC epoch milliseconds ──
+
+/// 2000-01-01 in epoch milliseconds; earlier values are bogus or zero-filled.
+const MIN_TIMESTAMP_MS: i64 = 946_684_800_000;
+/// Allowed clock skew into the future (one day).
+const MAX_FUTURE_SKEW_MS: i64 = 86_400_000;
+
+/// Epoch value in seconds, milliseconds, microseconds or nanoseconds → milliseconds,
+/// with the unit guessed from magnitude. None if the result isn't a plausible date.
+fn normalize_epoch_ms(raw: i64) -> Option<i64> {
+    let ms = match raw {
+        r if r <= 0 => return None,
+        r if r < 100_000_000_000 => r.checked_mul(1000)?,
+        r if r < 100_000_000_000_000 => r,
+        r if r < 100_000_000_000_000_000 => r / 1000,
+        r => r / 1_000_000,
+    };
+    let now = chrono::Utc::now().timestamp_millis();
+    (MIN_TIMESTAMP_MS..=now + MAX_FUTURE_SKEW_MS).contains(&ms).then_some(ms)
+}
+
+/// Local-time display form of an epoch-milliseconds timestamp.
+fn format_local_time(ms: i64) -> Option<String> {
+    use chrono::TimeZone;
+    chrono::Local.timestamp_millis_opt(ms).single().map(|t| t.format("%Y-%m-%d %H:%M").to_string())
+}
+
+/// Per-message time from the usual field names, as epoch numbers or RFC 3339 strings.
+fn message_timestamp(msg: &serde_json::Value) -> Option<i64> {
+    ["timestamp", "createdAt", "created_at", "time", "date"].iter().find_map(|k| match msg.get(*k)? {
+        serde_json::Value::Number(n) => n.as_i64().and_then(normalize_epoch_ms),
+        serde_json::Value::String(s) => chrono::DateTime::parse_from_rfc3339(s).ok().map(|d| d.timestamp_millis()),
+        _ => None,
+    })
+}
+
 fn file_modified_time(path: &Path) -> Option<i64> {
     std::fs::metadata(path)
         .ok()
         .and_then(|m| m.modified().ok())
         .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
-        .map(|d| d.as_secs() as i64)
+        .map(|d| d.as_millis() as i64)
 }
 
 fn file_size(path: &Path) -> u64 {
@@ -453,32 +539,34 @@ fn file_size(path: &Path) -> u64 {
 
 // ── Core extraction ──
 
-fn extract_from_db(db_path: &Path) -> Vec<ConversationInfo> {
+fn extract_from_db(db_path: &Path, rules: &ConversationRules, limits: &ReadLimits) -> Vec<ConversationInfo> {
+    crate::db_pool::with_read_conn(db_path, |conn| extract_from_conn(conn, db_path, rules, limits)).unwrap_or_default()
+}
+
+fn extract_from_conn(
+    conn: &Connection,
+    db_path: &Path,
+    rules: &ConversationRules,
+    limits: &ReadLimits,
+) -> Vec<ConversationInfo> {
     let mut results = Vec::new();
     let db_str = db_path.display().to_string();
     let modified = file_modified_time(db_path);
 
-    let conn = match Connection::open_with_flags(
-        db_path,
-        rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY | rusqlite::OpenFlags::SQLITE_OPEN_NO_MUTEX,
-    ) {
-        Ok(c) => c,
-        Err(_) => return results,
-    };
-
-    let tables = get_tables(&conn);
+    let tables = get_tables(conn);
     let has_disk_kv = tables.iter().any(|t| t == "cursorDiskKV");
     let mut processed_keys = HashSet::new();
 
     // ── ItemTable ──
     if tables.iter().any(|t| t == "ItemTable") {
         // 1. Aggregated chat data keys (full read + multi-format parse)
-        for key in CHAT_DATA_KEYS {
-            let size = query_value_size(&conn, "ItemTable", key);
+        for rule in &rules.chat_data_keys {
+            let key = rule.pattern.as_str();
+            let size = query_value_size(conn, "ItemTable", key);
             if size < 10 { continue; }
             processed_keys.insert(key.to_string());
-            if size < MAX_FULL_READ {
-                if let Some(value) = query_value_full(&conn, "ItemTable", key) {
+            if size < limits.max_full_read {
+                if let Some(value) = query_value_full(conn, "ItemTable", key) {
                     results.extend(parse_chat_value(&value, &db_str, key, modified));
                 }
             } else {
@@ -490,19 +578,20 @@ fn extract_from_db(db_path: &Path) -> Vec<ConversationInfo> {
                     message_count: 0,
                     size_bytes: size,
                     last_modified: modified,
+                    ..Default::default()
                 });
             }
         }
 
         // 2. Individual conversation keys (preview read)
         if !has_disk_kv {
-            for pattern in ITEM_TABLE_LIKE {
-                let entries = scan_keys_preview(&conn, "ItemTable", pattern);
+            for rule in &rules.item_table_like {
+                let entries = scan_keys_preview(conn, "ItemTable", &rule.pattern, limits.preview_len);
                 for entry in &entries {
-                    if processed_keys.contains(&entry.key) || is_ignored_key(&entry.key) { continue; }
+                    if processed_keys.contains(&entry.key) || rules.is_ignored(&entry.key) { continue; }
                     processed_keys.insert(entry.key.clone());
                     if entry.size > 20 {
-                        if let Some(conv) = extract_from_preview(entry, &db_str, modified) {
+                        if let Some(conv) = extract_from_preview(conn, "ItemTable", entry, &db_str, modified, limits) {
                             results.push(conv);
                         }
                     }
@@ -513,14 +602,14 @@ fn extract_from_db(db_path: &Path) -> Vec<ConversationInfo> {
         // 3. Discovery scan for unknown key patterns
         if results.is_empty() {
             for pattern in DISCOVERY_LIKE {
-                let entries = scan_keys_preview(&conn, "ItemTable", pattern);
+                let entries = scan_keys_preview(conn, "ItemTable", pattern, limits.preview_len);
                 for entry in &entries {
-                    if processed_keys.contains(&entry.key) || is_ignored_key(&entry.key) { continue; }
+                    if processed_keys.contains(&entry.key) || rules.is_ignored(&entry.key) { continue; }
                     processed_keys.insert(entry.key.clone());
                     if entry.size > 100 {
                         // Try full read for aggregated keys, preview for individual
-                        if entry.size < MAX_FULL_READ && entry.size > 1000 {
-                            if let Some(value) = query_value_full(&conn, "ItemTable", &entry.key) {
+                        if entry.size < limits.max_full_read && entry.size > 1000 {
+                            if let Some(value) = query_value_full(conn, "ItemTable", &entry.key) {
                                 let convs = parse_chat_value(&value, &db_str, &entry.key, modified);
                                 if !convs.is_empty() {
                                     results.extend(convs);
@@ -528,7 +617,7 @@ fn extract_from_db(db_path: &Path) -> Vec<ConversationInfo> {
                                 }
                             }
                         }
-                        if let Some(conv) = extract_from_preview(entry, &db_str, modified) {
+                        if let Some(conv) = extract_from_preview(conn, "ItemTable", entry, &db_str, modified, limits) {
                             results.push(conv);
                         }
                     }
@@ -539,7 +628,7 @@ fn extract_from_db(db_path: &Path) -> Vec<ConversationInfo> {
 
     // ── cursorDiskKV (Cursor v2.0+) ──
     if has_disk_kv {
-        results.extend(extract_cursor_disk_kv(&conn, &db_str, modified));
+        results.extend(extract_cursor_disk_kv(conn, &db_str, modified));
     }
 
     results
@@ -644,7 +733,7 @@ fn parse_cursor_composer_data(
 
     let created_at = obj.get("createdAt")
         .and_then(|v| v.as_i64())
-        .map(|ms| ms / 1000);
+        .and_then(normalize_epoch_ms);
 
     Some(ConversationInfo {
         id: format!("{}:{}:{}", db_path, key, if composer_id.is_empty() { key } else { &composer_id }),
@@ -654,6 +743,7 @@ fn parse_cursor_composer_data(
         message_count: msg_count,
         size_bytes: size,
         last_modified: created_at.or(modified),
+        ..Default::default()
     })
 }
 
@@ -663,6 +753,9 @@ fn parse_cursor_composer_data(
 pub struct ConversationMessage {
     pub role: String,
     pub content: String,
+    /// UTC epoch milliseconds, when the source records one per message.
+    #[serde(default)]
+    pub timestamp: Option<i64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -744,7 +837,7 @@ fn extract_messages_from_item(item: &serde_json::Value) -> Vec<ConversationMessa
 
             if content.is_empty() { continue; }
 
-            messages.push(ConversationMessage { role, content });
+            messages.push(ConversationMessage { role, content, timestamp: message_timestamp(msg) });
         }
     }
 
@@ -761,7 +854,7 @@ fn extract_messages_from_item(item: &serde_json::Value) -> Vec<ConversationMessa
                     .to_string();
 
                 if content.is_empty() { continue; }
-                messages.push(ConversationMessage { role, content });
+                messages.push(ConversationMessage { role, content, timestamp: message_timestamp(bubble) });
             }
         }
     }
@@ -818,35 +911,68 @@ pub fn get_conversation_content(
     source_key: &str,
     conversation_id: &str,
 ) -> Result<ConversationContent, String> {
-    let conn = Connection::open_with_flags(
-        source_db,
-        rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY | rusqlite::OpenFlags::SQLITE_OPEN_NO_MUTEX,
-    ).map_err(|e| format!("Failed to open DB: {}", e))?;
+    let cli_session = Path::new(source_db).join(source_key);
+    if Path::new(source_db).is_dir() && crate::ai_cli::is_session_file(&cli_session) {
+        return get_cli_agent_content(&cli_session);
+    }
+    if source_key.ends_with(ZED_CONTEXT_EXT) {
+        return get_zed_context_content(&Path::new(source_db).join(source_key));
+    }
+    if source_key.ends_with(".json") && is_copilot_session_dir(Path::new(source_db)) {
+        return get_copilot_session_content(&Path::new(source_db).join(source_key));
+    }
+    if source_key.ends_with(".json") && is_continue_sessions_dir(Path::new(source_db)) {
+        return get_continue_session_content(&Path::new(source_db).join(source_key));
+    }
+    if source_key.ends_with(".json") && Path::new(source_db).is_dir() {
+        return get_jetbrains_chat_content(&Path::new(source_db).join(source_key));
+    }
+    if is_cline_tasks_dir(Path::new(source_db)) {
+        return get_cline_task_content(&Path::new(source_db).join(source_key));
+    }
+    if Path::new(source_db).is_dir() {
+        return get_windsurf_cascade_content(&conversation_file_path(Path::new(source_db), source_key));
+    }
 
-    let tables = get_tables(&conn);
+    let db = Path::new(source_db);
+    let tables = crate::db_pool::with_read_conn(db, get_tables)?;
+
+    if tables.iter().any(|t| t == "threads") {
+        return crate::db_pool::with_read_conn(db, |conn| get_zed_thread_content(conn, source_key))?;
+    }
+    if is_antigravity_key(source_key) {
+        return crate::db_pool::with_read_conn(db, |conn| get_antigravity_content(conn, source_key))?;
+    }
 
     // Try to read the value from available tables
-    let value = if tables.contains(&"cursorDiskKV".to_string()) {
-        query_value_full(&conn, "cursorDiskKV", source_key)
-            .or_else(|| query_value_full(&conn, "ItemTable", source_key))
-    } else if tables.contains(&"ItemTable".to_string()) {
-        query_value_full(&conn, "ItemTable", source_key)
-    } else {
-        None
-    };
+    let value = crate::db_pool::with_read_conn(db, |conn| {
+        if tables.contains(&"cursorDiskKV".to_string()) {
+            query_value_full(conn, "cursorDiskKV", source_key)
+                .or_else(|| query_value_full(conn, "ItemTable", source_key))
+        } else if tables.contains(&"ItemTable".to_string()) {
+            query_value_full(conn, "ItemTable", source_key)
+        } else {
+            None
+        }
+    })?;
 
     let value = value.ok_or_else(|| "Key not found in database".to_string())?;
 
     let parsed: serde_json::Value = serde_json::from_str(&value)
         .map_err(|e| format!("Failed to parse JSON: {}", e))?;
 
-    // Check if this is a Cursor composerData entry (has composerId + conversationState)
-    if source_key.starts_with("composerData:") {
-        return Ok(extract_cursor_composer_content(&parsed, source_key));
+    // Cursor composerData: the transcript lives in bubbleId rows, the composer only has metadata
+    if let Some(composer_id) = source_key.strip_prefix("composerData:") {
+        let mut content = extract_cursor_composer_content(&parsed, source_key);
+        let bubbles = crate::db_pool::with_read_conn(db, |conn| read_cursor_bubbles(conn, &parsed, composer_id))?;
+        if !bubbles.is_empty() {
+            content.messages = bubbles;
+        }
+        return Ok(content);
     }
 
     // Determine if this is an aggregated key or individual conversation
-    let is_aggregated = CHAT_DATA_KEYS.contains(&source_key);
+    let is_aggregated = crate::rules::current_rules().is_chat_data_key(source_key);
 
     let (title, messages) = if is_aggregated && !conversation_id.is_empty() {
         // Find specific conversation within aggregated data
@@ -888,8 +1014,8 @@ pub fn get_conversation_content(
 }
 
 /// Extract viewable content from Cursor's composerData format.
-/// Cursor v2.0+ stores full messages in encrypted agentKv:blob BLOBs,
-/// while composerData only has metadata. We extract everything available.
+/// Used as is when the composer has no readable bubbleId rows: Cursor v2.0+
+/// may keep messages in encrypted agentKv:blob BLOBs, leaving only metadata.
 fn extract_cursor_composer_content(
     parsed: &serde_json::Value,
     source_key: &str,
@@ -920,6 +1046,7 @@ fn extract_cursor_composer_content(
             messages.push(ConversationMessage {
                 role: "system".to_string(),
                 content: overview,
+                timestamp: None,
             });
         }
     }
@@ -930,6 +1057,7 @@ fn extract_cursor_composer_content(
         messages.push(ConversationMessage {
             role: "user".to_string(),
             content: user_text,
+            timestamp: None,
         });
     }
 
@@ -939,6 +1067,7 @@ fn extract_cursor_composer_content(
             messages.push(ConversationMessage {
                 role: "assistant".to_string(),
                 content: subtitle.to_string(),
+                timestamp: None,
             });
         }
     }
@@ -956,6 +1085,7 @@ fn extract_cursor_composer_content(
             messages.push(ConversationMessage {
                 role: "assistant".to_string(),
                 content: todo_text,
+                timestamp: None,
             });
         }
     }
@@ -970,6 +1100,7 @@ fn extract_cursor_composer_content(
                 messages.push(ConversationMessage {
                     role: "system".to_string(),
                     content: format!("New files:\n  {}", file_list.join("\n  ")),
+                    timestamp: None,
                 });
             }
         }
@@ -991,6 +1122,7 @@ fn extract_cursor_composer_content(
             messages.push(ConversationMessage {
                 role: "system".to_string(),
                 content: info,
+                timestamp: None,
             });
         }
     }
@@ -999,11 +1131,73 @@ fn extract_cursor_composer_content(
     messages.push(ConversationMessage {
         role: "system".to_string(),
         content: "Note: Full conversation messages are stored in Cursor's encrypted binary format and cannot be displayed. Only metadata is shown above.".to_string(),
+        timestamp: None,
     });
 
     ConversationContent { title, messages }
 }
 
+/// The composer's `bubbleId:{composerId}:{bubbleId}` rows as messages, in the
+/// order of its `fullConversationHeadersOnly`; bubbles missing from the headers
+/// follow in key order. Empty when the composer has no readable bubbles.
+fn read_cursor_bubbles(conn: &Connection, composer: &serde_json::Value, composer_id: &str) -> Vec<ConversationMessage> {
+    // Range rather than LIKE so the key index is used; ';' sorts right after ':'
+    let prefix = format!("bubbleId:{}:", composer_id);
+    let upper = format!("bubbleId:{};", composer_id);
+    let mut bubbles: Vec<(String, serde_json::Value)> = conn
+        .prepare("SELECT key, value FROM cursorDiskKV WHERE key >= ?1 AND key < ?2 ORDER BY key")
+        .and_then(|mut stmt| {
+            let rows = stmt.query_map([&prefix, &upper], |row| {
+                let value = match row.get_ref(1)? {
+                    rusqlite::types::ValueRef::Text(b) | rusqlite::types::ValueRef::Blob(b) => String::from_utf8_lossy(b).into_owned(),
+                    _ => String::new(),
+                };
+                Ok((row.get::<_, String>(0)?, value))
+            })?;
+            Ok(rows
+                .filter_map(|r| r.ok())
+                .filter_map(|(key, value)| Some((key[prefix.len()..].to_string(), serde_json::from_str(&value).ok()?)))
+                .collect())
+        })
+        .unwrap_or_default();
+
+    let order: HashMap<&str, usize> = composer
+        .get("fullConversationHeadersOnly")
+        .and_then(|v| v.as_array())
+        .map(|headers| {
+            headers
+                .iter()
+                .enumerate()
+                .filter_map(|(i, h)| Some((h.get("bubbleId")?.as_str()?, i)))
+                .collect()
+        })
+        .unwrap_or_default();
+    // Stable, so unlisted bubbles keep their key order after the listed ones
+    bubbles.sort_by_key(|(id, _)| order.get(id.as_str()).copied().unwrap_or(usize::MAX));
+
+    bubbles.iter().filter_map(|(_, bubble)| cursor_bubble_message(bubble)).collect()
+}
+
+fn cursor_bubble_message(bubble: &serde_json::Value) -> Option<ConversationMessage> {
+    let role = match bubble.get("type").and_then(|v| v.as_i64()) {
+        Some(1) => "user".to_string(),
+        Some(2) => "assistant".to_string(),
+        _ => normalize_role(bubble),
+    };
+    let text = ["text", "rawText"]
+        .iter()
+        .filter_map(|k| bubble.get(*k).and_then(|v| v.as_str()))
+        .find(|t| !t.trim().is_empty())
+        .map(str::to_string);
+    // Agent steps without prose: show which tool ran rather than nothing
+    let content = text.or_else(|| {
+        let tool = bubble.get("toolFormerData")?;
+        let name = tool.get("name").and_then(|v| v.as_str()).unwrap_or("tool");
+        Some(format!("[{}]", name))
+    })?;
+    Some(ConversationMessage { role, content, timestamp: message_timestamp(bubble) })
+}
+
 /// Extract user text from Cursor composerData, trying text field then richText (Lexical).
 fn extract_user_text_from_composer(obj: Option<&serde_json::Map<String, serde_json::Value>>) -> String {
     // Try plain text field first
@@ -1035,194 +1229,1813 @@ fn extract_user_text_from_composer(obj: Option<&serde_json::Map<String, serde_js
     String::new()
 }
 
-// ── Delete conversations ──
+// ── Zed (threads/threads.db + conversations/*.zed.json) ──
 
-pub fn delete_conversation(source_db: &str, source_key: &str) -> Result<u64, String> {
-    let db_path = Path::new(source_db);
+const ZED_CONTEXT_EXT: &str = ".zed.json";
 
-    // Windsurf .pb files: source_db is the cascade directory, source_key is the UUID filename
-    if db_path.is_dir() {
-        let pb_file = db_path.join(format!("{}.pb", source_key));
-        if pb_file.exists() {
-            let size = file_size(&pb_file);
-            std::fs::remove_file(&pb_file)
-                .map_err(|e| format!("Failed to delete .pb file: {}", e))?;
-            return Ok(size);
-        }
-        return Err("File not found".into());
-    }
+/// Decode a row of Zed's `threads` table. Newer builds zstd-compress the JSON.
+fn decode_zed_thread_data(data_type: &str, data: &[u8]) -> Option<serde_json::Value> {
+    let bytes = if data_type == "zstd" {
+        zstd::stream::decode_all(data).ok()?
+    } else {
+        data.to_vec()
+    };
+    serde_json::from_slice(&bytes).ok()
+}
 
-    // SQLite-based deletion
-    let conn = Connection::open(source_db)
-        .map_err(|e| format!("Failed to open DB: {}", e))?;
+fn parse_rfc3339_ms(s: &str) -> Option<i64> {
+    chrono::DateTime::parse_from_rfc3339(s).ok().map(|d| d.timestamp_millis())
+}
 
-    let tables = get_tables(&conn);
-    let size = query_value_size(&conn, "ItemTable", source_key)
-        .max(query_value_size(&conn, "cursorDiskKV", source_key));
+/// Messages of a Zed agent thread. Older threads use `{role, segments}`,
+/// newer ones wrap each message as `{"User": {...}}` / `{"Agent": {...}}`.
+fn extract_zed_thread_messages(thread: &serde_json::Value) -> Vec<ConversationMessage> {
+    let mut messages = Vec::new();
+    let arr = match thread.get("messages").and_then(|m| m.as_array()) {
+        Some(a) => a,
+        None => return messages,
+    };
 
-    for table in &["ItemTable", "cursorDiskKV"] {
-        if tables.contains(&table.to_string()) {
-            let sql = format!("DELETE FROM [{}] WHERE key = ?1", table);
-            if let Ok(count) = conn.execute(&sql, [source_key]) {
-                if count > 0 {
-                    let _ = conn.execute_batch("VACUUM");
-                    return Ok(size);
+    for msg in arr {
+        let (role, body) = if msg.get("role").is_some() {
+            (normalize_role(msg), msg)
+        } else if let Some(obj) = msg.as_object() {
+            match obj.iter().next() {
+                Some((kind, body)) => {
+                    let role = match kind.as_str() {
+                        "User" => "user",
+                        "Agent" => "assistant",
+                        _ => "system",
+                    };
+                    (role.to_string(), body)
                 }
+                None => continue,
             }
+        } else {
+            continue;
+        };
+
+        let parts: Vec<String> = body.get("segments")
+            .or_else(|| body.get("content"))
+            .and_then(|c| c.as_array())
+            .map(|items| {
+                items.iter()
+                    .filter_map(|item| {
+                        // {"type": "text", "text": "..."} or {"Text": "..."}
+                        if item.get("type").and_then(|t| t.as_str()) == Some("text") {
+                            return item.get("text").and_then(|t| t.as_str()).map(|t| t.to_string());
+                        }
+                        item.get("Text").and_then(|t| t.as_str()).map(|t| t.to_string())
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let content = if parts.is_empty() {
+            body.get("text").map(extract_message_content).unwrap_or_default()
+        } else {
+            parts.join("\n")
+        };
+        if content.is_empty() { continue; }
+        messages.push(ConversationMessage { role, content, timestamp: message_timestamp(body) });
+    }
+    messages
+}
+
+fn extract_zed_threads(db_path: &Path) -> Vec<ConversationInfo> {
+    crate::db_pool::with_read_conn(db_path, |conn| extract_zed_threads_from_conn(conn, db_path)).unwrap_or_default()
+}
+
+fn extract_zed_threads_from_conn(conn: &Connection, db_path: &Path) -> Vec<ConversationInfo> {
+    let db_str = db_path.display().to_string();
+    let mut stmt = match conn.prepare("SELECT id, summary, updated_at, data_type, data FROM threads") {
+        Ok(s) => s,
+        Err(_) => return Vec::new(),
+    };
+    let rows = stmt.query_map([], |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            row.get::<_, String>(1).unwrap_or_default(),
+            row.get::<_, String>(2).unwrap_or_default(),
+            row.get::<_, String>(3).unwrap_or_default(),
+            row.get::<_, Vec<u8>>(4).unwrap_or_default(),
+        ))
+    });
+
+    let mut results = Vec::new();
+    if let Ok(rows) = rows {
+        for (id, summary, updated_at, data_type, data) in rows.filter_map(|r| r.ok()) {
+            let message_count = decode_zed_thread_data(&data_type, &data)
+                .and_then(|t| t.get("messages").and_then(|m| m.as_array()).map(|m| m.len()))
+                .unwrap_or(0);
+            results.push(ConversationInfo {
+                id: format!("zed:{}", id),
+                title: if summary.is_empty() { format!("Thread {}", id.chars().take(8).collect::<String>()) } else { summary },
+                source_db: db_str.clone(),
+                source_key: id,
+                message_count,
+                size_bytes: data.len() as u64,
+                last_modified: parse_rfc3339_ms(&updated_at),
+                ..Default::default()
+            });
         }
     }
+    results
+}
 
-    Err("Conversation key not found".into())
+/// Zed text threads: one JSON file per conversation. The whole buffer lives in
+/// `text`; each message records the byte offset where it starts.
+fn extract_zed_context(path: &Path, dir_str: &str) -> Option<ConversationInfo> {
+    let fname = path.file_name()?.to_string_lossy().to_string();
+    let data = std::fs::read_to_string(path).ok()?;
+    let parsed: serde_json::Value = serde_json::from_str(&data).ok()?;
+    let title = parsed.get("summary")
+        .and_then(|s| s.as_str())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_string())
+        .unwrap_or_else(|| fname.trim_end_matches(ZED_CONTEXT_EXT).to_string());
+    Some(ConversationInfo {
+        id: format!("zedctx:{}", fname),
+        title,
+        source_db: dir_str.to_string(),
+        source_key: fname,
+        message_count: parsed.get("messages").and_then(|m| m.as_array()).map(|m| m.len()).unwrap_or(0),
+        size_bytes: data.len() as u64,
+        last_modified: file_modified_time(path),
+        ..Default::default()
+    })
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct BatchDeleteRequest {
-    pub source_db: String,
-    pub source_key: String,
+fn get_zed_context_content(path: &Path) -> Result<ConversationContent, String> {
+    let data = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read conversation: {}", e))?;
+    let parsed: serde_json::Value = serde_json::from_str(&data)
+        .map_err(|e| format!("Failed to parse JSON: {}", e))?;
+
+    let text = parsed.get("text").and_then(|t| t.as_str()).unwrap_or("");
+    let starts: Vec<(usize, String)> = parsed.get("messages")
+        .and_then(|m| m.as_array())
+        .map(|arr| {
+            arr.iter()
+                .map(|m| {
+                    let start = m.get("start").and_then(|s| s.as_u64()).unwrap_or(0) as usize;
+                    let role = m.get("metadata").map(normalize_role).unwrap_or_else(|| "unknown".into());
+                    (start, role)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut messages = Vec::new();
+    for (i, (start, role)) in starts.iter().enumerate() {
+        let end = starts.get(i + 1).map(|(s, _)| *s).unwrap_or(text.len()).min(text.len());
+        let content = text.get((*start).min(end)..end).unwrap_or("").trim();
+        if content.is_empty() { continue; }
+        messages.push(ConversationMessage { role: role.clone(), content: content.to_string(), timestamp: None });
+    }
+
+    let fname = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+    let title = parsed.get("summary")
+        .and_then(|s| s.as_str())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_string())
+        .unwrap_or_else(|| fname.trim_end_matches(ZED_CONTEXT_EXT).to_string());
+    Ok(ConversationContent { title, messages })
 }
 
-pub fn delete_conversations_batch(items: &[BatchDeleteRequest]) -> Result<u64, String> {
-    let mut total_freed: u64 = 0;
-    let mut errors = Vec::new();
+fn get_zed_thread_content(conn: &Connection, id: &str) -> Result<ConversationContent, String> {
+    let (summary, data_type, data) = conn.query_row(
+        "SELECT summary, data_type, data FROM threads WHERE id = ?1",
+        [id],
+        |row| Ok((
+            row.get::<_, String>(0).unwrap_or_default(),
+            row.get::<_, String>(1).unwrap_or_default(),
+            row.get::<_, Vec<u8>>(2).unwrap_or_default(),
+        )),
+    ).map_err(|_| "Thread not found in database".to_string())?;
+
+    let thread = decode_zed_thread_data(&data_type, &data)
+        .ok_or_else(|| "Failed to decode thread data".to_string())?;
+    Ok(ConversationContent {
+        title: if summary.is_empty() { id.to_string() } else { summary },
+        messages: extract_zed_thread_messages(&thread),
+    })
+}
 
-    // Group by source_db to minimize DB open/close and batch VACUUM
-    let mut groups: std::collections::HashMap<String, Vec<String>> = std::collections::HashMap::new();
-    for item in items {
-        groups.entry(item.source_db.clone()).or_default().push(item.source_key.clone());
+/// Delete a Zed agent thread, returning the size of its data.
+fn delete_zed_thread(conn: &Connection, id: &str) -> Option<u64> {
+    let size = conn.query_row("SELECT length(data) FROM threads WHERE id = ?1", [id], |row| row.get::<_, i64>(0))
+        .unwrap_or(0) as u64;
+    match conn.execute("DELETE FROM threads WHERE id = ?1", [id]) {
+        Ok(count) if count > 0 => Some(size),
+        _ => None,
     }
+}
 
-    for (source_db, keys) in &groups {
-        let db_path = Path::new(source_db);
+// ── JetBrains AI Assistant: one JSON file per chat session ──
 
-        if db_path.is_dir() {
-            // Windsurf .pb files
-            for key in keys {
-                let pb_file = db_path.join(format!("{}.pb", key));
-                if pb_file.exists() {
-                    let size = file_size(&pb_file);
-                    match std::fs::remove_file(&pb_file) {
-                        Ok(_) => total_freed += size,
-                        Err(e) => errors.push(format!("{}: {}", key, e)),
-                    }
-                }
-            }
-        } else if db_path.exists() {
-            // SQLite DB
-            let conn = match Connection::open(source_db) {
-                Ok(c) => c,
-                Err(e) => { errors.push(format!("{}: {}", source_db, e)); continue; }
-            };
-            let tables = get_tables(&conn);
-            for key in keys {
-                for table in &["ItemTable", "cursorDiskKV"] {
-                    if !tables.contains(&table.to_string()) { continue; }
-                    let size = query_value_size(&conn, table, key);
-                    let sql = format!("DELETE FROM [{}] WHERE key = ?1", table);
-                    if let Ok(count) = conn.execute(&sql, [key.as_str()]) {
-                        if count > 0 {
-                            total_freed += size;
-                            break;
-                        }
-                    }
-                }
-            }
-            let _ = conn.execute_batch("VACUUM");
+/// AI Assistant writes chat sessions as JSON files into `aia*` / `*chat*` folders
+/// of the system dir (globally, or per project under `projects/<name>.<hash>/`).
+const JETBRAINS_CHAT_DIR_PREFIX: &str = "aia";
+const JETBRAINS_CHAT_DIR_MARKER: &str = "chat";
+const JETBRAINS_CHAT_SEARCH_DEPTH: usize = 4;
+
+fn is_json_file(path: &Path) -> bool {
+    path.is_file() && path.extension().map(|e| e == "json").unwrap_or(false)
+}
+
+/// Chat session folders in every version's system dir.
+fn jetbrains_chat_dirs(ide: &crate::ide_detector::IdeInfo) -> Vec<std::path::PathBuf> {
+    let mut dirs = Vec::new();
+    for system_dir in ide.versioned_folders.iter().filter_map(|vf| vf.cache_path.as_ref()) {
+        for entry in walkdir::WalkDir::new(system_dir)
+            .max_depth(JETBRAINS_CHAT_SEARCH_DEPTH)
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_dir())
+        {
+            let name = entry.file_name().to_string_lossy().to_lowercase();
+            if !name.starts_with(JETBRAINS_CHAT_DIR_PREFIX) && !name.contains(JETBRAINS_CHAT_DIR_MARKER) { continue; }
+            let has_json = std::fs::read_dir(entry.path())
+                .map(|d| d.filter_map(|e| e.ok()).any(|e| is_json_file(&e.path())))
+                .unwrap_or(false);
+            if has_json { dirs.push(entry.into_path()); }
         }
     }
+    dirs
+}
 
-    if !errors.is_empty() && total_freed == 0 {
-        return Err(errors.join("; "));
-    }
-    Ok(total_freed)
+fn jetbrains_chat_title(parsed: &serde_json::Value, messages: &[ConversationMessage]) -> String {
+    parsed.get("title")
+        .or_else(|| parsed.get("name"))
+        .or_else(|| parsed.get("chatTitle"))
+        .and_then(|t| t.as_str())
+        .map(|t| t.to_string())
+        .filter(|t| !t.is_empty())
+        .or_else(|| messages.iter().find(|m| m.role == "user").map(|m| m.content.chars().take(80).collect()))
+        .unwrap_or_default()
 }
 
-// ── Public API ──
+fn extract_jetbrains_chat(path: &Path, dir_str: &str) -> Option<ConversationInfo> {
+    let fname = path.file_name()?.to_string_lossy().to_string();
+    let data = std::fs::read_to_string(path).ok()?;
+    let parsed: serde_json::Value = serde_json::from_str(&data).ok()?;
+    let messages = extract_messages_from_item(&parsed);
+    if messages.is_empty() { return None; }
+    let title = jetbrains_chat_title(&parsed, &messages);
+    Some(ConversationInfo {
+        id: format!("jb:{}:{}", dir_str, fname),
+        title: if title.is_empty() { fname.trim_end_matches(".json").to_string() } else { title },
+        source_db: dir_str.to_string(),
+        source_key: fname,
+        message_count: messages.len(),
+        size_bytes: data.len() as u64,
+        last_modified: file_modified_time(path),
+        ..Default::default()
+    })
+}
 
-pub fn scan_conversations(ide: &crate::ide_detector::IdeInfo) -> ConversationListResult {
-    let mut conversations = Vec::new();
-    let mut db_files = Vec::new();
-    let mut total_size: u64 = 0;
+fn get_jetbrains_chat_content(path: &Path) -> Result<ConversationContent, String> {
+    let data = std::fs::read_to_string(path).map_err(|e| format!("Failed to read chat: {}", e))?;
+    let parsed: serde_json::Value = serde_json::from_str(&data)
+        .map_err(|e| format!("Failed to parse JSON: {}", e))?;
+    let messages = extract_messages_from_item(&parsed);
+    let title = jetbrains_chat_title(&parsed, &messages);
+    Ok(ConversationContent {
+        title: if title.is_empty() { path.file_name().unwrap_or_default().to_string_lossy().to_string() } else { title },
+        messages,
+    })
+}
 
-    // ── globalStorage/state.vscdb (MAIN database) ──
-    if let Some(ref gs) = ide.global_storage_path {
-        let db = gs.join("state.vscdb");
-        if db.exists() {
-            let size = file_size(&db);
-            total_size += size;
-            db_files.push(DbFileInfo {
-                path: db.display().to_string(),
-                size,
-                name: "globalStorage/state.vscdb".into(),
-                modified: file_modified_time(&db),
-            });
-            conversations.extend(extract_from_db(&db));
-        }
+// ── Copilot Chat sessions: workspaceStorage/<hash>/chatSessions/*.json ──
 
-        let backup = gs.join("state.vscdb.backup");
-        if backup.exists() {
-            let size = file_size(&backup);
-            total_size += size;
-            db_files.push(DbFileInfo {
-                path: backup.display().to_string(),
-                size,
-                name: "globalStorage/state.vscdb.backup".into(),
-                modified: file_modified_time(&backup),
-            });
+/// Newer VS Code keeps chat sessions out of state.vscdb, one JSON file per
+/// session; Copilot Edits snapshots go to a sibling `chatEditingSessions/<id>/`.
+const COPILOT_SESSION_DIRS: &[&str] = &["chatSessions", "emptyWindowChatSessions"];
+const COPILOT_EDITING_DIR: &str = "chatEditingSessions";
+
+fn is_copilot_session_dir(dir: &Path) -> bool {
+    dir.file_name().map(|n| COPILOT_SESSION_DIRS.iter().any(|d| n == *d)).unwrap_or(false)
+}
+
+/// The Copilot Edits folder belonging to a session file, if there is one.
+fn copilot_editing_dir(session_file: &Path) -> Option<std::path::PathBuf> {
+    let dir = session_file.parent()?;
+    if dir.file_name()? != "chatSessions" { return None; }
+    let editing = dir.parent()?.join(COPILOT_EDITING_DIR).join(session_file.file_stem()?);
+    editing.is_dir().then_some(editing)
+}
+
+fn dir_size(path: &Path) -> u64 {
+    walkdir::WalkDir::new(path)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| e.metadata().ok())
+        .map(|m| m.len())
+        .sum()
+}
+
+/// Text of a markdown part: either `{value}` or `{kind, content: {value}}`.
+fn copilot_part_text(part: &serde_json::Value) -> Option<String> {
+    let text = match part.get("value").or_else(|| part.get("content").and_then(|c| c.get("value"))) {
+        Some(serde_json::Value::String(s)) => s.clone(),
+        _ => {
+            // Agent tool calls carry a one-line description instead of markdown
+            let message = part.get("pastTenseMessage").or_else(|| part.get("invocationMessage"))?;
+            let text = message.as_str().or_else(|| message.get("value").and_then(|v| v.as_str()))?;
+            format!("[{}]", text)
         }
-    }
+    };
+    (!text.trim().is_empty()).then_some(text)
+}
 
-    // ── workspaceStorage/*/state.vscdb ──
-    if let Some(ref ws) = ide.workspace_storage_path {
-        if ws.exists() {
-            if let Ok(entries) = std::fs::read_dir(ws) {
-                for entry in entries.filter_map(|e| e.ok()) {
-                    if !entry.file_type().map(|t| t.is_dir()).unwrap_or(false) { continue; }
-                    let db = entry.path().join("state.vscdb");
-                    if !db.exists() { continue; }
-                    let size = file_size(&db);
-                    if size < 1024 { continue; }
-                    total_size += size;
-                    let hash = entry.file_name().to_string_lossy().to_string();
-                    let short_hash = if hash.len() > 8 { &hash[..8] } else { &hash };
-                    db_files.push(DbFileInfo {
-                        path: db.display().to_string(),
-                        size,
-                        name: format!("workspaceStorage/{}/state.vscdb", short_hash),
-                        modified: file_modified_time(&db),
-                    });
-                    conversations.extend(extract_from_db(&db));
-                }
-            }
+/// Each request becomes the user's message followed by the joined response parts.
+fn copilot_session_messages(session: &serde_json::Value) -> Vec<ConversationMessage> {
+    let mut messages = Vec::new();
+    for request in session.get("requests").and_then(|r| r.as_array()).into_iter().flatten() {
+        let timestamp = message_timestamp(request);
+        let prompt = request.get("message").and_then(|m| m.get("text")).and_then(|t| t.as_str()).unwrap_or_default();
+        if !prompt.trim().is_empty() {
+            messages.push(ConversationMessage { role: "user".into(), content: prompt.to_string(), timestamp });
+        }
+        let reply: Vec<String> = request
+            .get("response")
+            .and_then(|r| r.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(copilot_part_text)
+            .collect();
+        if !reply.is_empty() {
+            messages.push(ConversationMessage { role: "assistant".into(), content: reply.join(""), timestamp });
         }
     }
+    messages
+}
 
-    // ── Windsurf: ~/.codeium/windsurf/cascade/*.pb (protobuf conversation files) ──
-    if ide.id == "windsurf" {
-        if let Some(home) = std::env::var_os("USERPROFILE")
-            .or_else(|| std::env::var_os("HOME"))
-        {
-            let cascade_dir = Path::new(&home).join(".codeium/windsurf/cascade");
-            if cascade_dir.exists() {
-                if let Ok(entries) = std::fs::read_dir(&cascade_dir) {
-                    for entry in entries.filter_map(|e| e.ok()) {
-                        let path = entry.path();
-                        if path.extension().map(|e| e == "pb").unwrap_or(false) {
-                            let size = file_size(&path);
-                            if size < 50 { continue; }
-                            let modified = file_modified_time(&path);
-                            let fname = path.file_stem()
-                                .unwrap_or_default().to_string_lossy().to_string();
-                            total_size += size;
-                            conversations.push(ConversationInfo {
-                                id: format!("pb:{}:{}", cascade_dir.display(), fname),
-                                title: format!("Cascade {}", &fname[..8.min(fname.len())]),
-                                source_db: cascade_dir.display().to_string(),
-                                source_key: fname.clone(),
-                                message_count: 0,
-                                size_bytes: size,
-                                last_modified: modified,
-                            });
-                        }
+fn copilot_session_title(session: &serde_json::Value, messages: &[ConversationMessage]) -> String {
+    session.get("customTitle")
+        .and_then(|t| t.as_str())
+        .filter(|t| !t.is_empty())
+        .map(|t| t.to_string())
+        .or_else(|| messages.iter().find(|m| m.role == "user").map(|m| m.content.lines().next().unwrap_or_default().chars().take(80).collect()))
+        .unwrap_or_default()
+}
+
+fn extract_copilot_session(path: &Path, dir_str: &str) -> Option<ConversationInfo> {
+    let fname = path.file_name()?.to_string_lossy().to_string();
+    let data = std::fs::read_to_string(path).ok()?;
+    let session: serde_json::Value = serde_json::from_str(&data).ok()?;
+    let messages = copilot_session_messages(&session);
+    if messages.is_empty() { return None; }
+    let title = copilot_session_title(&session, &messages);
+    let editing_size = copilot_editing_dir(path).map(|d| dir_size(&d)).unwrap_or(0);
+    Some(ConversationInfo {
+        id: format!("copilot:{}:{}", dir_str, fname),
+        title: if title.is_empty() { fname.trim_end_matches(".json").to_string() } else { title },
+        source_db: dir_str.to_string(),
+        source_key: fname,
+        message_count: messages.len(),
+        size_bytes: data.len() as u64 + editing_size,
+        last_modified: session.get("lastMessageDate")
+            .and_then(|v| v.as_i64())
+            .and_then(normalize_epoch_ms)
+            .or_else(|| file_modified_time(path)),
+        ..Default::default()
+    })
+}
+
+fn get_copilot_session_content(path: &Path) -> Result<ConversationContent, String> {
+    let data = std::fs::read_to_string(path).map_err(|e| format!("Failed to read chat session: {}", e))?;
+    let session: serde_json::Value = serde_json::from_str(&data)
+        .map_err(|e| format!("Failed to parse JSON: {}", e))?;
+    let messages = copilot_session_messages(&session);
+    let title = copilot_session_title(&session, &messages);
+    Ok(ConversationContent {
+        title: if title.is_empty() { path.file_name().unwrap_or_default().to_string_lossy().to_string() } else { title },
+        messages,
+    })
+}
+
+/// Sessions in one chat session folder, and their combined size.
+fn scan_copilot_sessions(dir: &Path) -> (Vec<ConversationInfo>, u64) {
+    let dir_str = dir.display().to_string();
+    let mut conversations = Vec::new();
+    let mut size: u64 = 0;
+    if let Ok(entries) = std::fs::read_dir(dir) {
+        for entry in entries.filter_map(|e| e.ok()) {
+            let path = entry.path();
+            if !is_json_file(&path) { continue; }
+            if let Some(conv) = extract_copilot_session(&path, &dir_str) {
+                size += conv.size_bytes;
+                conversations.push(conv);
+            }
+        }
+    }
+    (conversations, size)
+}
+
+// ── Cline and Roo Code: globalStorage/<extension>/tasks/<id>/ ──
+
+/// Extension folders holding Cline-format task folders (Roo Code is a Cline fork).
+const CLINE_EXTENSION_IDS: &[&str] = &["saoudrizwan.claude-dev", "rooveterinaryinc.roo-cline"];
+/// Anthropic-format message list: the transcript.
+const CLINE_HISTORY_FILE: &str = "api_conversation_history.json";
+/// What the sidebar shows; its first entry is the task prompt.
+const CLINE_UI_FILE: &str = "ui_messages.json";
+/// Task list the extension renders its history view from.
+const CLINE_TASK_HISTORY_FILE: &str = "state/taskHistory.json";
+
+fn is_cline_tasks_dir(dir: &Path) -> bool {
+    dir.file_name().map(|n| n == "tasks").unwrap_or(false)
+        && dir.parent().and_then(|p| p.file_name()).map(|n| CLINE_EXTENSION_IDS.iter().any(|id| n == *id)).unwrap_or(false)
+}
+
+fn read_json_within(path: &Path, limit: u64) -> Option<serde_json::Value> {
+    if file_size(path) > limit { return None; }
+    serde_json::from_str(&std::fs::read_to_string(path).ok()?).ok()
+}
+
+/// Prose of one content block; tool calls show their name, tool output is left out.
+fn cline_block_text(block: &serde_json::Value) -> Option<String> {
+    match block.get("type").and_then(|t| t.as_str()) {
+        Some("text") => block.get("text").and_then(|t| t.as_str()).map(str::to_string),
+        Some("tool_use") => Some(format!("[{}]", block.get("name").and_then(|n| n.as_str()).unwrap_or("tool"))),
+        _ => None,
+    }
+}
+
+fn cline_messages(history: &serde_json::Value) -> Vec<ConversationMessage> {
+    history.as_array().into_iter().flatten().filter_map(|msg| {
+        let content = match msg.get("content")? {
+            serde_json::Value::String(s) => s.clone(),
+            serde_json::Value::Array(blocks) => blocks.iter().filter_map(cline_block_text).collect::<Vec<_>>().join("\n\n"),
+            _ => return None,
+        };
+        if content.trim().is_empty() { return None; }
+        Some(ConversationMessage { role: normalize_role(msg), content, timestamp: message_timestamp(msg) })
+    }).collect()
+}
+
+/// The task prompt: first sidebar message, else the first user message.
+fn cline_task_title(task_dir: &Path, messages: &[ConversationMessage], limit: u64) -> Option<String> {
+    let ui_title = read_json_within(&task_dir.join(CLINE_UI_FILE), limit).and_then(|ui| {
+        ui.as_array()?.iter().find_map(|m| m.get("text").and_then(|t| t.as_str()).filter(|t| !t.trim().is_empty()).map(str::to_string))
+    });
+    ui_title
+        .or_else(|| messages.iter().find(|m| m.role == "user").map(|m| m.content.clone()))
+        // The API copy wraps the prompt in <task> tags
+        .map(|t| t.trim().trim_start_matches("<task>").trim().lines().next().unwrap_or_default().chars().take(80).collect())
+}
+
+fn extract_cline_task(task_dir: &Path, dir_str: &str, limits: &ReadLimits) -> Option<ConversationInfo> {
+    let id = task_dir.file_name()?.to_string_lossy().to_string();
+    let history_path = task_dir.join(CLINE_HISTORY_FILE);
+    if !history_path.exists() && !task_dir.join(CLINE_UI_FILE).exists() { return None; }
+    // Transcripts run to hundreds of MB; past the ceiling only the size is reported
+    let messages = read_json_within(&history_path, limits.max_full_read).map(|h| cline_messages(&h)).unwrap_or_default();
+    let title = cline_task_title(task_dir, &messages, limits.max_full_read);
+    Some(ConversationInfo {
+        id: format!("cline:{}:{}", dir_str, id),
+        title: title.unwrap_or_else(|| format!("Task {}", id)),
+        source_db: dir_str.to_string(),
+        source_key: id,
+        message_count: messages.len(),
+        size_bytes: dir_size(task_dir),
+        last_modified: file_modified_time(&history_path).or_else(|| file_modified_time(task_dir)),
+        ..Default::default()
+    })
+}
+
+fn get_cline_task_content(task_dir: &Path) -> Result<ConversationContent, String> {
+    let data = std::fs::read_to_string(task_dir.join(CLINE_HISTORY_FILE)).map_err(|e| format!("Failed to read task history: {}", e))?;
+    let history: serde_json::Value = serde_json::from_str(&data)
+        .map_err(|e| format!("Failed to parse JSON: {}", e))?;
+    let messages = cline_messages(&history);
+    let title = cline_task_title(task_dir, &messages, u64::MAX)
+        .unwrap_or_else(|| task_dir.file_name().unwrap_or_default().to_string_lossy().to_string());
+    Ok(ConversationContent { title, messages })
+}
+
+/// Drop a conversation from the tool's own list (a JSON array of objects keyed
+/// by `id_field`) so it doesn't show a dead entry. The extension may be running,
+/// so the list is written next to the original and renamed over it: a failed
+/// write leaves the old list whole.
+fn prune_index_entry(index_path: &Path, id_field: &str, id: &str) -> Result<(), String> {
+    let Some(serde_json::Value::Array(mut entries)) = read_json_within(index_path, u64::MAX) else { return Ok(()) };
+    let before = entries.len();
+    entries.retain(|e| e.get(id_field).and_then(|v| v.as_str()) != Some(id));
+    if entries.len() == before {
+        return Ok(());
+    }
+    let data = serde_json::to_string(&entries).map_err(|e| format!("Failed to update {}: {}", index_path.display(), e))?;
+    let mut tmp_name = index_path.file_name().unwrap_or_default().to_os_string();
+    tmp_name.push(".tmp");
+    let tmp = index_path.with_file_name(tmp_name);
+    std::fs::write(&tmp, data).and_then(|_| std::fs::rename(&tmp, index_path)).map_err(|e| {
+        let _ = std::fs::remove_file(&tmp);
+        format!("Failed to update {}: {}", index_path.display(), e)
+    })
+}
+
+/// Task folders under one extension's `tasks/` dir, and their combined size.
+fn scan_cline_tasks(dir: &Path, limits: &ReadLimits) -> (Vec<ConversationInfo>, u64) {
+    let dir_str = dir.display().to_string();
+    let mut conversations = Vec::new();
+    let mut size: u64 = 0;
+    if let Ok(entries) = std::fs::read_dir(dir) {
+        for entry in entries.filter_map(|e| e.ok()) {
+            if !entry.file_type().map(|t| t.is_dir()).unwrap_or(false) { continue; }
+            if let Some(conv) = extract_cline_task(&entry.path(), &dir_str, limits) {
+                size += conv.size_bytes;
+                conversations.push(conv);
+            }
+        }
+    }
+    (conversations, size)
+}
+
+// ── Continue: ~/.continue/sessions/<id>.json ──
+
+/// Listed index of sessions, next to the session files.
+const CONTINUE_INDEX_FILE: &str = "sessions.json";
+/// Title Continue gives every session until it names one.
+const CONTINUE_DEFAULT_TITLE: &str = "New Session";
+
+fn continue_sessions_dir() -> Option<std::path::PathBuf> {
+    dirs::home_dir().map(|h| h.join(".continue").join("sessions")).filter(|d| d.is_dir())
+}
+
+fn is_continue_sessions_dir(dir: &Path) -> bool {
+    dir.file_name().map(|n| n == "sessions").unwrap_or(false)
+        && dir.parent().and_then(|p| p.file_name()).map(|n| n == ".continue").unwrap_or(false)
+}
+
+fn continue_session_messages(session: &serde_json::Value) -> Vec<ConversationMessage> {
+    session.get("history").and_then(|h| h.as_array()).into_iter().flatten().filter_map(|item| {
+        let message = item.get("message")?;
+        let content = extract_message_content(message.get("content")?);
+        if content.trim().is_empty() { return None; }
+        Some(ConversationMessage { role: normalize_role(message), content, timestamp: message_timestamp(item) })
+    }).collect()
+}
+
+fn continue_session_title(session: &serde_json::Value, messages: &[ConversationMessage]) -> String {
+    session.get("title")
+        .and_then(|t| t.as_str())
+        .filter(|t| !t.is_empty() && *t != CONTINUE_DEFAULT_TITLE)
+        .map(|t| t.to_string())
+        .or_else(|| messages.iter().find(|m| m.role == "user").map(|m| m.content.lines().next().unwrap_or_default().chars().take(80).collect()))
+        .unwrap_or_default()
+}
+
+fn extract_continue_session(path: &Path, dir_str: &str, limits: &ReadLimits) -> Option<ConversationInfo> {
+    let fname = path.file_name()?.to_string_lossy().to_string();
+    if fname == CONTINUE_INDEX_FILE { return None; }
+    let size = file_size(path);
+    let session = read_json_within(path, limits.max_full_read);
+    let messages = session.as_ref().map(continue_session_messages).unwrap_or_default();
+    let title = session.as_ref().map(|s| continue_session_title(s, &messages)).unwrap_or_default();
+    Some(ConversationInfo {
+        id: format!("continue:{}:{}", dir_str, fname),
+        title: if title.is_empty() { fname.trim_end_matches(".json").to_string() } else { title },
+        source_db: dir_str.to_string(),
+        source_key: fname,
+        message_count: messages.len(),
+        size_bytes: size,
+        last_modified: file_modified_time(path),
+        ..Default::default()
+    })
+}
+
+fn get_continue_session_content(path: &Path) -> Result<ConversationContent, String> {
+    let data = std::fs::read_to_string(path).map_err(|e| format!("Failed to read session: {}", e))?;
+    let session: serde_json::Value = serde_json::from_str(&data)
+        .map_err(|e| format!("Failed to parse JSON: {}", e))?;
+    let messages = continue_session_messages(&session);
+    let title = continue_session_title(&session, &messages);
+    Ok(ConversationContent {
+        title: if title.is_empty() { path.file_name().unwrap_or_default().to_string_lossy().to_string() } else { title },
+        messages,
+    })
+}
+
+fn scan_continue_sessions(dir: &Path, limits: &ReadLimits) -> (Vec<ConversationInfo>, u64) {
+    let dir_str = dir.display().to_string();
+    let mut conversations = Vec::new();
+    let mut size: u64 = 0;
+    if let Ok(entries) = std::fs::read_dir(dir) {
+        for entry in entries.filter_map(|e| e.ok()) {
+            let path = entry.path();
+            if !is_json_file(&path) { continue; }
+            if let Some(conv) = extract_continue_session(&path, &dir_str, limits) {
+                size += conv.size_bytes;
+                conversations.push(conv);
+            }
+        }
+    }
+    (conversations, size)
+}
+
+// ── CLI agents: Claude Code, Gemini CLI, Codex CLI and aider transcripts (see ai_cli) ──
+
+/// Pseudo IDE id the conversations page lists CLI agent sessions under.
+pub const CLI_AGENTS_ID: &str = "cli_agents";
+
+/// Every CLI agent session, one db file entry per project.
+pub fn scan_cli_agents(sort: ConversationSort) -> ConversationListResult {
+    let limits = read_limits();
+    let mut conversations = Vec::new();
+    let mut db_files = Vec::new();
+    for project in crate::ai_cli::conversation_projects() {
+        for session in &project.sessions {
+            // Codex nests rollouts in date folders and aider keeps one history per project,
+            // so the folder is the session's own
+            let dir_str = session.path.parent().unwrap_or(&project.storage_path).display().to_string();
+            let fname = session.path.file_name().unwrap_or_default().to_string_lossy().to_string();
+            let message_count = if session.size <= limits.max_full_read {
+                crate::ai_cli::session_messages(&session.path).map(|m| m.len()).unwrap_or(0)
+            } else {
+                0
+            };
+            conversations.push(ConversationInfo {
+                id: format!("cli:{}:{}", dir_str, fname),
+                title: if session.title.is_empty() { session.id.clone() } else { session.title.clone() },
+                source_db: dir_str,
+                source_key: fname,
+                message_count,
+                size_bytes: session.size,
+                last_modified: session.last_modified,
+                ..Default::default()
+            });
+        }
+        db_files.push(DbFileInfo {
+            path: project.storage_path.display().to_string(),
+            size: project.size,
+            name: project.project,
+            modified: project.last_modified,
+        });
+    }
+    let total_size = conversations.iter().map(|c| c.size_bytes).sum();
+    finish_scan(CLI_AGENTS_ID, conversations, db_files, Vec::new(), total_size, sort)
+}
+
+fn get_cli_agent_content(path: &Path) -> Result<ConversationContent, String> {
+    let messages = crate::ai_cli::session_messages(path)?;
+    let title = messages
+        .iter()
+        .find(|m| m.role == "user")
+        .map(|m| m.content.lines().next().unwrap_or_default().chars().take(80).collect())
+        .unwrap_or_else(|| path.file_stem().unwrap_or_default().to_string_lossy().to_string());
+    Ok(ConversationContent { title, messages })
+}
+
+/// File or folder backing a directory-based conversation: Cline tasks are keyed by
+/// folder name, Windsurf cascades by `.pb` file stem, everything else (Zed text
+/// threads, JetBrains, Copilot and Continue chats, CLI agent transcripts) by file name.
+fn conversation_file_path(dir: &Path, source_key: &str) -> std::path::PathBuf {
+    let entry = dir.join(source_key);
+    if Path::new(source_key).extension().is_some() || entry.is_dir() {
+        entry
+    } else {
+        dir.join(format!("{}.pb", source_key))
+    }
+}
+
+/// Remove a file-per-conversation entry. Copilot sessions take their edit
+/// snapshots along; Cline tasks are whole folders.
+fn remove_conversation_entry(entry: &Path) -> Result<u64, String> {
+    // The tool's index goes first: if it can't be updated nothing is deleted,
+    // so the item fails whole and a retry starts over
+    if entry.is_dir() {
+        if let (Some(tasks_dir), Some(id)) = (entry.parent(), entry.file_name()) {
+            if let (true, Some(extension_dir)) = (is_cline_tasks_dir(tasks_dir), tasks_dir.parent()) {
+                prune_index_entry(&extension_dir.join(CLINE_TASK_HISTORY_FILE), "id", &id.to_string_lossy())?;
+            }
+        }
+        let size = dir_size(entry);
+        crate::cleaner::remove_tree(entry).map_err(|e| format!("Failed to delete task folder: {}", e))?;
+        return Ok(size);
+    }
+    if let (Some(dir), Some(stem)) = (entry.parent(), entry.file_stem()) {
+        if is_continue_sessions_dir(dir) {
+            prune_index_entry(&dir.join(CONTINUE_INDEX_FILE), "sessionId", &stem.to_string_lossy())?;
+        }
+    }
+    // Claude Code keeps a per-session folder next to the transcript
+    if crate::ai_cli::is_session_file(entry) {
+        let result = crate::ai_cli::prune_sessions(&[entry.to_path_buf()]);
+        let item = &result.items[0];
+        return match item.status {
+            // A per-session folder that couldn't go doesn't bring the transcript back
+            ItemStatus::Done | ItemStatus::Failed if !entry.exists() => Ok(item.bytes_freed),
+            _ => Err(format!("Failed to delete session: {}", item.error.as_deref().unwrap_or_default())),
+        };
+    }
+    let editing = copilot_editing_dir(entry);
+    let size = file_size(entry) + editing.as_deref().map(dir_size).unwrap_or(0);
+    crate::cleaner::remove_file(entry).map_err(|e| format!("Failed to delete conversation file: {}", e))?;
+    if let Some(editing) = editing {
+        crate::cleaner::remove_tree(&editing).map_err(|e| format!("Failed to delete editing session: {}", e))?;
+    }
+    Ok(size)
+}
+
+// ── Protobuf conversations: Windsurf cascades and Antigravity trajectories ──
+
+/// Role names some protobuf turns carry as plain strings.
+const PROTOBUF_ROLE_WORDS: &[&str] = &["user", "assistant", "system", "model", "human", "ai", "bot"];
+const PROTOBUF_TITLE_CHARS: std::ops::RangeInclusive<usize> = 3..=120;
+
+/// What a schema-less read of a protobuf conversation yields. Neither IDE
+/// publishes its schema, so turns are taken to be the most repeated
+/// sub-message that carries text, and the title the first sentence-like
+/// top-level string.
+struct DecodedProtobuf {
+    title: Option<String>,
+    messages: Vec<ConversationMessage>,
+    /// Latest timestamp anywhere in the value.
+    last_modified: Option<i64>,
+}
+
+fn find_protobuf_turns<'n, 'a>(node: &'n protowire::Node<'a>, best: &mut Vec<&'n protowire::Node<'a>>) {
+    let protowire::Node::Message(fields) = node else { return };
+    let mut groups: HashMap<u64, Vec<&'n protowire::Node<'a>>> = HashMap::new();
+    for field in fields {
+        if matches!(field.node, protowire::Node::Message(_)) && !field.node.texts().is_empty() {
+            groups.entry(field.number).or_default().push(&field.node);
+        }
+        find_protobuf_turns(&field.node, best);
+    }
+    for group in groups.into_values() {
+        if group.len() >= 2 && group.len() > best.len() {
+            *best = group;
+        }
+    }
+}
+
+fn protobuf_turn_message(turn: &protowire::Node) -> Option<ConversationMessage> {
+    let texts = turn.texts();
+    let role = texts
+        .iter()
+        .map(|t| t.trim().to_lowercase())
+        .find(|t| PROTOBUF_ROLE_WORDS.contains(&t.as_str()))
+        .map(|r| match r.as_str() {
+            "user" | "human" => "user".to_string(),
+            "system" => "system".to_string(),
+            _ => "assistant".to_string(),
+        })
+        .unwrap_or_else(|| "message".to_string());
+    // Turns also hold ids and paths; the longest string is the prose
+    let content = texts
+        .into_iter()
+        .filter(|t| !PROTOBUF_ROLE_WORDS.contains(&t.trim().to_lowercase().as_str()))
+        .max_by_key(|t| t.len())?
+        .trim()
+        .to_string();
+    if content.is_empty() {
+        return None;
+    }
+    Some(ConversationMessage { role, content, timestamp: turn.timestamps().into_iter().next() })
+}
+
+fn decode_protobuf_conversation(data: &[u8]) -> Option<DecodedProtobuf> {
+    let root = protowire::Node::Message(protowire::decode(data)?);
+    let mut turns = Vec::new();
+    find_protobuf_turns(&root, &mut turns);
+    let messages: Vec<ConversationMessage> = turns.iter().filter_map(|t| protobuf_turn_message(t)).collect();
+
+    let protowire::Node::Message(fields) = &root else { return None };
+    // Ids and paths have no spaces; a title reads like a sentence
+    let title = fields
+        .iter()
+        .filter_map(|f| match f.node {
+            protowire::Node::Text(t) => Some(t.trim()),
+            _ => None,
+        })
+        .find(|t| PROTOBUF_TITLE_CHARS.contains(&t.chars().count()) && !t.contains('\n') && t.contains(' '))
+        .map(str::to_string)
+        .or_else(|| messages.first().map(|m| m.content.lines().next().unwrap_or_default().chars().take(80).collect()));
+
+    if title.is_none() && messages.is_empty() {
+        return None;
+    }
+    Some(DecodedProtobuf { title, messages, last_modified: root.timestamps().into_iter().max() })
+}
+
+fn extract_windsurf_cascade(path: &Path, dir_str: &str, limits: &ReadLimits) -> ConversationInfo {
+    let fname = path.file_stem().unwrap_or_default().to_string_lossy().to_string();
+    let size = file_size(path);
+    // Encrypted or oversized files keep the file-name placeholder
+    let decoded = if size <= limits.max_full_read {
+        std::fs::read(path).ok().and_then(|data| decode_protobuf_conversation(&data))
+    } else {
+        None
+    };
+    let (title, message_count, last_modified) = match decoded {
+        Some(c) => (c.title, c.messages.len(), c.last_modified),
+        None => (None, 0, None),
+    };
+    ConversationInfo {
+        id: format!("pb:{}:{}", dir_str, fname),
+        title: title.unwrap_or_else(|| format!("Cascade {}", fname.chars().take(8).collect::<String>())),
+        source_db: dir_str.to_string(),
+        source_key: fname,
+        message_count,
+        size_bytes: size,
+        last_modified: last_modified.or_else(|| file_modified_time(path)),
+        ..Default::default()
+    }
+}
+
+fn get_windsurf_cascade_content(path: &Path) -> Result<ConversationContent, String> {
+    let data = std::fs::read(path).map_err(|e| format!("Failed to read cascade: {}", e))?;
+    let decoded = decode_protobuf_conversation(&data).ok_or_else(|| "Cascade file is encrypted or not in a readable format".to_string())?;
+    Ok(ConversationContent {
+        title: decoded.title.unwrap_or_else(|| path.file_stem().unwrap_or_default().to_string_lossy().to_string()),
+        messages: decoded.messages,
+    })
+}
+
+fn is_antigravity_key(key: &str) -> bool {
+    key.starts_with("jetskiStateSync.") || key.starts_with("antigravityUnifiedStateSync.")
+}
+
+/// Antigravity keeps its protobuf either as raw bytes or base64 text.
+fn decode_antigravity(raw: &[u8]) -> Option<DecodedProtobuf> {
+    use base64::Engine;
+    let text = std::str::from_utf8(raw).map(str::trim).unwrap_or_default();
+    let looks_base64 = !text.is_empty() && text.bytes().all(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'/' | b'='));
+    looks_base64
+        .then(|| base64::engine::general_purpose::STANDARD.decode(text).ok())
+        .flatten()
+        .and_then(|bytes| decode_protobuf_conversation(&bytes))
+        .or_else(|| decode_protobuf_conversation(raw))
+}
+
+fn query_value_bytes(conn: &Connection, table: &str, key: &str) -> Option<Vec<u8>> {
+    let sql = format!("SELECT value FROM [{}] WHERE key = ?1", table);
+    conn.query_row(&sql, [key], |row| {
+        Ok(match row.get_ref(0)? {
+            rusqlite::types::ValueRef::Text(b) | rusqlite::types::ValueRef::Blob(b) => b.to_vec(),
+            _ => Vec::new(),
+        })
+    })
+    .ok()
+}
+
+/// Antigravity's protobuf trajectory keys, read whole rather than from the text
+/// preview, which mangles binary values.
+fn extract_antigravity_protobuf(
+    conn: &Connection,
+    table: &str,
+    entry: &KeyEntry,
+    db_path: &str,
+    modified: Option<i64>,
+    limits: &ReadLimits,
+) -> Option<ConversationInfo> {
+    let decoded = if entry.size <= limits.max_full_read {
+        query_value_bytes(conn, table, &entry.key).and_then(|raw| decode_antigravity(&raw))
+    } else {
+        None
+    };
+    let (title, message_count, last_modified) = match decoded {
+        Some(d) => (d.title, d.messages.len(), d.last_modified),
+        None => (None, 0, None),
+    };
+    Some(ConversationInfo {
+        id: format!("{}:{}", db_path, entry.key),
+        title: title.unwrap_or_else(|| "Antigravity Session".to_string()),
+        source_db: db_path.to_string(),
+        source_key: entry.key.clone(),
+        message_count,
+        size_bytes: entry.size,
+        last_modified: last_modified.or(modified),
+        ..Default::default()
+    })
+}
+
+fn get_antigravity_content(conn: &Connection, source_key: &str) -> Result<ConversationContent, String> {
+    let raw = query_value_bytes(conn, "ItemTable", source_key).ok_or_else(|| "Key not found in database".to_string())?;
+    let decoded = decode_antigravity(&raw).ok_or_else(|| "Value is not in a readable protobuf format".to_string())?;
+    Ok(ConversationContent {
+        title: decoded.title.unwrap_or_else(|| "Antigravity Session".to_string()),
+        messages: decoded.messages,
+    })
+}
+
+// ── Language detection (by Unicode script, no dictionaries) ──
+
+/// Hiragana, Katakana (full- and half-width) and the Katakana extensions.
+fn is_kana(c: char) -> bool {
+    matches!(c as u32, 0x3040..=0x309F | 0x30A0..=0x30FF | 0x31F0..=0x31FF | 0xFF66..=0xFF9F)
+}
+
+/// CJK ideographs, including the extension blocks and the `々` iteration mark.
+fn is_han(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x3005 | 0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xF900..=0xFAFF | 0x20000..=0x2FA1F | 0x30000..=0x3134F
+    )
+}
+
+/// Dominant script of `text`. Kana is checked before Han: any kana means
+/// Japanese, since Japanese text mixes kana with Han characters; Han alone is Chinese.
+pub fn detect_language(text: &str) -> &'static str {
+    let (mut han, mut kana, mut hangul, mut cyrillic, mut latin) = (0usize, 0usize, 0usize, 0usize, 0usize);
+    for c in text.chars() {
+        if is_kana(c) {
+            kana += 1;
+        } else if is_han(c) {
+            han += 1;
+        } else if matches!(c as u32, 0xAC00..=0xD7AF | 0x1100..=0x11FF | 0x3130..=0x318F) {
+            hangul += 1;
+        } else if matches!(c as u32, 0x0400..=0x04FF) {
+            cyrillic += 1;
+        } else if c.is_ascii_alphabetic() || matches!(c as u32, 0x00C0..=0x024F) {
+            latin += 1;
+        }
+    }
+    if kana > 0 && kana + han >= hangul {
+        return "ja";
+    }
+    // One CJK character carries roughly as much as a Latin word
+    let scores = [("zh", han * 3), ("ko", hangul * 3), ("ru", cyrillic), ("latin", latin)];
+    match scores.iter().max_by_key(|(_, n)| *n) {
+        Some((lang, n)) if *n > 0 => lang,
+        _ => "",
+    }
+}
+
+// ── Raw values (archive / restore) ──
+
+/// Where a conversation's raw value came from, so it can be written back as-is.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum RawLocation {
+    /// Row of a key/value table (`ItemTable`, `cursorDiskKV`); `text` if the
+    /// value column held TEXT rather than a BLOB.
+    Table { table: String, text: bool },
+    /// Row of Zed's `threads` table; the raw bytes are its `data` column.
+    ZedThread { summary: String, updated_at: String, data_type: String },
+    /// A file in a per-conversation folder (Windsurf `.pb`, Zed text threads, JetBrains chats).
+    File { name: String },
+}
+
+/// Another row stored alongside a conversation's value: a Cursor composer's
+/// bubbles, checkpoints and diffs, which its delete takes along.
+#[derive(Debug, Clone)]
+pub struct RawRow {
+    pub key: String,
+    pub text: bool,
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RawConversation {
+    pub location: RawLocation,
+    pub data: Vec<u8>,
+    pub related: Vec<RawRow>,
+}
+
+/// A value column as (is TEXT, bytes); None for NULL/numbers.
+fn raw_value(value: rusqlite::types::ValueRef) -> Option<(bool, Vec<u8>)> {
+    match value {
+        rusqlite::types::ValueRef::Text(b) => Some((true, b.to_vec())),
+        rusqlite::types::ValueRef::Blob(b) => Some((false, b.to_vec())),
+        _ => None,
+    }
+}
+
+/// The rows `delete_conversation_key` removes along with `key`.
+fn related_rows(conn: &Connection, table: &str, key: &str) -> Result<Vec<RawRow>, String> {
+    let ranges = cursor_composer_ranges(table, key);
+    if ranges.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut stmt = conn
+        .prepare("SELECT key, value FROM cursorDiskKV WHERE key >= ?1 AND key < ?2 ORDER BY key")
+        .map_err(|e| format!("Failed to read related rows: {}", e))?;
+    let mut related = Vec::new();
+    for (start, end) in &ranges {
+        let rows = stmt
+            .query_map([start, end], |row| Ok((row.get::<_, String>(0)?, raw_value(row.get_ref(1)?))))
+            .map_err(|e| format!("Failed to read related rows: {}", e))?;
+        related.extend(
+            rows.filter_map(|r| r.ok()).filter_map(|(key, value)| value.map(|(text, data)| RawRow { key, text, data })),
+        );
+    }
+    Ok(related)
+}
+
+/// The full stored value behind a conversation, byte for byte, with the rows
+/// deleting it would take along.
+pub fn read_raw_conversation(source_db: &str, source_key: &str) -> Result<RawConversation, String> {
+    let db_path = Path::new(source_db);
+    if db_path.is_dir() {
+        let file = conversation_file_path(db_path, source_key);
+        if file.is_dir() {
+            return Err("Task folders can't be archived".into());
+        }
+        let data = std::fs::read(&file).map_err(|e| format!("Failed to read conversation file: {}", e))?;
+        let name = file.file_name().unwrap_or_default().to_string_lossy().to_string();
+        return Ok(RawConversation { location: RawLocation::File { name }, data, related: Vec::new() });
+    }
+
+    crate::db_pool::with_read_conn(db_path, |conn| {
+        let tables = get_tables(conn);
+        if tables.iter().any(|t| t == "threads") {
+            return conn
+                .query_row("SELECT summary, updated_at, data_type, data FROM threads WHERE id = ?1", [source_key], |row| {
+                    Ok(RawConversation {
+                        location: RawLocation::ZedThread {
+                            summary: row.get::<_, String>(0).unwrap_or_default(),
+                            updated_at: row.get::<_, String>(1).unwrap_or_default(),
+                            data_type: row.get::<_, String>(2).unwrap_or_default(),
+                        },
+                        data: row.get::<_, Vec<u8>>(3).unwrap_or_default(),
+                        related: Vec::new(),
+                    })
+                })
+                .map_err(|_| "Thread not found in database".to_string());
+        }
+        for table in ["cursorDiskKV", "ItemTable"] {
+            if !tables.iter().any(|t| t == table) { continue; }
+            let sql = format!("SELECT value FROM [{}] WHERE key = ?1", table);
+            let value = conn.query_row(&sql, [source_key], |row| Ok(raw_value(row.get_ref(0)?)));
+            if let Ok(Some((text, data))) = value {
+                let related = related_rows(conn, table, source_key)?;
+                return Ok(RawConversation { location: RawLocation::Table { table: table.to_string(), text }, data, related });
+            }
+        }
+        Err("Conversation key not found".to_string())
+    })?
+}
+
+fn open_for_restore(db_path: &Path) -> Result<Connection, String> {
+    if !db_path.is_file() {
+        return Err(format!("{} no longer exists", db_path.display()));
+    }
+    if crate::cleaner::is_marked(db_path) {
+        return Err(format!("{} is in a folder marked to be left alone", db_path.display()));
+    }
+    crate::db_pool::close(db_path);
+    Connection::open(db_path).map_err(|e| format!("Failed to open DB: {}", e))
+}
+
+fn insert_raw_value(conn: &Connection, sql: &str, key: &str, text: bool, data: &[u8]) -> Result<(), String> {
+    let inserted = if text {
+        let value = std::str::from_utf8(data).map_err(|e| format!("Archived text value is not UTF-8: {}", e))?;
+        conn.execute(sql, rusqlite::params![key, value])
+    } else {
+        conn.execute(sql, rusqlite::params![key, data])
+    };
+    inserted.map(|_| ()).map_err(|e| format!("Failed to write {}: {}", key, e))
+}
+
+/// Put a raw value back where `read_raw_conversation` found it. Never
+/// overwrites: if the IDE has since stored something else under the same key,
+/// thread id or file name, this fails instead of clobbering it.
+pub fn write_raw_conversation(source_db: &str, source_key: &str, raw: &RawConversation) -> Result<(), String> {
+    let db_path = Path::new(source_db);
+    match &raw.location {
+        RawLocation::File { name } => {
+            let file = db_path.join(name);
+            if file.exists() {
+                return if std::fs::read(&file).map(|d| d == raw.data).unwrap_or(false) {
+                    Ok(())
+                } else {
+                    Err("A different conversation file already exists".into())
+                };
+            }
+            std::fs::create_dir_all(db_path).map_err(|e| format!("Failed to create {}: {}", db_path.display(), e))?;
+            std::fs::write(&file, &raw.data).map_err(|e| format!("Failed to write conversation file: {}", e))
+        }
+        RawLocation::Table { table, text } => {
+            let conn = open_for_restore(db_path)?;
+            conn.execute_batch(&format!("CREATE TABLE IF NOT EXISTS [{}] (key TEXT UNIQUE ON CONFLICT REPLACE, value BLOB)", table))
+                .map_err(|e| format!("Failed to create {}: {}", table, e))?;
+            let existing = conn
+                .query_row(&format!("SELECT value FROM [{}] WHERE key = ?1", table), [source_key], |row| {
+                    Ok(raw_value(row.get_ref(0)?).map(|(_, data)| data).unwrap_or_default())
+                })
+                .ok();
+            if existing.as_ref().is_some_and(|current| *current != raw.data) {
+                return Err("The IDE has stored a different value under this key since".into());
+            }
+            let tx = conn.unchecked_transaction().map_err(|e| format!("Failed to write to {}: {}", table, e))?;
+            if existing.is_none() {
+                insert_raw_value(&tx, &format!("INSERT INTO [{}] (key, value) VALUES (?1, ?2)", table), source_key, *text, &raw.data)?;
+            }
+            // Rows the IDE has written again since are left as they are
+            for row in &raw.related {
+                insert_raw_value(&tx, "INSERT OR IGNORE INTO cursorDiskKV (key, value) VALUES (?1, ?2)", &row.key, row.text, &row.data)?;
+            }
+            tx.commit().map_err(|e| format!("Failed to write to {}: {}", table, e))
+        }
+        RawLocation::ZedThread { summary, updated_at, data_type } => open_for_restore(db_path)?
+            .execute(
+                "INSERT INTO threads (id, summary, updated_at, data_type, data) VALUES (?1, ?2, ?3, ?4, ?5)",
+                rusqlite::params![source_key, summary, updated_at, data_type, raw.data],
+            )
+            .map(|_| ())
+            .map_err(|e| format!("Failed to write thread: {}", e)),
+    }
+}
+
+// ── Delete conversations ──
+
+/// Row prefixes a Cursor composer keeps under its id besides `composerData:<id>`:
+/// `bubbleId:<id>:*` messages, `checkpointId:<id>:*`, `codeBlockDiff:<id>:*`
+/// and `messageRequestContext:<id>:*`. Sorted, so reading the ranges in this
+/// order gives the rows in key order.
+///
+/// `agentKv:blob:<hash>` rows are deliberately not here. They are keyed by a
+/// content hash, not by composer id, so identical content is stored once and
+/// can back several chats; a composer only points at them from inside its
+/// encoded conversation state, which isn't parsed. Deleting blobs we can't
+/// attribute could take another chat's messages, so they stay and aren't
+/// counted in a delete's size.
+const CURSOR_COMPOSER_PREFIXES: &[&str] = &["bubbleId", "checkpointId", "codeBlockDiff", "messageRequestContext"];
+
+/// For a composer key, one `[start, end)` key range per prefix
+/// (`bubbleId:<id>:` up to `bubbleId:<id>;`), so the key index does the lookup.
+fn cursor_composer_ranges(table: &str, key: &str) -> Vec<(String, String)> {
+    let Some(id) = key.strip_prefix("composerData:").filter(|id| table == "cursorDiskKV" && !id.is_empty()) else {
+        return Vec::new();
+    };
+    CURSOR_COMPOSER_PREFIXES.iter().map(|p| (format!("{}:{}:", p, id), format!("{}:{};", p, id))).collect()
+}
+
+/// Size of a conversation key's value, plus a Cursor composer's other rows.
+fn conversation_key_size(conn: &Connection, table: &str, key: &str) -> u64 {
+    let own = query_value_size(conn, table, key);
+    let rows: i64 = cursor_composer_ranges(table, key)
+        .iter()
+        .map(|(start, end)| {
+            conn.query_row(
+                "SELECT COALESCE(SUM(length(value)), 0) FROM cursorDiskKV WHERE key >= ?1 AND key < ?2",
+                [start, end],
+                |row| row.get::<_, i64>(0),
+            )
+            .unwrap_or(0)
+        })
+        .sum();
+    own + rows as u64
+}
+
+/// Delete a conversation key, and with a Cursor composer all its other rows in
+/// the same transaction. False when the key wasn't there.
+fn delete_conversation_key(conn: &Connection, table: &str, key: &str) -> rusqlite::Result<bool> {
+    let tx = conn.unchecked_transaction()?;
+    let count = tx.execute(&format!("DELETE FROM [{}] WHERE key = ?1", table), [key])?;
+    if count > 0 {
+        for (start, end) in cursor_composer_ranges(table, key) {
+            tx.execute("DELETE FROM cursorDiskKV WHERE key >= ?1 AND key < ?2", [&start, &end])?;
+        }
+    }
+    tx.commit()?;
+    Ok(count > 0)
+}
+
+/// Delete one conversation. With `vacuum` false a SQLite database keeps the
+/// freed pages for reuse instead of shrinking (see `vacuum_database`); secure
+/// deletion mode always compacts.
+pub fn delete_conversation(source_db: &str, source_key: &str, vacuum: bool) -> Result<u64, String> {
+    let protected = crate::config::load_config().protected_conversations;
+    if protected.iter().any(|p| p.source_db == source_db && p.source_key == source_key) {
+        return Err("Conversation is protected; remove its protection first".into());
+    }
+    let freed = delete_conversation_at(source_db, source_key, vacuum)?;
+    crate::conversation_search::evict_conversations(&[(source_db, source_key)]);
+    Ok(freed)
+}
+
+fn delete_conversation_at(source_db: &str, source_key: &str, vacuum: bool) -> Result<u64, String> {
+    let db_path = Path::new(source_db);
+
+    // File-per-conversation dirs: Windsurf .pb cascades, Zed text threads, JetBrains, Copilot and Continue chats, Cline tasks, CLI agent transcripts
+    if db_path.is_dir() {
+        let file = conversation_file_path(db_path, source_key);
+        if file.exists() {
+            return remove_conversation_entry(&file);
+        }
+        return Err("File not found".into());
+    }
+
+    // SQLite-based deletion; pooled readers would block the VACUUM
+    let before = crate::db_pool::checkpointed_size(db_path);
+    {
+        let conn = crate::db_pool::open_for_delete(db_path)?;
+        delete_from_db(&conn, source_key)?;
+        if vacuum || crate::cleaner::secure_delete_enabled() {
+            let _ = crate::db_pool::vacuum(&conn);
+        }
+    }
+    // Measured once the connection is closed, checkpointed on both sides so WAL
+    // pages the IDE wrote earlier don't count as freed
+    Ok(before.saturating_sub(crate::db_pool::checkpointed_size(db_path)))
+}
+
+fn delete_from_db(conn: &Connection, source_key: &str) -> Result<(), String> {
+    let tables = get_tables(conn);
+    if tables.iter().any(|t| t == "threads") {
+        return delete_zed_thread(conn, source_key).map(|_| ()).ok_or_else(|| "Thread not found".to_string());
+    }
+    for table in &["ItemTable", "cursorDiskKV"] {
+        if tables.contains(&table.to_string()) {
+            if let Ok(true) = delete_conversation_key(conn, table, source_key) {
+                return Ok(());
+            }
+        }
+    }
+    Err("Conversation key not found".into())
+}
+
+/// Spread the bytes a database actually shrank by over its deleted items, in
+/// proportion to their value sizes, so the items add up to the real total.
+fn apportion_freed(results: &mut [Option<ItemResult>], indices: &[usize], freed: u64) {
+    let done: Vec<usize> = indices
+        .iter()
+        .copied()
+        .filter(|&i| results[i].as_ref().is_some_and(|r| r.status == ItemStatus::Done))
+        .collect();
+    let Some(&last) = done.last() else { return };
+    let weights: Vec<u64> = done.iter().map(|&i| results[i].as_ref().map(|r| r.bytes_freed).unwrap_or(0)).collect();
+    let total: u64 = weights.iter().sum();
+    let mut left = freed;
+    for (&i, &weight) in done.iter().zip(&weights) {
+        let share = if i == last {
+            left
+        } else if total == 0 {
+            freed / done.len() as u64
+        } else {
+            (freed as u128 * weight as u128 / total as u128) as u64
+        };
+        left -= share;
+        if let Some(r) = results[i].as_mut() {
+            r.bytes_freed = share;
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchDeleteRequest {
+    /// Echoed back in the item's result; defaults to `source_key`.
+    #[serde(default)]
+    pub id: String,
+    pub source_db: String,
+    pub source_key: String,
+}
+
+impl BatchDeleteRequest {
+    /// The id echoed back in results.
+    pub fn item_id(&self) -> String {
+        if self.id.is_empty() { self.source_key.clone() } else { self.id.clone() }
+    }
+}
+
+/// Conversation id inside an aggregated key: what follows `<ide>:<key>:` in
+/// `ConversationInfo::id`, empty for one-conversation keys.
+pub fn conversation_id_of(id: &str) -> String {
+    let parts: Vec<&str> = id.split(':').collect();
+    if parts.len() >= 3 { parts[2..].join(":") } else { String::new() }
+}
+
+/// Request indices per source database, so batch operations open each DB once.
+pub(crate) fn group_by_db(items: &[BatchDeleteRequest]) -> HashMap<&str, Vec<usize>> {
+    let mut groups: HashMap<&str, Vec<usize>> = HashMap::new();
+    for (i, item) in items.iter().enumerate() {
+        groups.entry(item.source_db.as_str()).or_default().push(i);
+    }
+    groups
+}
+
+/// Delete many conversations, one result per request (in request order).
+/// Requests are grouped by database so each DB is opened and, when `vacuum`
+/// (or secure deletion mode) is on, VACUUMed once.
+pub fn delete_conversations_batch(items: &[BatchDeleteRequest], vacuum: bool) -> BatchResult {
+    let item_id = BatchDeleteRequest::item_id;
+    let vacuum = vacuum || crate::cleaner::secure_delete_enabled();
+    let mut results: Vec<Option<ItemResult>> = vec![None; items.len()];
+
+    let protected = crate::config::load_config().protected_conversations;
+    for (i, item) in items.iter().enumerate() {
+        let id = item_id(item);
+        if protected.iter().any(|p| p.id == id || (p.source_db == item.source_db && p.source_key == item.source_key)) {
+            results[i] = Some(ItemResult::skipped(id, "Protected"));
+        }
+    }
+
+    let mut groups = group_by_db(items);
+    for indices in groups.values_mut() {
+        indices.retain(|&i| results[i].is_none());
+    }
+
+    for (source_db, indices) in groups.iter().filter(|(_, indices)| !indices.is_empty()) {
+        let db_path = Path::new(source_db);
+
+        if db_path.is_dir() {
+            // Windsurf .pb files, Zed text threads, JetBrains, Copilot and Continue chats, Cline tasks, CLI agent transcripts
+            for &i in indices {
+                let id = item_id(&items[i]);
+                let file = conversation_file_path(db_path, &items[i].source_key);
+                results[i] = Some(if !file.exists() {
+                    ItemResult::not_found(id)
+                } else {
+                    match remove_conversation_entry(&file) {
+                        Ok(size) => ItemResult::done(id, size),
+                        Err(e) => ItemResult::failed(id, e),
+                    }
+                });
+            }
+        } else if db_path.exists() {
+            // SQLite DB
+            let before = crate::db_pool::checkpointed_size(db_path);
+            let conn = match crate::db_pool::open_for_delete(db_path) {
+                Ok(c) => c,
+                Err(e) => {
+                    for &i in indices {
+                        results[i] = Some(ItemResult::failed(item_id(&items[i]), e.clone()));
+                    }
+                    continue;
+                }
+            };
+            let tables = get_tables(&conn);
+            let is_zed_threads = tables.iter().any(|t| t == "threads");
+            for &i in indices {
+                let id = item_id(&items[i]);
+                let key = items[i].source_key.as_str();
+                if is_zed_threads {
+                    results[i] = Some(match delete_zed_thread(&conn, key) {
+                        Some(size) => ItemResult::done(id, size),
+                        None => ItemResult::not_found(id),
+                    });
+                    continue;
+                }
+                let mut result = ItemResult::not_found(id.clone());
+                for table in &["ItemTable", "cursorDiskKV"] {
+                    if !tables.contains(&table.to_string()) { continue; }
+                    let size = conversation_key_size(&conn, table, key);
+                    match delete_conversation_key(&conn, table, key) {
+                        Ok(true) => {
+                            result = ItemResult::done(id.clone(), size);
+                            break;
+                        }
+                        Ok(false) => {}
+                        // Typically "database is locked" while the IDE is running
+                        Err(e) => result = ItemResult::failed(id.clone(), format!("Failed to delete from {}: {}", table, e)),
+                    }
+                }
+                results[i] = Some(result);
+            }
+            if vacuum {
+                let _ = crate::db_pool::vacuum(&conn);
+            }
+            drop(conn);
+            apportion_freed(&mut results, indices, before.saturating_sub(crate::db_pool::checkpointed_size(db_path)));
+        } else {
+            for &i in indices {
+                results[i] = Some(ItemResult::not_found(item_id(&items[i])));
+            }
+        }
+    }
+
+    let gone: Vec<(&str, &str)> = items
+        .iter()
+        .zip(&results)
+        .filter(|(_, r)| r.as_ref().is_some_and(|r| matches!(r.status, ItemStatus::Done | ItemStatus::NotFound)))
+        .map(|(item, _)| (item.source_db.as_str(), item.source_key.as_str()))
+        .collect();
+    crate::conversation_search::evict_conversations(&gone);
+
+    BatchResult::from_items(results.into_iter().flatten().collect())
+}
+
+// ── Compaction: one VACUUM after a bulk cleanup ──
+
+pub const VACUUM_PROGRESS_EVENT: &str = "vacuum-progress";
+const VACUUM_PROGRESS_INTERVAL: std::time::Duration = std::time::Duration::from_millis(500);
+/// Pages copied back per backup step, with a progress report after each.
+const VACUUM_STEP_PAGES: i32 = 256;
+/// Busy/locked backup steps tolerated (at the progress interval) before giving up.
+const VACUUM_BUSY_RETRIES: u32 = 40;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VacuumProgress {
+    pub path: String,
+    /// Database plus WAL size when compaction started.
+    pub size_before: u64,
+    /// Pages written so far, over both passes: the compacted copy, then
+    /// copying it back over the database.
+    pub pages_done: u64,
+    pub pages_total: u64,
+    pub elapsed_ms: u64,
+    pub done: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VacuumResult {
+    pub path: String,
+    pub size_before: u64,
+    pub size_after: u64,
+    pub freed_bytes: u64,
+    pub elapsed_ms: u64,
+}
+
+/// VACUUM one of the scanned databases of `source_id`, after deletes made
+/// without it. A plain VACUUM reports no progress, so this runs it as
+/// `VACUUM INTO` a sibling file (progress from that file's size) and then
+/// copies the result back page by page through the backup API.
+pub fn vacuum_database(
+    source_id: &str,
+    path: &Path,
+    on_progress: impl Fn(&VacuumProgress) + Sync,
+) -> Result<VacuumResult, String> {
+    let scan = last_scan(source_id).ok_or_else(|| "Scan the conversations first".to_string())?;
+    if !scan.db_files.iter().any(|f| Path::new(&f.path) == path) {
+        return Err(format!("{} is not one of the scanned databases", path.display()));
+    }
+    if crate::cleaner::is_marked(path) {
+        return Err(format!("{} is in a folder marked to be left alone", path.display()));
+    }
+    if !crate::db_pool::is_sqlite_file(path) {
+        return Err(format!("{} is not a SQLite database", path.display()));
+    }
+    let size_before = crate::db_pool::checkpointed_size(path);
+    let started = std::time::Instant::now();
+
+    crate::db_pool::close(path);
+    let mut conn = Connection::open(path).map_err(|e| format!("Failed to open DB: {}", e))?;
+    let pragma = |name: &str| conn.query_row(&format!("PRAGMA {}", name), [], |row| row.get::<_, i64>(0)).unwrap_or(0).max(0) as u64;
+    let page_count = pragma("page_count");
+    let page_size = pragma("page_size").max(512);
+
+    let pages_done = std::sync::atomic::AtomicU64::new(0);
+    // The copy back is at most as long as the first pass; corrected once it starts
+    let pages_total = std::sync::atomic::AtomicU64::new(page_count * 2);
+    let report = |done: bool| {
+        on_progress(&VacuumProgress {
+            path: path.display().to_string(),
+            size_before,
+            pages_done: pages_done.load(std::sync::atomic::Ordering::Relaxed),
+            pages_total: pages_total.load(std::sync::atomic::Ordering::Relaxed),
+            elapsed_ms: started.elapsed().as_millis() as u64,
+            done,
+        })
+    };
+
+    let mut compacted_name = path.file_name().unwrap_or_default().to_os_string();
+    compacted_name.push(".vacuum");
+    let compacted = path.with_file_name(compacted_name);
+    let _ = std::fs::remove_file(&compacted);
+
+    let result = (|| {
+        let (stop, ticks) = std::sync::mpsc::channel::<()>();
+        let (pages_done, compacted) = (&pages_done, &compacted);
+        std::thread::scope(|scope| {
+            scope.spawn(move || {
+                report(false);
+                while let Err(std::sync::mpsc::RecvTimeoutError::Timeout) = ticks.recv_timeout(VACUUM_PROGRESS_INTERVAL) {
+                    pages_done.store((file_size(compacted) / page_size).min(page_count), std::sync::atomic::Ordering::Relaxed);
+                    report(false);
+                }
+            });
+            let result = conn.execute("VACUUM INTO ?1", [compacted.to_string_lossy().into_owned()]);
+            drop(stop);
+            result
+        })
+        .map_err(|e| format!("Failed to compact database: {}", e))?;
+
+        let source = Connection::open_with_flags(compacted, rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY)
+            .map_err(|e| format!("Failed to open compacted copy: {}", e))?;
+        let backup = rusqlite::backup::Backup::new(&source, &mut conn).map_err(|e| format!("Failed to compact database: {}", e))?;
+        let mut busy = 0;
+        loop {
+            match backup.step(VACUUM_STEP_PAGES).map_err(|e| format!("Failed to compact database: {}", e))? {
+                rusqlite::backup::StepResult::Done => break,
+                rusqlite::backup::StepResult::Busy | rusqlite::backup::StepResult::Locked => {
+                    busy += 1;
+                    if busy > VACUUM_BUSY_RETRIES {
+                        return Err("Failed to compact database: it stayed locked by another process".to_string());
+                    }
+                    std::thread::sleep(VACUUM_PROGRESS_INTERVAL);
+                }
+                _ => {}
+            }
+            let progress = backup.progress();
+            let copied = progress.pagecount.saturating_sub(progress.remaining).max(0) as u64;
+            pages_total.store(page_count + progress.pagecount.max(0) as u64, std::sync::atomic::Ordering::Relaxed);
+            pages_done.store(page_count + copied, std::sync::atomic::Ordering::Relaxed);
+            report(false);
+        }
+        Ok(())
+    })();
+    let _ = std::fs::remove_file(&compacted);
+    result?;
+
+    if crate::cleaner::secure_delete_enabled() {
+        crate::db_pool::checkpoint(&conn);
+    }
+    drop(conn);
+    pages_done.store(pages_total.load(std::sync::atomic::Ordering::Relaxed), std::sync::atomic::Ordering::Relaxed);
+    report(true);
+    let size_after = crate::db_pool::checkpointed_size(path);
+    Ok(VacuumResult {
+        path: path.display().to_string(),
+        size_before,
+        size_after,
+        freed_bytes: size_before.saturating_sub(size_after),
+        elapsed_ms: started.elapsed().as_millis() as u64,
+    })
+}
+
+// ── Duplicates: one chat stored in both globalStorage and a workspaceStorage DB ──
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConversationCopy {
+    pub source_db: String,
+    pub source_key: String,
+    pub size_bytes: u64,
+}
+
+/// The chat's own id (`composerId`, `chatId`, ...) for conversations read from
+/// SQLite, the part of `ConversationInfo::id` after `<db>:<key>:`. Positional
+/// `item_<n>` ids aren't stable across databases and never match.
+fn duplicate_key(conv: &ConversationInfo) -> Option<&str> {
+    let prefix = format!("{}:{}:", conv.source_db, conv.source_key);
+    let chat_id = conv.id.strip_prefix(&prefix)?;
+    (!chat_id.is_empty() && !chat_id.starts_with("item_")).then_some(chat_id)
+}
+
+/// Keep one entry per chat id: the one with the most messages, then the newest,
+/// then the globalStorage copy. The others become its `duplicates`.
+fn merge_duplicates(conversations: Vec<ConversationInfo>) -> Vec<ConversationInfo> {
+    let mut groups: HashMap<String, Vec<usize>> = HashMap::new();
+    for (i, conv) in conversations.iter().enumerate() {
+        if let Some(key) = duplicate_key(conv) {
+            groups.entry(key.to_string()).or_default().push(i);
+        }
+    }
+    let mut redundant: HashMap<usize, usize> = HashMap::new();
+    for indices in groups.values().filter(|g| g.len() > 1) {
+        let rank = |&i: &usize| {
+            let c = &conversations[i];
+            (c.message_count, c.last_modified.unwrap_or(0), c.source_db.contains("globalStorage"))
+        };
+        let kept = *indices.iter().max_by_key(|i| rank(i)).unwrap_or(&indices[0]);
+        for &i in indices.iter().filter(|&&i| i != kept) {
+            redundant.insert(i, kept);
+        }
+    }
+    if redundant.is_empty() {
+        return conversations;
+    }
+
+    let mut copies: HashMap<usize, Vec<ConversationCopy>> = HashMap::new();
+    for (&i, &kept) in &redundant {
+        let c = &conversations[i];
+        copies.entry(kept).or_default().push(ConversationCopy {
+            source_db: c.source_db.clone(),
+            source_key: c.source_key.clone(),
+            size_bytes: c.size_bytes,
+        });
+    }
+    conversations
+        .into_iter()
+        .enumerate()
+        .filter(|(i, _)| !redundant.contains_key(i))
+        .map(|(i, mut conv)| {
+            conv.duplicates = copies.remove(&i).unwrap_or_default();
+            conv
+        })
+        .collect()
+}
+
+/// Delete the redundant copies the source's last scan merged away. Copies that
+/// share their key with other chats (aggregated `allComposers`-style values, or
+/// array keys several scanned conversations came from) are left alone:
+/// deleting the key would take those chats with it.
+pub fn delete_redundant_copies(source_id: &str) -> Result<BatchResult, String> {
+    let scan = last_scan(source_id).ok_or_else(|| "Scan the conversations first".to_string())?;
+
+    let mut holders: HashMap<(&str, &str), usize> = HashMap::new();
+    for conv in &scan.conversations {
+        let locations = std::iter::once((conv.source_db.as_str(), conv.source_key.as_str()))
+            .chain(conv.duplicates.iter().map(|d| (d.source_db.as_str(), d.source_key.as_str())));
+        for location in locations.collect::<HashSet<_>>() {
+            *holders.entry(location).or_default() += 1;
+        }
+    }
+
+    let rules = crate::rules::current_rules();
+    let mut shared = Vec::new();
+    let mut requests = Vec::new();
+    for conv in scan.conversations.iter().filter(|c| !c.protected) {
+        for copy in &conv.duplicates {
+            let id = format!("{}:{}", copy.source_db, copy.source_key);
+            let holder_count = holders.get(&(copy.source_db.as_str(), copy.source_key.as_str())).copied().unwrap_or(0);
+            if rules.is_chat_data_key(&copy.source_key) || holder_count > 1 {
+                shared.push(ItemResult::skipped(id, "Stored together with other conversations"));
+            } else {
+                requests.push(BatchDeleteRequest { id, source_db: copy.source_db.clone(), source_key: copy.source_key.clone() });
+            }
+        }
+    }
+    let mut items = delete_conversations_batch(&requests, true).items;
+    items.extend(shared);
+    Ok(BatchResult::from_items(items))
+}
+
+// ── Stray files: WAL and journal files left behind by a deleted database ──
+
+/// Companion files of `db` (which is gone) that are still on disk, named after `name`.
+fn stray_companions(db: &Path, name: &str) -> Vec<DbFileInfo> {
+    crate::db_pool::COMPANION_SUFFIXES
+        .iter()
+        .map(|suffix| (crate::db_pool::companion_path(db, suffix), suffix))
+        .filter(|(path, _)| path.is_file())
+        .map(|(path, suffix)| DbFileInfo {
+            size: file_size(&path),
+            modified: file_modified_time(&path),
+            name: format!("{}{}", name, suffix),
+            path: path.display().to_string(),
+        })
+        .collect()
+}
+
+/// Delete the stray files of the source's last scan. A file whose database has
+/// reappeared since is skipped: SQLite still needs it.
+pub fn delete_stray_files(source_id: &str) -> Result<BatchResult, String> {
+    let scan = last_scan(source_id).ok_or_else(|| "Scan the conversations first".to_string())?;
+    let items = scan
+        .stray_files
+        .iter()
+        .map(|stray| {
+            let path = Path::new(&stray.path);
+            let db = crate::db_pool::COMPANION_SUFFIXES.iter().find_map(|suffix| stray.path.strip_suffix(suffix));
+            if db.is_some_and(|db| Path::new(db).exists()) {
+                return ItemResult::skipped(&stray.path, "The database exists again");
+            }
+            if !path.exists() {
+                return ItemResult::not_found(&stray.path);
+            }
+            let size = file_size(path);
+            match crate::cleaner::remove_file(path) {
+                Ok(()) => ItemResult::done(&stray.path, size),
+                Err(e) => ItemResult::failed(&stray.path, format!("Failed to delete {}: {}", stray.name, e)),
+            }
+        })
+        .collect();
+    Ok(BatchResult::from_items(items))
+}
+
+// ── Public API ──
+
+pub fn scan_conversations(ide: &crate::ide_detector::IdeInfo, sort: ConversationSort) -> ConversationListResult {
+    let mut conversations = Vec::new();
+    let mut db_files = Vec::new();
+    let mut stray_files = Vec::new();
+    let mut total_size: u64 = 0;
+    let rules = crate::rules::current_rules();
+    let limits = read_limits();
+
+    // ── globalStorage/state.vscdb (MAIN database) ──
+    if let Some(ref gs) = ide.global_storage_path {
+        let db = gs.join("state.vscdb");
+        if db.exists() {
+            let size = crate::db_pool::checkpointed_size(&db);
+            total_size += size;
+            db_files.push(DbFileInfo {
+                path: db.display().to_string(),
+                size,
+                name: "globalStorage/state.vscdb".into(),
+                modified: file_modified_time(&db),
+            });
+            conversations.extend(extract_from_db(&db, &rules, &limits));
+        } else {
+            stray_files.extend(stray_companions(&db, "globalStorage/state.vscdb"));
+        }
+
+        let backup = gs.join("state.vscdb.backup");
+        if backup.exists() {
+            let size = file_size(&backup);
+            total_size += size;
+            db_files.push(DbFileInfo {
+                path: backup.display().to_string(),
+                size,
+                name: "globalStorage/state.vscdb.backup".into(),
+                modified: file_modified_time(&backup),
+            });
+        }
+    }
+
+    // ── globalStorage/emptyWindowChatSessions/*.json (Copilot Chat without a folder open) ──
+    if let Some(ref gs) = ide.global_storage_path {
+        let dir = gs.join("emptyWindowChatSessions");
+        let (sessions, size) = scan_copilot_sessions(&dir);
+        if !sessions.is_empty() {
+            total_size += size;
+            db_files.push(DbFileInfo {
+                path: dir.display().to_string(),
+                size,
+                name: "globalStorage/emptyWindowChatSessions/".into(),
+                modified: file_modified_time(&dir),
+            });
+            conversations.extend(sessions);
+        }
+    }
+
+    // ── ~/.continue/sessions/*.json, shared by every IDE the Continue extension has run in ──
+    let continue_installed = ide.global_storage_path.as_ref().is_some_and(|gs| gs.join("continue.continue").is_dir());
+    if let Some(dir) = continue_sessions_dir().filter(|_| continue_installed) {
+        let (sessions, size) = scan_continue_sessions(&dir, &limits);
+        if !sessions.is_empty() {
+            total_size += size;
+            db_files.push(DbFileInfo {
+                path: dir.display().to_string(),
+                size,
+                name: ".continue/sessions/".into(),
+                modified: file_modified_time(&dir),
+            });
+            conversations.extend(sessions);
+        }
+    }
+
+    // ── globalStorage/<extension>/tasks/<id>/ (Cline and Roo Code task folders) ──
+    if let Some(ref gs) = ide.global_storage_path {
+        for extension in CLINE_EXTENSION_IDS {
+            let dir = gs.join(extension).join("tasks");
+            let (tasks, size) = scan_cline_tasks(&dir, &limits);
+            if tasks.is_empty() { continue; }
+            total_size += size;
+            db_files.push(DbFileInfo {
+                path: dir.display().to_string(),
+                size,
+                name: format!("globalStorage/{}/tasks/", extension),
+                modified: file_modified_time(&dir),
+            });
+            conversations.extend(tasks);
+        }
+    }
+
+    // ── workspaceStorage/*/state.vscdb + chatSessions/*.json ──
+    if let Some(ref ws) = ide.workspace_storage_path {
+        if ws.exists() {
+            if let Ok(entries) = std::fs::read_dir(ws) {
+                for entry in entries.filter_map(|e| e.ok()) {
+                    if !entry.file_type().map(|t| t.is_dir()).unwrap_or(false) { continue; }
+                    let hash = entry.file_name().to_string_lossy().to_string();
+                    let short_hash = if hash.len() > 8 { &hash[..8] } else { &hash };
+
+                    let sessions_dir = entry.path().join("chatSessions");
+                    let (sessions, size) = scan_copilot_sessions(&sessions_dir);
+                    if !sessions.is_empty() {
+                        total_size += size;
+                        db_files.push(DbFileInfo {
+                            path: sessions_dir.display().to_string(),
+                            size,
+                            name: format!("workspaceStorage/{}/chatSessions/", short_hash),
+                            modified: file_modified_time(&sessions_dir),
+                        });
+                        conversations.extend(sessions);
+                    }
+
+                    let db = entry.path().join("state.vscdb");
+                    if !db.exists() {
+                        stray_files.extend(stray_companions(&db, &format!("workspaceStorage/{}/state.vscdb", short_hash)));
+                        continue;
+                    }
+                    let size = crate::db_pool::checkpointed_size(&db);
+                    if size < 1024 { continue; }
+                    total_size += size;
+                    db_files.push(DbFileInfo {
+                        path: db.display().to_string(),
+                        size,
+                        name: format!("workspaceStorage/{}/state.vscdb", short_hash),
+                        modified: file_modified_time(&db),
+                    });
+                    conversations.extend(extract_from_db(&db, &rules, &limits));
+                }
+            }
+        }
+    }
+
+    // ── Windsurf: ~/.codeium/windsurf/cascade/*.pb (protobuf conversation files) ──
+    if ide.id == "windsurf" {
+        if let Some(home) = std::env::var_os("USERPROFILE")
+            .or_else(|| std::env::var_os("HOME"))
+        {
+            let cascade_dir = Path::new(&home).join(".codeium/windsurf/cascade");
+            if cascade_dir.exists() {
+                if let Ok(entries) = std::fs::read_dir(&cascade_dir) {
+                    for entry in entries.filter_map(|e| e.ok()) {
+                        let path = entry.path();
+                        if path.extension().map(|e| e == "pb").unwrap_or(false) {
+                            if file_size(&path) < 50 { continue; }
+                            let conv = extract_windsurf_cascade(&path, &cascade_dir.display().to_string(), &limits);
+                            total_size += conv.size_bytes;
+                            conversations.push(conv);
+                        }
                     }
                 }
                 // Add cascade dir as a "db file" entry
@@ -1241,17 +3054,618 @@ pub fn scan_conversations(ide: &crate::ide_detector::IdeInfo) -> ConversationLis
         }
     }
 
-    // Default sort: most recent first
-    conversations.sort_by(|a, b| {
-        let ta = a.last_modified.unwrap_or(0);
-        let tb = b.last_modified.unwrap_or(0);
-        tb.cmp(&ta)
-    });
+    // ── Zed: threads/threads.db (agent threads) + conversations/*.zed.json (text threads) ──
+    if ide.id == "zed" {
+        for dir in crate::ide_detector::zed_conversation_dirs() {
+            let dir_name = dir.file_name().unwrap_or_default().to_string_lossy().to_string();
+            if dir_name == "threads" {
+                let db = dir.join("threads.db");
+                if !db.exists() {
+                    stray_files.extend(stray_companions(&db, "threads/threads.db"));
+                    continue;
+                }
+                let size = crate::db_pool::checkpointed_size(&db);
+                total_size += size;
+                db_files.push(DbFileInfo {
+                    path: db.display().to_string(),
+                    size,
+                    name: "threads/threads.db".into(),
+                    modified: file_modified_time(&db),
+                });
+                conversations.extend(extract_zed_threads(&db));
+            } else if dir_name == "conversations" {
+                let dir_str = dir.display().to_string();
+                let mut dir_size: u64 = 0;
+                if let Ok(entries) = std::fs::read_dir(&dir) {
+                    for entry in entries.filter_map(|e| e.ok()) {
+                        let path = entry.path();
+                        if !path.to_string_lossy().ends_with(ZED_CONTEXT_EXT) { continue; }
+                        if let Some(conv) = extract_zed_context(&path, &dir_str) {
+                            dir_size += conv.size_bytes;
+                            conversations.push(conv);
+                        }
+                    }
+                }
+                total_size += dir_size;
+                db_files.push(DbFileInfo {
+                    path: dir_str,
+                    size: dir_size,
+                    name: "conversations/".into(),
+                    modified: file_modified_time(&dir),
+                });
+            }
+        }
+    }
+
+    // ── JetBrains: AI Assistant chat sessions in the system dirs ──
+    if ide.ide_type == crate::ide_detector::IdeType::JetBrains {
+        let system_root = ide.versioned_folders.iter().filter_map(|vf| vf.cache_path.as_ref()).collect::<Vec<_>>();
+        for dir in jetbrains_chat_dirs(ide) {
+            let dir_str = dir.display().to_string();
+            let mut dir_size: u64 = 0;
+            if let Ok(entries) = std::fs::read_dir(&dir) {
+                for entry in entries.filter_map(|e| e.ok()) {
+                    let path = entry.path();
+                    if !is_json_file(&path) { continue; }
+                    if let Some(conv) = extract_jetbrains_chat(&path, &dir_str) {
+                        dir_size += conv.size_bytes;
+                        conversations.push(conv);
+                    }
+                }
+            }
+            if dir_size == 0 { continue; }
+            total_size += dir_size;
+            let name = system_root
+                .iter()
+                .find_map(|root| dir.strip_prefix(root).ok().map(|rel| format!("{}/{}/", root.file_name().unwrap_or_default().to_string_lossy(), rel.display())))
+                .unwrap_or_else(|| dir_str.clone());
+            db_files.push(DbFileInfo {
+                path: dir_str,
+                size: dir_size,
+                name,
+                modified: file_modified_time(&dir),
+            });
+        }
+    }
+
+    finish_scan(&ide.id, merge_duplicates(conversations), db_files, stray_files, total_size, sort)
+}
+
+/// Language and local time for every conversation, sorted, and remembered for paging.
+fn finish_scan(
+    source_id: &str,
+    mut conversations: Vec<ConversationInfo>,
+    db_files: Vec<DbFileInfo>,
+    stray_files: Vec<DbFileInfo>,
+    total_size: u64,
+    sort: ConversationSort,
+) -> ConversationListResult {
+    let protected: HashSet<String> = crate::config::load_config().protected_conversations.into_iter().map(|p| p.id).collect();
+    let mut annotations = crate::conversation_notes::load_annotations();
+    for conv in &mut conversations {
+        conv.language = detect_language(&conv.title).to_string();
+        conv.last_modified_local = conv.last_modified.and_then(format_local_time);
+        conv.protected = protected.contains(&conv.id);
+        if let Some(annotation) = annotations.remove(&conv.id) {
+            conv.tags = annotation.tags;
+            conv.note = annotation.note;
+        }
+    }
+
+    sort_conversations(&mut conversations, sort);
 
-    ConversationListResult {
-        ide_id: ide.id.clone(),
+    let result = ConversationListResult {
+        ide_id: source_id.to_string(),
         conversations,
         db_files,
         total_size,
+        stray_files,
+    };
+    let mut guard = LAST_SCANS.lock().unwrap_or_else(|e| e.into_inner());
+    guard.get_or_insert_with(HashMap::new).insert(result.ide_id.clone(), result.clone());
+    result
+}
+
+/// A protected conversation stored at or below `path`: its database or
+/// conversation folder lies inside it, or `path` is its own file. Bulk cleanups
+/// that remove whole files and folders refuse such paths.
+pub fn protected_conversation_under<'a>(
+    protected: &'a [crate::config::ProtectedConversation],
+    path: &Path,
+) -> Option<&'a crate::config::ProtectedConversation> {
+    protected.iter().find(|p| {
+        let db = Path::new(&p.source_db);
+        db.starts_with(path) || (db.is_dir() && conversation_file_path(db, &p.source_key).starts_with(path))
+    })
+}
+
+/// Pin or unpin a conversation, and flag it in the remembered scans so pages
+/// served without a rescan agree.
+pub fn set_conversation_protected(conv: &ConversationInfo, protected: bool) -> Result<(), String> {
+    let entry = crate::config::ProtectedConversation {
+        id: conv.id.clone(),
+        source_db: conv.source_db.clone(),
+        source_key: conv.source_key.clone(),
+    };
+    crate::config::set_conversation_protected(entry, protected)?;
+    let mut guard = LAST_SCANS.lock().unwrap_or_else(|e| e.into_inner());
+    for c in guard.iter_mut().flat_map(|scans| scans.values_mut()).flat_map(|r| r.conversations.iter_mut()) {
+        if c.id == conv.id {
+            c.protected = protected;
+        }
+    }
+    Ok(())
+}
+
+/// Save a conversation's tags and note, and update the remembered scans.
+pub fn annotate_conversation(id: &str, tags: &[String], note: &str) -> Result<crate::conversation_notes::ConversationAnnotation, String> {
+    let annotation = crate::conversation_notes::set_annotation(id, tags, note)?;
+    let mut guard = LAST_SCANS.lock().unwrap_or_else(|e| e.into_inner());
+    for c in guard.iter_mut().flat_map(|scans| scans.values_mut()).flat_map(|r| r.conversations.iter_mut()) {
+        if c.id == id {
+            c.tags = annotation.tags.clone();
+            c.note = annotation.note.clone();
+        }
+    }
+    Ok(annotation)
+}
+
+// ── Sorting (done here so the webview never re-sorts large lists) ──
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ConversationSortField {
+    Date,
+    Size,
+    MessageCount,
+    Title,
+    /// Source database / folder, so conversations from one store stay together.
+    Source,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ConversationSort {
+    pub field: ConversationSortField,
+    pub descending: bool,
+}
+
+impl Default for ConversationSort {
+    /// Most recent first.
+    fn default() -> Self {
+        Self { field: ConversationSortField::Date, descending: true }
+    }
+}
+
+/// Latest scan per IDE, paged by `query_conversations` without rescanning.
+static LAST_SCANS: Mutex<Option<HashMap<String, ConversationListResult>>> = Mutex::new(None);
+
+/// The source's latest scan, if it has been scanned.
+pub fn last_scan(source_id: &str) -> Option<ConversationListResult> {
+    let guard = LAST_SCANS.lock().unwrap_or_else(|e| e.into_inner());
+    guard.as_ref().and_then(|scans| scans.get(source_id)).cloned()
+}
+
+pub fn sort_conversations(conversations: &mut [ConversationInfo], sort: ConversationSort) {
+    conversations.sort_by(|a, b| {
+        let ord = match sort.field {
+            ConversationSortField::Date => a.last_modified.unwrap_or(0).cmp(&b.last_modified.unwrap_or(0)),
+            ConversationSortField::Size => a.size_bytes.cmp(&b.size_bytes),
+            ConversationSortField::MessageCount => a.message_count.cmp(&b.message_count),
+            ConversationSortField::Title => a.title.to_lowercase().cmp(&b.title.to_lowercase()),
+            ConversationSortField::Source => a.source_db.cmp(&b.source_db),
+        };
+        let ord = if sort.descending { ord.reverse() } else { ord };
+        // Ties: newest first
+        ord.then_with(|| b.last_modified.unwrap_or(0).cmp(&a.last_modified.unwrap_or(0)))
+    });
+}
+
+// ── Paging and filtering (the webview only ever holds one page) ──
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ConversationQuery {
+    #[serde(default)]
+    pub sort: ConversationSort,
+    #[serde(default)]
+    pub offset: usize,
+    /// None returns everything after `offset`.
+    #[serde(default)]
+    pub limit: Option<usize>,
+    /// Case-insensitive substring of the title.
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub min_size: Option<u64>,
+    #[serde(default)]
+    pub max_size: Option<u64>,
+    /// One of the `ConversationInfo::language` codes.
+    #[serde(default)]
+    pub language: Option<String>,
+    /// One of the user's tags, case-insensitive.
+    #[serde(default)]
+    pub tag: Option<String>,
+}
+
+impl ConversationQuery {
+    fn matches(&self, conv: &ConversationInfo, title: Option<&str>) -> bool {
+        title.map_or(true, |t| conv.title.to_lowercase().contains(t))
+            && self.min_size.map_or(true, |min| conv.size_bytes >= min)
+            && self.max_size.map_or(true, |max| conv.size_bytes <= max)
+            && self.language.as_deref().map_or(true, |lang| conv.language == lang)
+            && self.tag.as_deref().map_or(true, |tag| conv.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConversationPage {
+    pub ide_id: String,
+    pub conversations: Vec<ConversationInfo>,
+    pub offset: usize,
+    /// Conversations matching the filters, across all pages.
+    pub matched: usize,
+    pub matched_size: u64,
+    /// Every conversation of the IDE, filtered or not.
+    pub total_conversations: usize,
+    pub db_files: Vec<DbFileInfo>,
+    pub total_size: u64,
+    /// Distinct title languages among all conversations, for the filter picker.
+    pub languages: Vec<String>,
+    /// Distinct tags among all conversations, for the filter picker.
+    pub tags: Vec<String>,
+    /// Redundant copies merged into the IDE's conversations, and their size.
+    pub duplicate_copies: usize,
+    pub duplicate_size: u64,
+    pub stray_files: Vec<DbFileInfo>,
+}
+
+/// Filter, sort and slice one scan result.
+pub fn page_conversations(result: &ConversationListResult, query: &ConversationQuery) -> ConversationPage {
+    let title = query.title.as_deref().map(str::trim).filter(|t| !t.is_empty()).map(str::to_lowercase);
+    let mut matched: Vec<ConversationInfo> =
+        result.conversations.iter().filter(|c| query.matches(c, title.as_deref())).cloned().collect();
+    sort_conversations(&mut matched, query.sort);
+    let languages: Vec<String> = result
+        .conversations
+        .iter()
+        .map(|c| c.language.as_str())
+        .filter(|l| !l.is_empty())
+        .collect::<std::collections::BTreeSet<_>>()
+        .into_iter()
+        .map(String::from)
+        .collect();
+    let tags: Vec<String> = result
+        .conversations
+        .iter()
+        .flat_map(|c| c.tags.iter().cloned())
+        .collect::<std::collections::BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    ConversationPage {
+        ide_id: result.ide_id.clone(),
+        offset: query.offset,
+        matched: matched.len(),
+        matched_size: matched.iter().map(|c| c.size_bytes).sum(),
+        conversations: matched.into_iter().skip(query.offset).take(query.limit.unwrap_or(usize::MAX)).collect(),
+        total_conversations: result.conversations.len(),
+        db_files: result.db_files.clone(),
+        total_size: result.total_size,
+        languages,
+        tags,
+        duplicate_copies: result.conversations.iter().map(|c| c.duplicates.len()).sum(),
+        duplicate_size: result.conversations.iter().flat_map(|c| &c.duplicates).map(|d| d.size_bytes).sum(),
+        stray_files: result.stray_files.clone(),
+    }
+}
+
+/// One page of the IDE's conversations. Pages come from the last scan unless
+/// `rescan` is set or the IDE hasn't been scanned yet.
+pub fn query_conversations(ide: &crate::ide_detector::IdeInfo, query: &ConversationQuery, rescan: bool) -> ConversationPage {
+    query_source(&ide.id, query, rescan, |sort| scan_conversations(ide, sort))
+}
+
+/// `query_conversations` for the CLI agents source.
+pub fn query_cli_agents(query: &ConversationQuery, rescan: bool) -> ConversationPage {
+    query_source(CLI_AGENTS_ID, query, rescan, scan_cli_agents)
+}
+
+fn query_source(source_id: &str, query: &ConversationQuery, rescan: bool, scan: impl FnOnce(ConversationSort) -> ConversationListResult) -> ConversationPage {
+    if !rescan {
+        let guard = LAST_SCANS.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(result) = guard.as_ref().and_then(|scans| scans.get(source_id)) {
+            return page_conversations(result, query);
+        }
+    }
+    page_conversations(&scan(query.sort), query)
+}
+
+// ── Fuzzing entry points (cargo fuzz targets live in fuzz/) ──
+
+/// Drives the private parsers the way a scan does, for the fuzz targets and
+/// the property tests below.
+#[cfg(any(test, feature = "fuzzing"))]
+pub mod fuzzing {
+    use super::*;
+
+    const DB: &str = "fuzz/state.vscdb";
+    /// Smallest preview the settings allow, so long inputs also take the windowed path.
+    const PREVIEW_LEN: usize = 1_000;
+
+    pub fn parse_chat_value(json: &str) -> Vec<ConversationInfo> {
+        super::parse_chat_value(json, DB, "fuzz", None)
+    }
+
+    /// Store `value` under `key` in a scratch ItemTable and extract it from its preview.
+    pub fn extract_from_preview(key: &str, value: &str) -> Option<ConversationInfo> {
+        let conn = Connection::open_in_memory().ok()?;
+        conn.execute_batch("CREATE TABLE ItemTable (key TEXT UNIQUE ON CONFLICT REPLACE, value BLOB)").ok()?;
+        conn.execute("INSERT INTO ItemTable (key, value) VALUES (?1, ?2)", [key, value]).ok()?;
+        let entry = scan_keys_preview(&conn, "ItemTable", key, PREVIEW_LEN).into_iter().find(|e| e.key == key)?;
+        let limits = ReadLimits { preview_len: PREVIEW_LEN, max_full_read: DEFAULT_MAX_FULL_READ };
+        super::extract_from_preview(&conn, "ItemTable", &entry, DB, None, &limits)
+    }
+
+    /// Store `bytes` as an Antigravity BLOB in a scratch ItemTable and decode it.
+    pub fn decode_protobuf(bytes: &[u8]) -> Option<ConversationInfo> {
+        const KEY: &str = "jetskiStateSync.fuzz";
+        let conn = Connection::open_in_memory().ok()?;
+        conn.execute_batch("CREATE TABLE ItemTable (key TEXT UNIQUE ON CONFLICT REPLACE, value BLOB)").ok()?;
+        conn.execute("INSERT INTO ItemTable (key, value) VALUES (?1, ?2)", rusqlite::params![KEY, bytes]).ok()?;
+        let entry = KeyEntry { key: KEY.into(), preview: String::new(), size: bytes.len() as u64 };
+        let limits = ReadLimits { preview_len: PREVIEW_LEN, max_full_read: DEFAULT_MAX_FULL_READ };
+        extract_antigravity_protobuf(&conn, "ItemTable", &entry, DB, None, &limits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //! Property tests: random JSON shapes, truncated previews and invalid
+    //! UTF-8 must never panic the parsers and must not produce bogus titles.
+    //! Inputs come from a seeded generator, so a failure names its seed.
+
+    use super::*;
+
+    const CASES: u64 = 500;
+    const KEY: &str = "fuzz";
+    const DB: &str = "fuzz/state.vscdb";
+
+    /// Keys the parsers look for, so random objects hit the interesting paths.
+    const KEYS: &[&str] = &[
+        "tabs", "allComposers", "conversations", "chats", "history", "data", "items", "threads",
+        "sessions", "chatTitle", "title", "name", "subject", "description", "bubbles", "messages",
+        "conversation", "turns", "exchanges", "entries", "requests", "id", "chatId", "composerId",
+        "conversationId", "copilot", "text", "role", "type",
+    ];
+    const FRAGMENTS: &[&str] = &[
+        "hello", " ", "修复登录", "日本語のテスト", "Привет", "한국어", "🦀", "é", "\"", "\\", "\n",
+        "\u{0}", "\"title\":\"", "composerData:", "{", "}", "[", ":", ",",
+    ];
+
+    /// xorshift64*: tiny, deterministic, good enough to shape test inputs.
+    struct Rng(u64);
+
+    impl Rng {
+        fn new(seed: u64) -> Rng {
+            Rng(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0 ^= self.0 >> 12;
+            self.0 ^= self.0 << 25;
+            self.0 ^= self.0 >> 27;
+            self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+        }
+        fn below(&mut self, n: usize) -> usize {
+            (self.next_u64() % n.max(1) as u64) as usize
+        }
+        fn pick<'a>(&mut self, items: &[&'a str]) -> &'a str {
+            items[self.below(items.len())]
+        }
+    }
+
+    fn arb_string(rng: &mut Rng, max_parts: usize) -> String {
+        (0..rng.below(max_parts + 1)).map(|_| rng.pick(FRAGMENTS)).collect()
+    }
+
+    fn arb_json(rng: &mut Rng, depth: usize) -> serde_json::Value {
+        use serde_json::Value;
+        let kind = if depth == 0 { rng.below(5) } else { rng.below(8) };
+        match kind {
+            0 => Value::Null,
+            1 => Value::Bool(rng.below(2) == 0),
+            2 => Value::from(rng.next_u64() as i64),
+            3 => Value::from(rng.below(1_000_000) as f64 / 7.0),
+            4 => Value::String(arb_string(rng, 6)),
+            5 => Value::Array((0..rng.below(5)).map(|_| arb_json(rng, depth - 1)).collect()),
+            _ => {
+                let mut map = serde_json::Map::new();
+                for _ in 0..rng.below(7) {
+                    let key = if rng.below(4) == 0 { arb_string(rng, 2) } else { rng.pick(KEYS).to_string() };
+                    map.insert(key, arb_json(rng, depth - 1));
+                }
+                Value::Object(map)
+            }
+        }
+    }
+
+    /// Up to `n` cut points of `s`, all on char boundaries.
+    fn cut_points(rng: &mut Rng, s: &str, n: usize) -> Vec<usize> {
+        let mut cuts: Vec<usize> = (0..n).map(|_| rng.below(s.len() + 1)).collect();
+        for cut in &mut cuts {
+            while !s.is_char_boundary(*cut) {
+                *cut -= 1;
+            }
+        }
+        cuts
+    }
+
+    fn assert_well_formed(conv: &ConversationInfo, key: &str, input: &str) {
+        assert!(!conv.title.is_empty(), "empty title for {:?}", input);
+        assert_eq!(conv.source_key, key, "input {:?}", input);
+        assert!(conv.id.starts_with(DB), "id {:?} for {:?}", conv.id, input);
+    }
+
+    #[test]
+    fn parse_chat_value_accepts_any_json_shape() {
+        for seed in 0..CASES {
+            let mut rng = Rng::new(seed);
+            let json = arb_json(&mut rng, 4).to_string();
+            for conv in parse_chat_value(&json, DB, KEY, None) {
+                assert_well_formed(&conv, KEY, &json);
+            }
+            // Truncated values are what the preview hands over for large keys
+            for cut in cut_points(&mut rng, &json, 8) {
+                for conv in parse_chat_value(&json[..cut], DB, KEY, None) {
+                    assert_well_formed(&conv, KEY, &json[..cut]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn titles_survive_escaping_and_truncation() {
+        for seed in 0..CASES {
+            let mut rng = Rng::new(seed);
+            let title = format!("{}{}", rng.pick(FRAGMENTS), arb_string(&mut rng, 5));
+            let messages: Vec<serde_json::Value> = (0..rng.below(6))
+                .map(|i| serde_json::json!({ "role": if i % 2 == 0 { "user" } else { "assistant" }, "text": arb_string(&mut rng, 4) }))
+                .collect();
+            let json = serde_json::json!({ "title": title, "messages": messages }).to_string();
+
+            let parsed = parse_chat_value(&json, DB, KEY, None);
+            assert_eq!(parsed.len(), 1, "seed {}: {}", seed, json);
+            assert_eq!(parsed[0].title, title, "seed {}", seed);
+            assert_eq!(parsed[0].message_count, messages.len(), "seed {}", seed);
+            assert_eq!(count_messages_from_text(&json), messages.len(), "seed {}", seed);
+
+            // Any preview that still holds the closing quote yields the exact title
+            let title_end = json.find("\"title\":").unwrap() + 8 + serde_json::to_string(&title).unwrap().len();
+            for cut in cut_points(&mut rng, &json, 8) {
+                let extracted = extract_title_from_text(&json[..cut]);
+                if cut >= title_end {
+                    assert_eq!(extracted, title, "seed {}: cut {} of {}", seed, cut, json);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn text_fallbacks_never_panic() {
+        for seed in 0..CASES {
+            let mut rng = Rng::new(seed);
+            // Raw fragments: unbalanced quotes, dangling backslashes, split escapes
+            let text = arb_string(&mut rng, 24);
+            let title = extract_title_from_text(&text);
+            assert!(title.len() < 200, "seed {}: {:?}", seed, text);
+            count_messages_from_text(&text);
+
+            let key_title = clean_key_title(&format!("composerData:{}", arb_string(&mut rng, 3)));
+            assert!(key_title.starts_with("Composer"), "seed {}: {:?}", seed, key_title);
+            assert!(key_title.chars().count() <= "Composer ".len() + 8);
+        }
+    }
+
+    #[test]
+    fn extract_from_preview_handles_any_value() {
+        let keys = ["composerData:fuzz", "workbench.panel.aichat.view.aichat.chatdata", "memento/interactive-session", "aiService.prompts"];
+        for seed in 0..CASES {
+            let mut rng = Rng::new(seed);
+            let key = keys[rng.below(keys.len())];
+            let mut value = arb_json(&mut rng, 4).to_string();
+            // Some values outgrow the preview and go through the windowed reader
+            if rng.below(4) == 0 {
+                let filler = arb_json(&mut rng, 3).to_string();
+                value = format!("{{\"padding\":[{}],\"inner\":{}}}", vec![filler; 64].join(","), value);
+            }
+            let cut = cut_points(&mut rng, &value, 1)[0];
+            for input in [&value[..], &value[..cut]] {
+                if let Some(conv) = fuzzing::extract_from_preview(key, input) {
+                    assert_well_formed(&conv, key, input);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn protobuf_decoder_tolerates_arbitrary_bytes() {
+        for seed in 0..CASES {
+            let mut rng = Rng::new(seed);
+            let mut bytes = Vec::new();
+            for _ in 0..rng.below(40) {
+                match rng.below(4) {
+                    // Field tag + length prefix, as protobuf frames strings
+                    0 => bytes.extend([0x0a, rng.below(128) as u8]),
+                    // Invalid UTF-8: lone continuation/lead bytes, truncated sequences
+                    1 => bytes.extend_from_slice([&[0xff][..], &[0xc3], &[0xe6, 0x97], &[0x80, 0x80]][rng.below(4)]),
+                    2 => bytes.extend(arb_string(&mut rng, 3).into_bytes()),
+                    _ => bytes.extend((0..rng.below(16)).map(|_| rng.next_u64() as u8)),
+                }
+            }
+
+            if let Some(cascade) = decode_protobuf_conversation(&bytes) {
+                assert!(cascade.messages.iter().all(|m| !m.content.is_empty()), "seed {}", seed);
+            }
+            if let Some(conv) = fuzzing::decode_protobuf(&bytes) {
+                let single_line = !conv.title.trim().is_empty() && !conv.title.contains('\n');
+                assert!(single_line, "seed {}: title {:?}", seed, conv.title);
+            }
+        }
+    }
+
+    #[test]
+    fn protobuf_decoder_finds_framed_title() {
+        let title = "Refactor the scanner module";
+        let mut bytes = vec![0x0a, title.len() as u8];
+        bytes.extend(title.as_bytes());
+        bytes.extend([0x10, 0x05, 0x1a, 0x03, 0xff, 0xfe, 0x00]);
+        assert_eq!(fuzzing::decode_protobuf(&bytes).map(|c| c.title), Some(title.to_string()));
+
+        // Stored as base64 text, the way some Antigravity versions write it
+        use base64::Engine;
+        let encoded = base64::engine::general_purpose::STANDARD.encode(&bytes);
+        assert_eq!(fuzzing::decode_protobuf(encoded.as_bytes()).map(|c| c.title), Some(title.to_string()));
+    }
+
+    /// Length-delimited field `number` holding `payload`.
+    fn framed(number: u8, payload: &[u8]) -> Vec<u8> {
+        let mut out = vec![number << 3 | 2, payload.len() as u8];
+        out.extend_from_slice(payload);
+        out
+    }
+
+    #[test]
+    fn cascade_decoder_reads_turns_and_title() {
+        let turn = |role: &str, text: &str, seconds: u32| {
+            let mut ts = vec![0x08];
+            let mut s = seconds;
+            while s >= 0x80 {
+                ts.push((s as u8) | 0x80);
+                s >>= 7;
+            }
+            ts.push(s as u8);
+            [framed(1, role.as_bytes()), framed(2, text.as_bytes()), framed(3, &ts)].concat()
+        };
+        let bytes = [
+            framed(1, b"0b6f1c2e-5d1a-4c1e-9a55-3f0e8d7c6b5a"),
+            framed(2, b"Fix the login redirect"),
+            framed(3, &turn("user", "Why does login loop forever?", 1_767_225_600)),
+            framed(3, &turn("assistant", "The session cookie is never set.", 1_767_225_660)),
+        ]
+        .concat();
+
+        let cascade = decode_protobuf_conversation(&bytes).unwrap();
+        assert_eq!(cascade.title.as_deref(), Some("Fix the login redirect"));
+        let roles: Vec<&str> = cascade.messages.iter().map(|m| m.role.as_str()).collect();
+        assert_eq!(roles, ["user", "assistant"]);
+        assert_eq!(cascade.messages[1].content, "The session cookie is never set.");
+        assert_eq!(cascade.messages[0].timestamp, Some(1_767_225_600_000));
+        assert_eq!(cascade.last_modified, Some(1_767_225_660_000));
+    }
+
+    #[test]
+    fn kana_is_japanese_and_han_alone_is_chinese() {
+        assert_eq!(detect_language("ありがとう"), "ja");
+        assert_eq!(detect_language("ｶﾀｶﾅ"), "ja");
+        assert_eq!(detect_language("日本語のテスト"), "ja");
+        assert_eq!(detect_language("修复登录问题"), "zh");
+        assert_eq!(detect_language("𠀀𠀁 fix"), "zh");
+        assert_eq!(detect_language("로그인 오류 수정"), "ko");
     }
 }
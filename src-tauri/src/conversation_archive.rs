@@ -0,0 +1,265 @@
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+
+use crate::batch::{BatchResult, ItemResult, ItemStatus};
+use crate::conversation::{self, BatchDeleteRequest, ConversationContent, ConversationInfo, RawConversation, RawLocation, RawRow};
+
+const ARCHIVE_FILE: &str = "conversation_archive.db";
+
+/// A conversation moved out of its IDE into DevCleaner's archive.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchivedConversation {
+    /// Row id in the archive.
+    pub id: i64,
+    pub ide_id: String,
+    /// `ConversationInfo::id` at the time it was archived.
+    pub conversation_id: String,
+    pub title: String,
+    pub source_db: String,
+    pub source_key: String,
+    pub message_count: usize,
+    pub size_bytes: u64,
+    /// UTC epoch milliseconds.
+    pub last_modified: Option<i64>,
+    /// UTC epoch milliseconds.
+    pub archived_at: i64,
+}
+
+fn open_archive() -> Result<Connection, String> {
+    let dir = crate::config::get_app_data_dir();
+    std::fs::create_dir_all(&dir).map_err(|e| format!("Failed to create data dir: {}", e))?;
+    let conn = Connection::open(dir.join(ARCHIVE_FILE)).map_err(|e| format!("Failed to open archive: {}", e))?;
+    crate::db_pool::apply_secure_delete(&conn);
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS archived_conversations (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            ide_id TEXT NOT NULL,
+            conversation_id TEXT NOT NULL,
+            title TEXT NOT NULL,
+            source_db TEXT NOT NULL,
+            source_key TEXT NOT NULL,
+            location TEXT NOT NULL,
+            raw BLOB NOT NULL,
+            content TEXT NOT NULL,
+            message_count INTEGER NOT NULL,
+            size_bytes INTEGER NOT NULL,
+            last_modified INTEGER,
+            archived_at INTEGER NOT NULL
+        );
+        CREATE TABLE IF NOT EXISTS archived_rows (
+            archive_id INTEGER NOT NULL,
+            key TEXT NOT NULL,
+            text INTEGER NOT NULL,
+            value BLOB NOT NULL
+        );
+        CREATE INDEX IF NOT EXISTS archived_rows_archive_id ON archived_rows (archive_id);",
+    )
+    .map_err(|e| format!("Failed to create archive table: {}", e))?;
+    Ok(conn)
+}
+
+/// Copy one conversation's raw value and the rows its delete takes along
+/// (a Cursor composer's bubbles), plus the parsed messages for viewing, into
+/// the archive. Returns the new row id.
+fn archive_one(archive: &Connection, ide_id: &str, conv: &ConversationInfo) -> Result<i64, String> {
+    let raw = conversation::read_raw_conversation(&conv.source_db, &conv.source_key)?;
+    // Encrypted .pb cascades can't be parsed; their raw bytes are still kept
+    let content = conversation::get_conversation_content(&conv.source_db, &conv.source_key, &conversation::conversation_id_of(&conv.id))
+        .unwrap_or_else(|_| ConversationContent { title: conv.title.clone(), messages: Vec::new() });
+    let location = serde_json::to_string(&raw.location).map_err(|e| format!("Failed to serialize location: {}", e))?;
+    let content = serde_json::to_string(&content).map_err(|e| format!("Failed to serialize content: {}", e))?;
+    archive
+        .execute(
+            "INSERT INTO archived_conversations
+                (ide_id, conversation_id, title, source_db, source_key, location, raw, content, message_count, size_bytes, last_modified, archived_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+            params![
+                ide_id,
+                conv.id,
+                conv.title,
+                conv.source_db,
+                conv.source_key,
+                location,
+                raw.data,
+                content,
+                conv.message_count as i64,
+                conv.size_bytes as i64,
+                conv.last_modified,
+                chrono::Utc::now().timestamp_millis(),
+            ],
+        )
+        .map_err(|e| format!("Failed to write archive: {}", e))?;
+    let id = archive.last_insert_rowid();
+    for row in &raw.related {
+        let stored = archive.execute(
+            "INSERT INTO archived_rows (archive_id, key, text, value) VALUES (?1, ?2, ?3, ?4)",
+            params![id, row.key, row.text, row.data],
+        );
+        if let Err(e) = stored {
+            let _ = drop_entry(archive, id);
+            return Err(format!("Failed to write archive: {}", e));
+        }
+    }
+    Ok(id)
+}
+
+/// Remove an entry and its related rows from the archive.
+fn drop_entry(archive: &Connection, id: i64) -> rusqlite::Result<usize> {
+    archive.execute("DELETE FROM archived_rows WHERE archive_id = ?1", [id])?;
+    archive.execute("DELETE FROM archived_conversations WHERE id = ?1", [id])
+}
+
+fn related_rows(archive: &Connection, id: i64) -> Result<Vec<RawRow>, String> {
+    let mut stmt = archive
+        .prepare("SELECT key, text, value FROM archived_rows WHERE archive_id = ?1")
+        .map_err(|e| format!("Failed to read archive: {}", e))?;
+    let rows = stmt
+        .query_map([id], |row| Ok(RawRow { key: row.get(0)?, text: row.get(1)?, data: row.get(2)? }))
+        .map_err(|e| format!("Failed to read archive: {}", e))?;
+    rows.collect::<rusqlite::Result<Vec<_>>>().map_err(|e| format!("Failed to read archive: {}", e))
+}
+
+/// Archive, then delete from the IDE. Protected conversations and ones in a
+/// marked folder are reported as skipped without being copied. A conversation is only deleted once its copy
+/// is in the archive; if the delete fails the copy is dropped again, so
+/// retrying doesn't archive it twice.
+pub fn archive_conversations(ide_id: &str, conversations: &[ConversationInfo]) -> Result<BatchResult, String> {
+    let archive = open_archive()?;
+    let mut results: Vec<Option<ItemResult>> = vec![None; conversations.len()];
+    let mut archived: Vec<(usize, i64)> = Vec::new();
+    let protected = crate::config::load_config().protected_conversations;
+    for (i, conv) in conversations.iter().enumerate() {
+        if protected.iter().any(|p| p.id == conv.id || (p.source_db == conv.source_db && p.source_key == conv.source_key)) {
+            results[i] = Some(ItemResult::skipped(conv.id.clone(), "Protected"));
+            continue;
+        }
+        if crate::cleaner::is_marked(std::path::Path::new(&conv.source_db)) {
+            results[i] = Some(ItemResult::skipped(conv.id.clone(), "In a folder marked to be left alone"));
+            continue;
+        }
+        match archive_one(&archive, ide_id, conv) {
+            Ok(row) => archived.push((i, row)),
+            Err(e) => results[i] = Some(ItemResult::failed(conv.id.clone(), e)),
+        }
+    }
+
+    let requests: Vec<BatchDeleteRequest> = archived
+        .iter()
+        .map(|&(i, _)| BatchDeleteRequest {
+            id: conversations[i].id.clone(),
+            source_db: conversations[i].source_db.clone(),
+            source_key: conversations[i].source_key.clone(),
+        })
+        .collect();
+    let deleted = conversation::delete_conversations_batch(&requests, true);
+    for (&(i, row), item) in archived.iter().zip(deleted.items) {
+        if matches!(item.status, ItemStatus::Failed | ItemStatus::Skipped) {
+            let _ = drop_entry(&archive, row);
+        }
+        results[i] = Some(item);
+    }
+
+    Ok(BatchResult::from_items(results.into_iter().flatten().collect()))
+}
+
+/// Newest first.
+pub fn list_archived_conversations() -> Result<Vec<ArchivedConversation>, String> {
+    let archive = open_archive()?;
+    let mut stmt = archive
+        .prepare(
+            "SELECT id, ide_id, conversation_id, title, source_db, source_key, message_count, size_bytes, last_modified, archived_at
+             FROM archived_conversations ORDER BY archived_at DESC, id DESC",
+        )
+        .map_err(|e| format!("Failed to read archive: {}", e))?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok(ArchivedConversation {
+                id: row.get(0)?,
+                ide_id: row.get(1)?,
+                conversation_id: row.get(2)?,
+                title: row.get(3)?,
+                source_db: row.get(4)?,
+                source_key: row.get(5)?,
+                message_count: row.get::<_, i64>(6)? as usize,
+                size_bytes: row.get::<_, i64>(7)? as u64,
+                last_modified: row.get(8)?,
+                archived_at: row.get(9)?,
+            })
+        })
+        .map_err(|e| format!("Failed to read archive: {}", e))?;
+    Ok(rows.filter_map(|r| r.ok()).collect())
+}
+
+/// Messages as they were parsed when the conversation was archived.
+pub fn get_archived_conversation(id: i64) -> Result<ConversationContent, String> {
+    let archive = open_archive()?;
+    let content: String = archive
+        .query_row("SELECT content FROM archived_conversations WHERE id = ?1", [id], |row| row.get(0))
+        .map_err(|_| "Archived conversation not found".to_string())?;
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse archived content: {}", e))
+}
+
+/// Write an archived conversation back into the IDE it came from, then drop
+/// it from the archive. Fails, leaving the archive untouched, if the IDE has
+/// stored something else under the same key in the meantime.
+pub fn restore_conversation(archive_id: i64) -> Result<ArchivedConversation, String> {
+    let archive = open_archive()?;
+    let (entry, location, data) = archive
+        .query_row(
+            "SELECT id, ide_id, conversation_id, title, source_db, source_key, message_count, size_bytes, last_modified, archived_at, location, raw
+             FROM archived_conversations WHERE id = ?1",
+            [archive_id],
+            |row| {
+                Ok((
+                    ArchivedConversation {
+                        id: row.get(0)?,
+                        ide_id: row.get(1)?,
+                        conversation_id: row.get(2)?,
+                        title: row.get(3)?,
+                        source_db: row.get(4)?,
+                        source_key: row.get(5)?,
+                        message_count: row.get::<_, i64>(6)? as usize,
+                        size_bytes: row.get::<_, i64>(7)? as u64,
+                        last_modified: row.get(8)?,
+                        archived_at: row.get(9)?,
+                    },
+                    row.get::<_, String>(10)?,
+                    row.get::<_, Vec<u8>>(11)?,
+                ))
+            },
+        )
+        .map_err(|_| "Archived conversation not found".to_string())?;
+
+    let location: RawLocation = serde_json::from_str(&location).map_err(|e| format!("Failed to parse archived location: {}", e))?;
+    let related = related_rows(&archive, archive_id)?;
+    conversation::write_raw_conversation(&entry.source_db, &entry.source_key, &RawConversation { location, data, related })?;
+    drop_entry(&archive, archive_id)
+        .map_err(|e| format!("Restored, but failed to remove it from the archive: {}", e))?;
+    Ok(entry)
+}
+
+/// Drop conversations from the archive for good.
+pub fn delete_archived_conversations(ids: &[i64]) -> Result<BatchResult, String> {
+    let archive = open_archive()?;
+    let items = ids
+        .iter()
+        .map(|id| {
+            let size: i64 = archive
+                .query_row(
+                    "SELECT length(raw) + length(content)
+                        + (SELECT COALESCE(SUM(length(value)), 0) FROM archived_rows WHERE archive_id = ?1)
+                     FROM archived_conversations WHERE id = ?1",
+                    [id],
+                    |row| row.get(0),
+                )
+                .unwrap_or(0);
+            match drop_entry(&archive, *id) {
+                Ok(0) => ItemResult::not_found(id.to_string()),
+                Ok(_) => ItemResult::done(id.to_string(), size as u64),
+                Err(e) => ItemResult::failed(id.to_string(), format!("Failed to delete from archive: {}", e)),
+            }
+        })
+        .collect();
+    let _ = crate::db_pool::vacuum(&archive);
+    Ok(BatchResult::from_items(items))
+}
@@ -0,0 +1,380 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use zip::write::SimpleFileOptions;
+
+use crate::batch::{BatchResult, ItemResult};
+use crate::conversation::{self, BatchDeleteRequest, ConversationContent};
+
+const INDEX_FILE: &str = "index.json";
+/// Longest title kept in an archive file name.
+const MAX_NAME_CHARS: usize = 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ExportFormat {
+    /// Self-contained page with chat bubbles and highlighted code, for sharing.
+    Html,
+    /// One `{"role", "content", "timestamp"}` object per line, for other tools
+    /// and fine-tuning pipelines.
+    Jsonl,
+}
+
+impl ExportFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Html => "html",
+            ExportFormat::Jsonl => "jsonl",
+        }
+    }
+}
+
+/// Render a conversation the viewer already loaded.
+pub fn render_conversation(content: &ConversationContent, format: ExportFormat) -> String {
+    match format {
+        ExportFormat::Html => render_html(content),
+        ExportFormat::Jsonl => render_jsonl(content),
+    }
+}
+
+/// Read one conversation from its IDE and write it to `path` as `format`,
+/// with credentials replaced when `redact` is set.
+pub fn export_conversation(
+    source_db: &str,
+    source_key: &str,
+    conversation_id: &str,
+    format: ExportFormat,
+    path: &Path,
+    redact: bool,
+) -> Result<(), String> {
+    let mut content = conversation::get_conversation_content(source_db, source_key, conversation_id)?;
+    if content.messages.is_empty() {
+        return Err("Conversation has no readable messages".into());
+    }
+    if redact {
+        crate::secret_scan::redact_content(&mut content);
+    }
+    fs::write(path, render_conversation(&content, format)).map_err(|e| format!("Failed to write export: {}", e))
+}
+
+/// One row of the batch archive's `index.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportIndexEntry {
+    pub id: String,
+    pub title: String,
+    /// Archive entry; None when the conversation couldn't be read.
+    pub file: Option<String>,
+    pub message_count: usize,
+    pub source_db: String,
+    pub source_key: String,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportIndex {
+    pub created_at: String,
+    pub format: ExportFormat,
+    pub conversations: Vec<ExportIndexEntry>,
+}
+
+/// Title → something every file system accepts.
+fn file_stem(title: &str) -> String {
+    let stem: String = title
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == '-' || c == '_' || c == ' ' { c } else { '_' })
+        .take(MAX_NAME_CHARS)
+        .collect();
+    let stem = stem.trim().to_string();
+    if stem.is_empty() { "conversation".into() } else { stem }
+}
+
+/// Export many conversations into one zip at `target_zip`: one file per
+/// conversation plus an `index.json` listing every request and its outcome.
+/// Items are read grouped by database, the same way batch delete opens them;
+/// an item's `bytes_freed` is the size of its exported file. `redact` replaces
+/// credentials as in `export_conversation`.
+pub fn export_conversations_batch(items: &[BatchDeleteRequest], format: ExportFormat, target_zip: &Path, redact: bool) -> Result<BatchResult, String> {
+    let mut results: Vec<Option<ItemResult>> = vec![None; items.len()];
+    let mut index: Vec<Option<ExportIndexEntry>> = vec![None; items.len()];
+    let mut rendered: Vec<Option<(String, String)>> = vec![None; items.len()];
+
+    for indices in conversation::group_by_db(items).values() {
+        for &i in indices {
+            let item = &items[i];
+            let mut entry = ExportIndexEntry {
+                id: item.item_id(),
+                title: String::new(),
+                file: None,
+                message_count: 0,
+                source_db: item.source_db.clone(),
+                source_key: item.source_key.clone(),
+                error: None,
+            };
+            let content = conversation::get_conversation_content(&item.source_db, &item.source_key, &conversation::conversation_id_of(&item.id))
+                .and_then(|c| if c.messages.is_empty() { Err("Conversation has no readable messages".into()) } else { Ok(c) });
+            match content {
+                Ok(mut content) => {
+                    if redact {
+                        crate::secret_scan::redact_content(&mut content);
+                    }
+                    let name = format!("{:04}-{}.{}", i + 1, file_stem(&content.title), format.extension());
+                    entry.title = content.title.clone();
+                    entry.message_count = content.messages.len();
+                    entry.file = Some(name.clone());
+                    rendered[i] = Some((name, render_conversation(&content, format)));
+                }
+                Err(e) => {
+                    results[i] = Some(ItemResult::failed(entry.id.clone(), e.clone()));
+                    entry.error = Some(e);
+                }
+            }
+            index[i] = Some(entry);
+        }
+    }
+
+    let file = fs::File::create(target_zip).map_err(|e| format!("Failed to create archive: {}", e))?;
+    let mut zip = zip::ZipWriter::new(file);
+    let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+    for (i, item) in rendered.into_iter().enumerate() {
+        let Some((name, data)) = item else { continue };
+        let id = items[i].item_id();
+        let written = zip
+            .start_file(name.as_str(), options)
+            .map_err(|e| e.to_string())
+            .and_then(|_| zip.write_all(data.as_bytes()).map_err(|e| e.to_string()));
+        results[i] = Some(match written {
+            Ok(()) => ItemResult::done(id, data.len() as u64),
+            Err(e) => ItemResult::failed(id, format!("Failed to add {}: {}", name, e)),
+        });
+    }
+
+    let index = ExportIndex {
+        created_at: chrono::Local::now().to_rfc3339(),
+        format,
+        conversations: index.into_iter().flatten().collect(),
+    };
+    let data = serde_json::to_vec_pretty(&index).map_err(|e| format!("Failed to serialize index: {}", e))?;
+    zip.start_file(INDEX_FILE, options).map_err(|e| format!("Failed to add {}: {}", INDEX_FILE, e))?;
+    zip.write_all(&data).map_err(|e| format!("Failed to write {}: {}", INDEX_FILE, e))?;
+    zip.finish().map_err(|e| format!("Failed to finalize zip: {}", e))?;
+
+    Ok(BatchResult::from_items(results.into_iter().flatten().collect()))
+}
+
+// ── JSONL ──
+
+#[derive(Serialize)]
+struct JsonlMessage<'a> {
+    role: &'a str,
+    content: &'a str,
+    /// RFC 3339 UTC, or null when the IDE doesn't store per-message times.
+    timestamp: Option<String>,
+}
+
+fn render_jsonl(content: &ConversationContent) -> String {
+    content
+        .messages
+        .iter()
+        .filter_map(|m| {
+            let line = JsonlMessage {
+                role: &m.role,
+                content: &m.content,
+                timestamp: m.timestamp.and_then(chrono::DateTime::from_timestamp_millis).map(|t| t.to_rfc3339()),
+            };
+            serde_json::to_string(&line).ok()
+        })
+        .map(|line| line + "\n")
+        .collect()
+}
+
+// ── HTML ──
+
+const HTML_STYLE: &str = r#"
+:root { color-scheme: light dark; --bg: #f6f7f9; --fg: #1f2328; --muted: #6e7781; --user: #dbeafe; --assistant: #ffffff; --system: #fef3c7; --border: #d0d7de; --code-bg: #f0f2f4; --k: #cf222e; --s: #0a3069; --n: #0550ae; --c: #6e7781; }
+@media (prefers-color-scheme: dark) {
+  :root { --bg: #0d1117; --fg: #e6edf3; --muted: #8d96a0; --user: #1f3a5f; --assistant: #161b22; --system: #3d2e00; --border: #30363d; --code-bg: #0b0f14; --k: #ff7b72; --s: #a5d6ff; --n: #79c0ff; --c: #8b949e; }
+}
+* { box-sizing: border-box; }
+body { margin: 0; background: var(--bg); color: var(--fg); font: 15px/1.55 -apple-system, BlinkMacSystemFont, "Segoe UI", Roboto, "Noto Sans", "PingFang SC", "Microsoft YaHei", sans-serif; }
+header { max-width: 860px; margin: 0 auto; padding: 32px 20px 8px; }
+h1 { font-size: 20px; margin: 0 0 4px; }
+.meta { color: var(--muted); font-size: 12px; margin: 0; }
+main { max-width: 860px; margin: 0 auto; padding: 12px 20px 48px; display: flex; flex-direction: column; gap: 14px; }
+.msg { display: flex; flex-direction: column; max-width: 88%; }
+.msg.user { align-self: flex-end; align-items: flex-end; }
+.msg.assistant { align-self: flex-start; }
+.msg.system { align-self: center; max-width: 100%; }
+.role { font-size: 11px; text-transform: uppercase; letter-spacing: .04em; color: var(--muted); margin: 0 6px 3px; }
+.bubble { background: var(--assistant); border: 1px solid var(--border); border-radius: 14px; padding: 10px 14px; white-space: pre-wrap; overflow-wrap: anywhere; min-width: 0; max-width: 100%; }
+.user .bubble { background: var(--user); border-bottom-right-radius: 4px; }
+.assistant .bubble { border-bottom-left-radius: 4px; }
+.system .bubble { background: var(--system); font-size: 13px; }
+code { font: 13px/1.45 ui-monospace, SFMono-Regular, Menlo, Consolas, monospace; background: var(--code-bg); border-radius: 4px; padding: 1px 4px; }
+pre { background: var(--code-bg); border: 1px solid var(--border); border-radius: 8px; padding: 10px 12px; margin: 8px 0; overflow-x: auto; white-space: pre; }
+pre code { background: none; padding: 0; }
+pre[data-lang]::before { content: attr(data-lang); display: block; font-size: 11px; color: var(--muted); margin-bottom: 6px; }
+.k { color: var(--k); } .s { color: var(--s); } .n { color: var(--n); } .c { color: var(--c); font-style: italic; }
+"#;
+
+/// Words coloured as keywords in any language; a shared list keeps the page
+/// free of a highlighter library and is right often enough for chat snippets.
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "case", "catch", "class", "const", "continue", "def", "default", "defer", "do",
+    "elif", "else", "enum", "export", "extends", "false", "False", "finally", "fn", "for", "from", "func", "function",
+    "go", "if", "impl", "import", "in", "interface", "is", "lambda", "let", "loop", "match", "mod", "mut", "new", "nil",
+    "None", "not", "null", "package", "pass", "private", "protected", "pub", "public", "raise", "return", "self",
+    "Self", "static", "struct", "super", "switch", "this", "throw", "trait", "true", "True", "try", "type", "typeof",
+    "undefined", "use", "var", "void", "where", "while", "with", "yield",
+];
+
+/// Languages whose line comments start with `#`.
+const HASH_COMMENT_LANGS: &[&str] = &[
+    "py", "python", "sh", "bash", "zsh", "shell", "console", "rb", "ruby", "yaml", "yml", "toml", "r", "perl", "pl",
+    "dockerfile", "makefile", "ps1", "powershell", "nix", "conf", "ini",
+];
+
+fn escape_html(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+fn push_span(out: &mut String, class: &str, text: &[char]) {
+    let text: String = text.iter().collect();
+    out.push_str(&format!("<span class=\"{}\">{}</span>", class, escape_html(&text)));
+}
+
+/// Colour comments, strings, numbers and keywords. Not a parser: anything it
+/// doesn't recognise is emitted as plain escaped text.
+fn highlight(code: &str, lang: &str) -> String {
+    let hash_comments = HASH_COMMENT_LANGS.contains(&lang.to_lowercase().as_str());
+    let chars: Vec<char> = code.chars().collect();
+    let line_end = |from: usize| chars[from..].iter().position(|&c| c == '\n').map_or(chars.len(), |p| from + p);
+    let mut out = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        if (c == '/' && next == Some('/')) || (c == '#' && hash_comments) || (c == '-' && next == Some('-') && lang == "sql") {
+            let end = line_end(i);
+            push_span(&mut out, "c", &chars[i..end]);
+            i = end;
+        } else if c == '/' && next == Some('*') {
+            let end = chars[i + 2..]
+                .windows(2)
+                .position(|w| w == ['*', '/'])
+                .map_or(chars.len(), |p| i + 2 + p + 2);
+            push_span(&mut out, "c", &chars[i..end]);
+            i = end;
+        } else if c == '"' || c == '`' || (c == '\'' && chars[i + 1..line_end(i)].contains(&'\'')) {
+            // Backtick strings may span lines; the others stop at the line end
+            let mut j = i + 1;
+            while j < chars.len() && chars[j] != c && (c == '`' || chars[j] != '\n') {
+                j += if chars[j] == '\\' { 2 } else { 1 };
+            }
+            let end = (j + 1).min(chars.len());
+            push_span(&mut out, "s", &chars[i..end]);
+            i = end;
+        } else if c.is_ascii_digit() && (i == 0 || !(chars[i - 1].is_alphanumeric() || chars[i - 1] == '_')) {
+            let end = chars[i..]
+                .iter()
+                .position(|&c| !(c.is_ascii_alphanumeric() || c == '.' || c == '_'))
+                .map_or(chars.len(), |p| i + p);
+            push_span(&mut out, "n", &chars[i..end]);
+            i = end;
+        } else if c.is_alphabetic() || c == '_' {
+            let end = chars[i..]
+                .iter()
+                .position(|&c| !(c.is_alphanumeric() || c == '_'))
+                .map_or(chars.len(), |p| i + p);
+            let word: String = chars[i..end].iter().collect();
+            if KEYWORDS.contains(&word.as_str()) {
+                push_span(&mut out, "k", &chars[i..end]);
+            } else {
+                out.push_str(&escape_html(&word));
+            }
+            i = end;
+        } else {
+            out.push_str(&escape_html(&c.to_string()));
+            i += 1;
+        }
+    }
+    out
+}
+
+/// Escaped prose with `inline code` kept as `<code>`.
+fn render_text(text: &str) -> String {
+    if text.matches('`').count() % 2 != 0 {
+        return escape_html(text);
+    }
+    text.split('`')
+        .enumerate()
+        .map(|(i, part)| if i % 2 == 1 { format!("<code>{}</code>", escape_html(part)) } else { escape_html(part) })
+        .collect()
+}
+
+/// Message body: fenced code blocks become highlighted `<pre>`, the rest is prose.
+fn render_message_body(content: &str) -> String {
+    let mut out = String::new();
+    let mut prose: Vec<&str> = Vec::new();
+    let mut code: Option<(String, Vec<&str>)> = None;
+    for line in content.lines() {
+        let fence = line.trim_start().strip_prefix("```");
+        match (&mut code, fence) {
+            (Some((lang, lines)), Some(_)) => {
+                let lang_attr = if lang.is_empty() { String::new() } else { format!(" data-lang=\"{}\"", escape_html(lang)) };
+                out.push_str(&format!("<pre{}><code>{}</code></pre>", lang_attr, highlight(&lines.join("\n"), lang)));
+                code = None;
+            }
+            (Some((_, lines)), None) => lines.push(line),
+            (None, Some(lang)) => {
+                out.push_str(&render_text(prose.join("\n").trim_matches('\n')));
+                prose.clear();
+                code = Some((lang.trim().to_string(), Vec::new()));
+            }
+            (None, None) => prose.push(line),
+        }
+    }
+    // An unclosed fence is still shown as code
+    if let Some((lang, lines)) = code {
+        out.push_str(&format!("<pre><code>{}</code></pre>", highlight(&lines.join("\n"), &lang)));
+    }
+    out.push_str(&render_text(prose.join("\n").trim_matches('\n')));
+    out
+}
+
+fn render_html(content: &ConversationContent) -> String {
+    let messages: String = content
+        .messages
+        .iter()
+        .map(|m| {
+            let class = match m.role.as_str() {
+                "user" | "system" => m.role.as_str(),
+                _ => "assistant",
+            };
+            format!(
+                "<div class=\"msg {}\"><div class=\"role\">{}</div><div class=\"bubble\">{}</div></div>\n",
+                class,
+                escape_html(&m.role),
+                render_message_body(&m.content)
+            )
+        })
+        .collect();
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n<title>{title}</title>\n<style>{style}</style>\n</head>\n<body>\n<header><h1>{title}</h1><p class=\"meta\">{count} messages · exported {date} with DevCleaner</p></header>\n<main>\n{messages}</main>\n</body>\n</html>\n",
+        title = escape_html(&content.title),
+        style = HTML_STYLE,
+        count = content.messages.len(),
+        date = chrono::Local::now().format("%Y-%m-%d %H:%M"),
+        messages = messages,
+    )
+}
@@ -0,0 +1,91 @@
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+
+const NOTES_FILE: &str = "conversation_notes.db";
+
+/// The user's own tags and note for one conversation. They live in
+/// DevCleaner's data dir, never in the IDE's storage.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ConversationAnnotation {
+    pub tags: Vec<String>,
+    pub note: String,
+}
+
+fn open_notes() -> Result<Connection, String> {
+    let dir = crate::config::get_app_data_dir();
+    std::fs::create_dir_all(&dir).map_err(|e| format!("Failed to create data dir: {}", e))?;
+    let conn = Connection::open(dir.join(NOTES_FILE)).map_err(|e| format!("Failed to open notes: {}", e))?;
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS conversation_notes (
+            conversation_id TEXT PRIMARY KEY,
+            tags TEXT NOT NULL,
+            note TEXT NOT NULL,
+            updated_at INTEGER NOT NULL
+        );",
+    )
+    .map_err(|e| format!("Failed to create notes table: {}", e))?;
+    Ok(conn)
+}
+
+/// Trimmed, without empties or case-insensitive repeats, in the order given.
+fn normalize_tags(tags: &[String]) -> Vec<String> {
+    let mut seen = BTreeSet::new();
+    tags.iter()
+        .map(|t| t.trim())
+        .filter(|t| !t.is_empty() && seen.insert(t.to_lowercase()))
+        .map(String::from)
+        .collect()
+}
+
+/// Every annotation by `ConversationInfo::id`; empty if the database can't be read.
+pub fn load_annotations() -> HashMap<String, ConversationAnnotation> {
+    let Ok(conn) = open_notes() else { return HashMap::new() };
+    let Ok(mut stmt) = conn.prepare("SELECT conversation_id, tags, note FROM conversation_notes") else {
+        return HashMap::new();
+    };
+    let rows = stmt.query_map([], |row| {
+        let tags: String = row.get(1)?;
+        Ok((
+            row.get::<_, String>(0)?,
+            ConversationAnnotation { tags: serde_json::from_str(&tags).unwrap_or_default(), note: row.get(2)? },
+        ))
+    });
+    match rows {
+        Ok(rows) => rows.filter_map(|r| r.ok()).collect(),
+        Err(_) => HashMap::new(),
+    }
+}
+
+/// Replace a conversation's tags and note. Clearing both removes its row.
+pub fn set_annotation(conversation_id: &str, tags: &[String], note: &str) -> Result<ConversationAnnotation, String> {
+    let annotation = ConversationAnnotation { tags: normalize_tags(tags), note: note.trim().to_string() };
+    let conn = open_notes()?;
+    if annotation == ConversationAnnotation::default() {
+        conn.execute("DELETE FROM conversation_notes WHERE conversation_id = ?1", [conversation_id])
+            .map_err(|e| format!("Failed to clear notes: {}", e))?;
+        return Ok(annotation);
+    }
+    let tags = serde_json::to_string(&annotation.tags).map_err(|e| format!("Failed to encode tags: {}", e))?;
+    conn.execute(
+        "INSERT OR REPLACE INTO conversation_notes (conversation_id, tags, note, updated_at) VALUES (?1, ?2, ?3, ?4)",
+        params![conversation_id, tags, annotation.note, chrono::Utc::now().timestamp_millis()],
+    )
+    .map_err(|e| format!("Failed to save notes: {}", e))?;
+    Ok(annotation)
+}
+
+/// Add annotations brought from another machine. Conversations already
+/// annotated here keep their own. Returns how many were added.
+pub fn merge_annotations(annotations: HashMap<String, ConversationAnnotation>) -> Result<usize, String> {
+    let existing = load_annotations();
+    let mut added = 0;
+    for (id, annotation) in annotations {
+        if existing.contains_key(&id) || annotation == ConversationAnnotation::default() {
+            continue;
+        }
+        set_annotation(&id, &annotation.tags, &annotation.note)?;
+        added += 1;
+    }
+    Ok(added)
+}
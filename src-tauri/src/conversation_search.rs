@@ -0,0 +1,265 @@
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use crate::conversation::{self, ConversationInfo};
+use crate::ide_detector;
+
+const INDEX_FILE: &str = "conversation_index.db";
+const MAX_HITS: usize = 100;
+/// Trigram tokens can't match shorter terms; those fall back to a LIKE scan.
+const MIN_TERM_CHARS: usize = 3;
+const SNIPPET_BEFORE: usize = 24;
+const SNIPPET_AFTER: usize = 64;
+
+/// Wraps each matched term in `SearchHit::snippet`.
+pub const MATCH_START: char = '\u{2}';
+pub const MATCH_END: char = '\u{3}';
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchIndexStats {
+    /// Conversations (re)read because they were new or changed.
+    pub indexed: usize,
+    pub removed: usize,
+    pub total: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchHit {
+    pub ide_id: String,
+    pub conversation: ConversationInfo,
+    /// Excerpt around the best match, terms wrapped in `MATCH_START`/`MATCH_END`.
+    pub snippet: String,
+    /// Lower is better.
+    pub score: f64,
+}
+
+fn open_index() -> Result<Connection, String> {
+    let dir = crate::config::get_app_data_dir();
+    std::fs::create_dir_all(&dir).map_err(|e| format!("Failed to create data dir: {}", e))?;
+    let conn = Connection::open(dir.join(INDEX_FILE)).map_err(|e| format!("Failed to open search index: {}", e))?;
+    // Trigram rather than unicode61 so CJK titles, which have no spaces, match on substrings
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS indexed_conversations (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            ide_id TEXT NOT NULL,
+            conversation_id TEXT NOT NULL,
+            last_modified INTEGER,
+            size_bytes INTEGER NOT NULL,
+            info TEXT NOT NULL,
+            UNIQUE (ide_id, conversation_id)
+        );
+        CREATE VIRTUAL TABLE IF NOT EXISTS conversation_fts USING fts5(title, body, tokenize = 'trigram');",
+    )
+    .map_err(|e| format!("Failed to create search index: {}", e))?;
+    Ok(conn)
+}
+
+fn conversation_body(conv: &ConversationInfo) -> String {
+    conversation::get_conversation_content(&conv.source_db, &conv.source_key, &conversation::conversation_id_of(&conv.id))
+        .map(|content| content.messages.iter().map(|m| m.content.as_str()).collect::<Vec<_>>().join("\n\n"))
+        .unwrap_or_default()
+}
+
+fn remove_row(conn: &Connection, row: i64) -> Result<(), String> {
+    conn.execute("DELETE FROM conversation_fts WHERE rowid = ?1", [row])
+        .and_then(|_| conn.execute("DELETE FROM indexed_conversations WHERE id = ?1", [row]))
+        .map(|_| ())
+        .map_err(|e| format!("Failed to update search index: {}", e))
+}
+
+/// Drop deleted conversations, by location, from the index right away so
+/// searches stop returning them before the next update. Best effort: the next
+/// update would remove them anyway.
+pub fn evict_conversations(locations: &[(&str, &str)]) {
+    if locations.is_empty() || !crate::config::get_app_data_dir().join(INDEX_FILE).is_file() {
+        return;
+    }
+    let Ok(conn) = open_index() else { return };
+    for (source_db, source_key) in locations {
+        let rows: Vec<i64> = conn
+            .prepare(
+                "SELECT id FROM indexed_conversations
+                 WHERE json_extract(info, '$.source_db') = ?1 AND json_extract(info, '$.source_key') = ?2",
+            )
+            .and_then(|mut stmt| stmt.query_map([source_db, source_key], |row| row.get(0))?.collect())
+            .unwrap_or_default();
+        for row in rows {
+            let _ = remove_row(&conn, row);
+        }
+    }
+}
+
+/// Bring the index in line with every installed IDE's conversations and the CLI
+/// agent sessions. Only new or changed conversations (by modification time and
+/// size) are read again.
+pub fn update_search_index() -> Result<SearchIndexStats, String> {
+    let mut conn = open_index()?;
+    let tx = conn.transaction().map_err(|e| format!("Failed to update search index: {}", e))?;
+    let mut stats = SearchIndexStats { indexed: 0, removed: 0, total: 0 };
+
+    let ides = ide_detector::detect_installed_ides();
+    let sources = ides
+        .iter()
+        .filter(|i| i.installed)
+        .map(|ide| conversation::scan_conversations(ide, Default::default()))
+        .chain(std::iter::once_with(|| conversation::scan_cli_agents(Default::default())));
+
+    for listed in sources {
+        let source_id = &listed.ide_id;
+        let mut seen: HashSet<&str> = HashSet::new();
+        for conv in &listed.conversations {
+            seen.insert(conv.id.as_str());
+            let existing: Option<(i64, Option<i64>, i64)> = tx
+                .query_row(
+                    "SELECT id, last_modified, size_bytes FROM indexed_conversations WHERE ide_id = ?1 AND conversation_id = ?2",
+                    params![source_id, conv.id],
+                    |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+                )
+                .ok();
+            if let Some((row, last_modified, size)) = existing {
+                if last_modified == conv.last_modified && size == conv.size_bytes as i64 {
+                    continue;
+                }
+                remove_row(&tx, row)?;
+            }
+
+            let info = serde_json::to_string(conv).map_err(|e| format!("Failed to serialize conversation: {}", e))?;
+            tx.execute(
+                "INSERT INTO indexed_conversations (ide_id, conversation_id, last_modified, size_bytes, info) VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![source_id, conv.id, conv.last_modified, conv.size_bytes as i64, info],
+            )
+            .map_err(|e| format!("Failed to update search index: {}", e))?;
+            let row = tx.last_insert_rowid();
+            // Conversations that can't be decoded are still found by title
+            let body = conversation_body(conv);
+            tx.execute("INSERT INTO conversation_fts (rowid, title, body) VALUES (?1, ?2, ?3)", params![row, conv.title, body])
+                .map_err(|e| format!("Failed to update search index: {}", e))?;
+            stats.indexed += 1;
+        }
+
+        let stale: Vec<i64> = {
+            let mut stmt = tx
+                .prepare("SELECT id, conversation_id FROM indexed_conversations WHERE ide_id = ?1")
+                .map_err(|e| format!("Failed to read search index: {}", e))?;
+            let rows = stmt
+                .query_map([source_id], |row| Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?)))
+                .map_err(|e| format!("Failed to read search index: {}", e))?;
+            rows.filter_map(|r| r.ok()).filter(|(_, id)| !seen.contains(id.as_str())).map(|(row, _)| row).collect()
+        };
+        for row in stale {
+            remove_row(&tx, row)?;
+            stats.removed += 1;
+        }
+    }
+
+    stats.total = tx
+        .query_row("SELECT COUNT(*) FROM indexed_conversations", [], |row| row.get::<_, i64>(0))
+        .map_err(|e| format!("Failed to read search index: {}", e))? as usize;
+    tx.commit().map_err(|e| format!("Failed to update search index: {}", e))?;
+    Ok(stats)
+}
+
+/// Ranked matches for every whitespace-separated term of `query`, searched in
+/// titles and message bodies. Titles weigh more than bodies.
+pub fn search_conversations(query: &str, ide_filter: Option<&str>) -> Result<Vec<SearchHit>, String> {
+    let terms: Vec<&str> = query.split_whitespace().collect();
+    if terms.is_empty() {
+        return Ok(Vec::new());
+    }
+    let conn = open_index()?;
+    if terms.iter().all(|t| t.chars().count() >= MIN_TERM_CHARS) {
+        search_fts(&conn, &terms, ide_filter)
+    } else {
+        search_like(&conn, &terms, ide_filter)
+    }
+}
+
+fn search_fts(conn: &Connection, terms: &[&str], ide_filter: Option<&str>) -> Result<Vec<SearchHit>, String> {
+    // Quote every term so user input is never parsed as FTS5 query syntax
+    let expr = terms.iter().map(|t| format!("\"{}\"", t.replace('"', "\"\""))).collect::<Vec<_>>().join(" ");
+    let mut stmt = conn
+        .prepare(
+            "SELECT c.ide_id, c.info, snippet(conversation_fts, -1, char(2), char(3), '…', 16), bm25(conversation_fts, 10.0, 1.0) AS score
+             FROM conversation_fts JOIN indexed_conversations c ON c.id = conversation_fts.rowid
+             WHERE conversation_fts MATCH ?1 AND (?2 IS NULL OR c.ide_id = ?2)
+             ORDER BY score LIMIT ?3",
+        )
+        .map_err(|e| format!("Failed to search: {}", e))?;
+    let rows = stmt
+        .query_map(params![expr, ide_filter, MAX_HITS as i64], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?, row.get::<_, String>(2)?, row.get::<_, f64>(3)?))
+        })
+        .map_err(|e| format!("Failed to search: {}", e))?;
+    Ok(rows
+        .filter_map(|r| r.ok())
+        .filter_map(|(ide_id, info, snippet, score)| {
+            let conversation = serde_json::from_str(&info).ok()?;
+            Some(SearchHit { ide_id, conversation, snippet: snippet.replace('\n', " "), score })
+        })
+        .collect())
+}
+
+fn search_like(conn: &Connection, terms: &[&str], ide_filter: Option<&str>) -> Result<Vec<SearchHit>, String> {
+    let mut sql = String::from(
+        "SELECT c.ide_id, c.info, f.title, f.body
+         FROM conversation_fts f JOIN indexed_conversations c ON c.id = f.rowid
+         WHERE (?1 IS NULL OR c.ide_id = ?1)",
+    );
+    let patterns: Vec<String> = terms.iter().map(|t| format!("%{}%", t.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_"))).collect();
+    for i in 0..patterns.len() {
+        sql.push_str(&format!(" AND (f.title LIKE ?{0} ESCAPE '\\' OR f.body LIKE ?{0} ESCAPE '\\')", i + 2));
+    }
+    sql.push_str(&format!(" ORDER BY c.last_modified DESC LIMIT {}", MAX_HITS));
+
+    let mut values: Vec<&dyn rusqlite::ToSql> = vec![&ide_filter];
+    values.extend(patterns.iter().map(|p| p as &dyn rusqlite::ToSql));
+    let mut stmt = conn.prepare(&sql).map_err(|e| format!("Failed to search: {}", e))?;
+    let rows = stmt
+        .query_map(values.as_slice(), |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?, row.get::<_, String>(2)?, row.get::<_, String>(3)?))
+        })
+        .map_err(|e| format!("Failed to search: {}", e))?;
+    Ok(rows
+        .filter_map(|r| r.ok())
+        .filter_map(|(ide_id, info, title, body)| {
+            let conversation = serde_json::from_str(&info).ok()?;
+            let snippet = excerpt(&body, terms[0]).or_else(|| excerpt(&title, terms[0])).unwrap_or(title);
+            // No relevance model for substring matches: newest first, all ranked equal
+            Some(SearchHit { ide_id, conversation, snippet, score: 0.0 })
+        })
+        .collect())
+}
+
+/// Byte offset and byte length of the first case-insensitive occurrence of `needle`.
+fn find_ignore_case(haystack: &str, needle: &str) -> Option<(usize, usize)> {
+    haystack.char_indices().find_map(|(start, _)| {
+        let mut rest = haystack[start..].char_indices();
+        for n in needle.chars() {
+            let (_, h) = rest.next()?;
+            if !h.to_lowercase().eq(n.to_lowercase()) {
+                return None;
+            }
+        }
+        let end = rest.next().map(|(i, _)| start + i).unwrap_or(haystack.len());
+        Some((start, end - start))
+    })
+}
+
+/// A few words either side of `term`'s first occurrence in `text`, marked like FTS5 snippets.
+fn excerpt(text: &str, term: &str) -> Option<String> {
+    let (start, len) = find_ignore_case(text, term)?;
+    let before: String = {
+        let chars: Vec<char> = text[..start].chars().rev().take(SNIPPET_BEFORE + 1).collect();
+        let truncated = chars.len() > SNIPPET_BEFORE;
+        let kept: String = chars.into_iter().take(SNIPPET_BEFORE).collect::<Vec<_>>().into_iter().rev().collect();
+        if truncated { format!("…{}", kept) } else { kept }
+    };
+    let rest = &text[start + len..];
+    let after: String = rest.chars().take(SNIPPET_AFTER).collect();
+    let ellipsis = if rest.chars().nth(SNIPPET_AFTER).is_some() { "…" } else { "" };
+    Some(
+        format!("{}{}{}{}{}{}", before, MATCH_START, &text[start..start + len], MATCH_END, after, ellipsis)
+            .replace('\n', " "),
+    )
+}
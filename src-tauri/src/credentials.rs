@@ -0,0 +1,339 @@
+use serde::{Deserialize, Serialize};
+
+use crate::ide_detector::{IdeInfo, IdeType};
+
+/// A Credential Manager entry tied to one or more detected IDEs (secret not included).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CredentialEntry {
+    pub target_name: String,
+    pub user_name: String,
+    pub comment: String,
+    pub cred_type: u32,
+    pub persist: u32,
+    pub blob_size: u64,
+    pub last_written: Option<i64>,
+    pub ide_ids: Vec<String>,
+    /// Target used by every IDE of a family (JetBrains' "IntelliJ Platform ..."),
+    /// so it is never removed along with a single IDE.
+    #[serde(default)]
+    pub shared: bool,
+}
+
+/// Full credential as stored in a backup zip (credentials.json). The secret is
+/// encrypted with DPAPI for the current user, never written in plain text.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedCredential {
+    pub target_name: String,
+    pub user_name: String,
+    pub comment: String,
+    pub cred_type: u32,
+    pub persist: u32,
+    pub protected_blob_base64: String,
+}
+
+// ── Target name matching ──
+
+/// Prefixes JetBrains IDEs all write under; the target doesn't name the product.
+const JETBRAINS_SHARED_PREFIXES: &[&str] = &["IntelliJ Platform", "JetBrains"];
+
+/// Target name prefixes an IDE writes. VSCode forks store secrets through keytar
+/// as "<urlProtocol><extensionId>/<account>"; JetBrains IDEs share "IntelliJ Platform ...".
+fn credential_prefixes(ide: &IdeInfo) -> Vec<String> {
+    match ide.ide_type {
+        IdeType::VscodeBased => vec![crate::ide_detector::base_ide_id(&ide.id).replace('_', "")],
+        IdeType::JetBrains => JETBRAINS_SHARED_PREFIXES.iter().map(|p| p.to_string()).collect(),
+        IdeType::Zed => vec!["https://zed.dev".into()],
+        // Visual Studio (IdentityService cache) and Eclipse (secure_storage file) keep their own stores
+        IdeType::VisualStudio | IdeType::Eclipse => Vec::new(),
+        // macOS keychain, not Credential Manager
+        IdeType::TerminalEditor | IdeType::Xcode => Vec::new(),
+    }
+}
+
+/// IDEs owning a target name. The longest matching prefix wins, so "vscodium..."
+/// isn't attributed to VSCode; JetBrains IDEs all share the same prefix.
+fn match_target(target: &str, ides: &[IdeInfo]) -> Vec<String> {
+    let lower = target.to_lowercase();
+    let mut best_len = 0;
+    let mut ids: Vec<String> = Vec::new();
+    for ide in ides.iter().filter(|i| i.installed) {
+        let len = credential_prefixes(ide)
+            .iter()
+            .filter(|p| lower.starts_with(&p.to_lowercase()))
+            .map(|p| p.len())
+            .max()
+            .unwrap_or(0);
+        if len == 0 || len < best_len { continue; }
+        if len > best_len {
+            best_len = len;
+            ids.clear();
+        }
+        if !ids.contains(&ide.id) { ids.push(ide.id.clone()); }
+    }
+    ids
+}
+
+/// Credentials whose target names belong to the given IDEs.
+pub fn find_ide_credentials(ides: &[IdeInfo]) -> Vec<CredentialEntry> {
+    let mut results: Vec<CredentialEntry> = enumerate_credentials()
+        .into_iter()
+        .filter_map(|(mut entry, _)| {
+            entry.ide_ids = match_target(&entry.target_name, ides);
+            let lower = entry.target_name.to_lowercase();
+            entry.shared = JETBRAINS_SHARED_PREFIXES.iter().any(|p| lower.starts_with(&p.to_lowercase()));
+            if entry.ide_ids.is_empty() { None } else { Some(entry) }
+        })
+        .collect();
+    results.sort_by(|a, b| a.target_name.cmp(&b.target_name));
+    results
+}
+
+/// Read the secrets of the given credentials, DPAPI-protected, so they can go into a backup.
+pub fn read_credentials(entries: &[CredentialEntry]) -> Result<Vec<SavedCredential>, String> {
+    use base64::Engine;
+
+    let all = enumerate_credentials();
+    entries
+        .iter()
+        .map(|e| {
+            let (c, blob) = all
+                .iter()
+                .find(|(c, _)| c.target_name == e.target_name && c.cred_type == e.cred_type)
+                .ok_or_else(|| format!("Credential '{}' not found", e.target_name))?;
+            let protected = protect(blob).map_err(|err| format!("{}: {}", c.target_name, err))?;
+            Ok(SavedCredential {
+                target_name: c.target_name.clone(),
+                user_name: c.user_name.clone(),
+                comment: c.comment.clone(),
+                cred_type: c.cred_type,
+                persist: c.persist,
+                protected_blob_base64: base64::engine::general_purpose::STANDARD.encode(protected),
+            })
+        })
+        .collect()
+}
+
+// ── Windows Credential Manager (advapi32) ──
+
+#[cfg(target_os = "windows")]
+mod win {
+    use std::ffi::c_void;
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct FileTime {
+        pub low: u32,
+        pub high: u32,
+    }
+
+    #[repr(C)]
+    pub struct CredentialW {
+        pub flags: u32,
+        pub cred_type: u32,
+        pub target_name: *mut u16,
+        pub comment: *mut u16,
+        pub last_written: FileTime,
+        pub credential_blob_size: u32,
+        pub credential_blob: *mut u8,
+        pub persist: u32,
+        pub attribute_count: u32,
+        pub attributes: *mut c_void,
+        pub target_alias: *mut u16,
+        pub user_name: *mut u16,
+    }
+
+    #[link(name = "advapi32")]
+    extern "system" {
+        pub fn CredEnumerateW(filter: *const u16, flags: u32, count: *mut u32, credentials: *mut *mut *mut CredentialW) -> i32;
+        pub fn CredWriteW(credential: *const CredentialW, flags: u32) -> i32;
+        pub fn CredDeleteW(target_name: *const u16, cred_type: u32, flags: u32) -> i32;
+        pub fn CredFree(buffer: *mut c_void);
+    }
+
+    #[repr(C)]
+    pub struct DataBlob {
+        pub size: u32,
+        pub data: *mut u8,
+    }
+
+    pub const CRYPTPROTECT_UI_FORBIDDEN: u32 = 0x1;
+
+    #[link(name = "crypt32")]
+    extern "system" {
+        pub fn CryptProtectData(
+            data_in: *const DataBlob,
+            description: *const u16,
+            entropy: *const DataBlob,
+            reserved: *mut c_void,
+            prompt: *mut c_void,
+            flags: u32,
+            data_out: *mut DataBlob,
+        ) -> i32;
+        pub fn CryptUnprotectData(
+            data_in: *const DataBlob,
+            description: *mut *mut u16,
+            entropy: *const DataBlob,
+            reserved: *mut c_void,
+            prompt: *mut c_void,
+            flags: u32,
+            data_out: *mut DataBlob,
+        ) -> i32;
+    }
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        pub fn LocalFree(mem: *mut c_void) -> *mut c_void;
+    }
+
+    pub fn to_wide(s: &str) -> Vec<u16> {
+        s.encode_utf16().chain(std::iter::once(0)).collect()
+    }
+
+    /// # Safety
+    /// `p` must be null or point to a NUL-terminated UTF-16 string.
+    pub unsafe fn from_wide(p: *const u16) -> String {
+        if p.is_null() {
+            return String::new();
+        }
+        let mut len = 0;
+        while *p.add(len) != 0 {
+            len += 1;
+        }
+        String::from_utf16_lossy(std::slice::from_raw_parts(p, len))
+    }
+
+    /// FILETIME (100ns ticks since 1601) → Unix seconds.
+    pub fn filetime_to_unix(ft: FileTime) -> Option<i64> {
+        let ticks = ((ft.high as u64) << 32) | ft.low as u64;
+        if ticks == 0 {
+            return None;
+        }
+        Some((ticks / 10_000_000) as i64 - 11_644_473_600)
+    }
+}
+
+/// Every credential of the current user, with its secret blob.
+#[cfg(target_os = "windows")]
+fn enumerate_credentials() -> Vec<(CredentialEntry, Vec<u8>)> {
+    let mut results = Vec::new();
+    let mut count: u32 = 0;
+    let mut creds: *mut *mut win::CredentialW = std::ptr::null_mut();
+    unsafe {
+        if win::CredEnumerateW(std::ptr::null(), 0, &mut count, &mut creds) == 0 {
+            return results;
+        }
+        for i in 0..count as usize {
+            let c = &**creds.add(i);
+            let blob = if c.credential_blob.is_null() {
+                Vec::new()
+            } else {
+                std::slice::from_raw_parts(c.credential_blob, c.credential_blob_size as usize).to_vec()
+            };
+            results.push((
+                CredentialEntry {
+                    target_name: win::from_wide(c.target_name),
+                    user_name: win::from_wide(c.user_name),
+                    comment: win::from_wide(c.comment),
+                    cred_type: c.cred_type,
+                    persist: c.persist,
+                    blob_size: c.credential_blob_size as u64,
+                    last_written: win::filetime_to_unix(c.last_written),
+                    ide_ids: Vec::new(),
+                    shared: false,
+                },
+                blob,
+            ));
+        }
+        win::CredFree(creds as *mut std::ffi::c_void);
+    }
+    results
+}
+
+#[cfg(not(target_os = "windows"))]
+fn enumerate_credentials() -> Vec<(CredentialEntry, Vec<u8>)> {
+    Vec::new()
+}
+
+/// Encrypt (`encrypt`) or decrypt a secret with the current user's DPAPI key.
+#[cfg(target_os = "windows")]
+fn dpapi(data: &[u8], encrypt: bool) -> Result<Vec<u8>, String> {
+    let input = win::DataBlob { size: data.len() as u32, data: data.as_ptr() as *mut u8 };
+    let mut output = win::DataBlob { size: 0, data: std::ptr::null_mut() };
+    let null = std::ptr::null_mut();
+    let ok = unsafe {
+        if encrypt {
+            win::CryptProtectData(&input, std::ptr::null(), std::ptr::null(), null, null, win::CRYPTPROTECT_UI_FORBIDDEN, &mut output)
+        } else {
+            win::CryptUnprotectData(&input, std::ptr::null_mut(), std::ptr::null(), null, null, win::CRYPTPROTECT_UI_FORBIDDEN, &mut output)
+        }
+    };
+    if ok == 0 {
+        return Err(std::io::Error::last_os_error().to_string());
+    }
+    unsafe {
+        let out = std::slice::from_raw_parts(output.data, output.size as usize).to_vec();
+        win::LocalFree(output.data as *mut std::ffi::c_void);
+        Ok(out)
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn protect(secret: &[u8]) -> Result<Vec<u8>, String> {
+    dpapi(secret, true)
+}
+
+#[cfg(not(target_os = "windows"))]
+fn protect(_secret: &[u8]) -> Result<Vec<u8>, String> {
+    Err("DPAPI is only available on Windows".into())
+}
+
+#[cfg(target_os = "windows")]
+pub fn delete_credential(target_name: &str, cred_type: u32) -> Result<(), String> {
+    let target = win::to_wide(target_name);
+    if unsafe { win::CredDeleteW(target.as_ptr(), cred_type, 0) } == 0 {
+        return Err(format!("{}: {}", target_name, std::io::Error::last_os_error()));
+    }
+    Ok(())
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn delete_credential(target_name: &str, _cred_type: u32) -> Result<(), String> {
+    Err(format!("Credential Manager is only available on Windows ({})", target_name))
+}
+
+#[cfg(target_os = "windows")]
+pub fn write_credential(saved: &SavedCredential) -> Result<(), String> {
+    use base64::Engine;
+
+    let protected = base64::engine::general_purpose::STANDARD
+        .decode(&saved.protected_blob_base64)
+        .map_err(|e| format!("Invalid credential data for {}: {}", saved.target_name, e))?;
+    let mut blob = dpapi(&protected, false)
+        .map_err(|e| format!("Failed to decrypt credential {}: {}", saved.target_name, e))?;
+    let mut target = win::to_wide(&saved.target_name);
+    let mut user = win::to_wide(&saved.user_name);
+    let mut comment = win::to_wide(&saved.comment);
+    let cred = win::CredentialW {
+        flags: 0,
+        cred_type: saved.cred_type,
+        target_name: target.as_mut_ptr(),
+        comment: if saved.comment.is_empty() { std::ptr::null_mut() } else { comment.as_mut_ptr() },
+        last_written: win::FileTime { low: 0, high: 0 },
+        credential_blob_size: blob.len() as u32,
+        credential_blob: if blob.is_empty() { std::ptr::null_mut() } else { blob.as_mut_ptr() },
+        persist: saved.persist,
+        attribute_count: 0,
+        attributes: std::ptr::null_mut(),
+        target_alias: std::ptr::null_mut(),
+        user_name: if saved.user_name.is_empty() { std::ptr::null_mut() } else { user.as_mut_ptr() },
+    };
+    if unsafe { win::CredWriteW(&cred, 0) } == 0 {
+        return Err(format!("{}: {}", saved.target_name, std::io::Error::last_os_error()));
+    }
+    Ok(())
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn write_credential(saved: &SavedCredential) -> Result<(), String> {
+    Err(format!("Credential Manager is only available on Windows ({})", saved.target_name))
+}
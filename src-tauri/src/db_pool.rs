@@ -0,0 +1,206 @@
+use rusqlite::{Connection, OpenFlags};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Idle read-only connections kept per database.
+const MAX_IDLE_PER_DB: usize = 4;
+/// Databases with idle connections; the least recently used one is evicted past this.
+const MAX_DBS: usize = 64;
+/// Idle connections unused for this long are closed, so the app doesn't keep
+/// file handles (and WAL readers) open on IDE databases between scans.
+const IDLE_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Default)]
+struct DbSlot {
+    /// Bumped by `close`, so connections checked out before it are dropped on return.
+    generation: u64,
+    /// Connections checked out right now; the slot is kept while any are, so `close`
+    /// still reaches them.
+    in_use: usize,
+    idle: Vec<(Connection, Instant)>,
+}
+
+/// Close idle connections past `IDLE_TIMEOUT` and forget databases left with none.
+fn evict_expired(pool: &mut HashMap<PathBuf, DbSlot>, now: Instant) {
+    for slot in pool.values_mut() {
+        slot.idle.retain(|(_, since)| now.duration_since(*since) < IDLE_TIMEOUT);
+    }
+    pool.retain(|_, slot| !slot.idle.is_empty() || slot.in_use > 0);
+}
+
+/// Close the idle connections of the database whose newest one is the oldest.
+fn evict_lru(pool: &mut HashMap<PathBuf, DbSlot>) {
+    let lru = pool
+        .iter()
+        .filter(|(_, slot)| !slot.idle.is_empty())
+        .min_by_key(|(_, slot)| slot.idle.iter().map(|(_, since)| *since).max())
+        .map(|(key, _)| key.clone());
+    if let Some(slot) = lru.as_ref().and_then(|key| pool.get_mut(key)) {
+        slot.idle.clear();
+    }
+    pool.retain(|_, slot| !slot.idle.is_empty() || slot.in_use > 0);
+}
+
+static POOL: Mutex<Option<HashMap<PathBuf, DbSlot>>> = Mutex::new(None);
+
+fn pool_key(path: &Path) -> PathBuf {
+    std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+fn checkout(key: &Path) -> (Option<Connection>, u64) {
+    let mut guard = POOL.lock().unwrap_or_else(|e| e.into_inner());
+    let pool = guard.get_or_insert_with(HashMap::new);
+    evict_expired(pool, Instant::now());
+    let slot = pool.entry(key.to_path_buf()).or_default();
+    slot.in_use += 1;
+    (slot.idle.pop().map(|(conn, _)| conn), slot.generation)
+}
+
+fn release(pool: &mut HashMap<PathBuf, DbSlot>, key: &Path) {
+    if let Some(slot) = pool.get_mut(key) {
+        slot.in_use = slot.in_use.saturating_sub(1);
+    }
+}
+
+fn checkin(key: &Path, conn: Connection, generation: u64) {
+    let mut guard = POOL.lock().unwrap_or_else(|e| e.into_inner());
+    let pool = guard.get_or_insert_with(HashMap::new);
+    let now = Instant::now();
+    release(pool, key);
+    evict_expired(pool, now);
+    if pool.len() >= MAX_DBS && !pool.contains_key(key) {
+        evict_lru(pool);
+    }
+    let slot = pool.entry(key.to_path_buf()).or_default();
+    if slot.generation == generation && slot.idle.len() < MAX_IDLE_PER_DB {
+        slot.idle.push((conn, now));
+    }
+}
+
+/// Run `f` on a pooled read-only connection. Each concurrent caller gets its own
+/// connection, so scans, content views and stats on the same DB don't serialize.
+pub fn with_read_conn<T>(path: &Path, f: impl FnOnce(&Connection) -> T) -> Result<T, String> {
+    let key = pool_key(path);
+    let (idle, generation) = checkout(&key);
+    let conn = match idle {
+        Some(c) => c,
+        None => match Connection::open_with_flags(
+            path,
+            OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+        ) {
+            Ok(c) => c,
+            Err(e) => {
+                let mut guard = POOL.lock().unwrap_or_else(|e| e.into_inner());
+                release(guard.get_or_insert_with(HashMap::new), &key);
+                return Err(format!("Failed to open DB: {}", e));
+            }
+        },
+    };
+    let result = f(&conn);
+    checkin(&key, conn, generation);
+    Ok(result)
+}
+
+/// Drop pooled connections to a database before it's written to, VACUUMed or deleted.
+/// Connections in use right now are closed as soon as they're returned.
+pub fn close(path: &Path) {
+    let key = pool_key(path);
+    let mut guard = POOL.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(slot) = guard.as_mut().and_then(|pool| pool.get_mut(&key)) {
+        slot.generation += 1;
+        slot.idle.clear();
+    }
+}
+
+/// Open a database for deleting from it, after dropping pooled readers. In
+/// secure deletion mode, deleted content is zeroed instead of left in free pages.
+/// Refuses databases inside a folder marked to be left alone.
+pub fn open_for_delete(path: &Path) -> Result<Connection, String> {
+    if crate::cleaner::is_marked(path) {
+        return Err(format!("{} is in a folder marked to be left alone", path.display()));
+    }
+    close(path);
+    let conn = Connection::open(path).map_err(|e| format!("Failed to open DB: {}", e))?;
+    apply_secure_delete(&conn);
+    Ok(conn)
+}
+
+/// `PRAGMA secure_delete=ON` when the user opted into secure deletion.
+pub fn apply_secure_delete(conn: &Connection) {
+    if crate::cleaner::secure_delete_enabled() {
+        let _ = conn.execute_batch("PRAGMA secure_delete=ON");
+    }
+}
+
+/// VACUUM after deletes. In secure deletion mode the WAL is checkpointed and
+/// truncated too, since it still holds copies of the deleted pages.
+pub fn vacuum(conn: &Connection) -> Result<(), String> {
+    conn.execute_batch("VACUUM").map_err(|e| format!("Failed to compact database: {}", e))?;
+    if crate::cleaner::secure_delete_enabled() {
+        checkpoint(conn);
+    }
+    Ok(())
+}
+
+/// Fold the WAL back into the database and truncate it to zero. Does nothing
+/// while another connection (the IDE's) is in the middle of a transaction.
+pub fn checkpoint(conn: &Connection) {
+    let _ = conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(()));
+}
+
+// ── Database files ──
+
+const SQLITE_HEADER: &[u8] = b"SQLite format 3\0";
+/// Files SQLite keeps next to a database: the write-ahead log, its
+/// shared-memory index and the rollback journal.
+pub const COMPANION_SUFFIXES: &[&str] = &["-wal", "-shm", "-journal"];
+
+pub fn is_sqlite_file(path: &Path) -> bool {
+    use std::io::Read;
+    let mut header = [0u8; 16];
+    std::fs::File::open(path).and_then(|mut f| f.read_exact(&mut header)).is_ok() && header == SQLITE_HEADER
+}
+
+/// `<db>-wal`, `<db>-shm`, ...
+pub fn companion_path(db: &Path, suffix: &str) -> PathBuf {
+    let mut path = db.as_os_str().to_owned();
+    path.push(suffix);
+    PathBuf::from(path)
+}
+
+/// A database's footprint on disk: the file plus its companions.
+pub fn disk_size(db: &Path) -> u64 {
+    std::iter::once(db.to_path_buf())
+        .chain(COMPANION_SUFFIXES.iter().map(|s| companion_path(db, s)))
+        .filter_map(|p| std::fs::metadata(p).ok())
+        .map(|m| m.len())
+        .sum()
+}
+
+/// `disk_size` after checkpointing a non-empty WAL, so the size reflects the
+/// data rather than how long ago the IDE last checkpointed.
+pub fn checkpointed_size(db: &Path) -> u64 {
+    let wal_len = std::fs::metadata(companion_path(db, "-wal")).map(|m| m.len()).unwrap_or(0);
+    if wal_len > 0 {
+        if let Ok(conn) = Connection::open_with_flags(db, OpenFlags::SQLITE_OPEN_READ_WRITE | OpenFlags::SQLITE_OPEN_NO_MUTEX) {
+            checkpoint(&conn);
+        }
+    }
+    disk_size(db)
+}
+
+/// Drop every pooled connection under `dir` (before a clean/uninstall removes files there).
+pub fn close_under(dir: &Path) {
+    let dir = pool_key(dir);
+    let mut guard = POOL.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(pool) = guard.as_mut() {
+        for (key, slot) in pool.iter_mut() {
+            if key.starts_with(&dir) {
+                slot.generation += 1;
+                slot.idle.clear();
+            }
+        }
+    }
+}
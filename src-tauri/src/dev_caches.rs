@@ -0,0 +1,847 @@
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::batch::{BatchResult, ItemResult};
+
+/// Package-manager / build-tool caches outside any IDE. Each tool knows where
+/// its cache lives and, optionally, its own cache-clean command.
+struct DevCacheTool {
+    id: &'static str,
+    name: &'static str,
+    /// Executable run for the tool's own clean command.
+    program: &'static str,
+    clean_args: &'static [&'static str],
+    locate: fn() -> Vec<PathBuf>,
+    /// Deletes one located folder when not using the tool's command.
+    wipe: fn(&Path) -> (u64, u64, Vec<String>),
+    /// Lists a located folder's contents per package for the breakdown; such
+    /// entries can then be cleaned one by one.
+    itemize: Option<fn(&Path) -> Vec<DevCacheEntry>>,
+}
+
+const DEV_CACHE_TOOLS: &[DevCacheTool] = &[
+    DevCacheTool { id: "npm", name: "npm", program: "npm", clean_args: &["cache", "clean", "--force"], locate: npm_cache_dirs, wipe: crate::cleaner::clean_directory, itemize: None },
+    // `store prune` only drops packages no project references; deleting wipes the whole store
+    DevCacheTool { id: "pnpm", name: "pnpm", program: "pnpm", clean_args: &["store", "prune"], locate: pnpm_store_dirs, wipe: crate::cleaner::clean_directory, itemize: None },
+    DevCacheTool { id: "yarn", name: "Yarn", program: "yarn", clean_args: &["cache", "clean"], locate: yarn_cache_dirs, wipe: crate::cleaner::clean_directory, itemize: None },
+    DevCacheTool { id: "pip", name: "pip", program: "pip", clean_args: &["cache", "purge"], locate: pip_cache_dirs, wipe: crate::cleaner::clean_directory, itemize: None },
+    // pipx has no clean command; its cache only holds `pipx run` throwaway venvs
+    DevCacheTool { id: "pipx", name: "pipx", program: "pipx", clean_args: &[], locate: pipx_cache_dirs, wipe: crate::cleaner::clean_directory, itemize: None },
+    // No stable cargo command cleans the global cache; everything here is re-fetched on demand
+    DevCacheTool { id: "cargo", name: "Cargo", program: "cargo", clean_args: &[], locate: cargo_cache_dirs, wipe: crate::cleaner::clean_directory, itemize: None },
+    DevCacheTool { id: "conda", name: "Conda", program: "conda", clean_args: &["clean", "--all", "--yes"], locate: conda_cache_dirs, wipe: conda_wipe, itemize: None },
+    DevCacheTool { id: "go", name: "Go", program: "go", clean_args: &["clean", "-modcache", "-cache"], locate: go_cache_dirs, wipe: go_wipe, itemize: None },
+    // `gradle --stop` only stops daemons; old distributions and logs are removed directly
+    DevCacheTool { id: "gradle", name: "Gradle", program: "gradle", clean_args: &[], locate: gradle_cache_dirs, wipe: gradle_wipe, itemize: Some(gradle_cache_entries) },
+    // Everything is re-downloaded by the next build; stale-only cleanup lives in maven_repo
+    DevCacheTool { id: "maven", name: "Maven", program: "mvn", clean_args: &[], locate: maven_repo_dirs, wipe: crate::cleaner::clean_directory, itemize: None },
+    DevCacheTool { id: "scoop", name: "Scoop", program: "scoop", clean_args: &["cache", "rm", "*"], locate: scoop_cache_dirs, wipe: crate::cleaner::clean_directory, itemize: Some(scoop_cache_entries) },
+    // `choco cache remove` only clears the HTTP metadata cache, not downloads or lib-bkp
+    DevCacheTool { id: "chocolatey", name: "Chocolatey", program: "choco", clean_args: &[], locate: chocolatey_cache_dirs, wipe: crate::cleaner::clean_directory, itemize: Some(chocolatey_cache_entries) },
+    // Shared by Rider, Visual Studio and the dotnet CLI; restores re-download what is missing
+    DevCacheTool { id: "nuget", name: "NuGet", program: "dotnet", clean_args: &["nuget", "locals", "all", "--clear"], locate: nuget_cache_dirs, wipe: crate::cleaner::clean_directory, itemize: Some(nuget_cache_entries) },
+    // Language servers rebuild these indexes on the next project open; none has a clean command
+    DevCacheTool { id: "rust-analyzer", name: "rust-analyzer", program: "rust-analyzer", clean_args: &[], locate: rust_analyzer_cache_dirs, wipe: crate::cleaner::clean_directory, itemize: None },
+    DevCacheTool { id: "gopls", name: "gopls", program: "gopls", clean_args: &[], locate: gopls_cache_dirs, wipe: crate::cleaner::clean_directory, itemize: None },
+    DevCacheTool { id: "tsserver", name: "TypeScript (tsserver)", program: "tsc", clean_args: &[], locate: tsserver_cache_dirs, wipe: crate::cleaner::clean_directory, itemize: None },
+    DevCacheTool { id: "clangd", name: "clangd", program: "clangd", clean_args: &[], locate: clangd_cache_dirs, wipe: crate::cleaner::clean_directory, itemize: None },
+    // Setup payloads, crash dumps and extension-host leftovers Electron IDEs drop in %TEMP%
+    DevCacheTool { id: "ide-temp", name: "IDE temp files", program: "", clean_args: &[], locate: ide_temp_entries, wipe: ide_temp_wipe, itemize: None },
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DevCache {
+    pub id: String,
+    pub name: String,
+    pub paths: Vec<PathBuf>,
+    pub size: u64,
+    pub file_count: u64,
+    /// One entry per path when a tool keeps several caches (Yarn v4/v6/Berry, ...).
+    pub breakdown: Vec<DevCacheEntry>,
+    /// Display form of the tool's own clean command, e.g. "npm cache clean --force".
+    pub clean_command: Option<String>,
+    /// The tool is on PATH, so `clean_command` can be run.
+    pub tool_available: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DevCacheEntry {
+    /// Last two path components, e.g. "Cache/v6" or "berry/cache".
+    pub name: String,
+    pub path: PathBuf,
+    pub size: u64,
+}
+
+// ── Cache locations ──
+
+fn env_dir(var: &str) -> Option<PathBuf> {
+    std::env::var_os(var).filter(|v| !v.is_empty()).map(PathBuf::from)
+}
+
+/// `$npm_config_cache`, else %LOCALAPPDATA%\npm-cache on Windows, ~/.npm elsewhere.
+fn npm_cache_dirs() -> Vec<PathBuf> {
+    let dir = env_dir("npm_config_cache").or_else(|| {
+        if cfg!(target_os = "windows") {
+            dirs::data_local_dir().map(|l| l.join("npm-cache"))
+        } else {
+            dirs::home_dir().map(|h| h.join(".npm"))
+        }
+    });
+    dir.into_iter().filter(|p| p.is_dir()).collect()
+}
+
+fn push_dir(found: &mut Vec<PathBuf>, p: PathBuf) {
+    if p.is_dir() && !found.contains(&p) { found.push(p); }
+}
+
+/// pnpm's content-addressable store: `$npm_config_store_dir`, `$PNPM_HOME/store`, else
+/// %LOCALAPPDATA%\pnpm\store, ~/Library/pnpm/store or `$XDG_DATA_HOME/pnpm/store`.
+fn pnpm_store_dirs() -> Vec<PathBuf> {
+    let mut candidates: Vec<PathBuf> = Vec::new();
+    if let Some(dir) = env_dir("npm_config_store_dir") {
+        candidates.push(dir);
+    }
+    if let Some(home) = env_dir("PNPM_HOME") {
+        candidates.push(home.join("store"));
+    }
+    candidates.extend(dirs::data_local_dir().map(|d| d.join("pnpm").join("store")));
+    candidates.extend(dirs::home_dir().map(|h| h.join("Library").join("pnpm").join("store")));
+    candidates.extend(dirs::home_dir().map(|h| h.join(".pnpm-store")));
+
+    let mut found = Vec::new();
+    for p in candidates {
+        push_dir(&mut found, p);
+    }
+    found
+}
+
+/// Yarn classic keeps one folder per cache format (`v4`, `v6`, ...) under
+/// `$YARN_CACHE_FOLDER`, %LOCALAPPDATA%\Yarn\Cache, ~/Library/Caches/Yarn or
+/// ~/.cache/yarn; Berry's global cache is ~/.yarn/berry/cache.
+fn yarn_cache_dirs() -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    let classic_roots: Vec<PathBuf> = match env_dir("YARN_CACHE_FOLDER") {
+        Some(dir) => vec![dir],
+        None => [
+            dirs::data_local_dir().map(|d| d.join("Yarn").join("Cache")),
+            dirs::cache_dir().map(|d| d.join("Yarn")),
+            dirs::cache_dir().map(|d| d.join("yarn")),
+        ]
+        .into_iter()
+        .flatten()
+        .collect(),
+    };
+    for root in classic_roots {
+        let versions = std::fs::read_dir(&root).map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .filter(|e| {
+                    let name = e.file_name().to_string_lossy().to_string();
+                    name.starts_with('v') && name[1..].parse::<u32>().is_ok()
+                })
+                .map(|e| e.path())
+                .collect::<Vec<_>>()
+        });
+        for p in versions.unwrap_or_default() {
+            push_dir(&mut found, p);
+        }
+    }
+    let berry_root = env_dir("YARN_GLOBAL_FOLDER").or_else(|| dirs::home_dir().map(|h| h.join(".yarn").join("berry")));
+    if let Some(root) = berry_root {
+        push_dir(&mut found, root.join("cache"));
+    }
+    if let Some(local) = dirs::data_local_dir() {
+        push_dir(&mut found, local.join("Yarn").join("Berry").join("cache"));
+    }
+    found
+}
+
+/// `$PIP_CACHE_DIR`, else %LOCALAPPDATA%\pip\cache, ~/Library/Caches/pip or ~/.cache/pip.
+fn pip_cache_dirs() -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    if let Some(dir) = env_dir("PIP_CACHE_DIR") {
+        push_dir(&mut found, dir);
+        return found;
+    }
+    if cfg!(target_os = "windows") {
+        if let Some(local) = dirs::data_local_dir() {
+            push_dir(&mut found, local.join("pip").join("cache"));
+        }
+    } else if let Some(cache) = dirs::cache_dir() {
+        push_dir(&mut found, cache.join("pip"));
+    }
+    found
+}
+
+/// pipx's `run` cache: `$PIPX_HOME/.cache` (~/.local/pipx, ~/pipx on Windows)
+/// for older versions, the platform cache dir for pipx 1.3+.
+fn pipx_cache_dirs() -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    let homes: Vec<PathBuf> = match env_dir("PIPX_HOME") {
+        Some(home) => vec![home],
+        None => [
+            dirs::home_dir().map(|h| h.join(".local").join("pipx")),
+            dirs::home_dir().map(|h| h.join("pipx")),
+            dirs::data_local_dir().map(|d| d.join("pipx")),
+        ]
+        .into_iter()
+        .flatten()
+        .collect(),
+    };
+    for home in homes {
+        push_dir(&mut found, home.join(".cache"));
+    }
+    if let Some(cache) = dirs::cache_dir() {
+        push_dir(&mut found, cache.join("pipx"));
+    }
+    if let Some(local) = dirs::data_local_dir() {
+        push_dir(&mut found, local.join("pipx").join("pipx").join("Cache"));
+    }
+    found
+}
+
+/// `$CARGO_HOME` (~/.cargo): downloaded .crate files, their extracted sources,
+/// and git dependencies (bare repos + checkouts).
+fn cargo_cache_dirs() -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    let Some(home) = env_dir("CARGO_HOME").or_else(|| dirs::home_dir().map(|h| h.join(".cargo"))) else {
+        return found;
+    };
+    for sub in [["registry", "cache"], ["registry", "src"], ["git", "checkouts"], ["git", "db"]] {
+        push_dir(&mut found, home.join(sub[0]).join(sub[1]));
+    }
+    found
+}
+
+/// Install roots of Anaconda / Miniconda / Miniforge / Mambaforge.
+const CONDA_ROOT_NAMES: &[&str] = &["anaconda3", "miniconda3", "miniforge3", "mambaforge", "Anaconda3", "Miniconda3"];
+
+fn conda_roots() -> Vec<PathBuf> {
+    let mut bases: Vec<PathBuf> = Vec::new();
+    bases.extend(dirs::home_dir());
+    bases.extend(dirs::data_local_dir());
+    bases.extend(env_dir("ProgramData"));
+    if !cfg!(target_os = "windows") {
+        bases.push(PathBuf::from("/opt"));
+    }
+    let mut roots = Vec::new();
+    for base in bases {
+        for name in CONDA_ROOT_NAMES {
+            let root = base.join(name);
+            if root.join("conda-meta").is_dir() { push_dir(&mut roots, root); }
+        }
+    }
+    roots
+}
+
+/// Package caches (`<root>/pkgs`, ~/.conda/pkgs, `$CONDA_PKGS_DIRS`) plus orphaned
+/// environments: folders under `envs/` whose `conda-meta` is gone, i.e. left
+/// behind by an interrupted `conda remove` or a deleted install.
+fn conda_cache_dirs() -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    let roots = conda_roots();
+    let user_conda = dirs::home_dir().map(|h| h.join(".conda"));
+
+    if let Some(list) = std::env::var_os("CONDA_PKGS_DIRS") {
+        for dir in list.to_string_lossy().split([',', ';']).filter(|d| !d.is_empty()) {
+            push_dir(&mut found, PathBuf::from(dir));
+        }
+    }
+    for base in roots.iter().chain(user_conda.iter()) {
+        push_dir(&mut found, base.join("pkgs"));
+    }
+    for base in roots.iter().chain(user_conda.iter()) {
+        let envs = std::fs::read_dir(base.join("envs"))
+            .map(|entries| entries.filter_map(|e| e.ok()).map(|e| e.path()).collect::<Vec<_>>())
+            .unwrap_or_default();
+        for env in envs.into_iter().filter(|e| e.is_dir() && !e.join("conda-meta").exists()) {
+            push_dir(&mut found, env);
+        }
+    }
+    found
+}
+
+/// Like `conda clean --all`: a package cache loses its tarballs and index cache
+/// but keeps extracted packages, which environments may link to. An orphaned
+/// environment is removed entirely.
+fn conda_wipe(path: &Path) -> (u64, u64, Vec<String>) {
+    let is_env = path.parent().and_then(|p| p.file_name()).map(|n| n == "envs").unwrap_or(false);
+    if is_env {
+        let result = crate::cleaner::clean_directory(path);
+        let _ = crate::cleaner::remove_tree(path);
+        return result;
+    }
+
+    let (mut freed, mut deleted, mut errors) = crate::cleaner::clean_directory(&path.join("cache"));
+    let tarballs = std::fs::read_dir(path)
+        .map(|entries| entries.filter_map(|e| e.ok()).map(|e| e.path()).collect::<Vec<_>>())
+        .unwrap_or_default();
+    for file in tarballs.iter().filter(|p| p.is_file()) {
+        let name = file.file_name().unwrap_or_default().to_string_lossy();
+        if !(name.ends_with(".tar.bz2") || name.ends_with(".conda")) { continue; }
+        let size = file.metadata().map(|m| m.len()).unwrap_or(0);
+        match crate::cleaner::remove_file(file) {
+            Ok(()) => {
+                freed += size;
+                deleted += 1;
+            }
+            Err(e) => errors.push(format!("{}: {}", file.display(), e)),
+        }
+    }
+    (freed, deleted, errors)
+}
+
+/// The module cache (`$GOMODCACHE`, else the first `$GOPATH` entry's `pkg/mod`,
+/// GOPATH defaulting to ~/go) and the build cache (`$GOCACHE`, else `go-build`
+/// in the platform cache dir).
+fn go_cache_dirs() -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    let modcache = env_dir("GOMODCACHE").or_else(|| {
+        let gopath = match std::env::var_os("GOPATH").filter(|v| !v.is_empty()) {
+            Some(list) => std::env::split_paths(&list).next(),
+            None => dirs::home_dir().map(|h| h.join("go")),
+        };
+        gopath.map(|g| g.join("pkg").join("mod"))
+    });
+    if let Some(dir) = modcache {
+        push_dir(&mut found, dir);
+    }
+    // GOCACHE=off disables the build cache
+    let build_cache = match std::env::var("GOCACHE") {
+        Ok(v) if v == "off" => None,
+        Ok(v) if !v.is_empty() => Some(PathBuf::from(v)),
+        _ => dirs::cache_dir().map(|c| c.join("go-build")),
+    };
+    if let Some(dir) = build_cache {
+        push_dir(&mut found, dir);
+    }
+    found
+}
+
+/// Go writes the module cache read-only, so permissions are lifted before deleting.
+fn go_wipe(path: &Path) -> (u64, u64, Vec<String>) {
+    for entry in walkdir::WalkDir::new(path).into_iter().filter_map(|e| e.ok()) {
+        let Ok(meta) = entry.metadata() else { continue };
+        let mut perms = meta.permissions();
+        if !perms.readonly() { continue; }
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            perms.set_mode(perms.mode() | 0o200);
+        }
+        #[cfg(not(unix))]
+        #[allow(clippy::permissions_set_readonly_false)]
+        perms.set_readonly(false);
+        let _ = std::fs::set_permissions(entry.path(), perms);
+    }
+    crate::cleaner::clean_directory(path)
+}
+
+/// `caches/`, `daemon/` and `wrapper/dists/` under the Gradle user home.
+fn gradle_cache_dirs() -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    let Some(home) = crate::ide_detector::gradle_user_home() else { return found };
+    push_dir(&mut found, home.join("caches"));
+    push_dir(&mut found, home.join("daemon"));
+    push_dir(&mut found, home.join("wrapper").join("dists"));
+    found
+}
+
+/// Gradle version in a cache/daemon/distribution folder name
+/// ("8.5", "gradle-8.5-bin", "gradle-8.6-rc-1-all"), for ordering.
+fn gradle_version(name: &str) -> Option<Vec<u32>> {
+    let v = name.strip_prefix("gradle-").unwrap_or(name);
+    let v = v.trim_end_matches("-bin").trim_end_matches("-all");
+    let numeric = v.split('-').next()?;
+    numeric.split('.').map(|part| part.parse::<u32>().ok()).collect()
+}
+
+/// Child folders of `dir` that carry a Gradle version, newest first.
+fn gradle_versioned_children(dir: &Path) -> Vec<(Vec<u32>, PathBuf)> {
+    let mut children: Vec<(Vec<u32>, PathBuf)> = std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .filter(|e| e.path().is_dir())
+                .filter_map(|e| gradle_version(&e.file_name().to_string_lossy()).map(|v| (v, e.path())))
+                .collect()
+        })
+        .unwrap_or_default();
+    children.sort_by(|a, b| b.0.cmp(&a.0));
+    children
+}
+
+/// One entry per Gradle version (or per shared cache such as `modules-2`).
+fn gradle_cache_entries(dir: &Path) -> Vec<DevCacheEntry> {
+    let Ok(entries) = std::fs::read_dir(dir) else { return Vec::new() };
+    let kind = dir.file_name().unwrap_or_default().to_string_lossy().to_string();
+    entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_dir())
+        .map(|e| {
+            let path = e.path();
+            DevCacheEntry {
+                name: format!("{}/{}", kind, e.file_name().to_string_lossy()),
+                size: size_and_count(std::slice::from_ref(&path)).0,
+                path,
+            }
+        })
+        .collect()
+}
+
+/// Wrapper distributions and daemon folders of every version but the newest are
+/// removed, and the newest daemon folder loses its logs. `caches/` is emptied:
+/// Gradle re-downloads dependencies and rebuilds transforms on demand.
+fn gradle_wipe(path: &Path) -> (u64, u64, Vec<String>) {
+    let name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+    if name != "dists" && name != "daemon" {
+        return crate::cleaner::clean_directory(path);
+    }
+
+    let (mut freed, mut deleted, mut errors) = (0, 0, Vec::new());
+    let children = gradle_versioned_children(path);
+    for (_, old) in children.iter().skip(1) {
+        let (f, d, e) = crate::cleaner::clean_directory(old);
+        freed += f;
+        deleted += d;
+        errors.extend(e);
+        let _ = std::fs::remove_dir(old);
+    }
+    if name == "daemon" {
+        let logs = children.first().and_then(|(_, newest)| std::fs::read_dir(newest).ok());
+        for log in logs.into_iter().flatten().filter_map(|e| e.ok()).map(|e| e.path()) {
+            if !log.to_string_lossy().ends_with(".log") { continue; }
+            let size = log.metadata().map(|m| m.len()).unwrap_or(0);
+            match crate::cleaner::remove_file(&log) {
+                Ok(()) => {
+                    freed += size;
+                    deleted += 1;
+                }
+                // The running daemon keeps its own log open
+                Err(e) => errors.push(format!("{}: {}", log.display(), e)),
+            }
+        }
+    }
+    (freed, deleted, errors)
+}
+
+fn maven_repo_dirs() -> Vec<PathBuf> {
+    crate::maven_repo::maven_repo_dir().into_iter().collect()
+}
+
+/// Scoop's download cache (`$SCOOP_CACHE`, else `<root>/cache`) of every Scoop root.
+fn scoop_cache_dirs() -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    if let Some(dir) = env_dir("SCOOP_CACHE") {
+        push_dir(&mut found, dir);
+    }
+    for (root, _) in crate::package_managers::scoop_roots() {
+        push_dir(&mut found, root.join("cache"));
+    }
+    found
+}
+
+/// Cached downloads are named `<app>#<version>#<url>`; one entry per file.
+fn scoop_cache_entries(dir: &Path) -> Vec<DevCacheEntry> {
+    let Ok(entries) = std::fs::read_dir(dir) else { return Vec::new() };
+    entries
+        .filter_map(|e| e.ok())
+        .map(|e| {
+            let file_name = e.file_name().to_string_lossy().to_string();
+            let mut parts = file_name.splitn(3, '#');
+            let name = match (parts.next(), parts.next()) {
+                (Some(app), Some(version)) => format!("{} {}", app, version),
+                _ => file_name.clone(),
+            };
+            let path = e.path();
+            DevCacheEntry { name, size: size_and_count(std::slice::from_ref(&path)).0, path }
+        })
+        .collect()
+}
+
+/// `cacheLocation` from chocolatey.config, without pulling in an XML parser.
+fn chocolatey_cache_location(root: &Path) -> Option<PathBuf> {
+    let data = std::fs::read_to_string(root.join("config").join("chocolatey.config")).ok()?;
+    let line = data.lines().find(|l| l.contains("key=\"cacheLocation\""))?;
+    let start = line.find("value=\"")? + "value=\"".len();
+    let end = start + line[start..].find('"')?;
+    Some(PathBuf::from(&line[start..end])).filter(|p| !p.as_os_str().is_empty())
+}
+
+/// Chocolatey's previous package versions (`lib-bkp`), failed installs (`lib-bad`)
+/// and downloaded installers/.nupkg files (`cacheLocation`, default %TEMP%\chocolatey).
+fn chocolatey_cache_dirs() -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    let Some(root) = crate::package_managers::chocolatey_root() else { return found };
+    push_dir(&mut found, root.join("lib-bkp"));
+    push_dir(&mut found, root.join("lib-bad"));
+    let download_cache = chocolatey_cache_location(&root).unwrap_or_else(|| std::env::temp_dir().join("chocolatey"));
+    push_dir(&mut found, download_cache);
+    found
+}
+
+/// One entry per package folder.
+fn chocolatey_cache_entries(dir: &Path) -> Vec<DevCacheEntry> {
+    let Ok(entries) = std::fs::read_dir(dir) else { return Vec::new() };
+    let kind = dir.file_name().unwrap_or_default().to_string_lossy().to_string();
+    entries
+        .filter_map(|e| e.ok())
+        .map(|e| {
+            let path = e.path();
+            DevCacheEntry {
+                name: format!("{}/{}", kind, e.file_name().to_string_lossy()),
+                size: size_and_count(std::slice::from_ref(&path)).0,
+                path,
+            }
+        })
+        .collect()
+}
+
+/// `$NUGET_PACKAGES`, else ~/.nuget/packages.
+fn nuget_global_packages_dir() -> Option<PathBuf> {
+    env_dir("NUGET_PACKAGES").or_else(|| dirs::home_dir().map(|h| h.join(".nuget").join("packages")))
+}
+
+/// NuGet's global-packages folder, HTTP cache (`$NUGET_HTTP_CACHE_PATH`, else
+/// `v3-cache` under %LOCALAPPDATA%\NuGet or ~/.local/share/NuGet; older clients
+/// used `http-cache`) and plugins cache.
+fn nuget_cache_dirs() -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    if let Some(dir) = nuget_global_packages_dir() {
+        push_dir(&mut found, dir);
+    }
+    if let Some(dir) = env_dir("NUGET_HTTP_CACHE_PATH") {
+        push_dir(&mut found, dir);
+    }
+    let local = if cfg!(target_os = "windows") {
+        dirs::data_local_dir().map(|l| l.join("NuGet"))
+    } else {
+        dirs::home_dir().map(|h| h.join(".local").join("share").join("NuGet"))
+    };
+    if let Some(local) = local {
+        for sub in ["v3-cache", "http-cache", "plugins-cache"] {
+            push_dir(&mut found, local.join(sub));
+        }
+    }
+    if let Some(dir) = env_dir("NUGET_PLUGINS_CACHE_PATH") {
+        push_dir(&mut found, dir);
+    }
+    found
+}
+
+/// One entry per package id in global-packages; the HTTP and plugin caches
+/// are listed whole since their contents are hashed feed folders.
+fn nuget_cache_entries(dir: &Path) -> Vec<DevCacheEntry> {
+    if nuget_global_packages_dir().as_deref() != Some(dir) {
+        let path = dir.to_path_buf();
+        return vec![DevCacheEntry { name: entry_name(dir), size: size_and_count(std::slice::from_ref(&path)).0, path }];
+    }
+    let Ok(entries) = std::fs::read_dir(dir) else { return Vec::new() };
+    entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_dir())
+        .map(|e| {
+            let path = e.path();
+            DevCacheEntry {
+                name: e.file_name().to_string_lossy().to_string(),
+                size: size_and_count(std::slice::from_ref(&path)).0,
+                path,
+            }
+        })
+        .collect()
+}
+
+/// rust-analyzer's cache: %LOCALAPPDATA%\rust-analyzer, ~/Library/Caches/rust-analyzer
+/// or `$XDG_CACHE_HOME/rust-analyzer`.
+fn rust_analyzer_cache_dirs() -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    if let Some(cache) = dirs::cache_dir() {
+        push_dir(&mut found, cache.join("rust-analyzer"));
+    }
+    found
+}
+
+/// gopls keeps its file and package index under Go's user cache dir
+/// (%LOCALAPPDATA%\gopls, ~/Library/Caches/gopls, ~/.cache/gopls).
+fn gopls_cache_dirs() -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    if let Some(cache) = dirs::cache_dir() {
+        push_dir(&mut found, cache.join("gopls"));
+    }
+    found
+}
+
+/// tsserver's automatic type acquisition cache (@types packages per TypeScript
+/// version): %LOCALAPPDATA%\Microsoft\TypeScript, else `<cache dir>/typescript`.
+fn tsserver_cache_dirs() -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    if cfg!(target_os = "windows") {
+        if let Some(local) = dirs::data_local_dir() {
+            push_dir(&mut found, local.join("Microsoft").join("TypeScript"));
+        }
+    } else if let Some(cache) = dirs::cache_dir() {
+        push_dir(&mut found, cache.join("typescript"));
+    }
+    found
+}
+
+/// clangd's background index for files outside a project: `<cache dir>/clangd`.
+fn clangd_cache_dirs() -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    if let Some(cache) = dirs::cache_dir() {
+        push_dir(&mut found, cache.join("clangd"));
+    }
+    found
+}
+
+// ── IDE temp files ──
+
+/// Name prefixes (lowercase) of what VS Code-family editors leave in the temp
+/// dir: update/installer payloads, `vscode-typescript*`, git askpass and
+/// extension-host scratch folders. Inno Setup's `is-*.tmp` folders aren't
+/// listed: every vendor's installer uses them.
+const IDE_TEMP_PREFIXES: &[&str] = &[
+    "vscode-", "vscodesetup-", "vscodeusersetup-", "codesetup-",
+    "cursor-", "cursorsetup-", "cursorusersetup-",
+    "windsurf-", "windsurfsetup-", "windsurfusersetup-",
+    "trae-", "kiro-", "vscodium-",
+];
+
+/// Electron crash dumps (`Code Crashes`, `Cursor Crashes`).
+const IDE_TEMP_SUFFIXES: &[&str] = &[" crashes"];
+
+/// Newer entries may belong to a running IDE or an update in progress; a
+/// long-running session can keep a scratch folder for days.
+const IDE_TEMP_MIN_AGE_SECS: u64 = 7 * 24 * 3600;
+
+fn is_ide_temp_name(name: &str) -> bool {
+    let name = name.to_lowercase();
+    IDE_TEMP_PREFIXES.iter().any(|p| name.starts_with(p))
+        || IDE_TEMP_SUFFIXES.iter().any(|s| name.ends_with(s))
+}
+
+/// Whether a running process has a file open: Windows refuses an exclusive open
+/// with a sharing violation. Unix has no such lock, and deleting an open file
+/// there leaves the process its copy, so nothing is reported.
+#[cfg(target_os = "windows")]
+fn is_file_held_open(path: &Path) -> bool {
+    use std::os::windows::fs::OpenOptionsExt;
+    const ERROR_SHARING_VIOLATION: i32 = 32;
+
+    match std::fs::OpenOptions::new().read(true).share_mode(0).open(path) {
+        Ok(_) => false,
+        Err(e) => e.raw_os_error() == Some(ERROR_SHARING_VIOLATION),
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+fn is_file_held_open(_path: &Path) -> bool {
+    false
+}
+
+/// A temp file, or any file inside a temp folder, still open in some process.
+fn is_held_open(path: &Path) -> bool {
+    walkdir::WalkDir::new(path)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .any(|e| is_file_held_open(e.path()))
+}
+
+/// IDE-owned files and folders directly in the temp dir, older than a week and
+/// not held open by a running IDE or installer.
+fn ide_temp_entries() -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(std::env::temp_dir()) else { return Vec::new() };
+    let mut found: Vec<PathBuf> = entries
+        .filter_map(|e| e.ok())
+        .filter(|e| is_ide_temp_name(&e.file_name().to_string_lossy()))
+        .filter(|e| {
+            e.metadata()
+                .and_then(|m| m.modified())
+                .ok()
+                .and_then(|t| t.elapsed().ok())
+                .is_some_and(|age| age.as_secs() >= IDE_TEMP_MIN_AGE_SECS)
+        })
+        .map(|e| e.path())
+        .filter(|p| !is_held_open(p))
+        .collect();
+    found.sort();
+    found
+}
+
+/// Remove one temp entry entirely; locked files (still in use) are reported and kept.
+fn ide_temp_wipe(path: &Path) -> (u64, u64, Vec<String>) {
+    let (size, count) = size_and_count(&[path.to_path_buf()]);
+    let removed = if path.is_dir() { crate::cleaner::remove_tree(path) } else { crate::cleaner::remove_file(path) };
+    match removed {
+        Ok(()) => (size, count, Vec::new()),
+        Err(_) if path.is_dir() => {
+            let (freed, deleted, errors) = crate::cleaner::clean_directory(path);
+            let _ = std::fs::remove_dir(path);
+            (freed, deleted, errors)
+        }
+        Err(e) => (0, 0, vec![format!("{}: {}", path.display(), e)]),
+    }
+}
+
+// ── Scan / clean ──
+
+fn size_and_count(paths: &[PathBuf]) -> (u64, u64) {
+    let mut size = 0;
+    let mut count = 0;
+    for p in paths {
+        for entry in walkdir::WalkDir::new(p).into_iter().filter_map(|e| e.ok()) {
+            if entry.file_type().is_file() {
+                if let Ok(meta) = entry.metadata() {
+                    size += meta.len();
+                    count += 1;
+                }
+            }
+        }
+    }
+    (size, count)
+}
+
+/// Command for `program`; on Windows through `cmd /C` so `.cmd` shims (npm.cmd) resolve.
+pub fn tool_command(program: &str) -> Command {
+    if cfg!(target_os = "windows") {
+        let mut cmd = crate::process::hidden_command("cmd");
+        cmd.args(["/C", program]);
+        cmd
+    } else {
+        Command::new(program)
+    }
+}
+
+pub fn tool_available(program: &str) -> bool {
+    tool_command(program)
+        .arg("--version")
+        .output()
+        .map(|o| o.status.success())
+        .unwrap_or(false)
+}
+
+fn entry_name(path: &std::path::Path) -> String {
+    let mut parts: Vec<String> = path.iter().rev().take(2).map(|c| c.to_string_lossy().to_string()).collect();
+    parts.reverse();
+    parts.join("/")
+}
+
+fn scan_tool(tool: &DevCacheTool) -> Option<DevCache> {
+    let paths = (tool.locate)();
+    if paths.is_empty() {
+        return None;
+    }
+    let (size, file_count) = size_and_count(&paths);
+    let has_command = !tool.clean_args.is_empty();
+    let breakdown = if let Some(itemize) = tool.itemize {
+        let mut entries: Vec<DevCacheEntry> = paths.iter().flat_map(|p| itemize(p)).collect();
+        entries.sort_by(|a, b| b.size.cmp(&a.size));
+        entries
+    } else if paths.len() > 1 {
+        paths
+            .iter()
+            .map(|p| DevCacheEntry { name: entry_name(p), path: p.clone(), size: size_and_count(std::slice::from_ref(p)).0 })
+            .collect()
+    } else {
+        Vec::new()
+    };
+    Some(DevCache {
+        id: tool.id.into(),
+        name: tool.name.into(),
+        paths,
+        size,
+        file_count,
+        breakdown,
+        clean_command: has_command.then(|| format!("{} {}", tool.program, tool.clean_args.join(" "))),
+        tool_available: has_command && tool_available(tool.program),
+    })
+}
+
+/// Every dev-tool cache found on this machine, largest first.
+pub fn scan_dev_caches() -> Vec<DevCache> {
+    let mut caches: Vec<DevCache> = DEV_CACHE_TOOLS.iter().filter_map(scan_tool).collect();
+    caches.sort_by(|a, b| b.size.cmp(&a.size));
+    caches
+}
+
+fn run_clean_command(tool: &DevCacheTool) -> Result<(), String> {
+    let output = tool_command(tool.program)
+        .args(tool.clean_args)
+        .output()
+        .map_err(|e| format!("Failed to run {}: {}", tool.program, e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "{} exited with code {}: {}",
+            tool.program,
+            output.status.code().unwrap_or(-1),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(())
+}
+
+/// Clean one cache, either through the tool's own command (`use_tool`) or by
+/// deleting the cache folder contents. `only` limits deletion to one of the
+/// tool's cache folders (a breakdown entry).
+pub fn clean_dev_cache(id: &str, use_tool: bool, only: Option<&std::path::Path>) -> Result<BatchResult, String> {
+    let tool = DEV_CACHE_TOOLS
+        .iter()
+        .find(|t| t.id == id)
+        .ok_or_else(|| format!("Unknown dev cache: {}", id))?;
+    let mut paths = (tool.locate)();
+    if let Some(only) = only {
+        if use_tool {
+            return Err(format!("{} can only clean all of its caches", tool.name));
+        }
+        // An itemized entry sits directly inside one of the cache folders
+        let is_item = tool.itemize.is_some() && only.parent().map(|parent| paths.iter().any(|p| p == parent)).unwrap_or(false);
+        if !is_item && !paths.iter().any(|p| p == only) {
+            return Err(format!("{} is not a {} cache", only.display(), tool.name));
+        }
+        if is_item {
+            let (freed, _, errors) = crate::cleaner::clean_directory(only);
+            if only.is_dir() {
+                let _ = std::fs::remove_dir(only);
+            }
+            let failed_paths = if errors.is_empty() { Vec::new() } else { crate::cleaner::remaining_files(only) };
+            let mut result = BatchResult::from_items(vec![ItemResult::from_errors(only.display().to_string(), freed, errors)]);
+            result.failed_paths = failed_paths;
+            return Ok(result);
+        }
+        paths.retain(|p| p == only);
+    }
+
+    if use_tool {
+        if tool.clean_args.is_empty() {
+            return Err(format!("{} has no clean command", tool.name));
+        }
+        // The tool's own command is one item, named after the tool
+        let (size_before, _) = size_and_count(&paths);
+        let errors: Vec<String> = run_clean_command(tool).err().into_iter().collect();
+        let (size_after, _) = size_and_count(&paths);
+        let freed = size_before.saturating_sub(size_after);
+        return Ok(BatchResult::from_items(vec![ItemResult::from_errors(id, freed, errors)]));
+    }
+
+    // Only the files a wipe left behind are recorded for retry, never the cache folder itself
+    let mut failed_paths = Vec::new();
+    let items = paths
+        .iter()
+        .map(|path| {
+            let item_id = path.display().to_string();
+            if !path.exists() {
+                return ItemResult::not_found(item_id);
+            }
+            let (freed, _, errors) = (tool.wipe)(path);
+            if !errors.is_empty() {
+                failed_paths.extend(crate::cleaner::remaining_files(path));
+            }
+            ItemResult::from_errors(item_id, freed, errors)
+        })
+        .collect();
+    let mut result = BatchResult::from_items(items);
+    result.failed_paths = failed_paths;
+    Ok(result)
+}
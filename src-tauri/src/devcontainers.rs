@@ -0,0 +1,235 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::batch::{BatchResult, ItemResult};
+
+/// Labels the Dev Containers extension / CLI put on the containers they create.
+const CONTAINER_LABELS: &[&str] = &["devcontainer.local_folder=", "devcontainer.config_file="];
+/// Images built for a dev container ("vsc-<folder>-<hash>[-uid|-features]") and the
+/// published base images.
+const IMAGE_PREFIXES: &[&str] = &[
+    "vsc-", "mcr.microsoft.com/devcontainers/", "mcr.microsoft.com/vscode/devcontainers/", "ghcr.io/devcontainers/",
+];
+/// Label prefix the Dev Containers tooling puts on what it creates. Volumes are
+/// only listed with it: a name like `vscode` alone could be anyone's.
+const DEVCONTAINER_LABEL: &str = "devcontainer.";
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum DevContainerKind {
+    Container,
+    Image,
+    Volume,
+    /// `devcontainercli-*` folders the devcontainers CLI keeps in the temp dir.
+    CliCache,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DevContainerItem {
+    pub kind: DevContainerKind,
+    /// What removal acts on: container/image ID, volume name or cache path.
+    pub id: String,
+    pub name: String,
+    pub size: u64,
+    /// Running container, or an image/volume a container still uses; never removed.
+    pub in_use: bool,
+    /// Project folder of a container, when labelled.
+    pub local_folder: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DevContainerReport {
+    /// `docker` answered; when false only the CLI cache is listed.
+    pub docker_available: bool,
+    pub items: Vec<DevContainerItem>,
+    pub total_size: u64,
+}
+
+/// What the UI sends back for removal.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DevContainerRef {
+    pub kind: DevContainerKind,
+    pub id: String,
+}
+
+// ── docker ──
+
+fn docker(args: &[&str]) -> Result<String, String> {
+    let output = crate::process::hidden_command("docker")
+        .args(args)
+        .output()
+        .map_err(|e| format!("Failed to run docker: {}", e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "docker {} exited with code {}: {}",
+            args.first().unwrap_or(&""),
+            output.status.code().unwrap_or(-1),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Docker's human sizes ("1.23GB", "512kB", "0B") use SI units.
+fn parse_docker_size(s: &str) -> u64 {
+    let s = s.trim();
+    let split = s.find(|c: char| c.is_ascii_alphabetic()).unwrap_or(s.len());
+    let Ok(value) = s[..split].parse::<f64>() else { return 0 };
+    let multiplier = match s[split..].to_ascii_uppercase().as_str() {
+        "" | "B" => 1.0,
+        "KB" => 1e3,
+        "MB" => 1e6,
+        "GB" => 1e9,
+        "TB" => 1e12,
+        _ => return 0,
+    };
+    (value * multiplier) as u64
+}
+
+fn field<'a>(v: &'a Value, key: &str) -> &'a str {
+    v.get(key).and_then(|f| f.as_str()).unwrap_or("")
+}
+
+/// "k=v,k2=v2" → value of `key`.
+fn label_value(labels: &str, key: &str) -> Option<String> {
+    labels.split(',').find_map(|l| l.strip_prefix(key)).map(|v| v.to_string())
+}
+
+fn count(v: &Value, key: &str) -> u64 {
+    match v.get(key) {
+        Some(Value::Number(n)) => n.as_u64().unwrap_or(0),
+        Some(Value::String(s)) => s.trim().parse().unwrap_or(0),
+        _ => 0,
+    }
+}
+
+/// Dev container items from `docker system df -v`, which has every size in one call.
+fn docker_items() -> Option<Vec<DevContainerItem>> {
+    let df: Value = serde_json::from_str(docker(&["system", "df", "-v", "--format", "{{json .}}"]).ok()?.trim()).ok()?;
+    let list = |key: &str| df.get(key).and_then(|v| v.as_array()).cloned().unwrap_or_default();
+    let mut items = Vec::new();
+
+    for c in list("Containers") {
+        let labels = field(&c, "Labels");
+        if !CONTAINER_LABELS.iter().any(|l| labels.contains(l)) {
+            continue;
+        }
+        items.push(DevContainerItem {
+            kind: DevContainerKind::Container,
+            id: field(&c, "ID").to_string(),
+            name: format!("{} ({})", field(&c, "Names"), field(&c, "Image")),
+            // "12.3MB (virtual 1.2GB)": only the writable layer is the container's own
+            size: parse_docker_size(field(&c, "Size").split(' ').next().unwrap_or("")),
+            in_use: field(&c, "State") == "running",
+            local_folder: label_value(labels, "devcontainer.local_folder="),
+        });
+    }
+
+    for i in list("Images") {
+        let repo = field(&i, "Repository");
+        if !IMAGE_PREFIXES.iter().any(|p| repo.starts_with(p)) {
+            continue;
+        }
+        items.push(DevContainerItem {
+            kind: DevContainerKind::Image,
+            id: field(&i, "ID").to_string(),
+            name: format!("{}:{}", repo, field(&i, "Tag")),
+            // Layers shared with other images aren't freed by removing this one
+            size: parse_docker_size(field(&i, "UniqueSize")),
+            in_use: count(&i, "Containers") > 0,
+            local_folder: None,
+        });
+    }
+
+    for v in list("Volumes") {
+        let name = field(&v, "Name");
+        if !field(&v, "Labels").contains(DEVCONTAINER_LABEL) {
+            continue;
+        }
+        items.push(DevContainerItem {
+            kind: DevContainerKind::Volume,
+            id: name.to_string(),
+            name: name.to_string(),
+            size: parse_docker_size(field(&v, "Size")),
+            in_use: count(&v, "Links") > 0,
+            local_folder: None,
+        });
+    }
+    Some(items)
+}
+
+// ── CLI cache ──
+
+fn cli_cache_items() -> Vec<DevContainerItem> {
+    let Ok(entries) = fs::read_dir(std::env::temp_dir()) else { return Vec::new() };
+    entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_name().to_string_lossy().starts_with("devcontainercli") && e.path().is_dir())
+        .map(|e| {
+            let path = e.path();
+            DevContainerItem {
+                kind: DevContainerKind::CliCache,
+                id: path.display().to_string(),
+                name: e.file_name().to_string_lossy().to_string(),
+                size: walkdir::WalkDir::new(&path)
+                    .into_iter()
+                    .filter_map(|e| e.ok())
+                    .filter(|e| e.file_type().is_file())
+                    .filter_map(|e| e.metadata().ok())
+                    .map(|m| m.len())
+                    .sum(),
+                in_use: false,
+                local_folder: None,
+            }
+        })
+        .collect()
+}
+
+/// Dev container containers, images and volumes plus the CLI cache, largest first.
+pub fn scan_devcontainers() -> DevContainerReport {
+    let docker = docker_items();
+    let docker_available = docker.is_some();
+    let mut items = docker.unwrap_or_default();
+    items.extend(cli_cache_items());
+    items.sort_by(|a, b| b.size.cmp(&a.size));
+    DevContainerReport { docker_available, total_size: items.iter().map(|i| i.size).sum(), items }
+}
+
+/// Remove the given items. Each is checked against a fresh scan first, so only
+/// dev container items that nothing is using are touched.
+pub fn remove_devcontainer_items(refs: &[DevContainerRef]) -> BatchResult {
+    let report = scan_devcontainers();
+    let mut failed_paths = Vec::new();
+    let items = refs
+        .iter()
+        .map(|r| {
+            let Some(item) = report.items.iter().find(|i| i.kind == r.kind && i.id == r.id) else {
+                return ItemResult::not_found(r.id.clone());
+            };
+            if item.in_use {
+                return ItemResult::failed(r.id.clone(), "In use by a container");
+            }
+            let removed = match item.kind {
+                DevContainerKind::Container => docker(&["rm", &item.id]).map(|_| ()),
+                DevContainerKind::Image => docker(&["rmi", &item.id]).map(|_| ()),
+                DevContainerKind::Volume => docker(&["volume", "rm", &item.id]).map(|_| ()),
+                DevContainerKind::CliCache => crate::cleaner::remove_tree(&PathBuf::from(&item.id))
+                    .map_err(|e| format!("Failed to delete: {}", e)),
+            };
+            match removed {
+                Ok(()) => ItemResult::done(r.id.clone(), item.size),
+                Err(e) => {
+                    // Docker objects are retried from the list; only the CLI cache is a path
+                    if item.kind == DevContainerKind::CliCache {
+                        failed_paths.push(PathBuf::from(&item.id));
+                    }
+                    ItemResult::failed(r.id.clone(), e)
+                }
+            }
+        })
+        .collect();
+    let mut result = BatchResult::from_items(items);
+    result.failed_paths = failed_paths;
+    result
+}
@@ -0,0 +1,55 @@
+//! VS Code-style extension folder names: `<publisher>.<name>-<version>[-<platform>]`.
+
+/// Lower-cased extension id and numeric version.
+pub type ExtensionVersion = (String, Vec<u64>);
+
+/// `major.minor.patch` at the start of `s`.
+fn starts_with_semver(s: &str) -> bool {
+    let numeric = |p: &str| !p.is_empty() && p.bytes().all(|b| b.is_ascii_digit());
+    let mut parts = s.splitn(3, '.');
+    matches!(
+        (parts.next(), parts.next(), parts.next()),
+        (Some(major), Some(minor), Some(patch)) if numeric(major) && numeric(minor) && patch.starts_with(|c: char| c.is_ascii_digit())
+    )
+}
+
+/// Index of the dash before the version: the last '-' followed by a semver, so
+/// names with "-3d" or "-2" parts keep them.
+fn version_dash(folder: &str) -> Option<usize> {
+    folder.rmatch_indices('-').map(|(i, _)| i).find(|&i| starts_with_semver(&folder[i + 1..]))
+}
+
+/// "ms-python.python-2024.1.0-win32-x64" → "ms-python.python"; names without
+/// a version come back whole.
+pub fn strip_version(folder: &str) -> &str {
+    version_dash(folder).map(|i| &folder[..i]).unwrap_or(folder)
+}
+
+/// "ms-python.python-2024.2.1-linux-x64" → ("ms-python.python", [2024, 2, 1]).
+pub fn parse_extension_folder(name: &str) -> Option<ExtensionVersion> {
+    let dash = version_dash(name)?;
+    let version = name[dash + 1..].split('-').next()?;
+    let parts: Option<Vec<u64>> = version.split('.').map(|p| p.parse().ok()).collect();
+    Some((name[..dash].to_lowercase(), parts?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_extension_folder, strip_version};
+
+    #[test]
+    fn version_is_split_at_the_last_semver_dash() {
+        assert_eq!(strip_version("ms-python.python-2024.1.0-win32-x64"), "ms-python.python");
+        assert_eq!(strip_version("acme.viewer-3d-tools-1.2.3"), "acme.viewer-3d-tools");
+        assert_eq!(strip_version("acme.tool-2"), "acme.tool-2");
+        assert_eq!(strip_version("acme.tool"), "acme.tool");
+    }
+
+    #[test]
+    fn folders_parse_into_id_and_version() {
+        assert_eq!(parse_extension_folder("ms-python.python-2024.2.1-linux-x64"), Some(("ms-python.python".into(), vec![2024, 2, 1])));
+        assert_eq!(parse_extension_folder("acme.tool-2-1.0.0"), Some(("acme.tool-2".into(), vec![1, 0, 0])));
+        assert_eq!(parse_extension_folder("Acme.Viewer-3d-tools-1.2.3"), Some(("acme.viewer-3d-tools".into(), vec![1, 2, 3])));
+        assert_eq!(parse_extension_folder("acme.tool"), None);
+    }
+}
@@ -0,0 +1,460 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::batch::{BatchResult, ItemResult};
+use crate::ide_detector::{IdeInfo, IdeType};
+
+const EXTENSION_LIST_FORMAT_VERSION: u32 = 1;
+
+/// Command-line launchers that can install extensions (`<cli> --install-extension <id>`).
+const EXTENSION_CLIS: &[(&str, &str)] = &[
+    ("vscode", "code"),
+    ("vscode_insiders", "code-insiders"),
+    ("vscodium", "codium"),
+    ("cursor", "cursor"),
+    ("windsurf", "windsurf"),
+    ("kiro", "kiro"),
+    ("trae", "trae"),
+    ("positron", "positron"),
+];
+
+/// One `<publisher>.<name>-<version>[-<platform>]` folder.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtensionCopy {
+    pub folder: String,
+    pub path: PathBuf,
+    pub version: String,
+    pub size: u64,
+    /// Referenced by `extensions.json`, i.e. the copy the IDE loads.
+    pub in_use: bool,
+    /// Listed in `.obsolete`: the IDE already uninstalled or replaced it.
+    pub obsolete: bool,
+    /// Obsolete, or an older copy that the IDE no longer references.
+    pub stale: bool,
+}
+
+/// Every copy of one extension id, newest first.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtensionGroup {
+    pub id: String,
+    pub copies: Vec<ExtensionCopy>,
+    pub size: u64,
+    pub stale_size: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtensionReport {
+    pub ide_id: String,
+    pub extension_path: PathBuf,
+    pub groups: Vec<ExtensionGroup>,
+    pub total_size: u64,
+    pub stale_size: u64,
+    pub stale_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtensionUninstallResult {
+    pub extension_id: String,
+    /// Extension folders and, if requested, the globalStorage folder.
+    pub removed: Vec<PathBuf>,
+    pub freed_bytes: u64,
+    pub errors: Vec<String>,
+    /// Folders that couldn't be removed.
+    #[serde(default)]
+    pub failed_paths: Vec<PathBuf>,
+    /// Set when `failed_paths` were recorded for `retry::retry_failed`.
+    #[serde(default)]
+    pub operation_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtensionListEntry {
+    pub id: String,
+    pub version: String,
+}
+
+/// Installed extensions of one IDE, as written by `export_extension_list`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtensionList {
+    pub format_version: u32,
+    pub created_at: String,
+    pub source_ide: String,
+    pub extensions: Vec<ExtensionListEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtensionInstallResult {
+    pub ide_id: String,
+    pub installed: Vec<String>,
+    /// Already present in the target IDE, so not reinstalled.
+    pub skipped: Vec<String>,
+    /// The CLI refused or failed; install these by hand.
+    pub failed: Vec<String>,
+}
+
+// ── Metadata ──
+
+fn path_size(path: &Path) -> u64 {
+    walkdir::WalkDir::new(path)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| e.metadata().ok())
+        .map(|m| m.len())
+        .sum()
+}
+
+/// Folder names in `.obsolete` (`{"<folder>": true, ...}`).
+fn read_obsolete(dir: &Path) -> HashMap<String, bool> {
+    fs::read_to_string(dir.join(".obsolete"))
+        .ok()
+        .and_then(|data| serde_json::from_str(&data).ok())
+        .unwrap_or_default()
+}
+
+/// Entries of `extensions.json`, the IDE's list of installed extensions.
+fn read_manifest(dir: &Path) -> Vec<serde_json::Value> {
+    fs::read_to_string(dir.join("extensions.json"))
+        .ok()
+        .and_then(|data| serde_json::from_str(&data).ok())
+        .unwrap_or_default()
+}
+
+/// Entries of every non-default profile's `User/profiles/<id>/extensions.json`.
+/// Profiles share the extensions folder but keep their own lists.
+fn read_profile_manifests(ide: &IdeInfo) -> Vec<serde_json::Value> {
+    let Some(profiles) = ide.config_path.as_ref().map(|c| c.join("User").join("profiles")) else { return Vec::new() };
+    fs::read_dir(profiles)
+        .map(|entries| entries.filter_map(|e| e.ok()).flat_map(|e| read_manifest(&e.path())).collect())
+        .unwrap_or_default()
+}
+
+/// Folder an `extensions.json` entry points at: `relativeLocation`, else the
+/// last segment of `location.path`.
+fn manifest_folder(entry: &serde_json::Value) -> Option<String> {
+    entry["relativeLocation"].as_str().map(str::to_string).or_else(|| {
+        let path = entry["location"]["path"].as_str().or_else(|| entry["location"]["fsPath"].as_str())?;
+        path.trim_end_matches(['/', '\\']).rsplit(['/', '\\']).next().map(str::to_string)
+    })
+}
+
+/// Drop `folders` from `.obsolete` and `extensions.json` after they were deleted,
+/// so the IDE doesn't look for them on the next start.
+fn forget_folders(dir: &Path, folders: &HashSet<String>) -> Result<(), String> {
+    let mut obsolete = read_obsolete(dir);
+    if obsolete.keys().any(|k| folders.contains(k)) {
+        obsolete.retain(|k, _| !folders.contains(k));
+        let data = serde_json::to_string(&obsolete).map_err(|e| format!("Failed to serialize .obsolete: {}", e))?;
+        fs::write(dir.join(".obsolete"), data).map_err(|e| format!("Failed to write .obsolete: {}", e))?;
+    }
+    let manifest = read_manifest(dir);
+    let kept: Vec<&serde_json::Value> = manifest
+        .iter()
+        .filter(|e| manifest_folder(e).map(|f| !folders.contains(&f)).unwrap_or(true))
+        .collect();
+    if kept.len() != manifest.len() {
+        let data = serde_json::to_string(&kept).map_err(|e| format!("Failed to serialize extensions.json: {}", e))?;
+        fs::write(dir.join("extensions.json"), data).map_err(|e| format!("Failed to write extensions.json: {}", e))?;
+    }
+    Ok(())
+}
+
+fn extensions_dir(ide: &IdeInfo) -> Result<&PathBuf, String> {
+    if ide.ide_type != IdeType::VscodeBased {
+        return Err(format!("{} doesn't use VS Code extensions", ide.name));
+    }
+    ide.extension_path.as_ref().ok_or_else(|| format!("{} has no extensions folder", ide.name))
+}
+
+// ── Duplicate and obsolete versions ──
+
+/// Group the IDE's extension folders by id. Copies listed in `.obsolete` are
+/// stale; so is every copy no `extensions.json` (the default one or a
+/// profile's) references when another copy of the same extension is newer or in use.
+pub fn analyze_extensions(ide: &IdeInfo) -> Result<ExtensionReport, String> {
+    let dir = extensions_dir(ide)?;
+    let obsolete = read_obsolete(dir);
+    let mut manifest = read_manifest(dir);
+    manifest.extend(read_profile_manifests(ide));
+    let referenced: HashSet<String> = manifest.iter().filter_map(manifest_folder).collect();
+
+    let mut by_id: HashMap<String, Vec<(Vec<u64>, ExtensionCopy)>> = HashMap::new();
+    for entry in fs::read_dir(dir).map_err(|e| format!("Failed to read {}: {}", dir.display(), e))?.filter_map(|e| e.ok()) {
+        if !entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
+            continue;
+        }
+        let folder = entry.file_name().to_string_lossy().to_string();
+        if folder.starts_with('.') {
+            continue;
+        }
+        let Some((id, version)) = crate::extension_folder::parse_extension_folder(&folder) else { continue };
+        let copy = ExtensionCopy {
+            version: version.iter().map(|n| n.to_string()).collect::<Vec<_>>().join("."),
+            size: path_size(&entry.path()),
+            path: entry.path(),
+            in_use: referenced.contains(&folder),
+            obsolete: obsolete.get(&folder).copied().unwrap_or(false),
+            stale: false,
+            folder,
+        };
+        by_id.entry(id).or_default().push((version, copy));
+    }
+
+    let mut groups: Vec<ExtensionGroup> = by_id
+        .into_iter()
+        .map(|(id, mut copies)| {
+            copies.sort_by(|a, b| b.0.cmp(&a.0));
+            let any_in_use = copies.iter().any(|(_, c)| c.in_use);
+            let copies: Vec<ExtensionCopy> = copies
+                .into_iter()
+                .enumerate()
+                .map(|(i, (_, mut c))| {
+                    // Nothing referenced (no extensions.json in older builds): the newest copy is the live one
+                    let superseded = if any_in_use { true } else { i > 0 };
+                    c.stale = !c.in_use && (c.obsolete || superseded);
+                    c
+                })
+                .collect();
+            ExtensionGroup {
+                id,
+                size: copies.iter().map(|c| c.size).sum(),
+                stale_size: copies.iter().filter(|c| c.stale).map(|c| c.size).sum(),
+                copies,
+            }
+        })
+        .collect();
+    groups.sort_by(|a, b| b.stale_size.cmp(&a.stale_size).then(b.size.cmp(&a.size)));
+
+    Ok(ExtensionReport {
+        ide_id: ide.id.clone(),
+        extension_path: dir.clone(),
+        total_size: groups.iter().map(|g| g.size).sum(),
+        stale_size: groups.iter().map(|g| g.stale_size).sum(),
+        stale_count: groups.iter().flat_map(|g| &g.copies).filter(|c| c.stale).count(),
+        groups,
+    })
+}
+
+/// Delete stale extension copies. Only paths a fresh analysis still reports
+/// as stale are removed, so the copy the IDE loads is never touched.
+pub fn clean_stale_extensions(ide: &IdeInfo, paths: &[PathBuf]) -> Result<BatchResult, String> {
+    let report = analyze_extensions(ide)?;
+    let stale: Vec<&ExtensionCopy> = report.groups.iter().flat_map(|g| &g.copies).filter(|c| c.stale).collect();
+
+    let mut removed = HashSet::new();
+    let mut failed_paths = Vec::new();
+    let mut items: Vec<ItemResult> = paths
+        .iter()
+        .map(|path| {
+            let id = path.display().to_string();
+            let Some(copy) = stale.iter().find(|c| c.path == *path) else {
+                return if path.exists() { ItemResult::skipped(id, "Not a stale extension copy") } else { ItemResult::not_found(id) };
+            };
+            match crate::cleaner::remove_tree(&copy.path) {
+                Ok(()) => {
+                    removed.insert(copy.folder.clone());
+                    ItemResult::done(id, copy.size)
+                }
+                Err(e) => {
+                    failed_paths.push(copy.path.clone());
+                    ItemResult::failed(id, e.to_string())
+                }
+            }
+        })
+        .collect();
+    if let Err(e) = forget_folders(&report.extension_path, &removed) {
+        items.push(ItemResult::failed(report.extension_path.display().to_string(), e));
+    }
+    let mut result = BatchResult::from_items(items);
+    result.failed_paths = failed_paths;
+    Ok(result)
+}
+
+// ── Uninstall ──
+
+/// Remove every copy of one extension, take it out of `extensions.json` and
+/// `.obsolete`, and optionally delete its `globalStorage/<id>` data.
+pub fn uninstall_extension(ide: &IdeInfo, extension_id: &str, clear_global_storage: bool) -> Result<ExtensionUninstallResult, String> {
+    let report = analyze_extensions(ide)?;
+    let id = extension_id.to_lowercase();
+    let group = report
+        .groups
+        .iter()
+        .find(|g| g.id == id)
+        .ok_or_else(|| format!("Extension '{}' is not installed in {}", extension_id, ide.name))?;
+
+    let mut result = ExtensionUninstallResult {
+        extension_id: id.clone(),
+        removed: Vec::new(),
+        freed_bytes: 0,
+        errors: Vec::new(),
+        failed_paths: Vec::new(),
+        operation_id: None,
+    };
+    let mut removed_folders = HashSet::new();
+    for copy in &group.copies {
+        match crate::cleaner::remove_tree(&copy.path) {
+            Ok(()) => {
+                result.freed_bytes += copy.size;
+                result.removed.push(copy.path.clone());
+                removed_folders.insert(copy.folder.clone());
+            }
+            Err(e) => {
+                result.errors.push(format!("{}: {}", copy.path.display(), e));
+                result.failed_paths.push(copy.path.clone());
+            }
+        }
+    }
+    if let Err(e) = forget_folders(&report.extension_path, &removed_folders) {
+        result.errors.push(e);
+    }
+
+    if clear_global_storage {
+        let data_dir = ide.global_storage_path.as_ref().and_then(|gs| {
+            fs::read_dir(gs)
+                .ok()?
+                .filter_map(|e| e.ok())
+                .find(|e| e.file_name().to_string_lossy().to_lowercase() == id)
+                .map(|e| e.path())
+        });
+        if let Some(data_dir) = data_dir {
+            crate::db_pool::close_under(&data_dir);
+            let size = path_size(&data_dir);
+            match crate::cleaner::remove_tree(&data_dir) {
+                Ok(()) => {
+                    result.freed_bytes += size;
+                    result.removed.push(data_dir);
+                }
+                Err(e) => {
+                    result.errors.push(format!("{}: {}", data_dir.display(), e));
+                    result.failed_paths.push(data_dir);
+                }
+            }
+        }
+    }
+    Ok(result)
+}
+
+// ── Export / import ──
+
+/// The CLI that installs extensions into `ide_id`, if it is on PATH.
+pub(crate) fn extension_cli(ide_id: &str) -> Option<&'static str> {
+    EXTENSION_CLIS
+        .iter()
+        .find(|(id, _)| *id == ide_id)
+        .map(|(_, cli)| *cli)
+        .filter(|cli| crate::dev_caches::tool_available(cli))
+}
+
+/// `publisher.name`, optionally `@version`. Specs from an imported file must
+/// match this before they reach the CLI, which runs through `cmd /C` on Windows.
+pub(crate) fn is_valid_extension_spec(spec: &str) -> bool {
+    let word = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '-');
+    let (id, version) = match spec.split_once('@') {
+        Some((id, version)) => (id, Some(version)),
+        None => (spec, None),
+    };
+    let id_ok = id.split_once('.').is_some_and(|(publisher, name)| word(publisher) && word(name));
+    let version_ok = version.map_or(true, |v| {
+        !v.is_empty() && v.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '+' | '-'))
+    });
+    id_ok && version_ok
+}
+
+/// Run `<cli> --install-extension` for each spec (`<id>` or `<id>@<version>`).
+/// Returns the specs that installed and the ones that didn't; malformed specs
+/// are never run.
+pub(crate) fn install_with_cli(cli: &str, specs: &[String]) -> (Vec<String>, Vec<String>) {
+    specs.iter().cloned().partition(|spec| {
+        is_valid_extension_spec(spec)
+            && crate::dev_caches::tool_command(cli)
+                .args(["--install-extension", spec])
+                .output()
+                .map(|o| o.status.success())
+                .unwrap_or(false)
+    })
+}
+
+/// Write the IDE's installed extensions, with the version it loads, to `path`.
+/// Extensions the IDE already uninstalled (`.obsolete` only) are left out.
+pub fn export_extension_list(ide: &IdeInfo, path: &Path) -> Result<ExtensionList, String> {
+    let report = analyze_extensions(ide)?;
+    let mut extensions: Vec<ExtensionListEntry> = report
+        .groups
+        .iter()
+        .filter_map(|g| {
+            let live = g.copies.iter().find(|c| c.in_use).or_else(|| g.copies.iter().find(|c| !c.obsolete))?;
+            Some(ExtensionListEntry { id: g.id.clone(), version: live.version.clone() })
+        })
+        .collect();
+    extensions.sort_by(|a, b| a.id.cmp(&b.id));
+
+    let list = ExtensionList {
+        format_version: EXTENSION_LIST_FORMAT_VERSION,
+        created_at: chrono::Local::now().to_rfc3339(),
+        source_ide: ide.id.clone(),
+        extensions,
+    };
+    let data = serde_json::to_string_pretty(&list).map_err(|e| format!("Failed to serialize extension list: {}", e))?;
+    fs::write(path, data).map_err(|e| format!("Failed to write extension list: {}", e))?;
+    Ok(list)
+}
+
+pub fn read_extension_list(path: &Path) -> Result<ExtensionList, String> {
+    let data = fs::read_to_string(path).map_err(|e| format!("Failed to read extension list: {}", e))?;
+    let list: ExtensionList = serde_json::from_str(&data).map_err(|e| format!("Failed to parse extension list: {}", e))?;
+    if list.format_version > EXTENSION_LIST_FORMAT_VERSION {
+        return Err(format!("Extension list format {} is newer than this version supports", list.format_version));
+    }
+    Ok(list)
+}
+
+/// Install the extensions listed in `path` into `ide` through its CLI, skipping
+/// ones it already has. With `exact_versions` each is pinned to the exported
+/// version (`<id>@<version>`), otherwise the marketplace's latest is installed.
+pub fn import_extension_list(ide: &IdeInfo, path: &Path, exact_versions: bool) -> Result<ExtensionInstallResult, String> {
+    let list = read_extension_list(path)?;
+    let cli = extension_cli(&ide.id).ok_or_else(|| format!("No command-line launcher for {} found on PATH", ide.name))?;
+
+    let present: HashSet<(String, String)> = analyze_extensions(ide)
+        .map(|r| {
+            r.groups
+                .into_iter()
+                .flat_map(|g| g.copies.into_iter().filter(|c| !c.obsolete).map(move |c| (g.id.clone(), c.version)))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut skipped = Vec::new();
+    let mut specs = Vec::new();
+    for entry in &list.extensions {
+        let id = entry.id.to_lowercase();
+        let installed = present.iter().any(|(i, v)| *i == id && (!exact_versions || *v == entry.version));
+        if installed {
+            skipped.push(entry.id.clone());
+            continue;
+        }
+        specs.push(if exact_versions { format!("{}@{}", entry.id, entry.version) } else { entry.id.clone() });
+    }
+    // The list file is untrusted; install_with_cli reports malformed ids as failed
+    let (installed, failed) = install_with_cli(cli, &specs);
+    Ok(ExtensionInstallResult { ide_id: ide.id.clone(), installed, skipped, failed })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extension_specs_reject_shell_syntax() {
+        assert!(is_valid_extension_spec("rust-lang.rust-analyzer"));
+        assert!(is_valid_extension_spec("ms-python.python@2024.2.1"));
+        assert!(!is_valid_extension_spec("a.b & calc"));
+        assert!(!is_valid_extension_spec("a.b@1.0\"&calc"));
+        assert!(!is_valid_extension_spec("no-publisher"));
+        assert!(!is_valid_extension_spec("a.b.c"));
+        assert!(!is_valid_extension_spec("a.b@"));
+    }
+}
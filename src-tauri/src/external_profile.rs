@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::ide_detector::{self, IdeInfo, ProfileDirs};
+use crate::scanner::{self, ScanSummary};
+
+/// Built-in profile folders that never belong to a real user.
+const SKIPPED_PROFILES: &[&str] = &["Public", "Default", "Default User", "All Users", "Shared", "Guest"];
+
+/// Where user profiles live below the root of a mounted system drive.
+const PROFILE_PARENTS: &[&str] = &["Users", "home"];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfileReport {
+    pub name: String,
+    pub dirs: ProfileDirs,
+    pub ides: Vec<IdeInfo>,
+    pub scan: ScanSummary,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExternalProfileReport {
+    pub root: PathBuf,
+    pub profiles: Vec<ProfileReport>,
+}
+
+/// User profiles under `root`: `root` itself when it is a home folder,
+/// otherwise every profile in `root/Users` or `root/home`.
+fn find_profiles(root: &Path) -> Vec<ProfileDirs> {
+    if let Some(dirs) = ProfileDirs::from_home(root) {
+        return vec![dirs];
+    }
+    let mut profiles: Vec<ProfileDirs> = PROFILE_PARENTS
+        .iter()
+        .filter_map(|parent| std::fs::read_dir(root.join(parent)).ok())
+        .flat_map(|entries| entries.filter_map(|e| e.ok()))
+        .filter(|e| e.file_type().map(|t| t.is_dir()).unwrap_or(false))
+        .filter(|e| !SKIPPED_PROFILES.iter().any(|s| e.file_name().eq_ignore_ascii_case(s)))
+        .filter_map(|e| ProfileDirs::from_home(&e.path()))
+        .collect();
+    profiles.sort_by(|a, b| a.home.cmp(&b.home));
+    profiles
+}
+
+fn analyze_profile(dirs: ProfileDirs) -> ProfileReport {
+    let name = dirs
+        .home
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| dirs.home.display().to_string());
+    let (ides, scan) = ide_detector::with_profile(dirs.clone(), || {
+        let ides: Vec<IdeInfo> = ide_detector::detect_all_ides().into_iter().filter(|i| i.installed).collect();
+        let scan = scanner::scan_all(&ides);
+        (ides, scan)
+    });
+    ProfileReport { name, dirs, ides, scan }
+}
+
+/// Detect and size the IDEs of another user's profile or a mounted disk image.
+/// Only reads metadata: nothing is opened for writing, no databases are
+/// touched and the result can't be fed to a clean.
+pub fn analyze_external_profile(root: &Path) -> Result<ExternalProfileReport, String> {
+    if !root.is_dir() {
+        return Err(format!("Failed to open {}: not a folder", root.display()));
+    }
+    let profiles = find_profiles(root);
+    if profiles.is_empty() {
+        return Err(format!("No user profiles found under {}", root.display()));
+    }
+    Ok(ExternalProfileReport {
+        root: root.to_path_buf(),
+        profiles: profiles.into_iter().map(analyze_profile).collect(),
+    })
+}
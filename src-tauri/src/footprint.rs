@@ -0,0 +1,256 @@
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::Instant;
+
+/// Top-level subsystems the footprint is aggregated over. Stale JetBrains
+/// versions and orphaned extension storage are left out: they are subsets of
+/// the IDE figures and would be counted twice.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum FootprintFamily {
+    Ide,
+    DevCache,
+    ToolboxBuild,
+    /// Rust `target/` folders and Python virtualenvs/caches under the project roots.
+    ProjectArtifact,
+    DevContainer,
+    LocalModel,
+    WslServer,
+    AiCliSession,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FootprintItem {
+    pub family: FootprintFamily,
+    pub id: String,
+    pub name: String,
+    /// Category, cache or build the size belongs to.
+    pub detail: String,
+    pub size: u64,
+    pub paths: Vec<PathBuf>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FamilyTotal {
+    pub family: FootprintFamily,
+    pub total_size: u64,
+    pub item_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeveloperFootprint {
+    /// Every item, largest first.
+    pub items: Vec<FootprintItem>,
+    /// Per-family totals, largest first.
+    pub families: Vec<FamilyTotal>,
+    pub grand_total: u64,
+    pub scan_duration_ms: u64,
+}
+
+fn ide_items() -> Vec<FootprintItem> {
+    let ides = crate::ide_detector::detect_installed_ides();
+    crate::scanner::scan_all(&ides)
+        .results
+        .into_iter()
+        .flat_map(|r| {
+            r.categories.into_iter().map(move |c| FootprintItem {
+                family: FootprintFamily::Ide,
+                id: format!("ide:{}:{}", r.ide_id, c.name),
+                name: r.ide_name.clone(),
+                detail: c.name,
+                size: c.total_size,
+                paths: c.paths,
+            })
+        })
+        .collect()
+}
+
+fn dev_cache_items() -> Vec<FootprintItem> {
+    crate::dev_caches::scan_dev_caches()
+        .into_iter()
+        .map(|c| FootprintItem {
+            family: FootprintFamily::DevCache,
+            id: format!("cache:{}", c.id),
+            detail: c.paths.first().map(|p| p.display().to_string()).unwrap_or_default(),
+            name: c.name,
+            size: c.size,
+            paths: c.paths,
+        })
+        .collect()
+}
+
+fn toolbox_items() -> Vec<FootprintItem> {
+    crate::stale_versions::find_toolbox_leftovers()
+        .into_iter()
+        .map(|l| FootprintItem {
+            family: FootprintFamily::ToolboxBuild,
+            id: format!("toolbox:{}", l.id),
+            name: l.product,
+            detail: format!("{} (current {})", l.build, l.current_build),
+            size: l.size,
+            paths: l.paths,
+        })
+        .collect()
+}
+
+/// Staleness cut-off passed to scanners that need one; the footprint counts
+/// everything regardless.
+const STALE_DAYS: u32 = 30;
+
+fn project_items() -> Vec<FootprintItem> {
+    let roots = crate::config::load_config().project_roots;
+    let rust = crate::project_artifacts::find_rust_targets(&roots).into_iter().map(|t| FootprintItem {
+        family: FootprintFamily::ProjectArtifact,
+        id: format!("project:{}", t.target_path.display()),
+        name: t.crate_name,
+        detail: t.target_path.display().to_string(),
+        size: t.size,
+        paths: vec![t.target_path],
+    });
+    let python = crate::project_artifacts::find_python_artifacts(&roots, STALE_DAYS).into_iter().map(|p| FootprintItem {
+        family: FootprintFamily::ProjectArtifact,
+        id: format!("project:{}", p.project_path.display()),
+        name: p.name,
+        detail: p.project_path.display().to_string(),
+        size: p.size,
+        paths: p.groups.into_iter().flat_map(|g| g.paths).collect(),
+    });
+    rust.chain(python).collect()
+}
+
+fn devcontainer_items() -> Vec<FootprintItem> {
+    crate::devcontainers::scan_devcontainers()
+        .items
+        .into_iter()
+        .map(|c| FootprintItem {
+            family: FootprintFamily::DevContainer,
+            id: format!("devcontainer:{}", c.id),
+            detail: c.local_folder.clone().unwrap_or_else(|| format!("{:?}", c.kind)),
+            paths: match c.kind {
+                crate::devcontainers::DevContainerKind::CliCache => vec![PathBuf::from(&c.id)],
+                _ => Vec::new(),
+            },
+            name: c.name,
+            size: c.size,
+        })
+        .collect()
+}
+
+/// Per model store rather than per model: Ollama models share blobs.
+fn local_model_items() -> Vec<FootprintItem> {
+    crate::local_models::analyze_local_models()
+        .stores
+        .into_iter()
+        .map(|s| FootprintItem {
+            family: FootprintFamily::LocalModel,
+            id: format!("models:{}", s.path.display()),
+            name: format!("{:?}", s.runtime),
+            detail: s.path.display().to_string(),
+            size: s.size,
+            paths: vec![s.path],
+        })
+        .collect()
+}
+
+fn wsl_items() -> Vec<FootprintItem> {
+    crate::wsl::scan_wsl_servers()
+        .servers
+        .into_iter()
+        .map(|w| FootprintItem {
+            family: FootprintFamily::WslServer,
+            id: format!("wsl:{}", w.path.display()),
+            name: w.product,
+            detail: format!("{} ({})", w.distro, w.user),
+            size: w.size,
+            paths: vec![w.path],
+        })
+        .collect()
+}
+
+fn ai_cli_items() -> Vec<FootprintItem> {
+    crate::ai_cli::analyze_ai_cli(STALE_DAYS)
+        .roots
+        .into_iter()
+        .map(|r| FootprintItem {
+            family: FootprintFamily::AiCliSession,
+            id: format!("ai-cli:{}", r.path.display()),
+            name: format!("{:?}", r.tool),
+            detail: r.path.display().to_string(),
+            size: r.size,
+            paths: vec![r.path],
+        })
+        .collect()
+}
+
+/// Drop items whose every path an earlier item already covers (the same
+/// folder or a parent of it), so Android Studio's Gradle caches aren't counted
+/// again next to the Gradle dev cache, or once per Android Studio install.
+fn drop_covered(items: Vec<FootprintItem>) -> Vec<FootprintItem> {
+    let mut claimed: Vec<PathBuf> = Vec::new();
+    let mut kept = Vec::new();
+    for item in items {
+        if !item.paths.is_empty() && item.paths.iter().all(|p| claimed.iter().any(|c| p.starts_with(c))) {
+            continue;
+        }
+        claimed.extend(item.paths.iter().cloned());
+        kept.push(item);
+    }
+    kept
+}
+
+/// Scan every subsystem concurrently and rank everything found on the machine.
+pub fn get_developer_footprint() -> DeveloperFootprint {
+    let start = Instant::now();
+
+    let items: Vec<FootprintItem> = std::thread::scope(|s| {
+        // Broader folders first, so drop_covered keeps them over their subfolders
+        let scanners = [
+            s.spawn(dev_cache_items),
+            s.spawn(local_model_items),
+            s.spawn(ai_cli_items),
+            s.spawn(wsl_items),
+            s.spawn(devcontainer_items),
+            s.spawn(toolbox_items),
+            s.spawn(ide_items),
+            s.spawn(project_items),
+        ];
+        scanners.into_iter().flat_map(|h| h.join().unwrap_or_default()).collect()
+    });
+    let mut items = drop_covered(items);
+    items.retain(|i| i.size > 0);
+    items.sort_by(|a, b| b.size.cmp(&a.size));
+
+    let mut families: Vec<FamilyTotal> = Vec::new();
+    for item in &items {
+        match families.iter_mut().find(|f| f.family == item.family) {
+            Some(f) => {
+                f.total_size += item.size;
+                f.item_count += 1;
+            }
+            None => families.push(FamilyTotal { family: item.family.clone(), total_size: item.size, item_count: 1 }),
+        }
+    }
+    families.sort_by(|a, b| b.total_size.cmp(&a.total_size));
+
+    DeveloperFootprint {
+        grand_total: families.iter().map(|f| f.total_size).sum(),
+        items,
+        families,
+        scan_duration_ms: start.elapsed().as_millis() as u64,
+    }
+}
+
+/// Plain-text footprint for the command line: per-family totals, then the
+/// largest items.
+pub fn format_report(footprint: &DeveloperFootprint, top: usize) -> String {
+    use crate::commands::format_bytes;
+
+    let mut out = format!("Developer footprint: {}\n\n", format_bytes(footprint.grand_total));
+    for f in &footprint.families {
+        out.push_str(&format!("  {:<16} {:>12}  ({} items)\n", format!("{:?}", f.family), format_bytes(f.total_size), f.item_count));
+    }
+    out.push_str(&format!("\nLargest {}:\n", top.min(footprint.items.len())));
+    for item in footprint.items.iter().take(top) {
+        out.push_str(&format!("  {:>12}  {} — {}\n", format_bytes(item.size), item.name, item.detail));
+    }
+    out
+}
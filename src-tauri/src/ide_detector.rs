@@ -1,10 +1,15 @@
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum IdeType {
     VscodeBased,
     JetBrains,
+    Zed,
+    VisualStudio,
+    Eclipse,
+    TerminalEditor,
+    Xcode,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -32,6 +37,19 @@ pub struct IdeInfo {
     pub workspace_storage_path: Option<PathBuf>,
     pub global_storage_path: Option<PathBuf>,
     pub versioned_folders: Vec<VersionedFolder>,
+    /// Install folder of a portable copy (user data lives in its `data/` folder).
+    pub portable_root: Option<PathBuf>,
+    /// Product-specific categories beyond the standard cache/log/storage fields.
+    pub extra_categories: Vec<ExtraCategory>,
+}
+
+/// A scannable/cleanable category that only some products have
+/// (e.g. Visual Studio's ComponentModelCache). Cleaned by name like the built-in ones.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtraCategory {
+    pub name: String,
+    pub category_type: crate::scanner::CategoryType,
+    pub paths: Vec<PathBuf>,
 }
 
 // ── VSCode-based IDE definitions ──
@@ -41,22 +59,23 @@ struct VscodeDefinition {
     id: &'static str,
     appdata_folder: &'static str,
     home_dot_folder: &'static str,
+    process_name: &'static str,
 }
 
 const VSCODE_DEFS: &[VscodeDefinition] = &[
-    VscodeDefinition { name: "Visual Studio Code", id: "vscode", appdata_folder: "Code", home_dot_folder: ".vscode" },
-    VscodeDefinition { name: "Cursor", id: "cursor", appdata_folder: "Cursor", home_dot_folder: ".cursor" },
-    VscodeDefinition { name: "Windsurf", id: "windsurf", appdata_folder: "Windsurf", home_dot_folder: ".windsurf" },
-    VscodeDefinition { name: "Kiro", id: "kiro", appdata_folder: "Kiro", home_dot_folder: ".kiro" },
-    VscodeDefinition { name: "Trae", id: "trae", appdata_folder: "Trae", home_dot_folder: ".trae" },
-    VscodeDefinition { name: "Trae CN", id: "trae_cn", appdata_folder: "Trae CN", home_dot_folder: ".trae-cn" },
-    VscodeDefinition { name: "Qoder", id: "qoder", appdata_folder: "Qoder", home_dot_folder: ".qoder" },
-    VscodeDefinition { name: "Antigravity", id: "antigravity", appdata_folder: "Antigravity", home_dot_folder: ".antigravity" },
-    VscodeDefinition { name: "PearAI", id: "pearai", appdata_folder: "PearAI", home_dot_folder: ".pearai" },
-    VscodeDefinition { name: "Aide", id: "aide", appdata_folder: "Aide", home_dot_folder: ".aide" },
-    VscodeDefinition { name: "Positron", id: "positron", appdata_folder: "Positron", home_dot_folder: ".positron" },
-    VscodeDefinition { name: "VSCodium", id: "vscodium", appdata_folder: "VSCodium", home_dot_folder: ".vscode-oss" },
-    VscodeDefinition { name: "Void", id: "void", appdata_folder: "Void", home_dot_folder: ".void" },
+    VscodeDefinition { name: "Visual Studio Code", id: "vscode", appdata_folder: "Code", home_dot_folder: ".vscode", process_name: "Code.exe" },
+    VscodeDefinition { name: "Cursor", id: "cursor", appdata_folder: "Cursor", home_dot_folder: ".cursor", process_name: "Cursor.exe" },
+    VscodeDefinition { name: "Windsurf", id: "windsurf", appdata_folder: "Windsurf", home_dot_folder: ".windsurf", process_name: "Windsurf.exe" },
+    VscodeDefinition { name: "Kiro", id: "kiro", appdata_folder: "Kiro", home_dot_folder: ".kiro", process_name: "Kiro.exe" },
+    VscodeDefinition { name: "Trae", id: "trae", appdata_folder: "Trae", home_dot_folder: ".trae", process_name: "Trae.exe" },
+    VscodeDefinition { name: "Trae CN", id: "trae_cn", appdata_folder: "Trae CN", home_dot_folder: ".trae-cn", process_name: "Trae.exe" },
+    VscodeDefinition { name: "Qoder", id: "qoder", appdata_folder: "Qoder", home_dot_folder: ".qoder", process_name: "Qoder.exe" },
+    VscodeDefinition { name: "Antigravity", id: "antigravity", appdata_folder: "Antigravity", home_dot_folder: ".antigravity", process_name: "Antigravity.exe" },
+    VscodeDefinition { name: "PearAI", id: "pearai", appdata_folder: "PearAI", home_dot_folder: ".pearai", process_name: "PearAI.exe" },
+    VscodeDefinition { name: "Aide", id: "aide", appdata_folder: "Aide", home_dot_folder: ".aide", process_name: "Aide.exe" },
+    VscodeDefinition { name: "Positron", id: "positron", appdata_folder: "Positron", home_dot_folder: ".positron", process_name: "Positron.exe" },
+    VscodeDefinition { name: "VSCodium", id: "vscodium", appdata_folder: "VSCodium", home_dot_folder: ".vscode-oss", process_name: "codium.exe" },
+    VscodeDefinition { name: "Void", id: "void", appdata_folder: "Void", home_dot_folder: ".void", process_name: "Void.exe" },
 ];
 
 // ── JetBrains product folder prefixes ──
@@ -85,103 +104,507 @@ const JETBRAINS_PRODUCTS: &[JetBrainsProduct] = &[
     JetBrainsProduct { name: "Fleet", id: "fleet", folder_prefixes: &["Fleet"], process_name: "Fleet.exe" },
 ];
 
+// ── Runtime definitions (built-ins + user-defined ide_definitions.json) ──
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VscodeIdeDefinition {
+    pub name: String,
+    pub id: String,
+    pub appdata_folder: String,
+    pub home_dot_folder: String,
+    #[serde(default)]
+    pub process_names: Vec<String>,
+    #[serde(default)]
+    pub custom: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JetBrainsIdeDefinition {
+    pub name: String,
+    pub id: String,
+    pub folder_prefixes: Vec<String>,
+    #[serde(default)]
+    pub process_names: Vec<String>,
+    #[serde(default)]
+    pub custom: bool,
+}
+
+/// Format of the user-editable ide_definitions.json file.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct IdeDefinitionsFile {
+    vscode: Vec<VscodeIdeDefinition>,
+    jetbrains: Vec<JetBrainsIdeDefinition>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdeDefinitions {
+    pub path: PathBuf,
+    pub vscode: Vec<VscodeIdeDefinition>,
+    pub jetbrains: Vec<JetBrainsIdeDefinition>,
+    pub error: Option<String>,
+}
+
+pub fn get_ide_definitions_path() -> PathBuf {
+    crate::config::get_app_data_dir().join("ide_definitions.json")
+}
+
+/// A user-supplied folder gets joined onto %APPDATA%, %LOCALAPPDATA% or home,
+/// so it must be exactly one plain folder name (no `..`, no absolute path).
+fn is_folder_name(name: &str) -> bool {
+    let mut components = Path::new(name).components();
+    matches!(components.next(), Some(std::path::Component::Normal(_))) && components.next().is_none()
+}
+
+/// JetBrains prefixes may name a vendor subfolder (`Google/AndroidStudio`),
+/// but only downwards.
+fn is_folder_prefix(prefix: &str) -> bool {
+    !prefix.is_empty() && Path::new(prefix).components().all(|c| matches!(c, std::path::Component::Normal(_)))
+}
+
+/// Built-in definitions followed by user-defined ones. User entries whose id
+/// collides with an existing definition, or whose folders aren't plain folder
+/// names, are skipped.
+pub fn load_ide_definitions() -> IdeDefinitions {
+    let path = get_ide_definitions_path();
+    let mut vscode: Vec<VscodeIdeDefinition> = VSCODE_DEFS
+        .iter()
+        .map(|d| VscodeIdeDefinition {
+            name: d.name.into(),
+            id: d.id.into(),
+            appdata_folder: d.appdata_folder.into(),
+            home_dot_folder: d.home_dot_folder.into(),
+            process_names: vec![d.process_name.into()],
+            custom: false,
+        })
+        .collect();
+    let mut jetbrains: Vec<JetBrainsIdeDefinition> = JETBRAINS_PRODUCTS
+        .iter()
+        .map(|p| JetBrainsIdeDefinition {
+            name: p.name.into(),
+            id: p.id.into(),
+            folder_prefixes: p.folder_prefixes.iter().map(|s| s.to_string()).collect(),
+            process_names: vec![p.process_name.into()],
+            custom: false,
+        })
+        .collect();
+    let mut error = None;
+
+    if path.exists() {
+        let parsed = std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|data| serde_json::from_str::<IdeDefinitionsFile>(&data).map_err(|e| e.to_string()));
+        match parsed {
+            Ok(file) => {
+                let mut skipped = Vec::new();
+                for mut def in file.vscode {
+                    let taken = vscode.iter().any(|d| d.id == def.id) || jetbrains.iter().any(|p| p.id == def.id);
+                    let folders_ok = is_folder_name(&def.appdata_folder) && is_folder_name(&def.home_dot_folder);
+                    if def.id.is_empty() || !folders_ok || taken {
+                        skipped.push(def.id);
+                        continue;
+                    }
+                    def.custom = true;
+                    vscode.push(def);
+                }
+                for mut def in file.jetbrains {
+                    let taken = vscode.iter().any(|d| d.id == def.id) || jetbrains.iter().any(|p| p.id == def.id);
+                    let prefixes_ok = !def.folder_prefixes.is_empty() && def.folder_prefixes.iter().all(|p| is_folder_prefix(p));
+                    if def.id.is_empty() || !prefixes_ok || taken {
+                        skipped.push(def.id);
+                        continue;
+                    }
+                    def.custom = true;
+                    jetbrains.push(def);
+                }
+                if !skipped.is_empty() {
+                    error = Some(format!("Skipped invalid or duplicate definitions: {}", skipped.join(", ")));
+                }
+            }
+            Err(e) => error = Some(format!("Failed to parse {}: {}", path.display(), e)),
+        }
+    }
+
+    IdeDefinitions { path, vscode, jetbrains, error }
+}
+
+// ── Profile override ──
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum ProfileLayout {
+    Windows,
+    MacOs,
+    Linux,
+}
+
+/// The per-user folders detection resolves against. Normally this machine's
+/// own; another user's profile or a mounted disk image for external analysis.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfileDirs {
+    pub home: PathBuf,
+    pub roaming: PathBuf,
+    pub local: PathBuf,
+    pub data_local: PathBuf,
+    pub layout: ProfileLayout,
+}
+
+impl ProfileDirs {
+    /// Work out the layout of a user profile folder from what it contains;
+    /// `None` if it doesn't look like a home folder at all.
+    pub fn from_home(home: &Path) -> Option<ProfileDirs> {
+        let (layout, roaming, local, data_local) = if home.join("AppData").join("Roaming").is_dir() {
+            let appdata = home.join("AppData");
+            (ProfileLayout::Windows, appdata.join("Roaming"), appdata.join("Local"), appdata.join("Local"))
+        } else if home.join("Library").join("Application Support").is_dir() {
+            let library = home.join("Library");
+            let support = library.join("Application Support");
+            (ProfileLayout::MacOs, support.clone(), library.join("Caches"), support)
+        } else if [".config", ".cache", ".local"].iter().any(|d| home.join(d).is_dir()) {
+            (
+                ProfileLayout::Linux,
+                home.join(".config"),
+                home.join(".cache"),
+                home.join(".local").join("share"),
+            )
+        } else {
+            return None;
+        };
+        Some(ProfileDirs { home: home.to_path_buf(), roaming, local, data_local, layout })
+    }
+}
+
+thread_local! {
+    static PROFILE: std::cell::RefCell<Option<ProfileDirs>> = const { std::cell::RefCell::new(None) };
+}
+
+/// Run `f` with detection pointed at `dirs` instead of the current user.
+/// Machine-wide sources (registry, Program Files, environment overrides,
+/// configured portable paths) are skipped for the duration.
+pub fn with_profile<T>(dirs: ProfileDirs, f: impl FnOnce() -> T) -> T {
+    let previous = PROFILE.with(|p| p.replace(Some(dirs)));
+    let result = f();
+    PROFILE.with(|p| *p.borrow_mut() = previous);
+    result
+}
+
+fn profile_dir(pick: impl FnOnce(&ProfileDirs) -> &PathBuf) -> Option<Option<PathBuf>> {
+    PROFILE.with(|p| p.borrow().as_ref().map(|d| Some(pick(d).clone())))
+}
+
+/// Whether detection is running against another user's profile (`with_profile`).
+pub(crate) fn is_external_profile() -> bool {
+    PROFILE.with(|p| p.borrow().is_some())
+}
+
+/// An environment override of a default location; ignored for external profiles.
+fn env_path(var: &str) -> Option<PathBuf> {
+    if is_external_profile() {
+        return None;
+    }
+    std::env::var_os(var).filter(|v| !v.is_empty()).map(PathBuf::from)
+}
+
 fn get_appdata_roaming() -> Option<PathBuf> {
-    dirs::config_dir()
+    profile_dir(|d| &d.roaming).unwrap_or_else(dirs::config_dir)
 }
 
 fn get_appdata_local() -> Option<PathBuf> {
-    dirs::cache_dir()
+    profile_dir(|d| &d.local).unwrap_or_else(dirs::cache_dir)
+}
+
+fn get_data_local() -> Option<PathBuf> {
+    profile_dir(|d| &d.data_local).unwrap_or_else(dirs::data_local_dir)
 }
 
 fn get_home_dir() -> Option<PathBuf> {
-    dirs::home_dir()
+    profile_dir(|d| &d.home).unwrap_or_else(dirs::home_dir)
+}
+
+fn get_temp_dir() -> PathBuf {
+    match profile_dir(|d| &d.local) {
+        Some(Some(local)) => local.join("Temp"),
+        _ => std::env::temp_dir(),
+    }
 }
 
 // ── Detect VSCode-based IDEs ──
 
-fn detect_vscode_ides() -> Vec<IdeInfo> {
+const VSCODE_CACHE_SUBDIRS: &[&str] = &[
+    "Cache", "CachedData", "CachedExtensions", "CachedExtensionVSIXs",
+    "CachedProfilesData", "Code Cache", "GPUCache", "DawnCache",
+    "DawnGraphiteCache", "Service Worker", "blob_storage",
+    "Network", "Session Storage", "Local Storage",
+    "IndexedDB", "WebStorage",
+];
+
+// Crashpad's dump folders; its settings and metadata files are left alone
+const ELECTRON_CRASHPAD_SUBDIRS: &[&str] = &["reports", "completed", "pending", "new", "attachments"];
+
+fn empty_ide_info(name: String, id: String, ide_type: IdeType) -> IdeInfo {
+    IdeInfo {
+        name,
+        id,
+        ide_type,
+        installed: false,
+        config_path: None,
+        cache_paths: Vec::new(),
+        log_paths: Vec::new(),
+        extension_path: None,
+        workspace_storage_path: None,
+        global_storage_path: None,
+        versioned_folders: vec![],
+        portable_root: None,
+        extra_categories: Vec::new(),
+    }
+}
+
+/// Fill config/cache/log/storage paths from a VSCode-style user data dir
+/// (`%APPDATA%\Code` normally, `<install>\data\user-data` in portable mode).
+fn apply_user_data_dir(info: &mut IdeInfo, base: &Path) {
+    info.installed = true;
+    info.config_path = Some(base.to_path_buf());
+
+    for sub in VSCODE_CACHE_SUBDIRS {
+        let p = base.join(sub);
+        if p.exists() { info.cache_paths.push(p); }
+    }
+
+    let logs = base.join("logs");
+    if logs.exists() { info.log_paths.push(logs); }
+
+    let crashpad = base.join("Crashpad");
+    let dumps = existing_paths(ELECTRON_CRASHPAD_SUBDIRS.iter().map(|s| crashpad.join(s)));
+    push_extra(info, "Crash Reports", crate::scanner::CategoryType::CrashReport, dumps);
+
+    let ws = base.join("User").join("workspaceStorage");
+    if ws.exists() { info.workspace_storage_path = Some(ws); }
+
+    let gs = base.join("User").join("globalStorage");
+    if gs.exists() { info.global_storage_path = Some(gs); }
+}
+
+fn detect_vscode_ides(defs: &[VscodeIdeDefinition]) -> Vec<IdeInfo> {
     let appdata_roaming = get_appdata_roaming();
     let appdata_local = get_appdata_local();
     let home = get_home_dir();
 
-    VSCODE_DEFS
+    // SquirrelTemp is shared by every Squirrel-installed app, so only one IDE lists it
+    let mut squirrel_attached = false;
+
+    defs
         .iter()
         .map(|def| {
-            let mut cache_paths = Vec::new();
-            let mut log_paths = Vec::new();
-            let mut config_path: Option<PathBuf> = None;
-            let mut extension_path: Option<PathBuf> = None;
-            let mut workspace_storage_path: Option<PathBuf> = None;
-            let mut global_storage_path: Option<PathBuf> = None;
-            let mut installed = false;
+            let mut info = empty_ide_info(def.name.clone(), def.id.clone(), IdeType::VscodeBased);
 
             if let Some(ref roaming) = appdata_roaming {
-                let base = roaming.join(def.appdata_folder);
+                let base = roaming.join(&def.appdata_folder);
                 if base.exists() {
-                    installed = true;
-                    config_path = Some(base.clone());
-
-                    for sub in &[
-                        "Cache", "CachedData", "CachedExtensions", "CachedExtensionVSIXs",
-                        "CachedProfilesData", "Code Cache", "GPUCache", "DawnCache",
-                        "DawnGraphiteCache", "Service Worker", "blob_storage",
-                        "Network", "Session Storage", "Local Storage",
-                        "IndexedDB", "WebStorage", "Crashpad",
-                    ] {
-                        let p = base.join(sub);
-                        if p.exists() { cache_paths.push(p); }
-                    }
-
-                    let logs = base.join("logs");
-                    if logs.exists() { log_paths.push(logs); }
-
-                    let ws = base.join("User").join("workspaceStorage");
-                    if ws.exists() { workspace_storage_path = Some(ws); }
-
-                    let gs = base.join("User").join("globalStorage");
-                    if gs.exists() { global_storage_path = Some(gs); }
+                    apply_user_data_dir(&mut info, &base);
                 }
             }
 
             if let Some(ref local) = appdata_local {
-                let local_base = local.join(def.appdata_folder);
+                let local_base = local.join(&def.appdata_folder);
                 if local_base.exists() {
-                    installed = true;
+                    info.installed = true;
                     for sub in &["Cache", "Code Cache", "GPUCache"] {
                         let p = local_base.join(sub);
-                        if p.exists() { cache_paths.push(p); }
+                        if p.exists() { info.cache_paths.push(p); }
                     }
                 }
             }
 
             if let Some(ref home_dir) = home {
-                let ext_dir = home_dir.join(def.home_dot_folder).join("extensions");
+                let ext_dir = home_dir.join(&def.home_dot_folder).join("extensions");
                 if ext_dir.exists() {
-                    extension_path = Some(ext_dir);
-                    installed = true;
+                    info.extension_path = Some(ext_dir);
+                    info.installed = true;
                 }
             }
 
-            IdeInfo {
-                name: def.name.into(),
-                id: def.id.into(),
-                ide_type: IdeType::VscodeBased,
-                installed,
-                config_path,
-                cache_paths,
-                log_paths,
-                extension_path,
-                workspace_storage_path,
-                global_storage_path,
-                versioned_folders: vec![],
+            if info.installed {
+                let mut installers = old_installer_paths(&def.appdata_folder, appdata_local.as_deref(), appdata_roaming.as_deref());
+                if !squirrel_attached {
+                    if let Some(squirrel) = appdata_local.as_ref().map(|l| l.join("SquirrelTemp")).filter(|p| p.exists()) {
+                        installers.push(squirrel);
+                        squirrel_attached = true;
+                    }
+                }
+                push_extra(&mut info, "Old installers", crate::scanner::CategoryType::Cache, installers);
             }
+
+            info
         })
         .collect()
 }
 
+/// Downloaded updates that were already applied (or abandoned): electron-updater's
+/// `%LOCALAPPDATA%\<app>-updater\pending` and the `Updates` folder next to the
+/// user data. The app re-downloads whatever it still needs, so these are always safe.
+fn old_installer_paths(appdata_folder: &str, local: Option<&Path>, roaming: Option<&Path>) -> Vec<PathBuf> {
+    let mut candidates = Vec::new();
+    if let Some(local) = local {
+        candidates.push(local.join(format!("{}-updater", appdata_folder.to_lowercase())).join("pending"));
+        candidates.push(local.join(appdata_folder).join("Updates"));
+    }
+    if let Some(roaming) = roaming {
+        candidates.push(roaming.join(appdata_folder).join("Updates"));
+    }
+    existing_paths(candidates)
+}
+
+// ── Detect portable VSCode-based installs (data/ folder next to the exe) ──
+
+/// Resolve a candidate path to the portable install root.
+/// Accepts either the folder containing the exe or its `data` folder itself.
+fn resolve_portable_root(path: &Path) -> Option<PathBuf> {
+    if path.join("data").join("user-data").is_dir() {
+        return Some(path.to_path_buf());
+    }
+    let is_data_dir = path.file_name().map(|n| n.eq_ignore_ascii_case("data")).unwrap_or(false);
+    if is_data_dir && path.join("user-data").is_dir() {
+        return path.parent().map(|p| p.to_path_buf());
+    }
+    None
+}
+
+/// Figure out which product a portable root belongs to: by executable name first,
+/// then by the folder name (e.g. "VSCode-win32-x64", "Cursor-portable").
+fn match_portable_definition<'a>(root: &Path, defs: &'a [VscodeIdeDefinition]) -> Option<&'a VscodeIdeDefinition> {
+    let folder = root.file_name().unwrap_or_default().to_string_lossy().to_lowercase();
+    let by_folder = defs
+        .iter()
+        .filter(|d| folder.contains(&d.appdata_folder.to_lowercase()))
+        .max_by_key(|d| d.appdata_folder.len());
+
+    let by_exe: Vec<&VscodeIdeDefinition> = defs
+        .iter()
+        .filter(|d| {
+            d.process_names.iter().any(|exe| {
+                let stem = exe.trim_end_matches(".exe");
+                root.join(exe).exists() || root.join(stem).exists() || root.join(stem.to_lowercase()).exists()
+            })
+        })
+        .collect();
+
+    match by_exe.len() {
+        0 => by_folder,
+        1 => Some(by_exe[0]),
+        // Several products share an exe name (Trae / Trae CN) — let the folder name decide
+        _ => by_folder.filter(|d| by_exe.iter().any(|e| e.id == d.id)).or(Some(by_exe[0])),
+    }
+}
+
+/// Install locations recorded in the Uninstall registry keys.
+#[cfg(target_os = "windows")]
+fn registry_install_locations() -> Vec<PathBuf> {
+    use winreg::enums::*;
+    use winreg::RegKey;
+
+    let mut locations = Vec::new();
+    let paths = [
+        (HKEY_LOCAL_MACHINE, r"SOFTWARE\Microsoft\Windows\CurrentVersion\Uninstall"),
+        (HKEY_LOCAL_MACHINE, r"SOFTWARE\WOW6432Node\Microsoft\Windows\CurrentVersion\Uninstall"),
+        (HKEY_CURRENT_USER, r"SOFTWARE\Microsoft\Windows\CurrentVersion\Uninstall"),
+    ];
+    for (hive, path) in &paths {
+        if let Ok(uninstall_key) = RegKey::predef(*hive).open_subkey(path) {
+            for name in uninstall_key.enum_keys().filter_map(|k| k.ok()) {
+                if let Ok(subkey) = uninstall_key.open_subkey(&name) {
+                    let loc: String = subkey.get_value("InstallLocation").unwrap_or_default();
+                    let loc = loc.trim().trim_matches('"');
+                    if !loc.is_empty() {
+                        locations.push(PathBuf::from(loc));
+                    }
+                }
+            }
+        }
+    }
+    locations
+}
+
+#[cfg(not(target_os = "windows"))]
+fn registry_install_locations() -> Vec<PathBuf> {
+    Vec::new()
+}
+
+/// Install folders of a VS Code-based IDE: its portable root, Uninstall registry
+/// locations whose executable belongs to it, and the usual macOS/Linux places.
+pub fn vscode_install_dirs(ide: &IdeInfo) -> Vec<PathBuf> {
+    if let Some(root) = &ide.portable_root {
+        return vec![root.clone()];
+    }
+    let defs = load_ide_definitions().vscode;
+    let Some(def) = defs.iter().find(|d| d.id == ide.id) else { return Vec::new() };
+    let mut dirs: Vec<PathBuf> = registry_install_locations()
+        .into_iter()
+        .filter(|loc| match_portable_definition(loc, &defs).is_some_and(|d| d.id == def.id))
+        .collect();
+    if cfg!(target_os = "macos") {
+        dirs.push(PathBuf::from("/Applications").join(format!("{}.app", def.name)));
+    } else if cfg!(target_os = "linux") {
+        dirs.push(PathBuf::from("/usr/share").join(def.appdata_folder.to_lowercase()));
+        dirs.push(PathBuf::from("/opt").join(&def.appdata_folder));
+    }
+    dirs.retain(|d| d.is_dir());
+    dirs.dedup();
+    dirs
+}
+
+fn detect_portable_vscode_ides(defs: &[VscodeIdeDefinition]) -> Vec<IdeInfo> {
+    if is_external_profile() {
+        return Vec::new();
+    }
+    let mut candidates = registry_install_locations();
+    candidates.extend(crate::config::load_config().portable_paths);
+    // Scoop's VS Code family manifests run in portable mode (data/ persisted by scoop)
+    candidates.extend(
+        crate::package_managers::scoop_installs()
+            .into_iter()
+            .filter(|i| i.install_dir.join("data").join("user-data").is_dir())
+            .map(|i| i.install_dir),
+    );
+
+    let mut roots: Vec<PathBuf> = Vec::new();
+    for c in &candidates {
+        if let Some(root) = resolve_portable_root(c) {
+            if !roots.contains(&root) { roots.push(root); }
+        }
+    }
+
+    let mut results: Vec<IdeInfo> = Vec::new();
+    for root in roots {
+        let (name, base_id) = match match_portable_definition(&root, defs) {
+            Some(def) => (format!("{} (Portable)", def.name), format!("{}_portable", def.id)),
+            None => (
+                format!("{} (Portable)", root.file_name().unwrap_or_default().to_string_lossy()),
+                "vscode_portable".to_string(),
+            ),
+        };
+
+        // Several portable copies of the same product get numbered ids
+        let count = results.iter().filter(|i| i.id == base_id || i.id.starts_with(&format!("{}_", base_id))).count();
+        let id = if count == 0 { base_id } else { format!("{}_{}", base_id, count + 1) };
+
+        let data = root.join("data");
+        let mut info = empty_ide_info(name, id, IdeType::VscodeBased);
+        apply_user_data_dir(&mut info, &data.join("user-data"));
+
+        let tmp = data.join("tmp");
+        if tmp.exists() { info.cache_paths.push(tmp); }
+
+        let ext_dir = data.join("extensions");
+        if ext_dir.exists() { info.extension_path = Some(ext_dir); }
+
+        info.portable_root = Some(root);
+        results.push(info);
+    }
+    results
+}
+
 // ── Detect JetBrains IDEs (versioned folder scanning) ──
 
-fn find_jetbrains_versioned_dirs(base: &PathBuf, prefixes: &[&str]) -> Vec<PathBuf> {
+fn find_jetbrains_versioned_dirs(base: &PathBuf, prefixes: &[String]) -> Vec<PathBuf> {
     let mut dirs = Vec::new();
     if !base.exists() { return dirs; }
     if let Ok(entries) = std::fs::read_dir(base) {
@@ -209,25 +632,89 @@ fn dir_size(path: &std::path::Path) -> u64 {
         .sum()
 }
 
+/// System dir subfolders scanned as their own categories instead of "Cache":
+/// indexes rebuild cheaply, compile-server caches are slow to regenerate.
+const JETBRAINS_SYSTEM_CATEGORIES: &[(&str, &str, crate::scanner::CategoryType)] = &[
+    ("index", "Indexes", crate::scanner::CategoryType::Cache),
+    ("caches", "Caches", crate::scanner::CategoryType::Cache),
+    ("compile-server", "Compile Server", crate::scanner::CategoryType::BuildCache),
+    ("LocalHistory", "Local History", crate::scanner::CategoryType::LocalHistory),
+];
+
+/// Files directly in `dir` whose lowercase name passes `matches`.
+fn files_matching(dir: &Path, matches: impl Fn(&str) -> bool) -> Vec<PathBuf> {
+    std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .filter(|e| e.file_type().map(|t| t.is_file()).unwrap_or(false))
+                .filter(|e| matches(&e.file_name().to_string_lossy().to_lowercase()))
+                .map(|e| e.path())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// JVM fatal-error logs and heap dumps: `java_error_in_<launcher>*` in the home
+/// dir (where the bundled vmoptions point -XX:ErrorFile/-XX:HeapDumpPath) and
+/// `hs_err_pid*.log` / `jbr_err_pid*.log` left next to the launcher.
+fn jetbrains_crash_files(product: &JetBrainsIdeDefinition, versioned_folders: &[VersionedFolder]) -> Vec<PathBuf> {
+    let launchers: Vec<String> = product
+        .process_names
+        .iter()
+        .map(|n| n.to_lowercase().trim_end_matches(".exe").trim_end_matches("64").to_string())
+        .collect();
+    let mut files = get_home_dir()
+        .map(|home| {
+            files_matching(&home, |name| {
+                launchers.iter().any(|l| name.starts_with(&format!("java_error_in_{}", l)))
+            })
+        })
+        .unwrap_or_default();
+    for install in versioned_folders.iter().filter_map(|vf| vf.install_path.as_ref()) {
+        files.extend(files_matching(&install.join("bin"), |name| {
+            (name.starts_with("hs_err_pid") || name.starts_with("jbr_err_pid")) && name.ends_with(".log")
+        }));
+    }
+    existing_paths(files)
+}
+
+/// Contents of a JetBrains system dir (%LOCALAPPDATA%\JetBrains\<product><version>)
+/// minus logs and the subfolders in JETBRAINS_SYSTEM_CATEGORIES.
+fn jetbrains_system_children(system_dir: &Path) -> Vec<PathBuf> {
+    std::fs::read_dir(system_dir)
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .filter(|e| {
+                    let name = e.file_name().to_string_lossy().to_string();
+                    name != "log" && !JETBRAINS_SYSTEM_CATEGORIES.iter().any(|(sub, _, _)| *sub == name)
+                })
+                .map(|e| e.path())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 /// Scan for JetBrains IDE installation directories.
 /// Returns (product_id, version_string, install_path) tuples.
-fn find_jetbrains_installs() -> Vec<(String, String, PathBuf)> {
+fn find_jetbrains_installs(products: &[JetBrainsIdeDefinition]) -> Vec<(String, String, PathBuf)> {
     let mut results: Vec<(String, String, PathBuf)> = Vec::new();
     let mut search_dirs: Vec<PathBuf> = Vec::new();
 
     // Standard Program Files locations
-    if let Ok(pf) = std::env::var("ProgramFiles") {
-        let jb = PathBuf::from(&pf).join("JetBrains");
+    if let Some(pf) = env_path("ProgramFiles") {
+        let jb = pf.join("JetBrains");
         if jb.exists() { search_dirs.push(jb); }
     }
-    if let Ok(pf86) = std::env::var("ProgramFiles(x86)") {
-        let jb = PathBuf::from(&pf86).join("JetBrains");
+    if let Some(pf86) = env_path("ProgramFiles(x86)") {
+        let jb = pf86.join("JetBrains");
         if jb.exists() && !search_dirs.contains(&jb) { search_dirs.push(jb); }
     }
 
     // Scan all drive letters for Program Files\JetBrains
     #[cfg(target_os = "windows")]
-    for letter in b'A'..=b'Z' {
+    for letter in (b'A'..=b'Z').filter(|_| !is_external_profile()) {
         let drive = format!("{}:\\Program Files\\JetBrains", letter as char);
         let p = PathBuf::from(&drive);
         if p.exists() && !search_dirs.contains(&p) {
@@ -247,12 +734,12 @@ fn find_jetbrains_installs() -> Vec<(String, String, PathBuf)> {
                 if !entry.file_type().map(|t| t.is_dir()).unwrap_or(false) { continue; }
                 let name = entry.file_name().to_string_lossy().to_string();
 
-                for product in JETBRAINS_PRODUCTS {
+                for product in products {
                     // Standalone format: "ProductName Version" e.g. "CLion 2022.3.3"
                     // Also matches "ProductName" without version (e.g. "IntelliJ IDEA")
-                    if name.starts_with(product.name) {
+                    if name.starts_with(product.name.as_str()) {
                         let version = name[product.name.len()..].trim().to_string();
-                        results.push((product.id.to_string(), version, entry.path()));
+                        results.push((product.id.clone(), version, entry.path()));
                         break;
                     }
                 }
@@ -263,12 +750,70 @@ fn find_jetbrains_installs() -> Vec<(String, String, PathBuf)> {
     results
 }
 
-fn detect_jetbrains_ides() -> Vec<IdeInfo> {
+// ── Android Studio: SDK / AVD / Gradle categories ──
+
+/// Android SDK root: $ANDROID_HOME, $ANDROID_SDK_ROOT, then the default install locations.
+pub fn android_sdk_dir() -> Option<PathBuf> {
+    let from_env = ["ANDROID_HOME", "ANDROID_SDK_ROOT"].iter().filter_map(|v| env_path(v));
+    let defaults = [
+        get_data_local().map(|l| l.join("Android").join("Sdk")),
+        get_home_dir().map(|h| h.join("Library").join("Android").join("sdk")),
+        get_home_dir().map(|h| h.join("Android").join("Sdk")),
+    ];
+    from_env.chain(defaults.into_iter().flatten()).find(|p| p.is_dir())
+}
+
+/// $GRADLE_USER_HOME or ~/.gradle.
+pub fn gradle_user_home() -> Option<PathBuf> {
+    env_path("GRADLE_USER_HOME")
+        .or_else(|| get_home_dir().map(|h| h.join(".gradle")))
+        .filter(|p| p.is_dir())
+}
+
+/// Emulator snapshots, rebuildable Gradle caches and SDK system images.
+/// Only snapshots and build caches are auto-selectable; system images are
+/// SDK binaries and use the Installer type so no clean mode picks them.
+fn android_extra_categories(info: &mut IdeInfo) {
+    use crate::scanner::CategoryType;
+
+    let avd_home = env_path("ANDROID_AVD_HOME")
+        .or_else(|| get_home_dir().map(|h| h.join(".android").join("avd")));
+    let snapshots: Vec<PathBuf> = avd_home
+        .and_then(|d| std::fs::read_dir(d).ok())
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .map(|e| e.path().join("snapshots"))
+                .filter(|p| p.is_dir())
+                .collect()
+        })
+        .unwrap_or_default();
+    push_extra(info, "AVD Snapshots", CategoryType::Cache, snapshots);
+
+    if let Some(gradle) = gradle_user_home() {
+        let caches = gradle.join("caches");
+        let mut paths = existing_paths([caches.join("build-cache-1"), gradle.join("daemon")]);
+        if let Ok(entries) = std::fs::read_dir(&caches) {
+            for entry in entries.filter_map(|e| e.ok()) {
+                if entry.file_name().to_string_lossy().starts_with("transforms-") {
+                    paths.push(entry.path());
+                }
+            }
+        }
+        push_extra(info, "Gradle Build Caches", CategoryType::Cache, paths);
+    }
+
+    if let Some(sdk) = android_sdk_dir() {
+        push_extra(info, "SDK System Images", CategoryType::Installer, existing_paths([sdk.join("system-images")]));
+    }
+}
+
+fn detect_jetbrains_ides(products: &[JetBrainsIdeDefinition]) -> Vec<IdeInfo> {
     let appdata_roaming = get_appdata_roaming();
     let appdata_local = get_appdata_local();
-    let all_installs = find_jetbrains_installs();
+    let all_installs = find_jetbrains_installs(products);
 
-    JETBRAINS_PRODUCTS
+    products
         .iter()
         .filter_map(|product| {
             let mut cache_paths = Vec::new();
@@ -276,27 +821,28 @@ fn detect_jetbrains_ides() -> Vec<IdeInfo> {
             let mut config_path: Option<PathBuf> = None;
             let mut extension_path: Option<PathBuf> = None;
             let mut versioned_folders: Vec<VersionedFolder> = Vec::new();
+            let mut system_categories: Vec<Vec<PathBuf>> = vec![Vec::new(); JETBRAINS_SYSTEM_CATEGORIES.len()];
 
             let config_dirs = appdata_roaming.as_ref().map(|r| {
-                find_jetbrains_versioned_dirs(&r.join("JetBrains"), product.folder_prefixes)
+                find_jetbrains_versioned_dirs(&r.join("JetBrains"), &product.folder_prefixes)
             }).unwrap_or_default();
 
             let cache_dirs = appdata_local.as_ref().map(|l| {
-                find_jetbrains_versioned_dirs(&l.join("JetBrains"), product.folder_prefixes)
+                find_jetbrains_versioned_dirs(&l.join("JetBrains"), &product.folder_prefixes)
             }).unwrap_or_default();
 
             // Collect install dirs for this product
             let product_installs: Vec<&(String, String, PathBuf)> = all_installs
                 .iter()
-                .filter(|(pid, _, _)| pid == product.id)
+                .filter(|(pid, _, _)| pid == &product.id)
                 .collect();
 
             // Build unified version set from config, cache, AND install dirs
             let mut version_set = std::collections::BTreeSet::new();
             for d in config_dirs.iter().chain(cache_dirs.iter()) {
                 let name = d.file_name().unwrap_or_default().to_string_lossy().to_string();
-                for prefix in product.folder_prefixes {
-                    if name.starts_with(prefix) {
+                for prefix in &product.folder_prefixes {
+                    if name.starts_with(prefix.as_str()) {
                         version_set.insert(name[prefix.len()..].to_string());
                     }
                 }
@@ -344,7 +890,11 @@ fn detect_jetbrains_ides() -> Vec<IdeInfo> {
                     let name = cd.file_name().unwrap_or_default().to_string_lossy();
                     if name.ends_with(ver.as_str()) {
                         vf.cache_path = Some(cd.clone());
-                        cache_paths.push(cd.clone());
+                        cache_paths.extend(jetbrains_system_children(cd));
+                        for (i, (sub, _, _)) in JETBRAINS_SYSTEM_CATEGORIES.iter().enumerate() {
+                            let p = cd.join(sub);
+                            if p.exists() { system_categories[i].push(p); }
+                        }
                         let log_dir = cd.join("log");
                         if log_dir.exists() {
                             vf.log_path = Some(log_dir.clone());
@@ -388,12 +938,14 @@ fn detect_jetbrains_ides() -> Vec<IdeInfo> {
             }
 
             let has_install = versioned_folders.iter().any(|vf| vf.install_path.is_some());
-            let installed = config_path.is_some() || !cache_paths.is_empty() || has_install;
-            if !installed { return None; }
+            let has_cache = versioned_folders.iter().any(|vf| vf.cache_path.is_some());
+            let installed = config_path.is_some() || has_cache || has_install;
+            // Fleet's own folders are checked below
+            if !installed && product.id != "fleet" { return None; }
 
-            Some(IdeInfo {
-                name: product.name.into(),
-                id: product.id.into(),
+            let mut info = IdeInfo {
+                name: product.name.clone(),
+                id: product.id.clone(),
                 ide_type: IdeType::JetBrains,
                 installed,
                 config_path,
@@ -403,41 +955,664 @@ fn detect_jetbrains_ides() -> Vec<IdeInfo> {
                 workspace_storage_path: None,
                 global_storage_path: None,
                 versioned_folders,
-            })
+                portable_root: None,
+                extra_categories: Vec::new(),
+            };
+            for ((_, name, category_type), paths) in JETBRAINS_SYSTEM_CATEGORIES.iter().zip(system_categories) {
+                push_extra(&mut info, name, category_type.clone(), paths);
+            }
+            let crash_files = jetbrains_crash_files(product, &info.versioned_folders);
+            push_extra(&mut info, "Crash Reports", crate::scanner::CategoryType::CrashReport, crash_files);
+            if product.id == "android_studio" {
+                android_extra_categories(&mut info);
+            }
+            if product.id == "fleet" {
+                info.installed |= fleet_layout(&mut info);
+            }
+            if !info.installed { return None; }
+            Some(info)
         })
         .collect()
 }
 
-// ── Public API ──
+// ── Fleet: unversioned ~/.fleet layout ──
 
-pub fn detect_installed_ides() -> Vec<IdeInfo> {
-    let mut all = detect_vscode_ides();
-    all.extend(detect_jetbrains_ides());
-    all
+// Rebuildable subdirs of ~/.fleet and of the system dir
+const FLEET_CACHE_SUBDIRS: &[&str] = &["caches", "cache", "system", "frontend-cache"];
+const FLEET_LOG_SUBDIRS: &[&str] = &["log", "logs"];
+// Per-workspace state (open files, layout, index snapshots)
+const FLEET_WORKSPACE_SUBDIRS: &[&str] = &["workspaces", "workspace"];
+
+/// `~/.fleet`: settings, plugins, logs and per-workspace state.
+fn fleet_home_dir() -> Option<PathBuf> {
+    get_home_dir().map(|h| h.join(".fleet")).filter(|p| p.is_dir())
+}
+
+/// Fleet's system dir has no version suffix, so the versioned-folder lookup
+/// never finds it: %LOCALAPPDATA%\JetBrains\Fleet, ~/.cache/JetBrains/Fleet,
+/// ~/Library/Caches/JetBrains/Fleet.
+fn fleet_system_dirs() -> Vec<PathBuf> {
+    existing_paths(
+        get_appdata_local()
+            .into_iter()
+            .map(|d| d.join("JetBrains").join("Fleet")),
+    )
+}
+
+/// Fill in Fleet's caches, logs, plugins and workspace state. Returns false
+/// when none of its folders exist.
+fn fleet_layout(info: &mut IdeInfo) -> bool {
+    let home = fleet_home_dir();
+    let system = fleet_system_dirs();
+    if home.is_none() && system.is_empty() {
+        return false;
+    }
+
+    let roots: Vec<&PathBuf> = home.iter().chain(system.iter()).collect();
+    for root in &roots {
+        info.cache_paths.extend(existing_paths(FLEET_CACHE_SUBDIRS.iter().map(|s| root.join(s))));
+        info.log_paths.extend(existing_paths(FLEET_LOG_SUBDIRS.iter().map(|s| root.join(s))));
+    }
+    if let Some(home) = home {
+        let plugins = home.join("plugins");
+        if plugins.exists() { info.extension_path = Some(plugins); }
+        info.workspace_storage_path = FLEET_WORKSPACE_SUBDIRS.iter().map(|s| home.join(s)).find(|p| p.is_dir());
+        if info.config_path.is_none() { info.config_path = Some(home); }
+    }
+
+    // macOS keeps logs under ~/Library/Logs
+    if let Some(home) = get_home_dir() {
+        let logs = home.join("Library").join("Logs").join("JetBrains").join("Fleet");
+        if logs.exists() { info.log_paths.push(logs); }
+    }
+    true
+}
+
+// ── Detect Zed (native editor, own config/data layout) ──
+
+const ZED_PROCESS_NAMES: &[&str] = &["Zed.exe", "zed-editor"];
+
+// Runtime downloads in the data dir that Zed re-fetches on demand
+const ZED_CACHE_SUBDIRS: &[&str] = &["languages", "node", "prettier", "copilot"];
+
+/// Zed's data dir: %LOCALAPPDATA%\Zed, ~/.local/share/zed, ~/Library/Application Support/Zed.
+/// Holds db/, extensions/, logs/, threads/ and conversations/.
+pub fn zed_data_dir() -> Option<PathBuf> {
+    let local = get_data_local()?;
+    ["Zed", "zed"].iter().map(|n| local.join(n)).find(|p| p.is_dir())
+}
+
+/// Zed's AI history: agent threads (threads/threads.db), text threads
+/// (conversations/*.zed.json) and the prompt library (prompts/).
+pub fn zed_conversation_dirs() -> Vec<PathBuf> {
+    let data = match zed_data_dir() {
+        Some(d) => d,
+        None => return Vec::new(),
+    };
+    ["threads", "conversations", "prompts"]
+        .iter()
+        .map(|sub| data.join(sub))
+        .filter(|p| p.is_dir())
+        .collect()
+}
+
+/// Zed's settings dir: %APPDATA%\Zed on Windows, ~/.config/zed elsewhere.
+fn zed_config_dir() -> Option<PathBuf> {
+    let mut candidates = Vec::new();
+    if let Some(roaming) = get_appdata_roaming() {
+        candidates.push(roaming.join("Zed"));
+        candidates.push(roaming.join("zed"));
+    }
+    if let Some(home) = get_home_dir() {
+        candidates.push(home.join(".config").join("zed"));
+    }
+    candidates.into_iter().find(|p| p.join("settings.json").exists() || p.join("keymap.json").exists())
+}
+
+fn detect_zed_ide() -> IdeInfo {
+    let data = zed_data_dir();
+    let config = zed_config_dir();
+    let mut info = empty_ide_info("Zed".into(), "zed".into(), IdeType::Zed);
+    info.installed = data.is_some() || config.is_some();
+    info.config_path = config;
+
+    if let Some(ref data) = data {
+        for sub in ZED_CACHE_SUBDIRS {
+            let p = data.join(sub);
+            if p.exists() { info.cache_paths.push(p); }
+        }
+
+        let logs = data.join("logs");
+        if logs.exists() { info.log_paths.push(logs); }
+
+        let ext_dir = data.join("extensions");
+        if ext_dir.exists() { info.extension_path = Some(ext_dir); }
+
+        // db/ holds per-workspace state (open panes, recent projects)
+        let db = data.join("db");
+        if db.exists() { info.workspace_storage_path = Some(db); }
+    }
+
+    // Separate cache dir on Linux/macOS (on Windows cache_dir is the data dir's parent)
+    if let Some(cache) = get_appdata_local() {
+        if let Some(p) = ["Zed", "zed"].iter().map(|n| cache.join(n)).find(|p| p.is_dir()) {
+            if Some(&p) != data.as_ref() { info.cache_paths.push(p); }
+        }
+    }
+
+    // macOS keeps logs under ~/Library/Logs
+    if let Some(home) = get_home_dir() {
+        let logs = home.join("Library").join("Logs").join("Zed");
+        if logs.exists() { info.log_paths.push(logs); }
+    }
+
+    info
+}
+
+// ── Detect Visual Studio 2019/2022 (per-instance folders) ──
+
+struct VisualStudioRelease {
+    name: &'static str,
+    id: &'static str,
+    /// Prefix of the per-instance folder names, e.g. "17.0_" in "17.0_1a2b3c4d".
+    instance_prefix: &'static str,
+}
+
+const VISUAL_STUDIO_RELEASES: &[VisualStudioRelease] = &[
+    VisualStudioRelease { name: "Visual Studio 2022", id: "visualstudio_2022", instance_prefix: "17.0_" },
+    VisualStudioRelease { name: "Visual Studio 2019", id: "visualstudio_2019", instance_prefix: "16.0_" },
+];
+
+const VISUAL_STUDIO_PROCESS_NAMES: &[&str] = &["devenv.exe"];
+
+// Rebuildable caches inside %LOCALAPPDATA%\Microsoft\VisualStudio\<instance>
+const VS_INSTANCE_CACHE_SUBDIRS: &[&[&str]] = &[&["ImageLibrary"], &["Designer", "ShadowCache"]];
+
+/// VS Installer's package layout root (%ProgramData%\Microsoft\VisualStudio\Packages).
+/// Only read for instance registrations: the cached packages are what Repair and
+/// Modify install from, so they are never offered for cleaning.
+fn vs_packages_dir() -> Option<PathBuf> {
+    let program_data = env_path("ProgramData")?;
+    Some(program_data.join("Microsoft").join("VisualStudio").join("Packages"))
+}
+
+/// Installed instances registered with VS Installer: instance id → install path.
+fn vs_registered_instances() -> Option<Vec<(String, PathBuf)>> {
+    let instances_dir = vs_packages_dir()?.join("_Instances");
+    let entries = std::fs::read_dir(&instances_dir).ok()?;
+    let mut instances = Vec::new();
+    for entry in entries.filter_map(|e| e.ok()) {
+        let id = entry.file_name().to_string_lossy().to_string();
+        let install_path = std::fs::read_to_string(entry.path().join("state.json"))
+            .ok()
+            .and_then(|data| serde_json::from_str::<serde_json::Value>(&data).ok())
+            .and_then(|v| v.get("installationPath").and_then(|p| p.as_str()).map(PathBuf::from))
+            .unwrap_or_default();
+        instances.push((id, install_path));
+    }
+    Some(instances)
 }
 
-pub fn get_process_names(ide_id: &str) -> Vec<&'static str> {
-    match ide_id {
-        "vscode" => vec!["Code.exe"],
-        "cursor" => vec!["Cursor.exe"],
-        "windsurf" => vec!["Windsurf.exe"],
-        "kiro" => vec!["Kiro.exe"],
-        "trae" => vec!["Trae.exe"],
-        "trae_cn" => vec!["Trae.exe"],
-        "qoder" => vec!["Qoder.exe"],
-        "antigravity" => vec!["Antigravity.exe"],
-        "pearai" => vec!["PearAI.exe"],
-        "aide" => vec!["Aide.exe"],
-        "positron" => vec!["Positron.exe"],
-        "vscodium" => vec!["codium.exe"],
-        "void" => vec!["Void.exe"],
-        _ => {
-            for p in JETBRAINS_PRODUCTS {
-                if p.id == ide_id {
-                    return vec![p.process_name];
+fn find_vs_instance_dirs(base: &Path, prefix: &str) -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = std::fs::read_dir(base)
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .filter(|e| e.file_type().map(|t| t.is_dir()).unwrap_or(false))
+                .filter(|e| e.file_name().to_string_lossy().starts_with(prefix))
+                .map(|e| e.path())
+                .collect()
+        })
+        .unwrap_or_default();
+    dirs.sort();
+    dirs
+}
+
+fn push_extra(info: &mut IdeInfo, name: &str, category_type: crate::scanner::CategoryType, paths: Vec<PathBuf>) {
+    if !paths.is_empty() {
+        info.extra_categories.push(ExtraCategory { name: name.into(), category_type, paths });
+    }
+}
+
+fn detect_visual_studio_ides() -> Vec<IdeInfo> {
+    use crate::scanner::CategoryType;
+
+    let local_base = get_appdata_local().map(|l| l.join("Microsoft").join("VisualStudio"));
+    let roaming_base = get_appdata_roaming().map(|r| r.join("Microsoft").join("VisualStudio"));
+    let registered = vs_registered_instances();
+
+    VISUAL_STUDIO_RELEASES
+        .iter()
+        .filter_map(|release| {
+            let local_dirs = local_base.as_ref().map(|b| find_vs_instance_dirs(b, release.instance_prefix)).unwrap_or_default();
+            let roaming_dirs = roaming_base.as_ref().map(|b| find_vs_instance_dirs(b, release.instance_prefix)).unwrap_or_default();
+            if local_dirs.is_empty() && roaming_dirs.is_empty() { return None; }
+
+            let mut info = empty_ide_info(release.name.into(), release.id.into(), IdeType::VisualStudio);
+            info.installed = true;
+
+            let mut component_caches = Vec::new();
+            let mut orphaned = Vec::new();
+            let mut versions: Vec<String> = local_dirs
+                .iter()
+                .chain(roaming_dirs.iter())
+                .map(|d| d.file_name().unwrap_or_default().to_string_lossy().to_string())
+                .collect();
+            versions.sort();
+            versions.dedup();
+
+            for version in &versions {
+                // Folder names are "<major>.0_<instanceId>", with an "Exp" suffix for the experimental hive
+                let instance_id = version[release.instance_prefix.len()..].trim_end_matches("Exp");
+                let install_path = registered
+                    .as_ref()
+                    .and_then(|r| r.iter().find(|(id, _)| id == instance_id))
+                    .map(|(_, p)| p.clone());
+                let local = local_base.as_ref().map(|b| b.join(version)).filter(|p| p.is_dir());
+                let roaming = roaming_base.as_ref().map(|b| b.join(version)).filter(|p| p.is_dir());
+
+                let mut vf = VersionedFolder {
+                    version: version.clone(),
+                    config_path: roaming.clone(),
+                    cache_path: None,
+                    log_path: None,
+                    plugins_path: None,
+                    install_path: install_path.clone().filter(|p| p.exists()),
+                    is_latest: false,
+                    total_size: 0,
+                };
+
+                // Only call an instance orphaned when VS Installer's registry could be read
+                if registered.is_some() && install_path.is_none() {
+                    orphaned.extend(local.iter().cloned());
+                    orphaned.extend(roaming.iter().cloned());
+                } else if let Some(ref local) = local {
+                    let cmc = local.join("ComponentModelCache");
+                    if cmc.exists() {
+                        vf.cache_path = Some(cmc.clone());
+                        component_caches.push(cmc);
+                    }
+                    for sub in VS_INSTANCE_CACHE_SUBDIRS {
+                        let p = sub.iter().fold(local.clone(), |p, part| p.join(part));
+                        if p.exists() { info.cache_paths.push(p); }
+                    }
+                    let ext = local.join("Extensions");
+                    if ext.exists() {
+                        vf.plugins_path = Some(ext.clone());
+                        if info.extension_path.is_none() { info.extension_path = Some(ext); }
+                    }
+                    if info.config_path.is_none() { info.config_path = roaming.clone(); }
                 }
+
+                vf.total_size = local.as_ref().map(|p| dir_size(p)).unwrap_or(0)
+                    + roaming.as_ref().map(|p| dir_size(p)).unwrap_or(0);
+                info.versioned_folders.push(vf);
+            }
+            if let Some(vf) = info.versioned_folders.iter_mut().rev().find(|vf| vf.install_path.is_some()) {
+                vf.is_latest = true;
+            }
+
+            push_extra(&mut info, "Component Model Cache (MEF)", CategoryType::Cache, component_caches);
+            push_extra(&mut info, "Orphaned Instance Data", CategoryType::WorkspaceStorage, orphaned);
+
+            Some(info)
+        })
+        .collect()
+}
+
+// ── Detect Eclipse (workspaces, ~/.eclipse, ~/.p2 bundle pool) ──
+
+const ECLIPSE_PROCESS_NAMES: &[&str] = &["eclipse.exe"];
+
+// Default workspace locations, relative to the home dir
+const ECLIPSE_DEFAULT_WORKSPACES: &[&str] = &["eclipse-workspace", "workspace", "Documents/workspace"];
+
+// Rebuildable index folders under <workspace>/.metadata/.plugins
+const ECLIPSE_INDEX_PLUGINS: &[&str] = &[
+    "org.eclipse.jdt.core",
+    "org.eclipse.cdt.core",
+    "org.eclipse.m2e.core",
+    "org.eclipse.wst.jsdt.core",
+    "org.eclipse.jdt.ui",
+];
+
+/// Unescape a Java .properties value ("C\:\\ws\nD\:\\ws2" → two lines).
+fn unescape_properties_value(value: &str) -> String {
+    let mut out = String::new();
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            Some(other) => out.push(other),
+            None => {}
+        }
+    }
+    out
+}
+
+/// Workspaces listed in RECENT_WORKSPACES of an install's org.eclipse.ui.ide.prefs.
+fn eclipse_recent_workspaces(configuration_dir: &Path) -> Vec<PathBuf> {
+    let prefs = configuration_dir.join(".settings").join("org.eclipse.ui.ide.prefs");
+    let data = std::fs::read_to_string(prefs).unwrap_or_default();
+    data.lines()
+        .filter_map(|line| line.strip_prefix("RECENT_WORKSPACES="))
+        .flat_map(|value| {
+            unescape_properties_value(value)
+                .lines()
+                .map(|l| PathBuf::from(l.trim()))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// `configuration` folders of Eclipse installs: per-user ones under ~/.eclipse
+/// and Oomph installer products under ~/eclipse/<package>/eclipse.
+fn eclipse_configuration_dirs(home: &Path) -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    for (base, nested) in [(home.join(".eclipse"), ""), (home.join("eclipse"), "eclipse")] {
+        if let Ok(entries) = std::fs::read_dir(&base) {
+            for entry in entries.filter_map(|e| e.ok()) {
+                let config = entry.path().join(nested).join("configuration");
+                if config.is_dir() { dirs.push(config); }
             }
-            vec![]
         }
     }
+    dirs
+}
+
+fn detect_eclipse_ide() -> Option<IdeInfo> {
+    use crate::scanner::CategoryType;
+
+    let home = get_home_dir()?;
+    let dot_eclipse = home.join(".eclipse");
+    let p2_pool = home.join(".p2");
+    let config_dirs = eclipse_configuration_dirs(&home);
+
+    let mut workspaces: Vec<PathBuf> = Vec::new();
+    let candidates = ECLIPSE_DEFAULT_WORKSPACES
+        .iter()
+        .map(|w| home.join(w))
+        .chain(config_dirs.iter().flat_map(|c| eclipse_recent_workspaces(c)));
+    for ws in candidates {
+        if ws.join(".metadata").is_dir() && !workspaces.contains(&ws) {
+            workspaces.push(ws);
+        }
+    }
+
+    if !dot_eclipse.is_dir() && !p2_pool.is_dir() && workspaces.is_empty() {
+        return None;
+    }
+
+    let mut info = empty_ide_info("Eclipse".into(), "eclipse".into(), IdeType::Eclipse);
+    info.installed = true;
+    if dot_eclipse.is_dir() { info.config_path = Some(dot_eclipse); }
+
+    // OSGi bundle cache, rebuilt on the next start
+    for config in &config_dirs {
+        let osgi = config.join("org.eclipse.osgi");
+        if osgi.exists() { info.cache_paths.push(osgi); }
+    }
+
+    let mut indexes = Vec::new();
+    let mut history = Vec::new();
+    for ws in &workspaces {
+        let metadata = ws.join(".metadata");
+        let plugins = metadata.join(".plugins");
+        for plugin in ECLIPSE_INDEX_PLUGINS {
+            let p = plugins.join(plugin);
+            if p.exists() { indexes.push(p); }
+        }
+        let hist = plugins.join("org.eclipse.core.resources").join(".history");
+        if hist.exists() { history.push(hist); }
+        let log = metadata.join(".log");
+        if log.exists() { info.log_paths.push(log); }
+    }
+
+    push_extra(&mut info, "Workspace Indexes", CategoryType::Cache, indexes);
+    push_extra(&mut info, "Local History", CategoryType::LocalHistory, history);
+    // Shared by every install provisioned from it (Oomph), so never auto-selected
+    if p2_pool.is_dir() {
+        info.extension_path = Some(p2_pool.clone());
+        push_extra(&mut info, "p2 Bundle Pool", CategoryType::Installer, vec![p2_pool]);
+    }
+
+    Some(info)
+}
+
+// ── Detect Neovim / Vim (terminal editors) ──
+
+const NEOVIM_PROCESS_NAMES: &[&str] = &["nvim.exe", "nvim-qt.exe"];
+const VIM_PROCESS_NAMES: &[&str] = &["vim.exe", "gvim.exe"];
+
+fn existing_paths(paths: impl IntoIterator<Item = PathBuf>) -> Vec<PathBuf> {
+    let mut out: Vec<PathBuf> = Vec::new();
+    for p in paths {
+        if p.exists() && !out.contains(&p) { out.push(p); }
+    }
+    out
+}
+
+/// `$XDG_<kind>_HOME` or its default under the home dir.
+fn xdg_dir(var: &str, default: &[&str]) -> Option<PathBuf> {
+    env_path(var).or_else(|| get_home_dir().map(|h| default.iter().fold(h, |p, part| p.join(part))))
+}
+
+/// Neovim keeps data+state in %LOCALAPPDATA%\nvim-data on Windows and splits
+/// them into ~/.local/share/nvim and ~/.local/state/nvim elsewhere.
+fn detect_neovim() -> Option<IdeInfo> {
+    use crate::scanner::CategoryType;
+
+    let local = get_data_local();
+    let windows_data = local.as_ref().map(|l| l.join("nvim-data")).filter(|p| p.is_dir());
+    let (data, state, cache, config) = match windows_data {
+        Some(data) => (
+            data.clone(),
+            data,
+            get_temp_dir().join("nvim"),
+            local.as_ref().map(|l| l.join("nvim")),
+        ),
+        None => (
+            xdg_dir("XDG_DATA_HOME", &[".local", "share"])?.join("nvim"),
+            xdg_dir("XDG_STATE_HOME", &[".local", "state"])?.join("nvim"),
+            xdg_dir("XDG_CACHE_HOME", &[".cache"])?.join("nvim"),
+            xdg_dir("XDG_CONFIG_HOME", &[".config"]).map(|c| c.join("nvim")),
+        ),
+    };
+    if !data.is_dir() && !state.is_dir() {
+        return None;
+    }
+
+    let mut info = empty_ide_info("Neovim".into(), "neovim".into(), IdeType::TerminalEditor);
+    info.installed = true;
+    info.config_path = config.filter(|c| c.is_dir());
+    info.cache_paths = existing_paths([cache]);
+    info.log_paths = existing_paths([state.join("log"), state.join("lsp.log"), data.join("log")]);
+    // Plugin manager checkouts (lazy.nvim, packer/native packages)
+    info.extension_path = [data.join("lazy"), data.join("site").join("pack")].into_iter().find(|p| p.is_dir());
+
+    push_extra(&mut info, "Swap Files", CategoryType::WorkspaceStorage, existing_paths([state.join("swap"), data.join("swap")]));
+    push_extra(&mut info, "Undo History", CategoryType::GlobalStorage, existing_paths([state.join("undo"), data.join("undo")]));
+    push_extra(&mut info, "ShaDa", CategoryType::GlobalStorage, existing_paths([state.join("shada"), data.join("shada")]));
+    // Re-installable on demand: mason.nvim LSP servers, nvim-treesitter parsers
+    push_extra(
+        &mut info,
+        "Plugin Caches (mason, treesitter)",
+        CategoryType::Cache,
+        existing_paths([data.join("mason"), data.join("site").join("parser"), data.join("lazy").join("nvim-treesitter").join("parser")]),
+    );
+
+    Some(info)
+}
+
+/// Vim: ~/.vim (Unix) or ~/vimfiles (Windows). Swap/undo dirs only exist when
+/// configured there, which is the usual setup.
+fn detect_vim() -> Option<IdeInfo> {
+    use crate::scanner::CategoryType;
+
+    let home = get_home_dir()?;
+    let base = [home.join(".vim"), home.join("vimfiles")].into_iter().find(|p| p.is_dir())?;
+
+    let mut info = empty_ide_info("Vim".into(), "vim".into(), IdeType::TerminalEditor);
+    info.installed = true;
+    info.config_path = Some(base.clone());
+    info.extension_path = [base.join("pack"), base.join("plugged"), base.join("bundle")].into_iter().find(|p| p.is_dir());
+
+    push_extra(&mut info, "Swap Files", CategoryType::WorkspaceStorage, existing_paths([base.join("swap"), base.join("swp")]));
+    push_extra(&mut info, "Undo History", CategoryType::GlobalStorage, existing_paths([base.join("undo"), base.join("undodir")]));
+    push_extra(&mut info, "viminfo", CategoryType::GlobalStorage, existing_paths([home.join(".viminfo"), home.join("_viminfo")]));
+
+    Some(info)
+}
+
+// ── Detect Xcode (macOS) ──
+
+const XCODE_PROCESS_NAMES: &[&str] = &["Xcode", "Simulator"];
+const XCODE_DEVICE_SUPPORT_DIRS: &[&str] = &[
+    "iOS DeviceSupport", "watchOS DeviceSupport", "tvOS DeviceSupport", "visionOS DeviceSupport",
+];
+
+/// Simulator runtimes older than the newest one of the same platform
+/// ("iOS 16.4.simruntime" when "iOS 17.2.simruntime" is installed).
+fn old_simulator_runtimes(runtimes_dir: &Path) -> Vec<PathBuf> {
+    let mut runtimes: Vec<(String, Vec<u32>, PathBuf)> = std::fs::read_dir(runtimes_dir)
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .filter_map(|e| {
+                    let name = e.file_name().to_string_lossy().to_string();
+                    let stem = name.strip_suffix(".simruntime")?;
+                    let (platform, version) = stem.rsplit_once(' ')?;
+                    let version: Vec<u32> = version.split('.').filter_map(|v| v.parse().ok()).collect();
+                    Some((platform.to_string(), version, e.path()))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    runtimes.sort_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)));
+
+    let mut seen: Vec<String> = Vec::new();
+    runtimes
+        .into_iter()
+        .filter_map(|(platform, _, path)| {
+            if seen.contains(&platform) {
+                Some(path)
+            } else {
+                seen.push(platform);
+                None
+            }
+        })
+        .collect()
+}
+
+/// Xcode keeps everything under ~/Library/Developer; DerivedData, device
+/// support symbols and simulator runtimes are the usual multi-GB offenders.
+fn detect_xcode() -> Option<IdeInfo> {
+    use crate::scanner::CategoryType;
+
+    let home = get_home_dir()?;
+    let developer = home.join("Library").join("Developer");
+    let xcode = developer.join("Xcode");
+    if !xcode.is_dir() {
+        return None;
+    }
+
+    let mut info = empty_ide_info("Xcode".into(), "xcode".into(), IdeType::Xcode);
+    info.installed = true;
+    info.config_path = Some(xcode.join("UserData")).filter(|p| p.is_dir());
+    info.cache_paths = existing_paths([home.join("Library").join("Caches").join("com.apple.dt.Xcode")]);
+    info.log_paths = existing_paths([home.join("Library").join("Logs").join("CoreSimulator")]);
+
+    // Module cache lives inside DerivedData but is shared by every project, so it gets its own category
+    let derived_data = xcode.join("DerivedData");
+    let module_cache = derived_data.join("ModuleCache.noindex");
+    let projects = std::fs::read_dir(&derived_data)
+        .map(|entries| entries.filter_map(|e| e.ok()).map(|e| e.path()).filter(|p| *p != module_cache).collect())
+        .unwrap_or_default();
+    push_extra(&mut info, "DerivedData", CategoryType::Cache, projects);
+    push_extra(&mut info, "Module Cache", CategoryType::Cache, existing_paths([module_cache]));
+    // Re-extracted from the device the next time it's connected
+    push_extra(
+        &mut info,
+        "Device Support",
+        CategoryType::Cache,
+        existing_paths(XCODE_DEVICE_SUPPORT_DIRS.iter().map(|d| xcode.join(d))),
+    );
+    // Runtimes are system-wide (/Library) and need admin rights to remove
+    push_extra(
+        &mut info,
+        "Old Simulator Runtimes",
+        CategoryType::Installer,
+        if is_external_profile() {
+            Vec::new()
+        } else {
+            old_simulator_runtimes(Path::new("/Library/Developer/CoreSimulator/Profiles/Runtimes"))
+        },
+    );
+
+    Some(info)
+}
+
+// ── Public API ──
+
+/// Detected IDEs minus the ones the user chose to ignore. Every scan, clean
+/// and uninstall path goes through this, so ignored IDEs are never touched.
+pub fn detect_installed_ides() -> Vec<IdeInfo> {
+    let ignored = crate::config::load_config().ignored_ides;
+    let mut all = detect_all_ides();
+    all.retain(|ide| !ignored.contains(&ide.id));
+    all
+}
+
+/// Every detected IDE, including ignored ones (for the ignore-list settings).
+pub fn detect_all_ides() -> Vec<IdeInfo> {
+    let defs = load_ide_definitions();
+    let mut all = detect_vscode_ides(&defs.vscode);
+    all.extend(detect_portable_vscode_ides(&defs.vscode));
+    all.push(detect_zed_ide());
+    all.extend(detect_visual_studio_ides());
+    all.extend(detect_eclipse_ide());
+    all.extend(detect_neovim());
+    all.extend(detect_vim());
+    all.extend(detect_xcode());
+    all.extend(detect_jetbrains_ides(&defs.jetbrains));
+    all
+}
+
+/// Strip the portable suffix ("cursor_portable_2" → "cursor").
+pub fn base_ide_id(ide_id: &str) -> &str {
+    ide_id.split("_portable").next().unwrap_or(ide_id)
+}
+
+pub fn get_process_names(ide_id: &str) -> Vec<String> {
+    let base = base_ide_id(ide_id);
+    if base == "zed" {
+        return ZED_PROCESS_NAMES.iter().map(|s| s.to_string()).collect();
+    }
+    if VISUAL_STUDIO_RELEASES.iter().any(|r| r.id == base) {
+        return VISUAL_STUDIO_PROCESS_NAMES.iter().map(|s| s.to_string()).collect();
+    }
+    if base == "eclipse" {
+        return ECLIPSE_PROCESS_NAMES.iter().map(|s| s.to_string()).collect();
+    }
+    if base == "neovim" {
+        return NEOVIM_PROCESS_NAMES.iter().map(|s| s.to_string()).collect();
+    }
+    if base == "vim" {
+        return VIM_PROCESS_NAMES.iter().map(|s| s.to_string()).collect();
+    }
+    if base == "xcode" {
+        return XCODE_PROCESS_NAMES.iter().map(|s| s.to_string()).collect();
+    }
+    let defs = load_ide_definitions();
+    defs.vscode
+        .iter()
+        .filter(|d| d.id == base)
+        .flat_map(|d| d.process_names.clone())
+        .chain(defs.jetbrains.iter().filter(|p| p.id == base).flat_map(|p| p.process_names.clone()))
+        .collect()
 }
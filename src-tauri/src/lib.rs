@@ -1,10 +1,44 @@
+pub mod ai_cli;
 pub mod backup;
+pub mod batch;
+pub mod chunked;
 pub mod cleaner;
 pub mod commands;
+pub mod config;
 pub mod conversation;
+pub mod conversation_archive;
+pub mod conversation_export;
+pub mod conversation_notes;
+pub mod conversation_search;
+pub mod credentials;
+pub mod db_pool;
+pub mod dev_caches;
+pub mod devcontainers;
+pub mod extension_folder;
+pub mod extensions;
+pub mod external_profile;
+pub mod footprint;
 pub mod ide_detector;
+pub mod local_models;
+pub mod maven_repo;
+pub mod migration;
+pub mod orphaned_storage;
+pub mod package_managers;
+pub mod policy;
+pub mod process;
+pub mod program_snapshots;
+pub mod protowire;
+pub mod project_artifacts;
+pub mod retry;
+pub mod rules;
+pub mod scan_snapshot;
 pub mod scanner;
+pub mod secret_scan;
+pub mod ssh_remote;
+pub mod stale_versions;
+pub mod tray;
 pub mod uninstaller;
+pub mod wsl;
 
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
@@ -21,6 +55,8 @@ pub fn run() {
                         .build(),
                 )?;
             }
+            tray::init(app)?;
+            policy::watch(app.handle().clone());
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
@@ -29,6 +65,9 @@ pub fn run() {
             commands::scan_single_ide,
             commands::check_ide_running,
             commands::clean_ide,
+            commands::purge_category_older_than,
+            commands::scan_family,
+            commands::clean_family,
             commands::list_backups,
             commands::delete_backup,
             commands::get_backup_dir_path,
@@ -39,14 +78,103 @@ pub fn run() {
             commands::get_ide_icons,
             commands::get_keep_option_sizes,
             commands::get_conversation_content,
+            commands::export_conversation,
+            commands::export_conversations_batch,
+            commands::scan_conversation_secrets,
+            commands::archive_conversations,
+            commands::list_archived_conversations,
+            commands::get_archived_conversation,
+            commands::restore_conversation,
+            commands::delete_archived_conversations,
+            commands::update_search_index,
+            commands::search_conversations,
             commands::delete_conversation,
             commands::delete_conversations_batch,
-            commands::scan_conversations,
+            commands::delete_duplicate_conversations,
+            commands::delete_stray_database_files,
+            commands::vacuum_database,
+            commands::set_conversation_protected,
+            commands::annotate_conversation,
+            commands::query_conversations,
             commands::scan_installed_programs,
             commands::find_residual_data,
             commands::uninstall_program,
             commands::delete_storage_entry,
             commands::format_bytes,
+            commands::get_portable_paths,
+            commands::add_portable_path,
+            commands::remove_portable_path,
+            commands::get_project_roots,
+            commands::add_project_root,
+            commands::remove_project_root,
+            commands::find_rust_targets,
+            commands::clean_rust_targets,
+            commands::clean_stale_rust_targets,
+            commands::find_python_artifacts,
+            commands::clean_python_artifacts,
+            commands::analyze_maven_repo,
+            commands::clean_maven_artifacts,
+            commands::analyze_local_models,
+            commands::delete_local_models,
+            commands::prune_model_revisions,
+            commands::list_failed_operations,
+            commands::retry_failed,
+            commands::analyze_extensions,
+            commands::clean_stale_extensions,
+            commands::uninstall_extension,
+            commands::export_extension_list,
+            commands::read_extension_list,
+            commands::import_extension_list,
+            commands::scan_wsl_servers,
+            commands::clean_wsl_items,
+            commands::scan_remote_servers,
+            commands::clean_remote_servers,
+            commands::analyze_external_profile,
+            commands::get_conversation_rules,
+            commands::get_conversation_read_limits,
+            commands::set_conversation_read_limits,
+            commands::get_ide_definitions,
+            commands::get_ide_credentials,
+            commands::restore_credentials,
+            commands::restore_registry,
+            commands::detect_all_ides,
+            commands::get_ignored_ides,
+            commands::set_ide_ignored,
+            commands::get_secure_delete,
+            commands::set_secure_delete,
+            commands::snapshot_installed_programs,
+            commands::list_program_snapshots,
+            commands::delete_program_snapshot,
+            commands::diff_program_snapshots,
+            commands::find_stale_versions,
+            commands::clean_stale_versions,
+            commands::find_toolbox_leftovers,
+            commands::clean_toolbox_leftovers,
+            commands::find_orphaned_storage,
+            commands::clean_orphaned_storage,
+            commands::get_policy_status,
+            commands::set_tray_labels,
+            commands::scan_dev_caches,
+            commands::clean_dev_cache,
+            commands::scan_devcontainers,
+            commands::remove_devcontainer_items,
+            commands::get_developer_footprint,
+            commands::export_curation,
+            commands::import_curation,
+            commands::export_migration,
+            commands::read_migration_manifest,
+            commands::import_migration,
+            commands::preview_settings_transfer,
+            commands::transfer_settings,
+            commands::save_scan_snapshot,
+            commands::load_scan_snapshot,
+            commands::analyze_ai_cli,
+            commands::get_ai_cli_session_summary,
+            commands::prune_ai_cli_sessions,
+            commands::scan_all_ides_chunked,
+            commands::list_storage_entries_chunked,
+            commands::fetch_chunk,
+            commands::release_chunked,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
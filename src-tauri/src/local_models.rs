@@ -0,0 +1,546 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+use crate::batch::{BatchResult, ItemResult};
+
+/// Registry Ollama pulls from when a model name has no host.
+const OLLAMA_REGISTRY: &str = "registry.ollama.ai";
+/// Namespace of the official models, left out of their names.
+const OLLAMA_LIBRARY: &str = "library";
+/// Weight files GPT4All keeps next to its settings and LocalDocs database.
+const GPT4ALL_MODEL_EXTENSIONS: &[&str] = &["gguf", "bin"];
+/// The ref `from_pretrained` resolves when no revision is given.
+const HF_DEFAULT_REF: &str = "main";
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ModelRuntime {
+    Ollama,
+    LmStudio,
+    Gpt4All,
+    HuggingFace,
+}
+
+/// A runtime's model folder.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelStore {
+    pub runtime: ModelRuntime,
+    pub path: PathBuf,
+    pub size: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocalModel {
+    pub runtime: ModelRuntime,
+    /// As the runtime names it, e.g. `llama3:8b`.
+    pub name: String,
+    /// What `delete_local_models` takes: the Ollama manifest, the LM Studio
+    /// model folder, the GPT4All weight file or the Hugging Face repo folder.
+    pub path: PathBuf,
+    pub size: u64,
+    /// Bytes deleting this model frees; blobs shared with other models stay.
+    pub unique_size: u64,
+    /// UTC epoch milliseconds.
+    pub last_modified: Option<i64>,
+    /// Hugging Face snapshots, latest first; empty for other runtimes.
+    pub revisions: Vec<ModelRevision>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelRevision {
+    pub commit: String,
+    /// Refs pointing at this commit, e.g. `main`.
+    pub refs: Vec<String>,
+    pub size: u64,
+    /// Bytes pruning this revision frees; files unchanged since other revisions stay.
+    pub unique_size: u64,
+    pub last_modified: Option<i64>,
+    /// Kept by `prune_model_revisions`.
+    pub is_latest: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocalModelReport {
+    pub stores: Vec<ModelStore>,
+    /// Largest first.
+    pub models: Vec<LocalModel>,
+    pub total_size: u64,
+}
+
+fn dir_size(path: &Path) -> u64 {
+    WalkDir::new(path)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| e.metadata().ok())
+        .map(|m| m.len())
+        .sum()
+}
+
+fn modified_ms(path: &Path) -> Option<i64> {
+    fs::metadata(path)
+        .ok()
+        .and_then(|m| m.modified().ok())
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_millis() as i64)
+}
+
+// ── Ollama: models/manifests/<registry>/<namespace>/<model>/<tag> → models/blobs/sha256-<hex> ──
+
+/// `$OLLAMA_MODELS`, else ~/.ollama/models.
+fn ollama_dir() -> Option<PathBuf> {
+    std::env::var_os("OLLAMA_MODELS")
+        .map(PathBuf::from)
+        .or_else(|| dirs::home_dir().map(|h| h.join(".ollama").join("models")))
+        .filter(|p| p.join("manifests").is_dir())
+}
+
+/// Blob files a manifest references (config and layers), by file name.
+fn ollama_blobs(manifest: &Path) -> Vec<String> {
+    let Some(parsed) = fs::read_to_string(manifest).ok().and_then(|d| serde_json::from_str::<Value>(&d).ok()) else {
+        return Vec::new();
+    };
+    let layers = parsed.get("layers").and_then(|l| l.as_array()).into_iter().flatten();
+    parsed
+        .get("config")
+        .into_iter()
+        .chain(layers)
+        .filter_map(|l| l.get("digest").and_then(|d| d.as_str()))
+        // Digests are `sha256:<hex>`; the files are named `sha256-<hex>`
+        .map(|d| d.replace(':', "-"))
+        .collect()
+}
+
+/// `registry.ollama.ai/library/llama3/8b` → `llama3:8b`; other namespaces and
+/// registries stay in the name the way `ollama list` shows them.
+fn ollama_model_name(manifests: &Path, manifest: &Path) -> Option<String> {
+    let parts: Vec<String> = manifest.strip_prefix(manifests).ok()?.iter().map(|c| c.to_string_lossy().to_string()).collect();
+    let [registry, namespace, model, tag] = parts.as_slice() else { return None };
+    let repo = match (registry.as_str(), namespace.as_str()) {
+        (OLLAMA_REGISTRY, OLLAMA_LIBRARY) => model.clone(),
+        (OLLAMA_REGISTRY, _) => format!("{}/{}", namespace, model),
+        _ => format!("{}/{}/{}", registry, namespace, model),
+    };
+    Some(format!("{}:{}", repo, tag))
+}
+
+fn ollama_manifests(root: &Path) -> Vec<PathBuf> {
+    WalkDir::new(root.join("manifests"))
+        .min_depth(4)
+        .max_depth(4)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .map(|e| e.into_path())
+        .collect()
+}
+
+fn ollama_models(root: &Path) -> Vec<LocalModel> {
+    let manifests_dir = root.join("manifests");
+    let blobs_dir = root.join("blobs");
+    let manifests: Vec<(PathBuf, Vec<String>)> = ollama_manifests(root).into_iter().map(|m| {
+        let blobs = ollama_blobs(&m);
+        (m, blobs)
+    }).collect();
+    let mut users: HashMap<&str, usize> = HashMap::new();
+    for blob in manifests.iter().flat_map(|(_, blobs)| blobs.iter().collect::<HashSet<_>>()) {
+        *users.entry(blob.as_str()).or_default() += 1;
+    }
+    manifests
+        .iter()
+        .filter_map(|(manifest, blobs)| {
+            let name = ollama_model_name(&manifests_dir, manifest)?;
+            let mut size = 0;
+            let mut unique_size = 0;
+            for blob in blobs.iter().collect::<HashSet<_>>() {
+                let blob_size = fs::metadata(blobs_dir.join(blob)).map(|m| m.len()).unwrap_or(0);
+                size += blob_size;
+                if users.get(blob.as_str()) == Some(&1) {
+                    unique_size += blob_size;
+                }
+            }
+            Some(LocalModel {
+                runtime: ModelRuntime::Ollama,
+                name,
+                path: manifest.clone(),
+                size,
+                unique_size,
+                last_modified: modified_ms(manifest),
+                revisions: Vec::new(),
+            })
+        })
+        .collect()
+}
+
+/// Delete one Ollama model: its manifest, then the blobs no other manifest uses.
+fn delete_ollama_model(root: &Path, manifest: &Path, failed_paths: &mut Vec<PathBuf>) -> (u64, Vec<String>) {
+    let manifests_dir = root.join("manifests");
+    let blobs = ollama_blobs(manifest);
+    if let Err(e) = crate::cleaner::remove_file(manifest) {
+        failed_paths.push(manifest.to_path_buf());
+        return (0, vec![format!("{}: {}", manifest.display(), e)]);
+    }
+    // Empty model/namespace/registry folders go too, the way `ollama rm` leaves them
+    for dir in manifest.ancestors().skip(1).take_while(|d| *d != manifests_dir) {
+        if fs::remove_dir(dir).is_err() {
+            break;
+        }
+    }
+
+    let mut freed = 0;
+    let mut errors = Vec::new();
+    let still_used: HashSet<String> = ollama_manifests(root).iter().flat_map(|m| ollama_blobs(m)).collect();
+    for blob in blobs.into_iter().collect::<HashSet<_>>() {
+        if still_used.contains(&blob) {
+            continue;
+        }
+        let path = root.join("blobs").join(&blob);
+        let size = fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+        match crate::cleaner::remove_file(&path) {
+            Ok(()) => freed += size,
+            Err(e) if path.exists() => {
+                errors.push(format!("{}: {}", path.display(), e));
+                failed_paths.push(path);
+            }
+            Err(_) => {}
+        }
+    }
+    (freed, errors)
+}
+
+// ── LM Studio: models/<publisher>/<model>/*.gguf ──
+
+/// ~/.lmstudio/models (0.3+) and the older ~/.cache/lm-studio/models; LM Studio
+/// uses these paths on every platform.
+fn lm_studio_dirs() -> Vec<PathBuf> {
+    let Some(home) = dirs::home_dir() else { return Vec::new() };
+    [home.join(".lmstudio").join("models"), home.join(".cache").join("lm-studio").join("models")]
+        .into_iter()
+        .filter(|p| p.is_dir())
+        .collect()
+}
+
+fn lm_studio_models(root: &Path) -> Vec<LocalModel> {
+    WalkDir::new(root)
+        .min_depth(2)
+        .max_depth(2)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_dir())
+        .filter_map(|e| {
+            let name = e.path().strip_prefix(root).ok()?.to_string_lossy().replace('\\', "/");
+            let size = dir_size(e.path());
+            Some(LocalModel {
+                runtime: ModelRuntime::LmStudio,
+                name,
+                size,
+                unique_size: size,
+                last_modified: modified_ms(e.path()),
+                revisions: Vec::new(),
+                path: e.into_path(),
+            })
+        })
+        .collect()
+}
+
+// ── GPT4All: weight files directly in its app data folder ──
+
+fn gpt4all_dir() -> Option<PathBuf> {
+    dirs::data_local_dir().map(|d| d.join("nomic.ai").join("GPT4All")).filter(|p| p.is_dir())
+}
+
+fn gpt4all_models(root: &Path) -> Vec<LocalModel> {
+    let Ok(entries) = fs::read_dir(root) else { return Vec::new() };
+    entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.is_file())
+        .filter(|p| p.extension().and_then(|x| x.to_str()).is_some_and(|x| GPT4ALL_MODEL_EXTENSIONS.contains(&x)))
+        .map(|path| {
+            let size = fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+            LocalModel {
+                runtime: ModelRuntime::Gpt4All,
+                name: path.file_name().unwrap_or_default().to_string_lossy().to_string(),
+                size,
+                unique_size: size,
+                last_modified: modified_ms(&path),
+                revisions: Vec::new(),
+                path,
+            }
+        })
+        .collect()
+}
+
+// ── Hugging Face hub: <type>s--<org>--<name>/{blobs,refs,snapshots/<commit>} ──
+
+/// `$HF_HUB_CACHE`, `$HF_HOME/hub`, else huggingface/hub under the XDG cache
+/// (~/.cache on every platform, like `huggingface_hub`).
+fn hf_hub_dir() -> Option<PathBuf> {
+    let env = |k: &str| std::env::var_os(k).filter(|v| !v.is_empty()).map(PathBuf::from);
+    env("HF_HUB_CACHE")
+        .or_else(|| env("HF_HOME").map(|h| h.join("hub")))
+        .or_else(|| {
+            env("XDG_CACHE_HOME")
+                .or_else(|| dirs::home_dir().map(|h| h.join(".cache")))
+                .map(|c| c.join("huggingface").join("hub"))
+        })
+        .filter(|p| p.is_dir())
+}
+
+/// `models--meta-llama--Llama-3.1-8B` → `meta-llama/Llama-3.1-8B`; datasets and
+/// spaces keep their prefix the way their hub URLs do.
+fn hf_repo_name(dir_name: &str) -> Option<String> {
+    let (kind, id) = dir_name.split_once("--")?;
+    let id = id.replace("--", "/");
+    match kind {
+        "models" => Some(id),
+        "datasets" | "spaces" => Some(format!("{}/{}", kind, id)),
+        _ => None,
+    }
+}
+
+/// Files of one snapshot, keyed by the blob they link to. Where the cache was
+/// written without symlinks (Windows without developer mode) the snapshot holds
+/// real copies, keyed by their own path.
+fn hf_snapshot_files(repo: &Path, snapshot: &Path) -> HashMap<String, u64> {
+    let blobs = repo.join("blobs");
+    WalkDir::new(snapshot)
+        .min_depth(1)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| !e.file_type().is_dir())
+        .filter_map(|e| {
+            if e.file_type().is_symlink() {
+                let blob = fs::read_link(e.path()).ok()?.file_name()?.to_string_lossy().to_string();
+                let size = fs::metadata(blobs.join(&blob)).map(|m| m.len()).unwrap_or(0);
+                Some((blob, size))
+            } else {
+                Some((e.path().to_string_lossy().to_string(), e.metadata().ok()?.len()))
+            }
+        })
+        .collect()
+}
+
+/// Ref name (relative to refs/, e.g. `main` or `pr/1`) → commit.
+fn hf_refs(repo: &Path) -> Vec<(String, String)> {
+    let refs = repo.join("refs");
+    WalkDir::new(&refs)
+        .min_depth(1)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| {
+            let name = e.path().strip_prefix(&refs).ok()?.to_string_lossy().replace('\\', "/");
+            let commit = fs::read_to_string(e.path()).ok()?.trim().to_string();
+            Some((name, commit))
+        })
+        .collect()
+}
+
+fn hf_revisions(repo: &Path) -> Vec<ModelRevision> {
+    let Ok(entries) = fs::read_dir(repo.join("snapshots")) else { return Vec::new() };
+    let snapshots: Vec<(String, PathBuf, HashMap<String, u64>)> = entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_dir())
+        .map(|e| {
+            let files = hf_snapshot_files(repo, &e.path());
+            (e.file_name().to_string_lossy().to_string(), e.path(), files)
+        })
+        .collect();
+    let mut users: HashMap<&str, usize> = HashMap::new();
+    for key in snapshots.iter().flat_map(|(_, _, files)| files.keys()) {
+        *users.entry(key.as_str()).or_default() += 1;
+    }
+    let refs = hf_refs(repo);
+    let mut revisions: Vec<ModelRevision> = snapshots
+        .iter()
+        .map(|(commit, path, files)| ModelRevision {
+            commit: commit.clone(),
+            refs: refs.iter().filter(|(_, c)| c == commit).map(|(r, _)| r.clone()).collect(),
+            size: files.values().sum(),
+            unique_size: files.iter().filter(|(k, _)| users.get(k.as_str()) == Some(&1)).map(|(_, v)| v).sum(),
+            last_modified: modified_ms(path),
+            is_latest: false,
+        })
+        .collect();
+    revisions.sort_by(|a, b| {
+        let is_default = |r: &ModelRevision| r.refs.iter().any(|n| n == HF_DEFAULT_REF);
+        is_default(b).cmp(&is_default(a)).then(b.last_modified.cmp(&a.last_modified))
+    });
+    if let Some(latest) = revisions.first_mut() {
+        latest.is_latest = true;
+    }
+    revisions
+}
+
+fn hf_models(hub: &Path) -> Vec<LocalModel> {
+    let Ok(entries) = fs::read_dir(hub) else { return Vec::new() };
+    entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_dir())
+        .filter_map(|e| {
+            let name = hf_repo_name(&e.file_name().to_string_lossy())?;
+            let path = e.path();
+            let size = dir_size(&path);
+            Some(LocalModel {
+                runtime: ModelRuntime::HuggingFace,
+                name,
+                size,
+                unique_size: size,
+                last_modified: modified_ms(&path),
+                revisions: hf_revisions(&path),
+                path,
+            })
+        })
+        .collect()
+}
+
+/// Delete every revision but the latest, as `huggingface-cli delete-cache` does:
+/// the snapshot folder, the refs pointing at it, and the blobs no kept revision
+/// links to.
+fn prune_hf_revisions(repo: &Path, revisions: &[ModelRevision], failed_paths: &mut Vec<PathBuf>) -> (u64, Vec<String>) {
+    let mut freed = 0;
+    let mut errors = Vec::new();
+    let snapshots = repo.join("snapshots");
+    let mut pruned_files: HashSet<String> = HashSet::new();
+    for revision in revisions.iter().filter(|r| !r.is_latest) {
+        let snapshot = snapshots.join(&revision.commit);
+        let files = hf_snapshot_files(repo, &snapshot);
+        if let Err(e) = crate::cleaner::remove_tree(&snapshot) {
+            errors.push(format!("{}: {}", snapshot.display(), e));
+            failed_paths.push(snapshot);
+            continue;
+        }
+        // Copies (no symlinks) are keyed by their path and went with the snapshot
+        for (key, size) in files {
+            if Path::new(&key).starts_with(&snapshot) {
+                freed += size;
+            } else {
+                pruned_files.insert(key);
+            }
+        }
+        for name in &revision.refs {
+            let path = repo.join("refs").join(name);
+            match crate::cleaner::remove_file(&path) {
+                Ok(()) => {}
+                Err(e) if path.exists() => {
+                    errors.push(format!("{}: {}", path.display(), e));
+                    failed_paths.push(path);
+                }
+                Err(_) => {}
+            }
+        }
+    }
+
+    let kept: HashSet<String> = revisions
+        .iter()
+        .filter(|r| snapshots.join(&r.commit).is_dir())
+        .flat_map(|r| hf_snapshot_files(repo, &snapshots.join(&r.commit)).into_keys())
+        .collect();
+    let blobs = repo.join("blobs");
+    for blob in pruned_files.difference(&kept) {
+        let path = blobs.join(blob);
+        let Ok(meta) = fs::metadata(&path) else { continue };
+        match crate::cleaner::remove_file(&path) {
+            Ok(()) => freed += meta.len(),
+            Err(e) => {
+                errors.push(format!("{}: {}", path.display(), e));
+                failed_paths.push(path);
+            }
+        }
+    }
+    (freed, errors)
+}
+
+// ── Public API ──
+
+/// Every model of every detected runtime, largest first.
+pub fn analyze_local_models() -> LocalModelReport {
+    let mut stores = Vec::new();
+    let mut models = Vec::new();
+    if let Some(root) = ollama_dir() {
+        models.extend(ollama_models(&root));
+        stores.push(ModelStore { runtime: ModelRuntime::Ollama, size: dir_size(&root), path: root });
+    }
+    for root in lm_studio_dirs() {
+        models.extend(lm_studio_models(&root));
+        stores.push(ModelStore { runtime: ModelRuntime::LmStudio, size: dir_size(&root), path: root });
+    }
+    if let Some(root) = gpt4all_dir() {
+        let found = gpt4all_models(&root);
+        // The folder also holds settings and LocalDocs; only the weights count
+        let size = found.iter().map(|m| m.size).sum();
+        models.extend(found);
+        stores.push(ModelStore { runtime: ModelRuntime::Gpt4All, size, path: root });
+    }
+    if let Some(root) = hf_hub_dir() {
+        models.extend(hf_models(&root));
+        stores.push(ModelStore { runtime: ModelRuntime::HuggingFace, size: dir_size(&root), path: root });
+    }
+    models.sort_by(|a, b| b.size.cmp(&a.size));
+    LocalModelReport { total_size: stores.iter().map(|s| s.size).sum(), stores, models }
+}
+
+/// Delete the given models (`LocalModel::path`). Paths that aren't a model of a
+/// detected runtime are refused.
+pub fn delete_local_models(paths: &[PathBuf]) -> BatchResult {
+    let models = analyze_local_models().models;
+    let mut failed_paths = Vec::new();
+    let items = paths
+        .iter()
+        .map(|path| {
+            let id = path.display().to_string();
+            let Some(model) = models.iter().find(|m| &m.path == path) else {
+                return if path.exists() { ItemResult::skipped(id, "Not a local model") } else { ItemResult::not_found(id) };
+            };
+            let removed = match model.runtime {
+                ModelRuntime::Ollama => match ollama_dir() {
+                    Some(root) => {
+                        let (freed, errors) = delete_ollama_model(&root, path, &mut failed_paths);
+                        return ItemResult::from_errors(id, freed, errors);
+                    }
+                    None => return ItemResult::skipped(id, "Not a local model"),
+                },
+                ModelRuntime::LmStudio | ModelRuntime::HuggingFace => crate::cleaner::remove_tree(path),
+                ModelRuntime::Gpt4All => crate::cleaner::remove_file(path),
+            };
+            match removed {
+                Ok(()) => ItemResult::done(id, model.size),
+                Err(e) => {
+                    failed_paths.push(path.clone());
+                    ItemResult::failed(id, format!("{}: {}", path.display(), e))
+                }
+            }
+        })
+        .collect();
+    let mut result = BatchResult::from_items(items);
+    result.failed_paths = failed_paths;
+    result
+}
+
+/// Prune every revision but the latest of the given Hugging Face repos
+/// (`LocalModel::path`).
+pub fn prune_model_revisions(paths: &[PathBuf]) -> BatchResult {
+    let models = analyze_local_models().models;
+    let mut failed_paths = Vec::new();
+    let items = paths
+        .iter()
+        .map(|path| {
+            let id = path.display().to_string();
+            match models.iter().find(|m| &m.path == path && m.runtime == ModelRuntime::HuggingFace) {
+                Some(model) => {
+                    let (freed, errors) = prune_hf_revisions(path, &model.revisions, &mut failed_paths);
+                    ItemResult::from_errors(id, freed, errors)
+                }
+                None if path.exists() => ItemResult::skipped(id, "Not a Hugging Face repo"),
+                None => ItemResult::not_found(id),
+            }
+        })
+        .collect();
+    let mut result = BatchResult::from_items(items);
+    result.failed_paths = failed_paths;
+    result
+}
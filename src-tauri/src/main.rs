@@ -2,5 +2,39 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 fn main() {
+  // `devcleaner footprint [--json]` prints the developer footprint instead of opening the window
+  let args: Vec<String> = std::env::args().skip(1).collect();
+  if args.first().map(String::as_str) == Some("footprint") {
+    attach_parent_console();
+    let footprint = app_lib::footprint::get_developer_footprint();
+    if args.iter().any(|a| a == "--json") {
+      println!("{}", serde_json::to_string_pretty(&footprint).unwrap_or_default());
+    } else {
+      print!("{}", app_lib::footprint::format_report(&footprint, 20));
+    }
+    return;
+  }
   app_lib::run();
 }
+
+/// A release build is a GUI-subsystem binary with no console of its own, so
+/// stdout goes nowhere unless we attach to the terminal that launched us.
+/// Redirected output (`> file`, pipes) already has valid handles and is unaffected.
+#[cfg(target_os = "windows")]
+fn attach_parent_console() {
+  const ATTACH_PARENT_PROCESS: u32 = u32::MAX;
+
+  #[link(name = "kernel32")]
+  extern "system" {
+    fn AttachConsole(process_id: u32) -> i32;
+  }
+
+  // Fails harmlessly when there is no parent console (e.g. launched from Explorer)
+  // or one is already attached (debug builds).
+  unsafe {
+    AttachConsole(ATTACH_PARENT_PROCESS);
+  }
+}
+
+#[cfg(not(target_os = "windows"))]
+fn attach_parent_console() {}
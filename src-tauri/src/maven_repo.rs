@@ -0,0 +1,195 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+use std::time::SystemTime;
+use walkdir::WalkDir;
+
+use crate::batch::{BatchResult, ItemResult};
+
+/// How many groupIds the report lists.
+const TOP_GROUPS: usize = 20;
+const SECS_PER_MONTH: i64 = 30 * 86_400;
+
+/// One `<groupId>/<artifactId>/<version>` folder of the local repository.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MavenArtifact {
+    pub group_id: String,
+    pub artifact_id: String,
+    pub version: String,
+    pub path: PathBuf,
+    pub size: u64,
+    /// Newest access/modification time of its files (epoch seconds).
+    pub last_used: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MavenGroup {
+    pub group_id: String,
+    pub size: u64,
+    pub artifact_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MavenRepoReport {
+    pub path: PathBuf,
+    pub total_size: u64,
+    pub artifact_count: usize,
+    /// Largest groupIds, largest first.
+    pub groups: Vec<MavenGroup>,
+    /// Versions not used for `stale_months`, oldest first.
+    pub stale: Vec<MavenArtifact>,
+    pub stale_size: u64,
+    pub stale_months: u32,
+}
+
+/// `<localRepository>` from ~/.m2/settings.xml, else ~/.m2/repository.
+pub fn maven_repo_dir() -> Option<PathBuf> {
+    let home = dirs::home_dir()?;
+    let m2 = home.join(".m2");
+    let configured = std::fs::read_to_string(m2.join("settings.xml")).ok().and_then(|data| {
+        let start = data.find("<localRepository>")? + "<localRepository>".len();
+        let end = start + data[start..].find("</localRepository>")?;
+        // ${user.home} is the only property commonly used here
+        let value = data[start..end].trim().replace("${user.home}", &home.to_string_lossy());
+        Some(PathBuf::from(value)).filter(|p| !p.as_os_str().is_empty())
+    });
+    Some(configured.unwrap_or_else(|| m2.join("repository"))).filter(|p| p.is_dir())
+}
+
+/// A version folder: holds the artifact's `.pom` (or its jar for pom-less installs).
+fn is_version_dir(dir: &Path) -> bool {
+    std::fs::read_dir(dir)
+        .map(|entries| {
+            entries.filter_map(|e| e.ok()).any(|e| {
+                let name = e.file_name().to_string_lossy().to_string();
+                name.ends_with(".pom") || name.ends_with(".jar")
+            })
+        })
+        .unwrap_or(false)
+}
+
+fn epoch_secs(t: SystemTime) -> Option<i64> {
+    t.duration_since(SystemTime::UNIX_EPOCH).ok().map(|d| d.as_secs() as i64)
+}
+
+fn read_artifact(repo: &Path, version_dir: &Path) -> Option<MavenArtifact> {
+    let rel = version_dir.strip_prefix(repo).ok()?;
+    let parts: Vec<String> = rel.iter().map(|c| c.to_string_lossy().to_string()).collect();
+    if parts.len() < 3 {
+        return None;
+    }
+    let (group, rest) = parts.split_at(parts.len() - 2);
+    let mut size = 0;
+    let mut last_used: Option<i64> = None;
+    for meta in WalkDir::new(version_dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| e.metadata().ok())
+    {
+        size += meta.len();
+        // Access times are often disabled (noatime, NTFS default), so take whichever is newer
+        let used = [meta.accessed().ok(), meta.modified().ok()].into_iter().flatten().filter_map(epoch_secs).max();
+        last_used = last_used.max(used);
+    }
+    Some(MavenArtifact {
+        group_id: group.join("."),
+        artifact_id: rest[0].clone(),
+        version: rest[1].clone(),
+        path: version_dir.to_path_buf(),
+        size,
+        last_used,
+    })
+}
+
+fn list_artifacts(repo: &Path) -> Vec<MavenArtifact> {
+    let mut artifacts = Vec::new();
+    let mut walker = WalkDir::new(repo).min_depth(3).into_iter();
+    while let Some(entry) = walker.next() {
+        let Ok(entry) = entry else { continue };
+        if !entry.file_type().is_dir() || !is_version_dir(entry.path()) { continue; }
+        walker.skip_current_dir();
+        artifacts.extend(read_artifact(repo, entry.path()));
+    }
+    artifacts
+}
+
+/// Size, largest groupIds and the versions unused for `stale_months`.
+pub fn analyze_maven_repo(stale_months: u32) -> Option<MavenRepoReport> {
+    let repo = maven_repo_dir()?;
+    let artifacts = list_artifacts(&repo);
+    let cutoff = chrono::Utc::now().timestamp() - stale_months as i64 * SECS_PER_MONTH;
+
+    let mut groups: HashMap<String, MavenGroup> = HashMap::new();
+    for a in &artifacts {
+        let g = groups.entry(a.group_id.clone()).or_insert_with(|| MavenGroup { group_id: a.group_id.clone(), size: 0, artifact_count: 0 });
+        g.size += a.size;
+        g.artifact_count += 1;
+    }
+    let mut groups: Vec<MavenGroup> = groups.into_values().collect();
+    groups.sort_by(|a, b| b.size.cmp(&a.size));
+    groups.truncate(TOP_GROUPS);
+
+    let total_size = artifacts.iter().map(|a| a.size).sum();
+    let artifact_count = artifacts.len();
+    let mut stale: Vec<MavenArtifact> = artifacts.into_iter().filter(|a| a.last_used.map(|t| t < cutoff).unwrap_or(true)).collect();
+    stale.sort_by_key(|a| a.last_used.unwrap_or(0));
+
+    Some(MavenRepoReport {
+        path: repo,
+        total_size,
+        artifact_count,
+        groups,
+        stale_size: stale.iter().map(|a| a.size).sum(),
+        stale,
+        stale_months,
+    })
+}
+
+/// Delete artifact version folders. Only version folders inside the local
+/// repository are accepted, whatever path the caller passes.
+pub fn clean_maven_artifacts(paths: &[PathBuf]) -> BatchResult {
+    let Some(repo) = maven_repo_dir() else {
+        return BatchResult::from_items(
+            paths.iter().map(|p| ItemResult::failed(p.display().to_string(), "No local Maven repository found")).collect(),
+        );
+    };
+    let mut failed_paths = Vec::new();
+    let items = paths
+        .iter()
+        .map(|path| {
+            let id = path.display().to_string();
+            // `..` is a component too, so `starts_with` alone would let a path climb back out
+            let inside = path
+                .strip_prefix(&repo)
+                .map(|r| r.components().count() >= 3 && r.components().all(|c| matches!(c, Component::Normal(_))))
+                .unwrap_or(false);
+            if !path.exists() {
+                return ItemResult::not_found(id);
+            }
+            if !inside || !is_version_dir(path) {
+                return ItemResult::skipped(id, format!("Not an artifact version in {}", repo.display()));
+            }
+            let (freed, _, mut errors) = crate::cleaner::clean_directory(path);
+            if errors.is_empty() {
+                if let Err(e) = std::fs::remove_dir(path) {
+                    errors.push(format!("{}: {}", path.display(), e));
+                }
+            }
+            // The artifact folder goes too once its last version is gone
+            let artifact_dir = path.parent().filter(|d| std::fs::read_dir(d).is_ok_and(|mut e| e.next().is_none()));
+            if let Some(artifact_dir) = artifact_dir {
+                if let Err(e) = std::fs::remove_dir(artifact_dir) {
+                    errors.push(format!("{}: {}", artifact_dir.display(), e));
+                }
+            }
+            if !errors.is_empty() {
+                failed_paths.push(path.clone());
+            }
+            ItemResult::from_errors(id, freed, errors)
+        })
+        .collect();
+    let mut result = BatchResult::from_items(items);
+    result.failed_paths = failed_paths;
+    result
+}
@@ -0,0 +1,602 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+use zip::write::SimpleFileOptions;
+
+use crate::ide_detector::{IdeInfo, IdeType};
+
+const MIGRATION_FORMAT_VERSION: u32 = 1;
+const MANIFEST_FILE: &str = "manifest.json";
+const CURATION_FILE: &str = "curation.json";
+const ANNOTATIONS_FILE: &str = "annotations.json";
+/// Settings folders also hold the odd log or cache; big files aren't settings.
+const MAX_SETTINGS_FILE: u64 = 5 * 1024 * 1024;
+/// Suffix given to a settings file the import replaced.
+const REPLACED_SUFFIX: &str = ".pre-migration";
+
+/// Settings files of VS Code-based IDEs, relative to `<config>/User`.
+const VSCODE_SETTINGS: &[&str] = &["settings.json", "keybindings.json", "tasks.json", "snippets", "profiles"];
+/// Zed keeps conversations and its DB next to the settings; only these are settings.
+const ZED_SETTINGS: &[&str] = &["settings.json", "keymap.json", "tasks.json", "themes", "snippets"];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MigratedIde {
+    pub id: String,
+    pub name: String,
+    pub ide_type: IdeType,
+    /// Archive entries under `ides/<id>/settings/`, relative to the settings root.
+    pub settings_files: Vec<String>,
+    /// Extension / plugin ids installed on the old machine.
+    pub extensions: Vec<String>,
+    /// Conversations exported to `ides/<id>/conversations/`.
+    pub conversations: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MigrationManifest {
+    pub format_version: u32,
+    pub created_at: String,
+    /// `std::env::consts::OS` of the exporting machine.
+    pub source_os: String,
+    pub ides: Vec<MigratedIde>,
+}
+
+/// One exported conversation, readable without DevCleaner.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportedConversation {
+    pub id: String,
+    pub title: String,
+    pub last_modified: Option<i64>,
+    pub messages: Vec<crate::conversation::ConversationMessage>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MigrationExportSummary {
+    pub path: PathBuf,
+    pub size: u64,
+    pub manifest: MigrationManifest,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MigrationImportOptions {
+    /// IDEs to restore; empty restores every IDE in the archive.
+    #[serde(default)]
+    pub ide_ids: Vec<String>,
+    /// Replace settings files that already exist (the old copy is kept as `*.pre-migration`).
+    #[serde(default)]
+    pub overwrite_settings: bool,
+    /// Run the IDE's CLI to install the extension list.
+    #[serde(default)]
+    pub install_extensions: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdeImportResult {
+    pub id: String,
+    pub restored_files: usize,
+    /// Existing files left alone because `overwrite_settings` was off.
+    pub skipped_files: usize,
+    pub installed_extensions: Vec<String>,
+    /// Extensions to install by hand (no CLI, install failed, or not requested).
+    pub pending_extensions: Vec<String>,
+    /// Where the exported conversations were put on this machine.
+    pub conversations_dir: Option<PathBuf>,
+    pub errors: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MigrationImportSummary {
+    pub ides: Vec<IdeImportResult>,
+    /// IDEs in the archive that aren't known on this machine.
+    pub skipped_ides: Vec<String>,
+    pub curation: Option<crate::config::CurationImportSummary>,
+    /// Conversation tags/notes added (ones already annotated here are kept).
+    pub added_annotations: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TransferStatus {
+    /// Missing in the target IDE.
+    New,
+    Identical,
+    /// The target has a different version of the file.
+    Conflict,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransferFile {
+    /// Relative to `<config>/User`, with `/` separators.
+    pub path: String,
+    pub size: u64,
+    pub status: TransferStatus,
+}
+
+/// What copying one VS Code-based IDE's settings onto another would do.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SettingsTransferPreview {
+    pub from_id: String,
+    pub to_id: String,
+    pub files: Vec<TransferFile>,
+    /// Extensions installed in the source but not in the target.
+    pub missing_extensions: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SettingsTransferOptions {
+    /// Files to copy (`TransferFile::path`); empty copies every file.
+    #[serde(default)]
+    pub files: Vec<String>,
+    /// Replace conflicting files (the old copy is kept as `*.pre-migration`).
+    #[serde(default)]
+    pub overwrite_conflicts: bool,
+    /// Install the missing extensions with the target IDE's CLI.
+    #[serde(default)]
+    pub install_extensions: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SettingsTransferResult {
+    pub copied_files: usize,
+    /// Conflicts left alone because `overwrite_conflicts` was off.
+    pub skipped_files: usize,
+    pub installed_extensions: Vec<String>,
+    pub pending_extensions: Vec<String>,
+    pub errors: Vec<String>,
+}
+
+// ── Export ──
+
+/// Settings root, and for IDEs whose root holds more than settings the
+/// entries under it that are settings.
+fn settings_root(ide: &IdeInfo) -> Option<(PathBuf, Option<&'static [&'static str]>)> {
+    let config = ide.config_path.as_ref()?;
+    Some(match ide.ide_type {
+        IdeType::VscodeBased => (config.join("User"), Some(VSCODE_SETTINGS)),
+        IdeType::Zed => (config.clone(), Some(ZED_SETTINGS)),
+        _ => (config.clone(), None),
+    })
+}
+
+/// Settings root and the settings files under it.
+fn settings_files(ide: &IdeInfo) -> Option<(PathBuf, Vec<PathBuf>)> {
+    let (root, names) = settings_root(ide)?;
+    let starts: Vec<PathBuf> = match names {
+        Some(names) => names.iter().map(|n| root.join(n)).filter(|p| p.exists()).collect(),
+        None => vec![root.clone()],
+    };
+    let skip = ide.extension_path.clone();
+    let files = starts
+        .iter()
+        .flat_map(|start| WalkDir::new(start).into_iter().filter_map(|e| e.ok()))
+        .filter(|e| e.file_type().is_file())
+        // JetBrains keeps plugins inside the config dir
+        .filter(|e| skip.as_ref().map(|s| !e.path().starts_with(s)).unwrap_or(true))
+        .filter(|e| e.metadata().map(|m| m.len() <= MAX_SETTINGS_FILE).unwrap_or(false))
+        .map(|e| e.into_path())
+        .collect();
+    Some((root, files))
+}
+
+fn extension_ids(ide: &IdeInfo) -> Vec<String> {
+    let Some(dir) = ide.extension_path.as_ref() else { return Vec::new() };
+    let Ok(entries) = fs::read_dir(dir) else { return Vec::new() };
+    let mut ids: Vec<String> = entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_dir())
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .filter(|name| !name.starts_with('.'))
+        .map(|name| match ide.ide_type {
+            IdeType::VscodeBased => crate::extension_folder::parse_extension_folder(&name).map(|(id, _)| id).unwrap_or(name),
+            _ => name,
+        })
+        .collect();
+    ids.sort();
+    ids.dedup();
+    ids
+}
+
+fn export_conversations(ide: &IdeInfo) -> Vec<ExportedConversation> {
+    let listed = crate::conversation::scan_conversations(ide, crate::conversation::ConversationSort::default());
+    listed
+        .conversations
+        .into_iter()
+        .map(|c| {
+            // Same id → conversation id mapping the conversation viewer uses
+            let conversation_id = crate::conversation::conversation_id_of(&c.id);
+            let messages = if c.id.starts_with("pb:") {
+                Vec::new()
+            } else {
+                crate::conversation::get_conversation_content(&c.source_db, &c.source_key, &conversation_id)
+                    .map(|content| content.messages)
+                    .unwrap_or_default()
+            };
+            ExportedConversation { id: c.id, title: c.title, last_modified: c.last_modified, messages }
+        })
+        .collect()
+}
+
+fn add_bytes<W: Write + std::io::Seek>(zip: &mut zip::ZipWriter<W>, name: &str, data: &[u8]) -> Result<(), String> {
+    let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+    zip.start_file(name, options).map_err(|e| format!("Failed to add {}: {}", name, e))?;
+    zip.write_all(data).map_err(|e| format!("Failed to write {}: {}", name, e))
+}
+
+/// Bundle the selected IDEs' settings, extension lists and (optionally)
+/// conversations plus DevCleaner's own curation into one zip at `path`.
+pub fn export_migration(path: &Path, ide_ids: &[String], include_conversations: bool) -> Result<MigrationExportSummary, String> {
+    let ides: Vec<IdeInfo> = crate::ide_detector::detect_installed_ides()
+        .into_iter()
+        .filter(|i| i.installed && ide_ids.contains(&i.id))
+        .collect();
+    if ides.is_empty() {
+        return Err("None of the selected IDEs are installed".into());
+    }
+
+    let file = fs::File::create(path).map_err(|e| format!("Failed to create archive: {}", e))?;
+    let mut zip = zip::ZipWriter::new(file);
+    let mut migrated = Vec::new();
+
+    for ide in &ides {
+        let mut settings = Vec::new();
+        if let Some((root, files)) = settings_files(ide) {
+            for file in files {
+                let Ok(rel) = file.strip_prefix(&root) else { continue };
+                let rel = rel.to_string_lossy().replace('\\', "/");
+                let data = fs::read(&file).map_err(|e| format!("Failed to read {}: {}", file.display(), e))?;
+                add_bytes(&mut zip, &format!("ides/{}/settings/{}", ide.id, rel), &data)?;
+                settings.push(rel);
+            }
+        }
+
+        let mut conversations = 0;
+        if include_conversations {
+            for (i, conv) in export_conversations(ide).iter().enumerate() {
+                let data = serde_json::to_vec_pretty(conv).map_err(|e| format!("Failed to serialize conversation: {}", e))?;
+                add_bytes(&mut zip, &format!("ides/{}/conversations/{:05}.json", ide.id, i), &data)?;
+                conversations += 1;
+            }
+        }
+
+        migrated.push(MigratedIde {
+            id: ide.id.clone(),
+            name: ide.name.clone(),
+            ide_type: ide.ide_type.clone(),
+            settings_files: settings,
+            extensions: extension_ids(ide),
+            conversations,
+        });
+    }
+
+    let curation = serde_json::to_vec_pretty(&crate::config::current_curation()?)
+        .map_err(|e| format!("Failed to serialize curation: {}", e))?;
+    add_bytes(&mut zip, CURATION_FILE, &curation)?;
+    let annotations = serde_json::to_vec_pretty(&crate::conversation_notes::load_annotations())
+        .map_err(|e| format!("Failed to serialize annotations: {}", e))?;
+    add_bytes(&mut zip, ANNOTATIONS_FILE, &annotations)?;
+
+    let manifest = MigrationManifest {
+        format_version: MIGRATION_FORMAT_VERSION,
+        created_at: chrono::Local::now().to_rfc3339(),
+        source_os: std::env::consts::OS.to_string(),
+        ides: migrated,
+    };
+    let data = serde_json::to_vec_pretty(&manifest).map_err(|e| format!("Failed to serialize manifest: {}", e))?;
+    add_bytes(&mut zip, MANIFEST_FILE, &data)?;
+    zip.finish().map_err(|e| format!("Failed to finalize zip: {}", e))?;
+
+    Ok(MigrationExportSummary {
+        path: path.to_path_buf(),
+        size: fs::metadata(path).map(|m| m.len()).unwrap_or(0),
+        manifest,
+    })
+}
+
+// ── Import ──
+
+fn open_archive(path: &Path) -> Result<zip::ZipArchive<fs::File>, String> {
+    let file = fs::File::open(path).map_err(|e| format!("Failed to open archive: {}", e))?;
+    zip::ZipArchive::new(file).map_err(|e| format!("Not a migration archive: {}", e))
+}
+
+fn read_entry(archive: &mut zip::ZipArchive<fs::File>, name: &str) -> Result<Vec<u8>, String> {
+    let mut entry = archive.by_name(name).map_err(|e| format!("{} missing from archive: {}", name, e))?;
+    let mut data = Vec::new();
+    entry.read_to_end(&mut data).map_err(|e| format!("Failed to read {}: {}", name, e))?;
+    Ok(data)
+}
+
+/// The archive's manifest, for showing what an import would restore.
+pub fn read_migration_manifest(path: &Path) -> Result<MigrationManifest, String> {
+    let data = read_entry(&mut open_archive(path)?, MANIFEST_FILE)?;
+    let manifest: MigrationManifest =
+        serde_json::from_slice(&data).map_err(|e| format!("Failed to parse manifest: {}", e))?;
+    if manifest.format_version > MIGRATION_FORMAT_VERSION {
+        return Err(format!("Archive format {} is newer than this version supports", manifest.format_version));
+    }
+    Ok(manifest)
+}
+
+/// Archive-relative path → path under `root`, refusing anything that climbs out.
+fn safe_join(root: &Path, rel: &str) -> Option<PathBuf> {
+    let rel = Path::new(rel);
+    rel.components().all(|c| matches!(c, std::path::Component::Normal(_))).then(|| root.join(rel))
+}
+
+enum Placed {
+    Written,
+    /// The target already had exactly this content.
+    Unchanged,
+    /// The target differs and `overwrite` was off.
+    Skipped,
+}
+
+/// Write a settings file, keeping a differing original as `*.pre-migration`.
+fn place_file(target: &Path, data: &[u8], overwrite: bool) -> Result<Placed, String> {
+    if target.exists() {
+        if fs::read(target).map(|current| current == data).unwrap_or(false) {
+            return Ok(Placed::Unchanged);
+        }
+        if !overwrite {
+            return Ok(Placed::Skipped);
+        }
+        let mut replaced = target.to_path_buf().into_os_string();
+        replaced.push(REPLACED_SUFFIX);
+        fs::rename(target, &replaced).map_err(|e| format!("Failed to keep a copy of {}: {}", target.display(), e))?;
+    }
+    target
+        .parent()
+        .map(fs::create_dir_all)
+        .unwrap_or(Ok(()))
+        .and_then(|_| fs::write(target, data))
+        .map_err(|e| format!("Failed to write {}: {}", target.display(), e))?;
+    Ok(Placed::Written)
+}
+
+fn install_extensions(ide_id: &str, ids: &[String], result: &mut IdeImportResult) {
+    // The manifest is untrusted input; only well-formed ids reach the CLI
+    let (ids, refused): (Vec<String>, Vec<String>) =
+        ids.iter().cloned().partition(|id| crate::extensions::is_valid_extension_spec(id));
+    for id in refused {
+        result.errors.push(format!("Refused invalid extension id: {}", id));
+    }
+    let ids = &ids;
+    let Some(cli) = crate::extensions::extension_cli(ide_id) else {
+        result.pending_extensions.extend(ids.iter().cloned());
+        return;
+    };
+    let (installed, failed) = crate::extensions::install_with_cli(cli, ids);
+    result.installed_extensions.extend(installed);
+    result.pending_extensions.extend(failed);
+}
+
+fn restore_ide(archive: &mut zip::ZipArchive<fs::File>, migrated: &MigratedIde, ide: &IdeInfo, options: &MigrationImportOptions) -> IdeImportResult {
+    let mut result = IdeImportResult {
+        id: migrated.id.clone(),
+        restored_files: 0,
+        skipped_files: 0,
+        installed_extensions: Vec::new(),
+        pending_extensions: Vec::new(),
+        conversations_dir: None,
+        errors: Vec::new(),
+    };
+
+    // Settings go to the same place the exporter read them from, resolved on this machine
+    match settings_root(ide) {
+        Some((root, names)) => {
+            for rel in &migrated.settings_files {
+                let Some(target) = safe_join(&root, rel) else {
+                    result.errors.push(format!("Skipped unsafe path: {}", rel));
+                    continue;
+                };
+                // Only the entries the exporter reads; the rest of `User/` (workspaceStorage,
+                // globalStorage, ...) isn't settings
+                let top = Path::new(rel).components().next().map(|c| c.as_os_str().to_string_lossy().to_string());
+                if names.is_some_and(|names| !top.is_some_and(|top| names.contains(&top.as_str()))) {
+                    result.errors.push(format!("Skipped non-settings path: {}", rel));
+                    continue;
+                }
+                let data = match read_entry(archive, &format!("ides/{}/settings/{}", migrated.id, rel)) {
+                    Ok(d) => d,
+                    Err(e) => {
+                        result.errors.push(e);
+                        continue;
+                    }
+                };
+                match place_file(&target, &data, options.overwrite_settings) {
+                    Ok(Placed::Written) => result.restored_files += 1,
+                    Ok(Placed::Unchanged) => {}
+                    Ok(Placed::Skipped) => result.skipped_files += 1,
+                    Err(e) => result.errors.push(e),
+                }
+            }
+        }
+        None if !migrated.settings_files.is_empty() => {
+            result.errors.push(format!("{} has no settings folder on this machine", ide.name));
+        }
+        None => {}
+    }
+
+    if options.install_extensions {
+        install_extensions(&migrated.id, &migrated.extensions, &mut result);
+    } else {
+        result.pending_extensions = migrated.extensions.clone();
+    }
+
+    // Conversations can't be written back into another machine's IDE databases;
+    // they're kept as readable JSON in DevCleaner's data folder
+    if migrated.conversations > 0 {
+        let dir = crate::config::get_app_data_dir().join("migrated_conversations").join(&migrated.id);
+        let prefix = format!("ides/{}/conversations/", migrated.id);
+        let names: Vec<String> = archive.file_names().filter(|n| n.starts_with(&prefix)).map(String::from).collect();
+        let copied = fs::create_dir_all(&dir).map_err(|e| e.to_string()).and_then(|_| {
+            for name in &names {
+                let Some(target) = safe_join(&dir, &name[prefix.len()..]) else {
+                    result.errors.push(format!("Skipped unsafe path: {}", name));
+                    continue;
+                };
+                let data = read_entry(archive, name)?;
+                fs::write(target, data).map_err(|e| e.to_string())?;
+            }
+            Ok(())
+        });
+        match copied {
+            Ok(()) => result.conversations_dir = Some(dir),
+            Err(e) => result.errors.push(format!("Failed to save conversations: {}", e)),
+        }
+    }
+
+    result
+}
+
+/// Restore an archive made by `export_migration` onto this machine: settings
+/// into each IDE's settings folder, extensions through the IDE's CLI, the
+/// curation and conversation tags/notes merged into DevCleaner's own settings.
+/// Conversations come back as readable JSON only, not into the IDE.
+pub fn import_migration(path: &Path, options: &MigrationImportOptions) -> Result<MigrationImportSummary, String> {
+    let manifest = read_migration_manifest(path)?;
+    let mut archive = open_archive(path)?;
+    let ides = crate::ide_detector::detect_installed_ides();
+
+    let mut summary =
+        MigrationImportSummary { ides: Vec::new(), skipped_ides: Vec::new(), curation: None, added_annotations: 0 };
+    for migrated in &manifest.ides {
+        if !options.ide_ids.is_empty() && !options.ide_ids.contains(&migrated.id) {
+            continue;
+        }
+        match ides.iter().find(|i| i.id == migrated.id && i.installed) {
+            Some(ide) => summary.ides.push(restore_ide(&mut archive, migrated, ide, options)),
+            None => summary.skipped_ides.push(migrated.id.clone()),
+        }
+    }
+
+    if let Ok(data) = read_entry(&mut archive, CURATION_FILE) {
+        let curation: crate::config::CurationExport =
+            serde_json::from_slice(&data).map_err(|e| format!("Failed to parse curation: {}", e))?;
+        summary.curation = Some(crate::config::merge_curation(curation)?);
+    }
+    if let Ok(data) = read_entry(&mut archive, ANNOTATIONS_FILE) {
+        let annotations = serde_json::from_slice(&data).map_err(|e| format!("Failed to parse annotations: {}", e))?;
+        summary.added_annotations = crate::conversation_notes::merge_annotations(annotations)?;
+    }
+    Ok(summary)
+}
+
+// ── IDE to IDE ──
+
+fn vscode_ide(ide_id: &str) -> Result<IdeInfo, String> {
+    let ide = crate::ide_detector::detect_installed_ides()
+        .into_iter()
+        .find(|i| i.id == ide_id && i.installed)
+        .ok_or_else(|| format!("IDE '{}' not found or not installed", ide_id))?;
+    if ide.ide_type != IdeType::VscodeBased {
+        return Err(format!("{} isn't a VS Code-based IDE", ide.name));
+    }
+    Ok(ide)
+}
+
+/// Source settings files keyed by their path relative to `<config>/User`.
+fn transfer_sources(from: &IdeInfo) -> Result<Vec<(String, PathBuf)>, String> {
+    let (root, files) = settings_files(from).ok_or_else(|| format!("{} has no settings folder", from.name))?;
+    Ok(files
+        .into_iter()
+        .filter_map(|f| {
+            let rel = f.strip_prefix(&root).ok()?.to_string_lossy().replace('\\', "/");
+            Some((rel, f))
+        })
+        .collect())
+}
+
+fn target_root(to: &IdeInfo) -> Result<PathBuf, String> {
+    to.config_path
+        .as_ref()
+        .map(|c| c.join("User"))
+        .ok_or_else(|| format!("{} has no settings folder", to.name))
+}
+
+fn missing_extensions(from: &IdeInfo, to: &IdeInfo) -> Vec<String> {
+    let present = extension_ids(to);
+    extension_ids(from).into_iter().filter(|id| !present.contains(id)).collect()
+}
+
+/// Compare `from`'s settings, keybindings, snippets and profiles with `to`'s
+/// without changing anything.
+pub fn preview_settings_transfer(from_id: &str, to_id: &str) -> Result<SettingsTransferPreview, String> {
+    if from_id == to_id {
+        return Err("Source and target IDE are the same".into());
+    }
+    let from = vscode_ide(from_id)?;
+    let to = vscode_ide(to_id)?;
+    let sources = transfer_sources(&from)?;
+    let target = target_root(&to)?;
+
+    let files = sources
+        .into_iter()
+        .map(|(rel, src)| {
+            let dest = target.join(&rel);
+            let status = if !dest.exists() {
+                TransferStatus::New
+            } else if fs::read(&src).ok() == fs::read(&dest).ok() {
+                TransferStatus::Identical
+            } else {
+                TransferStatus::Conflict
+            };
+            TransferFile { size: fs::metadata(&src).map(|m| m.len()).unwrap_or(0), path: rel, status }
+        })
+        .collect();
+
+    Ok(SettingsTransferPreview {
+        from_id: from.id.clone(),
+        to_id: to.id.clone(),
+        files,
+        missing_extensions: missing_extensions(&from, &to),
+    })
+}
+
+/// Copy settings files from one VS Code-based IDE to another and, optionally,
+/// install the extensions the target is missing.
+pub fn transfer_settings(from_id: &str, to_id: &str, options: &SettingsTransferOptions) -> Result<SettingsTransferResult, String> {
+    if from_id == to_id {
+        return Err("Source and target IDE are the same".into());
+    }
+    let from = vscode_ide(from_id)?;
+    let to = vscode_ide(to_id)?;
+    let sources = transfer_sources(&from)?;
+    let target = target_root(&to)?;
+
+    let mut result = SettingsTransferResult {
+        copied_files: 0,
+        skipped_files: 0,
+        installed_extensions: Vec::new(),
+        pending_extensions: Vec::new(),
+        errors: Vec::new(),
+    };
+    for (rel, src) in sources {
+        if !options.files.is_empty() && !options.files.contains(&rel) {
+            continue;
+        }
+        let outcome = fs::read(&src)
+            .map_err(|e| format!("Failed to read {}: {}", src.display(), e))
+            .and_then(|data| place_file(&target.join(&rel), &data, options.overwrite_conflicts));
+        match outcome {
+            Ok(Placed::Written) => result.copied_files += 1,
+            Ok(Placed::Unchanged) => {}
+            Ok(Placed::Skipped) => result.skipped_files += 1,
+            Err(e) => result.errors.push(e),
+        }
+    }
+
+    if options.install_extensions {
+        let missing = missing_extensions(&from, &to);
+        match crate::extensions::extension_cli(&to.id) {
+            Some(cli) => {
+                let (installed, failed) = crate::extensions::install_with_cli(cli, &missing);
+                result.installed_extensions = installed;
+                result.pending_extensions = failed;
+            }
+            None => result.pending_extensions = missing,
+        }
+    }
+    Ok(result)
+}
@@ -0,0 +1,187 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::batch::{BatchResult, ItemResult};
+use crate::ide_detector::{IdeInfo, IdeType};
+use crate::scanner::CategoryType;
+
+/// A globalStorage/workspaceStorage folder of an extension that is no longer installed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrphanedStorage {
+    pub ide_id: String,
+    pub ide_name: String,
+    /// Lowercase `publisher.name`.
+    pub extension_id: String,
+    /// GlobalStorage or WorkspaceStorage.
+    pub category_type: CategoryType,
+    pub path: PathBuf,
+    pub size: u64,
+}
+
+/// Lowercase ids of every extension in the user extensions folder, from
+/// `extensions.json` and the versioned folder names.
+fn installed_extension_ids(ext_dir: &Path) -> HashSet<String> {
+    let mut ids = HashSet::new();
+    if let Some(list) = fs::read_to_string(ext_dir.join("extensions.json"))
+        .ok()
+        .and_then(|d| serde_json::from_str::<serde_json::Value>(&d).ok())
+    {
+        for item in list.as_array().into_iter().flatten() {
+            if let Some(id) = item.get("identifier").and_then(|i| i.get("id")).and_then(|i| i.as_str()) {
+                ids.insert(id.to_lowercase());
+            }
+        }
+    }
+    if let Ok(entries) = fs::read_dir(ext_dir) {
+        for entry in entries.filter_map(|e| e.ok()).filter(|e| e.path().is_dir()) {
+            let name = entry.file_name().to_string_lossy().to_lowercase();
+            ids.insert(crate::extension_folder::strip_version(&name).to_string());
+        }
+    }
+    ids
+}
+
+/// Lowercase ids of the extensions shipped with the IDE itself
+/// (`resources/app/extensions/*/package.json`). They never show up in the user
+/// extensions folder but do keep storage.
+fn builtin_extension_ids(install_dirs: &[PathBuf]) -> HashSet<String> {
+    let mut ids = HashSet::new();
+    let roots = install_dirs.iter().flat_map(|d| {
+        [d.join("resources").join("app").join("extensions"), d.join("Contents").join("Resources").join("app").join("extensions")]
+    });
+    for ext_dir in roots.filter(|p| p.is_dir()) {
+        for dir in child_dirs(&ext_dir) {
+            let Some(manifest) = fs::read_to_string(dir.join("package.json"))
+                .ok()
+                .and_then(|d| serde_json::from_str::<serde_json::Value>(&d).ok())
+            else {
+                continue;
+            };
+            let field = |key: &str| manifest.get(key).and_then(|v| v.as_str()).unwrap_or_default().to_lowercase();
+            let (publisher, name) = (field("publisher"), field("name"));
+            if !publisher.is_empty() && !name.is_empty() {
+                ids.insert(format!("{}.{}", publisher, name));
+            }
+        }
+    }
+    ids
+}
+
+/// `publisher.name` folders only; skips editor-owned folders.
+fn extension_id_of(folder: &Path) -> Option<String> {
+    if !folder.is_dir() {
+        return None;
+    }
+    let name = folder.file_name()?.to_string_lossy().to_lowercase();
+    let (publisher, ext) = name.split_once('.')?;
+    if publisher.is_empty() || ext.is_empty() {
+        return None;
+    }
+    Some(name)
+}
+
+fn child_dirs(dir: &Path) -> Vec<PathBuf> {
+    fs::read_dir(dir)
+        .map(|entries| entries.filter_map(|e| e.ok()).map(|e| e.path()).filter(|p| p.is_dir()).collect())
+        .unwrap_or_default()
+}
+
+fn dir_size(path: &Path) -> u64 {
+    walkdir::WalkDir::new(path)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| e.metadata().ok())
+        .map(|m| m.len())
+        .sum()
+}
+
+/// Storage folders of uninstalled extensions for every VS Code-based IDE.
+/// IDEs without an extensions folder or a located install (for the built-in
+/// extensions) are skipped, since nothing can be cross-checked.
+pub fn find_orphaned_storage(ides: &[IdeInfo]) -> Vec<OrphanedStorage> {
+    let mut results = Vec::new();
+    for ide in ides.iter().filter(|i| i.installed && i.ide_type == IdeType::VscodeBased) {
+        let Some(ext_dir) = ide.extension_path.as_ref().filter(|p| p.is_dir()) else { continue };
+        let builtin = builtin_extension_ids(&crate::ide_detector::vscode_install_dirs(ide));
+        if builtin.is_empty() {
+            continue;
+        }
+        let mut installed = installed_extension_ids(ext_dir);
+        installed.extend(builtin);
+
+        let global = ide.global_storage_path.iter().flat_map(|gs| child_dirs(gs)).map(|p| (CategoryType::GlobalStorage, p));
+        // workspaceStorage/<workspace hash>/<publisher.name>
+        let workspace = ide
+            .workspace_storage_path
+            .iter()
+            .flat_map(|ws| child_dirs(ws))
+            .flat_map(|hash_dir| child_dirs(&hash_dir))
+            .map(|p| (CategoryType::WorkspaceStorage, p));
+
+        for (category_type, path) in global.chain(workspace) {
+            let Some(extension_id) = extension_id_of(&path) else { continue };
+            if installed.contains(&extension_id) {
+                continue;
+            }
+            let size = dir_size(&path);
+            results.push(OrphanedStorage {
+                ide_id: ide.id.clone(),
+                ide_name: ide.name.clone(),
+                extension_id,
+                category_type,
+                path,
+                size,
+            });
+        }
+    }
+    results.sort_by(|a, b| b.size.cmp(&a.size));
+    results
+}
+
+/// Delete the given orphaned folders. Paths that are no longer reported as
+/// orphaned (e.g. the extension was reinstalled since) are refused.
+pub fn clean_orphaned_storage(ides: &[IdeInfo], paths: &[PathBuf], create_backup: bool) -> BatchResult {
+    let orphans = find_orphaned_storage(ides);
+    let protected = crate::config::load_config().protected_conversations;
+
+    let mut failed_paths = Vec::new();
+    let items = paths
+        .iter()
+        .map(|path| {
+            let id = path.display().to_string();
+            let Some(orphan) = orphans.iter().find(|o| o.path == *path) else {
+                return if path.exists() {
+                    ItemResult::skipped(id, "Not orphaned extension storage")
+                } else {
+                    ItemResult::not_found(id)
+                };
+            };
+            if let Some(conv) = crate::conversation::protected_conversation_under(&protected, path) {
+                return ItemResult::skipped(id, format!("Holds protected conversation {}", conv.id));
+            }
+            if create_backup {
+                let ide = ides.iter().find(|i| i.id == orphan.ide_id).expect("orphan comes from ides");
+                if let Err(e) = crate::backup::create_backup(ide, std::slice::from_ref(path)) {
+                    return ItemResult::failed(id, format!("Backup failed, not deleted: {}", e));
+                }
+            }
+
+            let (freed, _, mut errors) = crate::cleaner::clean_directory(path);
+            if let Err(e) = crate::cleaner::remove_tree(path) {
+                if path.exists() {
+                    errors.push(format!("{}: {}", path.display(), e));
+                }
+            }
+            if !errors.is_empty() {
+                failed_paths.push(path.clone());
+            }
+            ItemResult::from_errors(id, freed, errors)
+        })
+        .collect();
+    let mut result = BatchResult::from_items(items);
+    result.failed_paths = failed_paths;
+    result
+}
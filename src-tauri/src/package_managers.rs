@@ -0,0 +1,180 @@
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Windows package managers whose installs often never reach the Uninstall registry keys.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum PackageManager {
+    Scoop,
+    Chocolatey,
+}
+
+#[derive(Debug, Clone)]
+pub struct PackageInstall {
+    pub manager: PackageManager,
+    pub package: String,
+    pub version: String,
+    /// Scoop: `apps/<app>/<version>`; Chocolatey: `lib/<package>`.
+    pub install_dir: PathBuf,
+    /// Scoop `--global` install (under ProgramData).
+    pub global: bool,
+}
+
+impl PackageInstall {
+    /// The package manager's own uninstall, which also drops its bookkeeping.
+    pub fn uninstall_command(&self) -> String {
+        match self.manager {
+            PackageManager::Scoop if self.global => format!("scoop uninstall --global {}", self.package),
+            PackageManager::Scoop => format!("scoop uninstall {}", self.package),
+            PackageManager::Chocolatey => format!("choco uninstall {} -y", self.package),
+        }
+    }
+
+    /// Still installed according to the package manager's folders.
+    pub fn exists(&self) -> bool {
+        self.install_dir.is_dir()
+    }
+}
+
+/// Package names of IDEs in the Scoop buckets and the Chocolatey community feed.
+const PACKAGE_IDE_IDS: &[(&str, &str)] = &[
+    ("vscode", "vscode"),
+    ("vscodium", "vscodium"),
+    ("cursor", "cursor"),
+    ("windsurf", "windsurf"),
+    ("kiro", "kiro"),
+    ("positron", "positron"),
+    ("zed", "zed"),
+    ("neovim", "neovim"),
+    ("vim", "vim"),
+    ("gvim", "vim"),
+    ("eclipse", "eclipse"),
+    ("eclipse-java", "eclipse"),
+    ("eclipse-jee", "eclipse"),
+    ("idea", "intellij"),
+    ("idea-ultimate", "intellij"),
+    ("intellijidea-community", "intellij"),
+    ("intellijidea-ultimate", "intellij"),
+    ("pycharm", "pycharm"),
+    ("pycharm-professional", "pycharm"),
+    ("pycharm-community", "pycharm"),
+    ("webstorm", "webstorm"),
+    ("goland", "goland"),
+    ("clion", "clion"),
+    ("rider", "rider"),
+    ("jetbrains-rider", "rider"),
+    ("phpstorm", "phpstorm"),
+    ("rubymine", "rubymine"),
+    ("datagrip", "datagrip"),
+    ("rustrover", "rustrover"),
+    ("dataspell", "dataspell"),
+    ("android-studio", "android_studio"),
+    ("androidstudio", "android_studio"),
+];
+
+/// IDE id for a Scoop app / Chocolatey package name.
+pub fn package_ide_id(package: &str) -> Option<&'static str> {
+    let lower = package.to_lowercase();
+    // Chocolatey ".install"/".portable" variants share the base package's IDE
+    let base = lower.trim_end_matches(".install").trim_end_matches(".portable");
+    PACKAGE_IDE_IDS.iter().find(|(name, _)| *name == base).map(|(_, id)| *id)
+}
+
+/// Scoop roots: `$SCOOP` (default `~/scoop`) and `$SCOOP_GLOBAL` (default `ProgramData\scoop`).
+pub fn scoop_roots() -> Vec<(PathBuf, bool)> {
+    let mut roots = Vec::new();
+    let user = std::env::var_os("SCOOP")
+        .map(PathBuf::from)
+        .or_else(|| dirs::home_dir().map(|h| h.join("scoop")));
+    if let Some(p) = user.filter(|p| p.join("apps").is_dir()) {
+        roots.push((p, false));
+    }
+    let global = std::env::var_os("SCOOP_GLOBAL")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("ProgramData").map(|d| PathBuf::from(d).join("scoop")));
+    if let Some(p) = global.filter(|p| p.join("apps").is_dir()) {
+        if !roots.iter().any(|(r, _)| r == &p) {
+            roots.push((p, true));
+        }
+    }
+    roots
+}
+
+/// `$ChocolateyInstall`, defaulting to `ProgramData\chocolatey`.
+pub fn chocolatey_root() -> Option<PathBuf> {
+    std::env::var_os("ChocolateyInstall")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("ProgramData").map(|d| PathBuf::from(d).join("chocolatey")))
+        .filter(|p| p.join("lib").is_dir())
+}
+
+fn scoop_manifest_version(app_dir: &Path) -> Option<String> {
+    let data = std::fs::read_to_string(app_dir.join("current").join("manifest.json")).ok()?;
+    let json: serde_json::Value = serde_json::from_str(&data).ok()?;
+    json.get("version")?.as_str().map(str::to_string)
+}
+
+/// `<version>` from a .nuspec, without pulling in an XML parser.
+fn nuspec_version(nuspec: &Path) -> Option<String> {
+    let data = std::fs::read_to_string(nuspec).ok()?;
+    let start = data.find("<version>")? + "<version>".len();
+    let end = start + data[start..].find("</version>")?;
+    Some(data[start..end].trim().to_string())
+}
+
+/// Scoop apps that map to a known IDE.
+pub fn scoop_installs() -> Vec<PackageInstall> {
+    let mut installs = Vec::new();
+    for (root, global) in scoop_roots() {
+        let Ok(entries) = std::fs::read_dir(root.join("apps")) else { continue };
+        for entry in entries.filter_map(|e| e.ok()) {
+            let package = entry.file_name().to_string_lossy().to_string();
+            if package_ide_id(&package).is_none() { continue; }
+            let app_dir = entry.path();
+            let version = scoop_manifest_version(&app_dir).unwrap_or_default();
+            // `current` is a junction; sizes are taken from the real version folder
+            let install_dir = Some(app_dir.join(&version))
+                .filter(|p| !version.is_empty() && p.is_dir())
+                .unwrap_or_else(|| app_dir.join("current"));
+            installs.push(PackageInstall { manager: PackageManager::Scoop, package, version, install_dir, global });
+        }
+    }
+    installs
+}
+
+/// Chocolatey packages that map to a known IDE.
+pub fn chocolatey_installs() -> Vec<PackageInstall> {
+    let Some(root) = chocolatey_root() else { return Vec::new() };
+    let Ok(entries) = std::fs::read_dir(root.join("lib")) else { return Vec::new() };
+    entries
+        .filter_map(|e| e.ok())
+        .filter_map(|entry| {
+            let package = entry.file_name().to_string_lossy().to_string();
+            package_ide_id(&package)?;
+            let install_dir = entry.path();
+            let version = nuspec_version(&install_dir.join(format!("{}.nuspec", package))).unwrap_or_default();
+            Some(PackageInstall { manager: PackageManager::Chocolatey, package, version, install_dir, global: true })
+        })
+        .collect()
+}
+
+/// Scoop and Chocolatey IDE installs.
+pub fn package_installs() -> Vec<PackageInstall> {
+    let mut installs = scoop_installs();
+    installs.extend(chocolatey_installs());
+    installs
+}
+
+/// Registry-key stand-in for a package install ("scoop:vscode", "choco:pycharm").
+pub fn package_key(install: &PackageInstall) -> String {
+    let prefix = match install.manager {
+        PackageManager::Scoop if install.global => "scoop-global",
+        PackageManager::Scoop => "scoop",
+        PackageManager::Chocolatey => "choco",
+    };
+    format!("{}:{}", prefix, install.package)
+}
+
+/// The install a `package_key` refers to, if it's still present.
+pub fn find_by_key(key: &str) -> Option<PackageInstall> {
+    package_installs().into_iter().find(|i| package_key(i) == key)
+}
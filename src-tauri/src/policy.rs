@@ -0,0 +1,110 @@
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+
+/// How often the watcher re-reads the policy file / registry value.
+const WATCH_INTERVAL: Duration = Duration::from_secs(5);
+/// Event sent to the webview whenever the policy changes.
+pub const POLICY_CHANGED_EVENT: &str = "policy-changed";
+
+/// Administrator kill-switch. While `destructive_disabled` is set every
+/// deleting/cleaning/uninstalling command is refused; scans keep working.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PolicyStatus {
+    pub destructive_disabled: bool,
+    /// Message from the administrator, if the policy carries one.
+    pub reason: Option<String>,
+    /// Where the policy was found: the file path or the registry key.
+    pub source: Option<String>,
+}
+
+/// Machine-wide policy file; its mere presence disables destructive operations.
+/// Optional content: `{ "reason": "..." }` or plain text.
+pub fn policy_file_path() -> PathBuf {
+    if cfg!(target_os = "windows") {
+        let program_data = std::env::var_os("ProgramData").map(PathBuf::from).unwrap_or_else(|| PathBuf::from(r"C:\ProgramData"));
+        program_data.join("DevCleaner").join("policy.json")
+    } else if cfg!(target_os = "macos") {
+        PathBuf::from("/Library/Application Support/DevCleaner/policy.json")
+    } else {
+        PathBuf::from("/etc/devcleaner/policy.json")
+    }
+}
+
+fn file_policy() -> Option<PolicyStatus> {
+    let path = policy_file_path();
+    if !path.is_file() {
+        return None;
+    }
+    // An unreadable or malformed file still counts: the switch fails closed
+    let data = std::fs::read_to_string(&path).unwrap_or_default();
+    let reason = match serde_json::from_str::<serde_json::Value>(&data) {
+        Ok(json) => json.get("reason").and_then(|r| r.as_str()).map(str::to_string),
+        Err(_) => Some(data.trim().to_string()),
+    };
+    Some(PolicyStatus {
+        destructive_disabled: true,
+        reason: reason.filter(|r| !r.is_empty()),
+        source: Some(path.display().to_string()),
+    })
+}
+
+/// `HKLM\SOFTWARE\Policies\DevCleaner`: DWORD `DisableDestructiveOperations`
+/// (non-zero = disabled) and an optional `Reason` string, as set by Group Policy.
+#[cfg(target_os = "windows")]
+fn registry_policy() -> Option<PolicyStatus> {
+    use winreg::enums::*;
+    use winreg::RegKey;
+
+    const KEY: &str = r"SOFTWARE\Policies\DevCleaner";
+    let key = RegKey::predef(HKEY_LOCAL_MACHINE).open_subkey(KEY).ok()?;
+    let disabled: u32 = key.get_value("DisableDestructiveOperations").ok()?;
+    if disabled == 0 {
+        return None;
+    }
+    let reason: Option<String> = key.get_value("Reason").ok();
+    Some(PolicyStatus {
+        destructive_disabled: true,
+        reason: reason.filter(|r| !r.is_empty()),
+        source: Some(format!(r"HKLM\{}", KEY)),
+    })
+}
+
+#[cfg(not(target_os = "windows"))]
+fn registry_policy() -> Option<PolicyStatus> {
+    None
+}
+
+/// Current policy, read fresh so a switch dropped in at runtime applies immediately.
+pub fn current_policy() -> PolicyStatus {
+    registry_policy().or_else(file_policy).unwrap_or_default()
+}
+
+/// Guard for every destructive command.
+pub fn ensure_destructive_allowed() -> Result<(), String> {
+    let policy = current_policy();
+    if !policy.destructive_disabled {
+        return Ok(());
+    }
+    let source = policy.source.unwrap_or_default();
+    match policy.reason {
+        Some(reason) => Err(format!("Disabled by administrator policy ({}): {}", source, reason)),
+        None => Err(format!("Disabled by administrator policy ({})", source)),
+    }
+}
+
+/// Poll the policy in the background and tell the webview when it changes.
+pub fn watch(app: AppHandle) {
+    std::thread::spawn(move || {
+        let mut last = current_policy();
+        loop {
+            std::thread::sleep(WATCH_INTERVAL);
+            let now = current_policy();
+            if now != last {
+                let _ = app.emit(POLICY_CHANGED_EVENT, &now);
+                last = now;
+            }
+        }
+    });
+}
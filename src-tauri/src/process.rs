@@ -0,0 +1,16 @@
+use std::ffi::OsStr;
+use std::process::Command;
+
+/// `Command` for a console program (reg, docker, ssh, tasklist, ...) that doesn't
+/// flash a console window on Windows, where a GUI app's children get one otherwise.
+pub fn hidden_command(program: impl AsRef<OsStr>) -> Command {
+    #[allow(unused_mut)]
+    let mut cmd = Command::new(program);
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+        cmd.creation_flags(CREATE_NO_WINDOW);
+    }
+    cmd
+}
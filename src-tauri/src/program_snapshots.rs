@@ -0,0 +1,146 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+use crate::uninstaller::UninstallEntry;
+
+/// Installed-programs list captured before/after an uninstall session.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProgramsSnapshot {
+    pub id: String,
+    pub label: String,
+    pub timestamp: String,
+    pub programs: Vec<UninstallEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProgramsSnapshotInfo {
+    pub id: String,
+    pub label: String,
+    pub timestamp: String,
+    pub program_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProgramsDiff {
+    pub before_id: String,
+    pub after_id: String,
+    /// Entries that disappeared between the two snapshots.
+    pub removed: Vec<UninstallEntry>,
+    pub added: Vec<UninstallEntry>,
+    /// Still-installed entries from the same publisher as something removed
+    /// (updaters, helper services and other bundled components).
+    pub leftover_related: Vec<UninstallEntry>,
+}
+
+fn get_snapshots_dir() -> PathBuf {
+    crate::config::get_app_data_dir().join("program_snapshots")
+}
+
+/// Ids are generated timestamps; anything else could escape the snapshot dir.
+fn is_valid_id(id: &str) -> bool {
+    !id.is_empty() && id.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+fn snapshot_path(id: &str) -> PathBuf {
+    get_snapshots_dir().join(format!("{}.json", id))
+}
+
+fn load_snapshot(id: &str) -> Result<ProgramsSnapshot, String> {
+    if !is_valid_id(id) {
+        return Err(format!("Invalid snapshot id '{}'", id));
+    }
+    let data = fs::read_to_string(snapshot_path(id))
+        .map_err(|e| format!("Failed to read snapshot '{}': {}", id, e))?;
+    serde_json::from_str(&data).map_err(|e| format!("Invalid snapshot '{}': {}", id, e))
+}
+
+/// Capture the current installed-programs list and save it.
+pub fn take_snapshot(label: &str) -> Result<ProgramsSnapshot, String> {
+    let dir = get_snapshots_dir();
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to create snapshot dir: {}", e))?;
+
+    let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S").to_string();
+    let mut id = timestamp.clone();
+    let mut n = 2;
+    while snapshot_path(&id).exists() {
+        id = format!("{}_{}", timestamp, n);
+        n += 1;
+    }
+
+    let snapshot = ProgramsSnapshot {
+        id,
+        label: label.to_string(),
+        timestamp,
+        programs: crate::uninstaller::list_uninstall_entries(),
+    };
+    let data = serde_json::to_string_pretty(&snapshot).map_err(|e| format!("Failed to serialize snapshot: {}", e))?;
+    fs::write(snapshot_path(&snapshot.id), data).map_err(|e| format!("Failed to write snapshot: {}", e))?;
+    Ok(snapshot)
+}
+
+pub fn list_snapshots() -> Vec<ProgramsSnapshotInfo> {
+    let mut list: Vec<ProgramsSnapshotInfo> = fs::read_dir(get_snapshots_dir())
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .filter_map(|e| {
+                    let id = e.path().file_stem()?.to_string_lossy().to_string();
+                    load_snapshot(&id).ok()
+                })
+                .map(|s| ProgramsSnapshotInfo {
+                    id: s.id,
+                    label: s.label,
+                    timestamp: s.timestamp,
+                    program_count: s.programs.len(),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    list.sort_by(|a, b| b.id.cmp(&a.id));
+    list
+}
+
+pub fn delete_snapshot(id: &str) -> Result<(), String> {
+    if !is_valid_id(id) {
+        return Err(format!("Invalid snapshot id '{}'", id));
+    }
+    fs::remove_file(snapshot_path(id)).map_err(|e| format!("Failed to delete snapshot '{}': {}", id, e))
+}
+
+/// Compare two snapshots. Without `after_id`, a fresh "after" snapshot is taken now.
+pub fn diff_snapshots(before_id: &str, after_id: Option<&str>) -> Result<ProgramsDiff, String> {
+    let before = load_snapshot(before_id)?;
+    let after = match after_id {
+        Some(id) => load_snapshot(id)?,
+        None => take_snapshot(&format!("After {}", before.label))?,
+    };
+
+    let removed: Vec<UninstallEntry> = before
+        .programs
+        .iter()
+        .filter(|p| !after.programs.iter().any(|a| a.registry_key == p.registry_key))
+        .cloned()
+        .collect();
+    let added: Vec<UninstallEntry> = after
+        .programs
+        .iter()
+        .filter(|a| !before.programs.iter().any(|p| p.registry_key == a.registry_key))
+        .cloned()
+        .collect();
+    let leftover_related: Vec<UninstallEntry> = after
+        .programs
+        .iter()
+        .filter(|a| !a.publisher.is_empty())
+        .filter(|a| removed.iter().any(|r| r.publisher == a.publisher))
+        .cloned()
+        .collect();
+
+    Ok(ProgramsDiff {
+        before_id: before.id,
+        after_id: after.id,
+        removed,
+        added,
+        leftover_related,
+    })
+}
@@ -0,0 +1,322 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use walkdir::WalkDir;
+
+use crate::batch::{BatchResult, ItemResult};
+
+/// How deep below a project root to look for projects.
+pub(crate) const MAX_SEARCH_DEPTH: usize = 6;
+/// Folders never descended into while searching for projects.
+pub(crate) const SKIPPED_DIRS: &[&str] = &["node_modules", ".git", ".hg", ".svn"];
+const SECS_PER_DAY: i64 = 86_400;
+
+/// A Cargo `target/` directory next to a `Cargo.toml`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RustTarget {
+    /// `[package] name` from Cargo.toml, or the folder name for workspaces.
+    pub crate_name: String,
+    pub project_path: PathBuf,
+    pub target_path: PathBuf,
+    pub size: u64,
+    /// Newest file mtime inside `target/` (epoch seconds): the last build.
+    pub last_modified: Option<i64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PythonArtifactKind {
+    PyCache,
+    PytestCache,
+    MypyCache,
+    /// A virtualenv: `pyvenv.cfg` next to `bin/` (or `Scripts/`) and `lib/`.
+    Venv,
+}
+
+/// All artifacts of one kind inside a Python project.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PythonArtifactGroup {
+    pub kind: PythonArtifactKind,
+    pub paths: Vec<PathBuf>,
+    pub size: u64,
+    /// Newest file mtime across the group (epoch seconds). For a virtualenv
+    /// that's its last install or first import of a package.
+    pub last_modified: Option<i64>,
+    /// Virtualenvs only: untouched for longer than the requested days.
+    pub stale: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PythonProject {
+    /// Folder name of the project.
+    pub name: String,
+    pub project_path: PathBuf,
+    pub groups: Vec<PythonArtifactGroup>,
+    pub size: u64,
+}
+
+/// `[package] name = "..."` without pulling in a TOML parser.
+fn cargo_package_name(manifest: &Path) -> Option<String> {
+    let data = std::fs::read_to_string(manifest).ok()?;
+    let mut in_package = false;
+    for line in data.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_package = line == "[package]";
+            continue;
+        }
+        if !in_package { continue; }
+        if let Some((key, value)) = line.split_once('=') {
+            if key.trim() == "name" {
+                return Some(value.trim().trim_matches('"').trim_matches('\'').to_string());
+            }
+        }
+    }
+    None
+}
+
+/// A `target` folder that cargo created: sits next to Cargo.toml and carries
+/// cargo's CACHEDIR.TAG or its rustc fingerprint file.
+fn is_cargo_target(dir: &Path) -> bool {
+    dir.file_name().map(|n| n == "target").unwrap_or(false)
+        && dir.parent().map(|p| p.join("Cargo.toml").is_file()).unwrap_or(false)
+        && (dir.join("CACHEDIR.TAG").is_file() || dir.join(".rustc_info.json").is_file())
+}
+
+fn size_and_newest(dir: &Path) -> (u64, Option<i64>) {
+    let mut size = 0;
+    let mut newest: Option<SystemTime> = None;
+    for meta in WalkDir::new(dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| e.metadata().ok())
+    {
+        size += meta.len();
+        if let Ok(m) = meta.modified() {
+            newest = newest.max(Some(m));
+        }
+    }
+    let newest = newest
+        .and_then(|t| t.duration_since(SystemTime::UNIX_EPOCH).ok())
+        .map(|d| d.as_secs() as i64);
+    (size, newest)
+}
+
+/// Every Cargo `target/` under `roots`, largest first.
+pub fn find_rust_targets(roots: &[PathBuf]) -> Vec<RustTarget> {
+    let mut targets: Vec<PathBuf> = Vec::new();
+    for root in roots {
+        let mut walker = WalkDir::new(root).max_depth(MAX_SEARCH_DEPTH).into_iter();
+        while let Some(entry) = walker.next() {
+            let Ok(entry) = entry else { continue };
+            if !entry.file_type().is_dir() { continue; }
+            let name = entry.file_name().to_string_lossy();
+            if SKIPPED_DIRS.contains(&name.as_ref()) || crate::cleaner::has_ignore_marker(entry.path()) {
+                walker.skip_current_dir();
+                continue;
+            }
+            if !is_cargo_target(entry.path()) { continue; }
+            walker.skip_current_dir();
+
+            targets.push(entry.into_path());
+        }
+    }
+    drop_nested(&mut targets, |t| t.as_path());
+
+    let mut results: Vec<RustTarget> = targets
+        .into_iter()
+        .map(|target_path| {
+            let project_path = target_path.parent().map(Path::to_path_buf).unwrap_or_default();
+            let crate_name = cargo_package_name(&project_path.join("Cargo.toml")).unwrap_or_else(|| {
+                project_path.file_name().unwrap_or_default().to_string_lossy().to_string()
+            });
+            let (size, last_modified) = size_and_newest(&target_path);
+            RustTarget { crate_name, project_path, target_path, size, last_modified }
+        })
+        .collect();
+    results.sort_by(|a, b| b.size.cmp(&a.size));
+    results
+}
+
+/// Targets not built for more than `days`, oldest first. A target with no files
+/// has no build time and is never considered stale.
+pub fn stale_rust_targets(roots: &[PathBuf], days: u32) -> Vec<RustTarget> {
+    let cutoff = chrono::Utc::now().timestamp() - days as i64 * SECS_PER_DAY;
+    let mut stale: Vec<RustTarget> = find_rust_targets(roots)
+        .into_iter()
+        .filter(|t| t.last_modified.map(|m| m < cutoff).unwrap_or(false))
+        .collect();
+    stale.sort_by_key(|t| t.last_modified);
+    stale
+}
+
+// ── Python ──
+
+/// Files that mark the root of a Python project.
+const PYTHON_PROJECT_MARKERS: &[&str] = &["pyproject.toml", "setup.py", "setup.cfg", "requirements.txt", "Pipfile"];
+
+fn python_artifact_kind(dir: &Path) -> Option<PythonArtifactKind> {
+    match dir.file_name()?.to_str()? {
+        "__pycache__" => Some(PythonArtifactKind::PyCache),
+        ".pytest_cache" => Some(PythonArtifactKind::PytestCache),
+        ".mypy_cache" => Some(PythonArtifactKind::MypyCache),
+        _ if is_venv(dir) => Some(PythonArtifactKind::Venv),
+        _ => None,
+    }
+}
+
+/// A stray `pyvenv.cfg` in a project root isn't enough: the folder must also
+/// have the interpreter and site-packages folders a virtualenv creates.
+fn is_venv(dir: &Path) -> bool {
+    dir.join("pyvenv.cfg").is_file()
+        && ["bin", "Scripts"].iter().any(|d| dir.join(d).is_dir())
+        && ["lib", "Lib"].iter().any(|d| dir.join(d).is_dir())
+}
+
+/// Sort by path and drop entries equal to or inside an earlier one (roots that
+/// overlap report the same folders twice). Descendants sort right after their
+/// ancestor, so one pass against the last kept entry is enough.
+fn drop_nested<T>(items: &mut Vec<T>, path: impl Fn(&T) -> &Path) {
+    items.sort_by(|a, b| path(a).cmp(path(b)));
+    let mut kept: Vec<T> = Vec::with_capacity(items.len());
+    for item in items.drain(..) {
+        if kept.last().map(|k| path(&item).starts_with(path(k))).unwrap_or(false) {
+            continue;
+        }
+        kept.push(item);
+    }
+    *items = kept;
+}
+
+/// Nearest folder at or above `dir` (but not above `root`) with a project marker,
+/// else `dir` itself.
+fn python_project_of(dir: &Path, root: &Path) -> PathBuf {
+    dir.ancestors()
+        .take_while(|a| a.starts_with(root))
+        .find(|a| PYTHON_PROJECT_MARKERS.iter().any(|m| a.join(m).is_file()))
+        .unwrap_or(dir)
+        .to_path_buf()
+}
+
+/// `__pycache__`, `.pytest_cache`, `.mypy_cache` and virtualenvs under `roots`,
+/// grouped per project, largest project first. Virtualenvs untouched for
+/// `stale_days` are marked stale.
+pub fn find_python_artifacts(roots: &[PathBuf], stale_days: u32) -> Vec<PythonProject> {
+    let cutoff = chrono::Utc::now().timestamp() - stale_days as i64 * SECS_PER_DAY;
+    let mut found: Vec<(PathBuf, PythonArtifactKind, PathBuf)> = Vec::new();
+    for root in roots {
+        let mut walker = WalkDir::new(root).max_depth(MAX_SEARCH_DEPTH).into_iter();
+        while let Some(entry) = walker.next() {
+            let Ok(entry) = entry else { continue };
+            if !entry.file_type().is_dir() { continue; }
+            let name = entry.file_name().to_string_lossy();
+            if SKIPPED_DIRS.contains(&name.as_ref()) || crate::cleaner::has_ignore_marker(entry.path()) {
+                walker.skip_current_dir();
+                continue;
+            }
+            let Some(kind) = python_artifact_kind(entry.path()) else { continue };
+            walker.skip_current_dir();
+
+            let path = entry.into_path();
+            let parent = path.parent().unwrap_or(root);
+            found.push((path.clone(), kind, python_project_of(parent, root)));
+        }
+    }
+
+    drop_nested(&mut found, |(p, _, _)| p.as_path());
+
+    let mut projects: Vec<PythonProject> = Vec::new();
+    let mut project_index: HashMap<PathBuf, usize> = HashMap::new();
+    for (path, kind, project_path) in found {
+        let (size, last_modified) = size_and_newest(&path);
+        let i = *project_index.entry(project_path.clone()).or_insert_with(|| {
+            let name = project_path.file_name().unwrap_or_default().to_string_lossy().to_string();
+            projects.push(PythonProject { name, project_path, groups: Vec::new(), size: 0 });
+            projects.len() - 1
+        });
+        let project = &mut projects[i];
+        project.size += size;
+        let group = match project.groups.iter_mut().position(|g| g.kind == kind) {
+            Some(i) => &mut project.groups[i],
+            None => {
+                project.groups.push(PythonArtifactGroup { kind, paths: Vec::new(), size: 0, last_modified: None, stale: false });
+                project.groups.last_mut().unwrap()
+            }
+        };
+        group.paths.push(path);
+        group.size += size;
+        group.last_modified = group.last_modified.max(last_modified);
+    }
+    for project in &mut projects {
+        for group in &mut project.groups {
+            group.stale = group.kind == PythonArtifactKind::Venv
+                && group.last_modified.map(|m| m < cutoff).unwrap_or(false);
+        }
+        project.groups.sort_by(|a, b| b.size.cmp(&a.size));
+    }
+    projects.sort_by(|a, b| b.size.cmp(&a.size));
+    projects
+}
+
+/// Delete the given Python cache folders and virtualenvs. Anything else is refused.
+pub fn clean_python_artifacts(paths: &[PathBuf]) -> BatchResult {
+    let mut failed_paths = Vec::new();
+    let items = paths
+        .iter()
+        .map(|path| {
+            let id = path.display().to_string();
+            if python_artifact_kind(path).is_none() {
+                return if path.exists() {
+                    ItemResult::skipped(id, "Not a Python cache or virtualenv")
+                } else {
+                    ItemResult::not_found(id)
+                };
+            }
+            let (freed, _, mut errors) = crate::cleaner::clean_directory(path);
+            if let Err(e) = crate::cleaner::remove_tree(path) {
+                if path.exists() {
+                    errors.push(format!("{}: {}", path.display(), e));
+                }
+            }
+            if !errors.is_empty() {
+                failed_paths.push(path.clone());
+            }
+            ItemResult::from_errors(id, freed, errors)
+        })
+        .collect();
+    let mut result = BatchResult::from_items(items);
+    result.failed_paths = failed_paths;
+    result
+}
+
+/// Delete the given `target/` folders. Anything that isn't a cargo target
+/// directory is refused, whatever path the caller passes.
+pub fn clean_rust_targets(paths: &[PathBuf]) -> BatchResult {
+    let mut failed_paths = Vec::new();
+    let items = paths
+        .iter()
+        .map(|path| {
+            let id = path.display().to_string();
+            if !is_cargo_target(path) {
+                return if path.exists() {
+                    ItemResult::skipped(id, "Not a Cargo target directory")
+                } else {
+                    ItemResult::not_found(id)
+                };
+            }
+            let (freed, _, mut errors) = crate::cleaner::clean_directory(path);
+            if let Err(e) = crate::cleaner::remove_tree(path) {
+                if path.exists() {
+                    errors.push(format!("{}: {}", path.display(), e));
+                }
+            }
+            if !errors.is_empty() {
+                failed_paths.push(path.clone());
+            }
+            ItemResult::from_errors(id, freed, errors)
+        })
+        .collect();
+    let mut result = BatchResult::from_items(items);
+    result.failed_paths = failed_paths;
+    result
+}
@@ -0,0 +1,140 @@
+//! Schema-less protobuf wire-format reader, for IDE stores whose `.proto`
+//! files aren't published. Length-delimited fields are classified as text or
+//! nested messages by shape, so results are best effort.
+
+const MAX_DEPTH: usize = 32;
+/// Unix seconds accepted as a `google.protobuf.Timestamp`: 2000-01-01 to 2100-01-01.
+const TIMESTAMP_RANGE: std::ops::Range<u64> = 946_684_800..4_102_444_800;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Node<'a> {
+    Varint(u64),
+    Fixed(u64),
+    Text(&'a str),
+    Message(Vec<Field<'a>>),
+    /// Length-delimited data that is neither text nor a well-formed message.
+    Bytes(&'a [u8]),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field<'a> {
+    pub number: u64,
+    pub node: Node<'a>,
+}
+
+fn read_varint(buf: &[u8], pos: &mut usize) -> Option<u64> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let byte = *buf.get(*pos)?;
+        *pos += 1;
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+    None
+}
+
+fn read_fixed(buf: &[u8], pos: &mut usize, len: usize) -> Option<u64> {
+    let bytes = buf.get(*pos..*pos + len)?;
+    *pos += len;
+    Some(bytes.iter().rev().fold(0u64, |acc, b| (acc << 8) | u64::from(*b)))
+}
+
+/// Valid UTF-8 without control characters other than tabs and line breaks.
+fn as_text(bytes: &[u8]) -> Option<&str> {
+    let text = std::str::from_utf8(bytes).ok()?;
+    let readable = !text.is_empty() && text.chars().all(|c| !c.is_control() || matches!(c, '\n' | '\r' | '\t'));
+    readable.then_some(text)
+}
+
+fn classify(bytes: &[u8], depth: usize) -> Node<'_> {
+    // Tags of fields 1-3 are control bytes, so text that starts printable is taken as text
+    if bytes.first().is_some_and(|b| *b >= 0x20) {
+        if let Some(text) = as_text(bytes) {
+            return Node::Text(text);
+        }
+    }
+    match parse(bytes, depth + 1) {
+        Some(fields) if !fields.is_empty() => Node::Message(fields),
+        _ => as_text(bytes).map(Node::Text).unwrap_or(Node::Bytes(bytes)),
+    }
+}
+
+fn parse(buf: &[u8], depth: usize) -> Option<Vec<Field<'_>>> {
+    if depth > MAX_DEPTH {
+        return None;
+    }
+    let mut fields = Vec::new();
+    let mut pos = 0;
+    while pos < buf.len() {
+        let tag = read_varint(buf, &mut pos)?;
+        let number = tag >> 3;
+        if number == 0 {
+            return None;
+        }
+        let node = match tag & 7 {
+            0 => Node::Varint(read_varint(buf, &mut pos)?),
+            1 => Node::Fixed(read_fixed(buf, &mut pos, 8)?),
+            5 => Node::Fixed(read_fixed(buf, &mut pos, 4)?),
+            2 => {
+                let len = usize::try_from(read_varint(buf, &mut pos)?).ok()?;
+                let bytes = buf.get(pos..pos.checked_add(len)?)?;
+                pos += len;
+                classify(bytes, depth)
+            }
+            // Groups (3, 4) are long deprecated; anything else isn't protobuf
+            _ => return None,
+        };
+        fields.push(Field { number, node });
+    }
+    Some(fields)
+}
+
+/// Every field of `buf`, or None unless the whole buffer is well-formed.
+pub fn decode(buf: &[u8]) -> Option<Vec<Field<'_>>> {
+    parse(buf, 0)
+}
+
+impl<'a> Node<'a> {
+    /// Every text in this node and below, depth first.
+    pub fn texts(&self) -> Vec<&'a str> {
+        let mut out = Vec::new();
+        self.collect_texts(&mut out);
+        out
+    }
+
+    fn collect_texts(&self, out: &mut Vec<&'a str>) {
+        match self {
+            Node::Text(t) => out.push(t),
+            Node::Message(fields) => fields.iter().for_each(|f| f.node.collect_texts(out)),
+            _ => {}
+        }
+    }
+
+    /// Milliseconds, if this is shaped like a `google.protobuf.Timestamp`.
+    pub fn timestamp_ms(&self) -> Option<i64> {
+        let Node::Message(fields) = self else { return None };
+        let mut seconds = None;
+        let mut nanos = 0;
+        for field in fields {
+            match (field.number, &field.node) {
+                (1, Node::Varint(s)) if TIMESTAMP_RANGE.contains(s) => seconds = Some(*s),
+                (2, Node::Varint(n)) if *n < 1_000_000_000 => nanos = *n,
+                _ => return None,
+            }
+        }
+        Some((seconds? * 1000 + nanos / 1_000_000) as i64)
+    }
+
+    /// Every timestamp in this node and below, depth first.
+    pub fn timestamps(&self) -> Vec<i64> {
+        if let Some(ms) = self.timestamp_ms() {
+            return vec![ms];
+        }
+        match self {
+            Node::Message(fields) => fields.iter().flat_map(|f| f.node.timestamps()).collect(),
+            _ => Vec::new(),
+        }
+    }
+}
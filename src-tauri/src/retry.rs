@@ -0,0 +1,223 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::batch::{BatchResult, ItemResult, ItemStatus};
+use crate::conversation::BatchDeleteRequest;
+
+/// Recent partial operations kept for retry; older ones are dropped.
+const MAX_OPERATIONS: usize = 20;
+const CLOSE_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum OperationKind {
+    Clean,
+    DeleteConversations,
+    Uninstall,
+}
+
+/// Something an operation failed to remove.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum FailedItem {
+    /// A file (clean) or folder (uninstall residual).
+    Path { path: PathBuf },
+    Conversation { request: BatchDeleteRequest },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FailedOperation {
+    pub id: String,
+    pub kind: OperationKind,
+    /// IDEs whose processes may be holding the items open.
+    pub ide_ids: Vec<String>,
+    pub created_at: String,
+    pub items: Vec<FailedItem>,
+}
+
+// ── Persistence ──
+
+fn operations_path() -> PathBuf {
+    crate::config::get_app_data_dir().join("failed_operations.json")
+}
+
+fn load_operations() -> Vec<FailedOperation> {
+    fs::read_to_string(operations_path())
+        .ok()
+        .and_then(|data| serde_json::from_str(&data).ok())
+        .unwrap_or_default()
+}
+
+fn save_operations(ops: &[FailedOperation]) -> Result<(), String> {
+    let path = operations_path();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create data dir: {}", e))?;
+    }
+    let data = serde_json::to_string_pretty(ops).map_err(|e| format!("Failed to serialize failed items: {}", e))?;
+    fs::write(&path, data).map_err(|e| format!("Failed to save failed items: {}", e))
+}
+
+/// Remember what an operation failed to remove. Returns the id to pass to
+/// `retry_failed`, or None when nothing failed.
+pub fn record(kind: OperationKind, ide_ids: Vec<String>, items: Vec<FailedItem>) -> Option<String> {
+    if items.is_empty() {
+        return None;
+    }
+    let now = chrono::Local::now();
+    let op = FailedOperation {
+        id: format!("{:?}_{}", kind, now.format("%Y%m%d_%H%M%S%3f")).to_lowercase(),
+        kind,
+        ide_ids,
+        created_at: now.to_rfc3339(),
+        items,
+    };
+    let id = op.id.clone();
+    let mut ops = load_operations();
+    ops.insert(0, op);
+    ops.truncate(MAX_OPERATIONS);
+    save_operations(&ops).ok()?;
+    Some(id)
+}
+
+/// Paths as failed items.
+pub fn path_items(paths: &[PathBuf]) -> Vec<FailedItem> {
+    paths.iter().map(|p| FailedItem::Path { path: p.clone() }).collect()
+}
+
+/// Recorded operations that still have failed items, newest first.
+pub fn list_failed_operations() -> Vec<FailedOperation> {
+    load_operations()
+}
+
+// ── Retry ──
+
+fn path_size(path: &Path) -> u64 {
+    walkdir::WalkDir::new(path)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| e.metadata().ok())
+        .map(|m| m.len())
+        .sum()
+}
+
+fn remove_path(path: &Path) -> ItemResult {
+    let id = path.display().to_string();
+    if !path.exists() {
+        return ItemResult::not_found(id);
+    }
+    let size = path_size(path);
+    crate::db_pool::close_under(path);
+    let removed = if path.is_dir() { crate::cleaner::remove_tree(path) } else { crate::cleaner::remove_file(path) };
+    match removed {
+        Ok(()) => ItemResult::done(id, size),
+        Err(e) => ItemResult::failed(id, format!("Failed to delete: {}", e)),
+    }
+}
+
+/// Try the failed items of `operation_id` again, optionally asking the IDEs
+/// involved to quit first. Items that still fail stay recorded under the same
+/// id (returned in `operation_id`); the operation is forgotten once none remain.
+pub fn retry_failed(operation_id: &str, close_ide: bool) -> Result<BatchResult, String> {
+    let mut ops = load_operations();
+    let index = ops
+        .iter()
+        .position(|op| op.id == operation_id)
+        .ok_or_else(|| format!("No failed items recorded for operation '{}'", operation_id))?;
+
+    if close_ide {
+        for ide_id in &ops[index].ide_ids {
+            close_ide_processes(&crate::ide_detector::get_process_names(ide_id));
+        }
+    }
+
+    let items = &ops[index].items;
+    let mut results: Vec<Option<ItemResult>> = vec![None; items.len()];
+    let mut conversations: Vec<(usize, BatchDeleteRequest)> = Vec::new();
+    for (i, item) in items.iter().enumerate() {
+        match item {
+            FailedItem::Path { path } => results[i] = Some(remove_path(path)),
+            FailedItem::Conversation { request } => conversations.push((i, request.clone())),
+        }
+    }
+    if !conversations.is_empty() {
+        let requests: Vec<BatchDeleteRequest> = conversations.iter().map(|(_, r)| r.clone()).collect();
+        let batch = crate::conversation::delete_conversations_batch(&requests, true);
+        for ((i, _), result) in conversations.iter().zip(batch.items) {
+            results[*i] = Some(result);
+        }
+    }
+    let results: Vec<ItemResult> = results.into_iter().flatten().collect();
+
+    let remaining: Vec<FailedItem> = items
+        .iter()
+        .zip(&results)
+        .filter(|(_, r)| r.status == ItemStatus::Failed)
+        .map(|(item, _)| item.clone())
+        .collect();
+    let still_failing = !remaining.is_empty();
+    if still_failing {
+        ops[index].items = remaining;
+    } else {
+        ops.remove(index);
+    }
+    save_operations(&ops)?;
+
+    let mut result = BatchResult::from_items(results);
+    result.operation_id = still_failing.then(|| operation_id.to_string());
+    Ok(result)
+}
+
+// ── Closing IDEs ──
+
+/// Ask the processes to quit (no force-kill, so editors can save state) and
+/// wait up to `CLOSE_TIMEOUT` for them to exit.
+fn close_ide_processes(process_names: &[String]) {
+    if process_names.is_empty() {
+        return;
+    }
+    request_close(process_names);
+    let started = Instant::now();
+    while any_running(process_names) && started.elapsed() < CLOSE_TIMEOUT {
+        std::thread::sleep(Duration::from_millis(250));
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn request_close(process_names: &[String]) {
+    for name in process_names {
+        let _ = crate::process::hidden_command("taskkill").args(["/IM", name, "/T"]).output();
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn any_running(process_names: &[String]) -> bool {
+    let Ok(output) = crate::process::hidden_command("tasklist").output() else { return false };
+    let list = String::from_utf8_lossy(&output.stdout).to_lowercase();
+    process_names.iter().any(|name| list.contains(&name.to_lowercase()))
+}
+
+/// Process names are Windows image names ("Code.exe"); match the bare name elsewhere.
+#[cfg(not(target_os = "windows"))]
+fn bare_name(name: &str) -> &str {
+    name.strip_suffix(".exe").unwrap_or(name)
+}
+
+#[cfg(not(target_os = "windows"))]
+fn request_close(process_names: &[String]) {
+    for name in process_names {
+        let _ = std::process::Command::new("pkill").args(["-TERM", "-x", bare_name(name)]).output();
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+fn any_running(process_names: &[String]) -> bool {
+    process_names.iter().any(|name| {
+        std::process::Command::new("pgrep")
+            .args(["-x", bare_name(name)])
+            .output()
+            .map(|o| o.status.success())
+            .unwrap_or(false)
+    })
+}
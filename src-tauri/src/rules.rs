@@ -0,0 +1,268 @@
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+// ── Built-in conversation key rules ──
+// A rules pack (conversation_rules.json in DevCleaner's data dir) can extend
+// these when an IDE update renames its keys, without shipping a new binary.
+
+// Exact keys for aggregated chat data (need full read)
+const BUILTIN_CHAT_DATA_KEYS: &[&str] = &[
+    "workbench.panel.aichat.view.aichat.chatdata",
+    "workbench.panel.chat.view.chatView.chatdata",
+    "aiChat.chatdata",
+    "chat.data",
+    "cascade.chatdata",
+    "cascade.conversations",
+    "composer.composerData",
+    "interactive.sessions",
+];
+
+// LIKE patterns for individual conversations in ItemTable
+const BUILTIN_ITEM_TABLE_LIKE: &[&str] = &[
+    "composerData:%",
+    "cascade.%",
+    "chat.%",
+    "aichat.%",
+    "aiChat.%",
+    "copilot.%",
+    "trae.%",
+    "marscode.%",
+    "kiro.%",
+    "memento/icube-ai-agent-storage",
+    "memento/interactive-session%",
+    "jetskiStateSync.agentManagerInitState",
+    "antigravityUnifiedStateSync.trajectorySummaries",
+];
+
+// Keys that look like conversations but are actually metadata/config.
+// `%` is a wildcard, like the LIKE patterns above.
+const BUILTIN_IGNORED_KEYS: &[&str] = &[
+    "chat.participantNameRegistry",
+    "chat.ChatSessionStore.index",
+    "chat.workspaceTransfer",
+    "chat.customModes",
+    "chat.setupContext",
+    "composer.planRegistry",
+    "workbench.panel.composerChatViewPane.%",
+    "windsurf.cascadeViewContainerId.%",
+    "workbench.panel.icube.%",
+    "workbench.panel.chat%",
+    "workbench.panel.chatSidebar%",
+    "workbench.panel.chatEditing%",
+    "workbench.view.trae.%",
+    "%AI.agent.model%",
+    "%AI.agent.modeList%",
+    "%sessionRelation:%",
+    "currentAgentData_%",
+    "icube_session_agent_map%",
+    "icube-ai-agent-storage-input-history%",
+    "chatHistoryNeedToBeMigrated%",
+    "hasAutoNewSession%",
+    "%.hidden",
+    "%.state",
+];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum RuleSource {
+    Builtin,
+    RulesPack,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeyRule {
+    pub pattern: String,
+    pub source: RuleSource,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ConversationRules {
+    pub chat_data_keys: Vec<KeyRule>,
+    pub item_table_like: Vec<KeyRule>,
+    pub ignored_keys: Vec<KeyRule>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RulesDiagnostics {
+    pub pack_path: PathBuf,
+    pub pack_loaded: bool,
+    pub pack_version: Option<String>,
+    pub error: Option<String>,
+    pub rules: ConversationRules,
+}
+
+/// On-disk rules pack format. Every list is optional and additive.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RulesPackFile {
+    pub version: Option<String>,
+    pub chat_data_keys: Vec<String>,
+    pub item_table_like: Vec<String>,
+    pub ignored_keys: Vec<String>,
+}
+
+/// Match a key against a pattern where `%` matches any run of characters.
+pub fn like_match(pattern: &str, key: &str) -> bool {
+    let parts: Vec<&str> = pattern.split('%').collect();
+    if parts.len() == 1 {
+        return pattern == key;
+    }
+    let first = parts[0];
+    let last = parts[parts.len() - 1];
+    if !key.starts_with(first) || key.len() < first.len() + last.len() || !key.ends_with(last) {
+        return false;
+    }
+    let mut rest = &key[first.len()..key.len() - last.len()];
+    for mid in &parts[1..parts.len() - 1] {
+        if mid.is_empty() { continue; }
+        match rest.find(mid) {
+            Some(pos) => rest = &rest[pos + mid.len()..],
+            None => return false,
+        }
+    }
+    true
+}
+
+impl ConversationRules {
+    fn builtin() -> Self {
+        let to_rules = |list: &[&str]| -> Vec<KeyRule> {
+            list.iter()
+                .map(|p| KeyRule { pattern: p.to_string(), source: RuleSource::Builtin })
+                .collect()
+        };
+        ConversationRules {
+            chat_data_keys: to_rules(BUILTIN_CHAT_DATA_KEYS),
+            item_table_like: to_rules(BUILTIN_ITEM_TABLE_LIKE),
+            ignored_keys: to_rules(BUILTIN_IGNORED_KEYS),
+        }
+    }
+
+    fn merge_pack(&mut self, pack: RulesPackFile) {
+        let add = |target: &mut Vec<KeyRule>, extra: Vec<String>| {
+            for pattern in extra {
+                if pattern.is_empty() || target.iter().any(|r| r.pattern == pattern) { continue; }
+                target.push(KeyRule { pattern, source: RuleSource::RulesPack });
+            }
+        };
+        add(&mut self.chat_data_keys, pack.chat_data_keys);
+        add(&mut self.item_table_like, pack.item_table_like);
+        add(&mut self.ignored_keys, pack.ignored_keys);
+    }
+
+    pub fn is_chat_data_key(&self, key: &str) -> bool {
+        self.chat_data_keys.iter().any(|r| r.pattern == key)
+    }
+
+    pub fn is_ignored(&self, key: &str) -> bool {
+        self.ignored_keys.iter().any(|r| like_match(&r.pattern, key))
+    }
+}
+
+// ── Rules pack loading (re-read whenever the file's mtime changes) ──
+
+struct LoadedRules {
+    modified: Option<SystemTime>,
+    diagnostics: RulesDiagnostics,
+}
+
+static LOADED: Mutex<Option<LoadedRules>> = Mutex::new(None);
+
+pub fn get_rules_pack_path() -> PathBuf {
+    crate::config::get_app_data_dir().join("conversation_rules.json")
+}
+
+fn load_rules(path: PathBuf) -> RulesDiagnostics {
+    let mut rules = ConversationRules::builtin();
+    let mut diag = RulesDiagnostics {
+        pack_path: path.clone(),
+        pack_loaded: false,
+        pack_version: None,
+        error: None,
+        rules: ConversationRules::default(),
+    };
+
+    if path.exists() {
+        match std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|data| serde_json::from_str::<RulesPackFile>(&data).map_err(|e| e.to_string()))
+        {
+            Ok(pack) => {
+                diag.pack_loaded = true;
+                diag.pack_version = pack.version.clone();
+                rules.merge_pack(pack);
+            }
+            Err(e) => diag.error = Some(format!("Invalid rules pack, using built-in rules: {}", e)),
+        }
+    }
+
+    diag.rules = rules;
+    diag
+}
+
+fn with_loaded<T>(f: impl FnOnce(&RulesDiagnostics) -> T) -> T {
+    let path = get_rules_pack_path();
+    let modified = std::fs::metadata(&path).and_then(|m| m.modified()).ok();
+
+    let mut guard = LOADED.lock().unwrap_or_else(|e| e.into_inner());
+    let stale = match guard.as_ref() {
+        Some(loaded) => loaded.modified != modified,
+        None => true,
+    };
+    if stale {
+        *guard = Some(LoadedRules { modified, diagnostics: load_rules(path) });
+    }
+    f(&guard.as_ref().expect("rules loaded above").diagnostics)
+}
+
+/// Current conversation key rules (built-ins merged with the rules pack).
+pub fn current_rules() -> ConversationRules {
+    with_loaded(|d| d.rules.clone())
+}
+
+pub fn get_rules_diagnostics() -> RulesDiagnostics {
+    with_loaded(|d| d.clone())
+}
+
+// ── Rules pack merge (curation import) ──
+
+/// The user's rules pack as written on disk (empty if there is none).
+pub fn read_rules_pack() -> Result<RulesPackFile, String> {
+    let path = get_rules_pack_path();
+    if !path.exists() {
+        return Ok(RulesPackFile::default());
+    }
+    let data = std::fs::read_to_string(&path).map_err(|e| format!("Failed to read rules pack: {}", e))?;
+    serde_json::from_str(&data).map_err(|e| format!("Failed to parse rules pack: {}", e))
+}
+
+/// Add the patterns of `extra` that the pack doesn't have yet; existing
+/// entries are never removed. Returns how many patterns were added.
+pub fn merge_into_rules_pack(extra: &RulesPackFile) -> Result<usize, String> {
+    let mut pack = read_rules_pack()?;
+    let mut added = 0;
+    let mut add = |target: &mut Vec<String>, patterns: &[String]| {
+        for pattern in patterns {
+            if pattern.is_empty() || target.contains(pattern) { continue; }
+            target.push(pattern.clone());
+            added += 1;
+        }
+    };
+    add(&mut pack.chat_data_keys, &extra.chat_data_keys);
+    add(&mut pack.item_table_like, &extra.item_table_like);
+    add(&mut pack.ignored_keys, &extra.ignored_keys);
+    if added == 0 {
+        return Ok(0);
+    }
+    if pack.version.is_none() {
+        pack.version = extra.version.clone();
+    }
+
+    let path = get_rules_pack_path();
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    let data = serde_json::to_string_pretty(&pack).map_err(|e| format!("Failed to serialize rules pack: {}", e))?;
+    std::fs::write(&path, data).map_err(|e| format!("Failed to write rules pack: {}", e))?;
+    Ok(added)
+}
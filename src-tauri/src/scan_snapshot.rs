@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+use crate::conversation::{self, ConversationListResult};
+use crate::ide_detector::{self, IdeInfo};
+use crate::scanner::{self, ScanSummary};
+
+const SNAPSHOT_FORMAT_VERSION: u32 = 1;
+
+/// Detection, scan and conversation inventory of one machine at one point in
+/// time, for comparing machines, sharing with support or reviewing later
+/// without re-scanning.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScanSnapshot {
+    pub format_version: u32,
+    pub created_at: String,
+    pub source_os: String,
+    pub ides: Vec<IdeInfo>,
+    pub scan: ScanSummary,
+    /// Only IDEs that have at least one conversation or conversation DB.
+    pub conversations: Vec<ConversationListResult>,
+}
+
+pub fn capture_snapshot() -> ScanSnapshot {
+    let ides = ide_detector::detect_installed_ides();
+    let scan = scanner::scan_all(&ides);
+    let conversations = ides
+        .iter()
+        .filter(|ide| ide.installed)
+        .map(|ide| conversation::scan_conversations(ide, Default::default()))
+        .filter(|r| !r.conversations.is_empty() || !r.db_files.is_empty())
+        .collect();
+    ScanSnapshot {
+        format_version: SNAPSHOT_FORMAT_VERSION,
+        created_at: chrono::Local::now().to_rfc3339(),
+        source_os: std::env::consts::OS.to_string(),
+        ides,
+        scan,
+        conversations,
+    }
+}
+
+/// Scan now and write the result to `path`.
+pub fn save_scan_snapshot(path: &Path) -> Result<ScanSnapshot, String> {
+    let snapshot = capture_snapshot();
+    let data = serde_json::to_string_pretty(&snapshot).map_err(|e| format!("Failed to serialize snapshot: {}", e))?;
+    fs::write(path, data).map_err(|e| format!("Failed to write snapshot: {}", e))?;
+    Ok(snapshot)
+}
+
+pub fn load_scan_snapshot(path: &Path) -> Result<ScanSnapshot, String> {
+    let data = fs::read_to_string(path).map_err(|e| format!("Failed to read snapshot: {}", e))?;
+    let snapshot: ScanSnapshot = serde_json::from_str(&data).map_err(|e| format!("Failed to parse snapshot: {}", e))?;
+    if snapshot.format_version > SNAPSHOT_FORMAT_VERSION {
+        return Err(format!("Snapshot format {} is newer than this version supports", snapshot.format_version));
+    }
+    Ok(snapshot)
+}
@@ -18,6 +18,12 @@ pub struct ScanCategory {
     pub total_size: u64,
     pub file_count: u64,
     pub paths: Vec<PathBuf>,
+    /// GlobalStorage only: per-extension (`publisher.name`) folders, largest first.
+    pub entries: Vec<StorageEntry>,
+    /// Folders holding a `.devcleaner-ignore` marker; left out of the size and
+    /// never cleaned.
+    #[serde(default)]
+    pub excluded_by_marker: Vec<PathBuf>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -28,6 +34,17 @@ pub enum CategoryType {
     Extension,
     CrashReport,
     GlobalStorage,
+    /// Installer payloads/package caches; never auto-selected by a clean mode.
+    Installer,
+    /// Site data (Local Storage, Session Storage, IndexedDB) holding login
+    /// state; left out of Safe mode and the tray's quick clean.
+    WebStorage,
+    /// Incremental build state (JetBrains compile server); regenerated by the
+    /// next build, but slowly, so only Aggressive mode selects it.
+    BuildCache,
+    /// Earlier revisions of the user's files kept by the IDE; never
+    /// auto-selected, usually trimmed by age instead.
+    LocalHistory,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -47,15 +64,27 @@ pub struct ScanSummary {
     pub scan_duration_ms: u64,
 }
 
-fn dir_size_and_count(path: &Path) -> (u64, u64) {
+/// Size and file count of `path`, skipping marked folders (collected into `excluded`).
+fn measure(path: &Path, excluded: &mut Vec<PathBuf>) -> (u64, u64) {
     let mut total_size: u64 = 0;
     let mut file_count: u64 = 0;
 
     if !path.exists() {
         return (0, 0);
     }
+    if crate::cleaner::is_marked(path) {
+        excluded.push(path.to_path_buf());
+        return (0, 0);
+    }
 
-    for entry in WalkDir::new(path).into_iter().filter_map(|e| e.ok()) {
+    let mut walker = WalkDir::new(path).into_iter();
+    while let Some(entry) = walker.next() {
+        let Ok(entry) = entry else { continue };
+        if entry.file_type().is_dir() && crate::cleaner::has_ignore_marker(entry.path()) {
+            excluded.push(entry.into_path());
+            walker.skip_current_dir();
+            continue;
+        }
         if entry.file_type().is_file() {
             if let Ok(meta) = entry.metadata() {
                 total_size += meta.len();
@@ -67,33 +96,67 @@ fn dir_size_and_count(path: &Path) -> (u64, u64) {
     (total_size, file_count)
 }
 
+/// Cache folders of VS Code-based IDEs that hold site data rather than cache.
+const WEB_STORAGE_FOLDERS: &[&str] = &["Local Storage", "Session Storage", "IndexedDB"];
+
+/// Cache paths grouped into categories. VSCode-based caches get one category
+/// per folder name (GPUCache, Code Cache, Local Storage, ...) so login state
+/// can be kept while the rest is cleared; other IDEs keep a single "Cache".
+fn cache_groups(ide: &crate::ide_detector::IdeInfo) -> Vec<(String, CategoryType, Vec<PathBuf>)> {
+    if ide.ide_type != crate::ide_detector::IdeType::VscodeBased {
+        return if ide.cache_paths.is_empty() {
+            Vec::new()
+        } else {
+            vec![("Cache".into(), CategoryType::Cache, ide.cache_paths.clone())]
+        };
+    }
+    let mut groups: Vec<(String, CategoryType, Vec<PathBuf>)> = Vec::new();
+    for p in &ide.cache_paths {
+        let name = p.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_else(|| "Cache".into());
+        match groups.iter_mut().find(|(n, _, _)| *n == name) {
+            Some((_, _, paths)) => paths.push(p.clone()),
+            None => {
+                let category_type =
+                    if WEB_STORAGE_FOLDERS.contains(&name.as_str()) { CategoryType::WebStorage } else { CategoryType::Cache };
+                groups.push((name, category_type, vec![p.clone()]))
+            }
+        }
+    }
+    groups
+}
+
 pub fn scan_ide(ide: &crate::ide_detector::IdeInfo) -> IdeScanResult {
     let mut categories = Vec::new();
     let mut total_size: u64 = 0;
     let mut total_files: u64 = 0;
 
     // Scan cache paths
-    if !ide.cache_paths.is_empty() {
+    for (name, category_type, paths) in cache_groups(ide) {
         let mut cat_size: u64 = 0;
         let mut cat_files: u64 = 0;
         let mut existing_paths = Vec::new();
+        let mut excluded = Vec::new();
 
-        for p in &ide.cache_paths {
+        for p in &paths {
             if p.exists() {
-                let (size, count) = dir_size_and_count(p);
+                let (size, count) = measure(p, &mut excluded);
                 cat_size += size;
                 cat_files += count;
-                existing_paths.push(p.clone());
+                if !excluded.contains(p) {
+                    existing_paths.push(p.clone());
+                }
             }
         }
 
-        if cat_size > 0 {
+        if cat_size > 0 || !excluded.is_empty() {
             categories.push(ScanCategory {
-                name: "Cache".into(),
-                category_type: CategoryType::Cache,
+                name,
+                category_type,
                 total_size: cat_size,
                 file_count: cat_files,
                 paths: existing_paths,
+                entries: Vec::new(),
+                excluded_by_marker: excluded,
             });
             total_size += cat_size;
             total_files += cat_files;
@@ -105,23 +168,28 @@ pub fn scan_ide(ide: &crate::ide_detector::IdeInfo) -> IdeScanResult {
         let mut cat_size: u64 = 0;
         let mut cat_files: u64 = 0;
         let mut existing_paths = Vec::new();
+        let mut excluded = Vec::new();
 
         for p in &ide.log_paths {
             if p.exists() {
-                let (size, count) = dir_size_and_count(p);
+                let (size, count) = measure(p, &mut excluded);
                 cat_size += size;
                 cat_files += count;
-                existing_paths.push(p.clone());
+                if !excluded.contains(p) {
+                    existing_paths.push(p.clone());
+                }
             }
         }
 
-        if cat_size > 0 {
+        if cat_size > 0 || !excluded.is_empty() {
             categories.push(ScanCategory {
                 name: "Logs".into(),
                 category_type: CategoryType::Log,
                 total_size: cat_size,
                 file_count: cat_files,
                 paths: existing_paths,
+                entries: Vec::new(),
+                excluded_by_marker: excluded,
             });
             total_size += cat_size;
             total_files += cat_files;
@@ -131,14 +199,17 @@ pub fn scan_ide(ide: &crate::ide_detector::IdeInfo) -> IdeScanResult {
     // Scan workspace storage
     if let Some(ref ws) = ide.workspace_storage_path {
         if ws.exists() {
-            let (size, count) = dir_size_and_count(ws);
-            if size > 0 {
+            let mut excluded = Vec::new();
+            let (size, count) = measure(ws, &mut excluded);
+            if size > 0 || !excluded.is_empty() {
                 categories.push(ScanCategory {
                     name: "Workspace Storage".into(),
                     category_type: CategoryType::WorkspaceStorage,
                     total_size: size,
                     file_count: count,
-                    paths: vec![ws.clone()],
+                    paths: if excluded.contains(ws) { Vec::new() } else { vec![ws.clone()] },
+                    entries: Vec::new(),
+                    excluded_by_marker: excluded,
                 });
                 total_size += size;
                 total_files += count;
@@ -149,14 +220,17 @@ pub fn scan_ide(ide: &crate::ide_detector::IdeInfo) -> IdeScanResult {
     // Scan extensions
     if let Some(ref ext) = ide.extension_path {
         if ext.exists() {
-            let (size, count) = dir_size_and_count(ext);
-            if size > 0 {
+            let mut excluded = Vec::new();
+            let (size, count) = measure(ext, &mut excluded);
+            if size > 0 || !excluded.is_empty() {
                 categories.push(ScanCategory {
                     name: "Extensions".into(),
                     category_type: CategoryType::Extension,
                     total_size: size,
                     file_count: count,
-                    paths: vec![ext.clone()],
+                    paths: if excluded.contains(ext) { Vec::new() } else { vec![ext.clone()] },
+                    entries: Vec::new(),
+                    excluded_by_marker: excluded,
                 });
                 total_size += size;
                 total_files += count;
@@ -164,17 +238,32 @@ pub fn scan_ide(ide: &crate::ide_detector::IdeInfo) -> IdeScanResult {
         }
     }
 
-    // Scan global storage (AI conversations, extension data)
+    // Scan global storage (AI conversations, extension data), broken down per extension
     if let Some(ref gs) = ide.global_storage_path {
         if gs.exists() {
-            let (size, count) = dir_size_and_count(gs);
-            if size > 0 {
+            let mut excluded = Vec::new();
+            let entries = storage_entries(gs, &mut excluded);
+            let (mut size, mut count) = (0u64, 0u64);
+            for e in &entries {
+                size += e.size;
+                count += e.file_count;
+            }
+            // Top-level files (state.vscdb, storage.json) belong to no extension
+            if let Ok(read_dir) = std::fs::read_dir(gs) {
+                for meta in read_dir.filter_map(|e| e.ok()).filter_map(|e| e.metadata().ok()).filter(|m| m.is_file()) {
+                    size += meta.len();
+                    count += 1;
+                }
+            }
+            if size > 0 || !excluded.is_empty() {
                 categories.push(ScanCategory {
                     name: "Global Storage".into(),
                     category_type: CategoryType::GlobalStorage,
                     total_size: size,
                     file_count: count,
-                    paths: vec![gs.clone()],
+                    paths: if excluded.contains(gs) { Vec::new() } else { vec![gs.clone()] },
+                    entries,
+                    excluded_by_marker: excluded,
                 });
                 total_size += size;
                 total_files += count;
@@ -182,6 +271,39 @@ pub fn scan_ide(ide: &crate::ide_detector::IdeInfo) -> IdeScanResult {
         }
     }
 
+    // Product-specific categories (Visual Studio caches, installer payloads, ...)
+    for extra in &ide.extra_categories {
+        let mut cat_size: u64 = 0;
+        let mut cat_files: u64 = 0;
+        let mut existing_paths = Vec::new();
+        let mut excluded = Vec::new();
+
+        for p in &extra.paths {
+            if p.exists() {
+                let (size, count) = measure(p, &mut excluded);
+                cat_size += size;
+                cat_files += count;
+                if !excluded.contains(p) {
+                    existing_paths.push(p.clone());
+                }
+            }
+        }
+
+        if cat_size > 0 || !excluded.is_empty() {
+            categories.push(ScanCategory {
+                name: extra.name.clone(),
+                category_type: extra.category_type.clone(),
+                total_size: cat_size,
+                file_count: cat_files,
+                paths: existing_paths,
+                entries: Vec::new(),
+                excluded_by_marker: excluded,
+            });
+            total_size += cat_size;
+            total_files += cat_files;
+        }
+    }
+
     IdeScanResult {
         ide_id: ide.id.clone(),
         ide_name: ide.name.clone(),
@@ -198,6 +320,9 @@ pub struct StorageEntry {
     pub size: u64,
     pub file_count: u64,
     pub modified: Option<i64>,
+    /// workspaceStorage only: last real use, from state.vscdb and hot-exit backups.
+    /// Folder mtime is unreliable there because Electron touches it on every launch.
+    pub last_activity: Option<i64>,
     pub is_ai_related: bool,
 }
 
@@ -211,18 +336,142 @@ fn is_ai_extension(name: &str) -> bool {
     ai_patterns.iter().any(|p| lower.contains(p))
 }
 
+// ── Workspace last activity ──
+
+/// ItemTable keys whose JSON values carry activity timestamps.
+const WORKSPACE_ACTIVITY_KEYS: [&str; 2] = ["%lastKnown%", "%history%"];
+/// 2015-01-01; anything earlier isn't a VS Code timestamp.
+const MIN_ACTIVITY_SECS: i64 = 1_420_070_400;
+
+fn file_mtime_secs(path: &Path) -> Option<i64> {
+    std::fs::metadata(path)
+        .ok()
+        .and_then(|m| m.modified().ok())
+        .and_then(|t| t.duration_since(SystemTime::UNIX_EPOCH).ok())
+        .map(|d| d.as_secs() as i64)
+}
+
+/// Seconds for a plausible epoch value in seconds or milliseconds.
+fn normalize_epoch(n: i64, now: i64) -> Option<i64> {
+    let secs = if n > 100_000_000_000 { n / 1000 } else { n };
+    (MIN_ACTIVITY_SECS..=now + 86_400).contains(&secs).then_some(secs)
+}
+
+/// Newest timestamp in any time-like field (`timestamp`, `lastModified`, `date`...).
+fn max_timestamp_in(value: &serde_json::Value, now: i64) -> Option<i64> {
+    match value {
+        serde_json::Value::Object(map) => map
+            .iter()
+            .filter_map(|(k, v)| {
+                let k = k.to_lowercase();
+                let own = if k.contains("time") || k.contains("date") || k.contains("last") {
+                    v.as_i64().and_then(|n| normalize_epoch(n, now))
+                } else {
+                    None
+                };
+                own.max(max_timestamp_in(v, now))
+            })
+            .max(),
+        serde_json::Value::Array(items) => items.iter().filter_map(|v| max_timestamp_in(v, now)).max(),
+        _ => None,
+    }
+}
+
+fn state_db_activity(db: &Path, now: i64) -> Option<i64> {
+    crate::db_pool::with_read_conn(db, |conn| {
+        let mut stmt = conn.prepare("SELECT value FROM ItemTable WHERE key LIKE ?1 OR key LIKE ?2").ok()?;
+        let rows = stmt
+            .query_map(WORKSPACE_ACTIVITY_KEYS, |row| row.get::<_, String>(0))
+            .ok()?;
+        rows.filter_map(|r| r.ok())
+            .filter_map(|v| serde_json::from_str::<serde_json::Value>(&v).ok())
+            .filter_map(|v| max_timestamp_in(&v, now))
+            .max()
+    })
+    .ok()
+    .flatten()
+}
+
+/// Folder/workspace URIs listed in `Backups/workspaces.json` (windows open at last
+/// exit, with hot-exit backups), plus that file's mtime.
+fn hot_exit_workspaces(workspace_storage: &Path) -> Option<(Vec<String>, i64)> {
+    let file = workspace_storage.parent()?.parent()?.join("Backups").join("workspaces.json");
+    let data = std::fs::read_to_string(&file).ok()?;
+    let parsed: serde_json::Value = serde_json::from_str(&data).ok()?;
+    let mut uris = Vec::new();
+    collect_strings(&parsed, &mut uris);
+    Some((uris, file_mtime_secs(&file)?))
+}
+
+fn collect_strings(value: &serde_json::Value, out: &mut Vec<String>) {
+    match value {
+        serde_json::Value::String(s) => out.push(s.clone()),
+        serde_json::Value::Array(items) => items.iter().for_each(|v| collect_strings(v, out)),
+        serde_json::Value::Object(map) => map.values().for_each(|v| collect_strings(v, out)),
+        _ => {}
+    }
+}
+
+/// Last activity of one workspaceStorage folder: newest of the state DB files'
+/// mtimes, timestamps inside its `lastKnown*`/history keys, and the hot-exit list.
+/// Another user's DB is never opened: that IDE may be running, and a reader
+/// there could block its writes or leave WAL files behind.
+fn workspace_last_activity(dir: &Path, hot_exit: Option<&(Vec<String>, i64)>, now: i64) -> Option<i64> {
+    let state_db = dir.join("state.vscdb");
+    let mut latest = [state_db.clone(), dir.join("state.vscdb.backup")]
+        .iter()
+        .filter_map(|p| file_mtime_secs(p))
+        .max();
+    if state_db.exists() && !crate::ide_detector::is_external_profile() {
+        latest = latest.max(state_db_activity(&state_db, now));
+    }
+    if let Some((uris, mtime)) = hot_exit {
+        let workspace_uri = std::fs::read_to_string(dir.join("workspace.json"))
+            .ok()
+            .and_then(|d| serde_json::from_str::<serde_json::Value>(&d).ok())
+            .and_then(|v| {
+                v.get("folder").or_else(|| v.get("workspace")).and_then(|u| u.as_str()).map(|u| u.to_string())
+            });
+        if workspace_uri.map(|u| uris.contains(&u)).unwrap_or(false) {
+            latest = latest.max(Some(*mtime));
+        }
+    }
+    latest
+}
+
 pub fn list_storage_entries(base_path: &Path) -> Vec<StorageEntry> {
+    let mut excluded = Vec::new();
+    storage_entries(base_path, &mut excluded)
+}
+
+/// Per-folder entries of `base_path`; marked folders go to `excluded` instead.
+fn storage_entries(base_path: &Path, excluded: &mut Vec<PathBuf>) -> Vec<StorageEntry> {
     let mut entries = Vec::new();
     if !base_path.exists() {
         return entries;
     }
+    if crate::cleaner::is_marked(base_path) {
+        excluded.push(base_path.to_path_buf());
+        return entries;
+    }
+
+    let is_workspace_storage = base_path.file_name().map(|n| n == "workspaceStorage").unwrap_or(false);
+    let hot_exit = if is_workspace_storage { hot_exit_workspaces(base_path) } else { None };
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
 
     if let Ok(read_dir) = std::fs::read_dir(base_path) {
         for entry in read_dir.filter_map(|e| e.ok()) {
             if entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
                 let name = entry.file_name().to_string_lossy().to_string();
                 let path = entry.path();
-                let (size, count) = dir_size_and_count(&path);
+                if crate::cleaner::has_ignore_marker(&path) {
+                    excluded.push(path);
+                    continue;
+                }
+                let (size, count) = measure(&path, excluded);
                 if size == 0 {
                     continue;
                 }
@@ -234,6 +483,11 @@ pub fn list_storage_entries(base_path: &Path) -> Vec<StorageEntry> {
                     .map(|d| d.as_secs() as i64);
 
                 let is_ai_related = is_ai_extension(&name);
+                let last_activity = if is_workspace_storage {
+                    workspace_last_activity(&path, hot_exit.as_ref(), now)
+                } else {
+                    None
+                };
 
                 entries.push(StorageEntry {
                     name,
@@ -241,6 +495,7 @@ pub fn list_storage_entries(base_path: &Path) -> Vec<StorageEntry> {
                     size,
                     file_count: count,
                     modified,
+                    last_activity,
                     is_ai_related,
                 });
             }
@@ -274,3 +529,13 @@ pub fn scan_all(ides: &[crate::ide_detector::IdeInfo]) -> ScanSummary {
         scan_duration_ms,
     }
 }
+
+/// Scan every installed IDE of one family (all VSCode-based, all JetBrains, ...).
+pub fn scan_family(ides: &[crate::ide_detector::IdeInfo], ide_type: &crate::ide_detector::IdeType) -> ScanSummary {
+    let family: Vec<crate::ide_detector::IdeInfo> = ides
+        .iter()
+        .filter(|ide| &ide.ide_type == ide_type)
+        .cloned()
+        .collect();
+    scan_all(&family)
+}
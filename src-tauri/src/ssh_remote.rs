@@ -0,0 +1,203 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use crate::batch::{BatchResult, ItemResult};
+
+/// Server folders checked on the remote host, relative to its `$HOME`.
+const SERVER_DIRS: &[&str] = &[
+    ".vscode-server", ".vscode-server-insiders", ".cursor-server", ".windsurf-server",
+    ".vscodium-server", ".trae-server", ".kiro-server",
+];
+const CONNECT_TIMEOUT_SECS: u32 = 10;
+
+/// Where to connect. Authentication is left to ssh (agent, keys, ~/.ssh/config);
+/// password prompts are disabled since there is no terminal to answer them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SshTarget {
+    /// Host name, IP or a `Host` alias from ~/.ssh/config.
+    pub host: String,
+    pub user: Option<String>,
+    pub port: Option<u16>,
+    pub identity_file: Option<PathBuf>,
+}
+
+/// One `bin/<commit>` or `cli/servers/<quality>-<commit>` folder on the host.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteServerBuild {
+    /// Absolute path on the remote host.
+    pub path: String,
+    pub size: u64,
+    /// Epoch seconds of the folder's last modification.
+    pub modified: i64,
+    /// A running process was started from this build.
+    pub in_use: bool,
+    /// Not the newest build of its folder and not in use.
+    pub stale: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteServerReport {
+    pub host: String,
+    pub builds: Vec<RemoteServerBuild>,
+    pub total_size: u64,
+    pub stale_size: u64,
+}
+
+// ── ssh ──
+
+/// Host/user must look like names, so nothing can be read as an ssh option.
+fn validate_name(value: &str, what: &str) -> Result<(), String> {
+    let ok = !value.is_empty()
+        && !value.starts_with('-')
+        && value.chars().all(|c| c.is_ascii_alphanumeric() || "._-:".contains(c));
+    if ok { Ok(()) } else { Err(format!("Invalid SSH {}: {}", what, value)) }
+}
+
+fn ssh_command(target: &SshTarget) -> Result<Command, String> {
+    validate_name(&target.host, "host")?;
+    if let Some(user) = &target.user {
+        validate_name(user, "user")?;
+    }
+    let mut cmd = crate::process::hidden_command("ssh");
+    cmd.args(["-o", "BatchMode=yes", "-o", &format!("ConnectTimeout={}", CONNECT_TIMEOUT_SECS)]);
+    if let Some(port) = target.port {
+        cmd.args(["-p", &port.to_string()]);
+    }
+    if let Some(identity) = &target.identity_file {
+        cmd.arg("-i").arg(identity);
+    }
+    cmd.arg("--");
+    cmd.arg(match &target.user {
+        Some(user) => format!("{}@{}", user, target.host),
+        None => target.host.clone(),
+    });
+    Ok(cmd)
+}
+
+/// Run a POSIX sh script on the host and return its stdout. The script is fed
+/// to `sh -s` on stdin rather than quoted onto the command line: the login
+/// shell may be fish or csh, and csh rejects newlines inside quotes.
+fn run_remote(target: &SshTarget, script: &str) -> Result<String, String> {
+    let mut child = ssh_command(target)?
+        .args(["sh", "-s"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to run ssh: {}", e))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(script.as_bytes())
+            .map_err(|e| format!("Failed to send script to {}: {}", target.host, e))?;
+    }
+    let output = child.wait_with_output().map_err(|e| format!("Failed to run ssh: {}", e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "ssh {} exited with code {}: {}",
+            target.host,
+            output.status.code().unwrap_or(-1),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Single-quote for sh.
+fn sh_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', r"'\''"))
+}
+
+// ── Listing ──
+
+/// Prints `path<TAB>mtime<TAB>size KiB<TAB>in use (0/1)` for every server build.
+fn list_script() -> String {
+    let dirs: Vec<String> = SERVER_DIRS.iter().map(|d| format!("\"$HOME\"/{}", d)).collect();
+    format!(
+        r#"for d in {}; do
+  for b in "$d"/bin/* "$d"/cli/servers/*; do
+    [ -d "$b" ] || continue
+    m=$(stat -c %Y "$b" 2>/dev/null || stat -f %m "$b" 2>/dev/null || echo 0)
+    s=$(du -sk "$b" 2>/dev/null | cut -f1)
+    if pgrep -f "$b" >/dev/null 2>&1; then u=1; else u=0; fi
+    printf '%s\t%s\t%s\t%s\n' "$b" "$m" "${{s:-0}}" "$u"
+  done
+done"#,
+        dirs.join(" ")
+    )
+}
+
+fn parse_listing(stdout: &str) -> Vec<RemoteServerBuild> {
+    let mut builds: Vec<RemoteServerBuild> = stdout
+        .lines()
+        .filter_map(|line| {
+            let mut cols = line.split('\t');
+            let path = cols.next()?.to_string();
+            let modified = cols.next()?.trim().parse().unwrap_or(0);
+            let size_kib: u64 = cols.next()?.trim().parse().unwrap_or(0);
+            let in_use = cols.next()?.trim() == "1";
+            Some(RemoteServerBuild { path, size: size_kib * 1024, modified, in_use, stale: false })
+        })
+        .collect();
+
+    // Newest build per folder (bin/ or cli/servers/ of each server dir) is the one launched next
+    let mut newest: HashMap<String, i64> = HashMap::new();
+    for b in &builds {
+        let parent = b.path.rsplit_once('/').map(|(p, _)| p.to_string()).unwrap_or_default();
+        let entry = newest.entry(parent).or_insert(i64::MIN);
+        *entry = (*entry).max(b.modified);
+    }
+    for b in &mut builds {
+        let parent = b.path.rsplit_once('/').map(|(p, _)| p).unwrap_or_default();
+        b.stale = !b.in_use && newest.get(parent) != Some(&b.modified);
+    }
+    builds.sort_by(|a, b| b.size.cmp(&a.size));
+    builds
+}
+
+/// Server builds on the host; those neither newest nor running are stale.
+pub fn scan_remote_servers(target: &SshTarget) -> Result<RemoteServerReport, String> {
+    let builds = parse_listing(&run_remote(target, &list_script())?);
+    Ok(RemoteServerReport {
+        host: target.host.clone(),
+        total_size: builds.iter().map(|b| b.size).sum(),
+        stale_size: builds.iter().filter(|b| b.stale).map(|b| b.size).sum(),
+        builds,
+    })
+}
+
+/// Remove stale builds. Paths are re-checked against a fresh listing, so a
+/// build that became current or started running in the meantime is kept.
+pub fn clean_remote_servers(target: &SshTarget, paths: &[String]) -> Result<BatchResult, String> {
+    let report = scan_remote_servers(target)?;
+    let mut items = Vec::new();
+    let mut to_remove = Vec::new();
+    for path in paths {
+        match report.builds.iter().find(|b| b.path == *path) {
+            Some(b) if b.stale => to_remove.push(b),
+            Some(_) => items.push(ItemResult::skipped(path.clone(), "Build is current or in use")),
+            None => items.push(ItemResult::not_found(path.clone())),
+        }
+    }
+    if !to_remove.is_empty() {
+        // One connection for all removals; each path reports its own outcome
+        let script: String = to_remove
+            .iter()
+            .map(|b| format!("rm -rf -- {p} 2>/dev/null; [ -e {p} ] || printf 'OK\\t%s\\n' {p}\n", p = sh_quote(&b.path)))
+            .collect();
+        let stdout = run_remote(target, &script)?;
+        for b in to_remove {
+            let removed = stdout.lines().any(|l| l.strip_prefix("OK\t") == Some(b.path.as_str()));
+            items.push(if removed {
+                ItemResult::done(b.path.clone(), b.size)
+            } else {
+                ItemResult::failed(b.path.clone(), "Could not remove (permissions?)")
+            });
+        }
+    }
+    // Report in request order
+    items.sort_by_key(|i| paths.iter().position(|p| *p == i.id));
+    Ok(BatchResult::from_items(items))
+}
@@ -0,0 +1,235 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::batch::{BatchResult, ItemResult};
+use crate::ide_detector::{IdeInfo, IdeType};
+
+/// A non-latest JetBrains version whose config/cache folders are still on disk
+/// (e.g. `IntelliJIdea2023.2` when 2024.1 is the newest).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StaleVersion {
+    pub ide_id: String,
+    pub ide_name: String,
+    pub version: String,
+    pub latest_version: String,
+    pub config_path: Option<PathBuf>,
+    pub cache_path: Option<PathBuf>,
+    pub size: u64,
+    /// The version itself is still installed; its folders are in use.
+    pub still_installed: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StaleVersionRequest {
+    pub ide_id: String,
+    pub version: String,
+}
+
+/// Every non-latest version of every detected JetBrains IDE.
+pub fn find_stale_versions(ides: &[IdeInfo]) -> Vec<StaleVersion> {
+    let mut results = Vec::new();
+    for ide in ides.iter().filter(|i| i.installed && i.ide_type == IdeType::JetBrains) {
+        let latest_version = ide
+            .versioned_folders
+            .iter()
+            .find(|vf| vf.is_latest)
+            .map(|vf| vf.version.clone())
+            .unwrap_or_default();
+        for vf in ide.versioned_folders.iter().filter(|vf| !vf.is_latest) {
+            if vf.config_path.is_none() && vf.cache_path.is_none() {
+                continue;
+            }
+            results.push(StaleVersion {
+                ide_id: ide.id.clone(),
+                ide_name: ide.name.clone(),
+                version: vf.version.clone(),
+                latest_version: latest_version.clone(),
+                config_path: vf.config_path.clone(),
+                cache_path: vf.cache_path.clone(),
+                size: vf.total_size,
+                still_installed: vf.install_path.is_some(),
+            });
+        }
+    }
+    results.sort_by(|a, b| b.size.cmp(&a.size));
+    results
+}
+
+/// Delete the config+cache folders of the selected old versions. Versions that
+/// are still installed or turn out to be the latest are refused. Item ids are
+/// `"<ide_id> <version>"`.
+pub fn clean_stale_versions(
+    ides: &[IdeInfo],
+    requests: &[StaleVersionRequest],
+    create_backup: bool,
+) -> BatchResult {
+    let stale = find_stale_versions(ides);
+
+    let mut failed_paths = Vec::new();
+    let items = requests
+        .iter()
+        .map(|req| {
+            let id = format!("{} {}", req.ide_id, req.version);
+            let Some(sv) = stale.iter().find(|s| s.ide_id == req.ide_id && s.version == req.version) else {
+                return ItemResult::skipped(id, "Not a stale version");
+            };
+            if sv.still_installed {
+                return ItemResult::skipped(id, "Still installed, uninstall it first");
+            }
+
+            let paths: Vec<PathBuf> = sv.config_path.iter().chain(sv.cache_path.iter()).cloned().collect();
+            if create_backup {
+                let ide = ides.iter().find(|i| i.id == sv.ide_id).expect("stale version comes from ides");
+                if let Err(e) = crate::backup::create_backup(ide, &paths) {
+                    return ItemResult::failed(id, format!("Backup failed, not deleted: {}", e));
+                }
+            }
+
+            let mut freed = 0;
+            let mut errors = Vec::new();
+            for path in &paths {
+                let (path_freed, _, mut path_errors) = crate::cleaner::clean_directory(path);
+                freed += path_freed;
+                if let Err(e) = fs::remove_dir(path) {
+                    if path.exists() {
+                        path_errors.push(format!("{}: {}", path.display(), e));
+                    }
+                }
+                if !path_errors.is_empty() {
+                    failed_paths.push(path.clone());
+                }
+                errors.extend(path_errors);
+            }
+            ItemResult::from_errors(id, freed, errors)
+        })
+        .collect();
+    let mut result = BatchResult::from_items(items);
+    result.failed_paths = failed_paths;
+    result
+}
+
+// ── JetBrains Toolbox leftover builds ──
+
+/// A superseded build kept by Toolbox under `apps\<product>\ch-*\<build>`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolboxLeftover {
+    /// "<product>/<channel>/<build>", passed back to `clean_toolbox_leftovers`.
+    pub id: String,
+    pub product: String,
+    pub channel: String,
+    pub build: String,
+    pub current_build: String,
+    /// The build folder plus its `<build>.plugins` / `<build>.vmoptions` siblings.
+    pub paths: Vec<PathBuf>,
+    pub size: u64,
+}
+
+fn toolbox_apps_dir() -> Option<PathBuf> {
+    dirs::data_local_dir()
+        .map(|d| d.join("JetBrains").join("Toolbox").join("apps"))
+        .filter(|p| p.is_dir())
+}
+
+/// "241.14494.240" → [241, 14494, 240]; None for anything that isn't a build number.
+fn parse_build(name: &str) -> Option<Vec<u64>> {
+    let parts: Option<Vec<u64>> = name.split('.').map(|p| p.parse().ok()).collect();
+    parts.filter(|p| p.len() >= 2)
+}
+
+fn path_size(path: &Path) -> u64 {
+    walkdir::WalkDir::new(path)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| e.metadata().ok())
+        .map(|m| m.len())
+        .sum()
+}
+
+fn child_dirs(dir: &Path) -> Vec<PathBuf> {
+    fs::read_dir(dir)
+        .map(|entries| entries.filter_map(|e| e.ok()).map(|e| e.path()).filter(|p| p.is_dir()).collect())
+        .unwrap_or_default()
+}
+
+/// Every build in each Toolbox channel except the newest one, which Toolbox launches.
+pub fn find_toolbox_leftovers() -> Vec<ToolboxLeftover> {
+    let mut results = Vec::new();
+    let Some(apps) = toolbox_apps_dir() else { return results };
+
+    for product_dir in child_dirs(&apps) {
+        let product = product_dir.file_name().unwrap_or_default().to_string_lossy().to_string();
+        let channels = child_dirs(&product_dir)
+            .into_iter()
+            .filter(|c| c.file_name().map(|n| n.to_string_lossy().starts_with("ch-")).unwrap_or(false));
+        for channel_dir in channels {
+            let channel = channel_dir.file_name().unwrap_or_default().to_string_lossy().to_string();
+            let mut builds: Vec<(Vec<u64>, String)> = child_dirs(&channel_dir)
+                .iter()
+                .filter_map(|b| {
+                    let name = b.file_name()?.to_string_lossy().to_string();
+                    parse_build(&name).map(|v| (v, name))
+                })
+                .collect();
+            builds.sort();
+            let Some((_, current_build)) = builds.pop() else { continue };
+
+            for (_, build) in builds {
+                let paths: Vec<PathBuf> = [build.clone(), format!("{}.plugins", build), format!("{}.vmoptions", build)]
+                    .iter()
+                    .map(|n| channel_dir.join(n))
+                    .filter(|p| p.exists())
+                    .collect();
+                let size = paths.iter().map(|p| path_size(p)).sum();
+                results.push(ToolboxLeftover {
+                    id: format!("{}/{}/{}", product, channel, build),
+                    product: product.clone(),
+                    channel: channel.clone(),
+                    build,
+                    current_build: current_build.clone(),
+                    paths,
+                    size,
+                });
+            }
+        }
+    }
+
+    results.sort_by(|a, b| b.size.cmp(&a.size));
+    results
+}
+
+/// Delete the given leftover builds. Only builds that are still reported as
+/// leftovers are touched, so the current build can never be removed.
+pub fn clean_toolbox_leftovers(ids: &[String]) -> BatchResult {
+    let leftovers = find_toolbox_leftovers();
+
+    let mut failed_paths = Vec::new();
+    let items = ids
+        .iter()
+        .map(|id| {
+            let Some(leftover) = leftovers.iter().find(|l| l.id == *id) else {
+                return ItemResult::skipped(id.clone(), "Not a leftover Toolbox build");
+            };
+            let mut freed = 0;
+            let mut errors = Vec::new();
+            for path in &leftover.paths {
+                let (path_freed, _, mut path_errors) = crate::cleaner::clean_directory(path);
+                freed += path_freed;
+                if path.exists() {
+                    if let Err(e) = crate::cleaner::remove_tree(path).or_else(|_| crate::cleaner::remove_file(path)) {
+                        path_errors.push(format!("{}: {}", path.display(), e));
+                    }
+                }
+                if !path_errors.is_empty() {
+                    failed_paths.push(path.clone());
+                }
+                errors.extend(path_errors);
+            }
+            ItemResult::from_errors(id.clone(), freed, errors)
+        })
+        .collect();
+    let mut result = BatchResult::from_items(items);
+    result.failed_paths = failed_paths;
+    result
+}
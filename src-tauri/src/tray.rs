@@ -0,0 +1,234 @@
+use serde::Deserialize;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::menu::{Menu, MenuItem, PredefinedMenuItem};
+use tauri::tray::{TrayIcon, TrayIconBuilder};
+use tauri::{App, AppHandle, Manager};
+use tauri_plugin_dialog::{DialogExt, MessageDialogButtons, MessageDialogKind};
+
+use crate::scanner::CategoryType;
+
+const TRAY_ID: &str = "devcleaner-footprint";
+/// How often the background monitor rescans for reclaimable space.
+const REFRESH_INTERVAL: Duration = Duration::from_secs(30 * 60);
+/// `LAST_RECLAIMABLE` before the first scan finishes.
+const NOT_SCANNED: u64 = u64::MAX;
+
+static QUICK_CLEAN_RUNNING: AtomicBool = AtomicBool::new(false);
+static LAST_RECLAIMABLE: AtomicU64 = AtomicU64::new(NOT_SCANNED);
+static LABELS: Mutex<Option<TrayLabels>> = Mutex::new(None);
+
+/// Tray menu and quick-clean dialog text in the UI language, sent by the
+/// frontend from its en/zh translations. `{{size}}` is filled in here.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TrayLabels {
+    pub reclaimable: String,
+    pub scanning: String,
+    pub cleaning: String,
+    pub quick_clean: String,
+    pub open: String,
+    pub quit: String,
+    pub confirm_title: String,
+    pub confirm_message: String,
+    pub confirm_clean: String,
+    pub confirm_cancel: String,
+}
+
+impl Default for TrayLabels {
+    /// Used until the window has loaded its translations.
+    fn default() -> Self {
+        TrayLabels {
+            reclaimable: "Reclaimable: {{size}}".into(),
+            scanning: "Reclaimable: scanning…".into(),
+            cleaning: "Cleaning…".into(),
+            quick_clean: "Quick clean (caches & logs)".into(),
+            open: "Open DevCleaner".into(),
+            quit: "Quit".into(),
+            confirm_title: "DevCleaner quick clean".into(),
+            confirm_message: "Delete {{size}} of caches and logs from every IDE that isn't running? Settings, extensions and conversations are not touched.".into(),
+            confirm_clean: "Clean".into(),
+            confirm_cancel: "Cancel".into(),
+        }
+    }
+}
+
+fn labels() -> TrayLabels {
+    LABELS.lock().ok().and_then(|l| l.clone()).unwrap_or_default()
+}
+
+/// The menu items whose text follows the UI language.
+struct TrayMenu {
+    size_item: MenuItem<tauri::Wry>,
+    quick_item: MenuItem<tauri::Wry>,
+    open_item: MenuItem<tauri::Wry>,
+    quit_item: MenuItem<tauri::Wry>,
+}
+
+/// Categories the "Safe" clean mode selects; quick clean touches nothing else.
+fn is_quick_clean_category(category_type: &CategoryType) -> bool {
+    matches!(category_type, CategoryType::Cache | CategoryType::Log)
+}
+
+/// Reclaimable bytes under the "Safe" mode (caches and logs of every detected IDE).
+pub fn reclaimable_bytes() -> u64 {
+    let ides = crate::ide_detector::detect_installed_ides();
+    crate::scanner::scan_all(&ides)
+        .results
+        .iter()
+        .flat_map(|r| r.categories.iter())
+        .filter(|c| is_quick_clean_category(&c.category_type))
+        .map(|c| c.total_size)
+        .sum()
+}
+
+/// Show the last reclaimable figure in the current language.
+fn show_reclaimable(size_item: &MenuItem<tauri::Wry>, tray: &TrayIcon<tauri::Wry>) {
+    let labels = labels();
+    let text = match LAST_RECLAIMABLE.load(Ordering::SeqCst) {
+        NOT_SCANNED => labels.scanning,
+        bytes => labels.reclaimable.replace("{{size}}", &crate::commands::format_bytes(bytes)),
+    };
+    let _ = size_item.set_text(&text);
+    let _ = tray.set_tooltip(Some(format!("DevCleaner — {}", text)));
+}
+
+fn refresh(size_item: &MenuItem<tauri::Wry>, tray: &TrayIcon<tauri::Wry>) {
+    LAST_RECLAIMABLE.store(reclaimable_bytes(), Ordering::SeqCst);
+    show_reclaimable(size_item, tray);
+}
+
+/// Switch the tray to `labels`, e.g. after the user changed the UI language.
+pub fn set_labels(app: &AppHandle, labels: TrayLabels) {
+    if let Ok(mut current) = LABELS.lock() {
+        *current = Some(labels.clone());
+    }
+    let Some(menu) = app.try_state::<TrayMenu>() else { return };
+    let _ = menu.quick_item.set_text(&labels.quick_clean);
+    let _ = menu.open_item.set_text(&labels.open);
+    let _ = menu.quit_item.set_text(&labels.quit);
+    if let Some(tray) = app.tray_by_id(TRAY_ID) {
+        if !QUICK_CLEAN_RUNNING.load(Ordering::SeqCst) {
+            show_reclaimable(&menu.size_item, &tray);
+        }
+    }
+}
+
+/// Ask before deleting anything from the tray, where there's no window to review
+/// the selection in. Blocks, so it must run off the main thread.
+fn confirm_quick_clean(app: &AppHandle, labels: &TrayLabels, bytes: u64) -> bool {
+    app.dialog()
+        .message(labels.confirm_message.replace("{{size}}", &crate::commands::format_bytes(bytes)))
+        .title(&labels.confirm_title)
+        .kind(MessageDialogKind::Warning)
+        .buttons(MessageDialogButtons::OkCancelCustom(labels.confirm_clean.clone(), labels.confirm_cancel.clone()))
+        .blocking_show()
+}
+
+/// Clean caches and logs of every IDE that isn't running, after a confirmation.
+/// No backup: these categories are regenerated by the IDEs themselves.
+fn quick_clean(app: &AppHandle, size_item: &MenuItem<tauri::Wry>, tray: &TrayIcon<tauri::Wry>) {
+    if QUICK_CLEAN_RUNNING.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    if let Err(e) = crate::policy::ensure_destructive_allowed() {
+        let _ = size_item.set_text(&e);
+        QUICK_CLEAN_RUNNING.store(false, Ordering::SeqCst);
+        return;
+    }
+
+    // One scan of exactly the IDEs that will be cleaned: the confirmed size and
+    // the clean both come from it, and running IDEs count toward neither
+    let targets: Vec<_> = crate::ide_detector::detect_installed_ides()
+        .into_iter()
+        .filter(|ide| ide.installed && crate::commands::check_ide_running(ide.id.clone()).is_empty())
+        .filter_map(|ide| {
+            let scan = crate::scanner::scan_ide(&ide);
+            let categories: Vec<String> = scan
+                .categories
+                .iter()
+                .filter(|c| is_quick_clean_category(&c.category_type))
+                .map(|c| c.name.clone())
+                .collect();
+            (!categories.is_empty()).then_some((ide, scan, categories))
+        })
+        .collect();
+    let bytes: u64 = targets
+        .iter()
+        .flat_map(|(_, scan, _)| scan.categories.iter())
+        .filter(|c| is_quick_clean_category(&c.category_type))
+        .map(|c| c.total_size)
+        .sum();
+
+    let labels = labels();
+    if !confirm_quick_clean(app, &labels, bytes) {
+        QUICK_CLEAN_RUNNING.store(false, Ordering::SeqCst);
+        return;
+    }
+    let _ = size_item.set_text(&labels.cleaning);
+
+    let mut failed_ides = Vec::new();
+    let mut failed_paths = Vec::new();
+    for (ide, scan, categories) in &targets {
+        let result = crate::cleaner::clean_scanned(ide, scan, categories);
+        if !result.failed_paths.is_empty() {
+            failed_ides.push(result.ide_id);
+            failed_paths.extend(result.failed_paths);
+        }
+    }
+    // Locked files are recorded for `retry_failed`, as for a clean started from the window
+    crate::retry::record(crate::retry::OperationKind::Clean, failed_ides, crate::retry::path_items(&failed_paths));
+
+    refresh(size_item, tray);
+    QUICK_CLEAN_RUNNING.store(false, Ordering::SeqCst);
+}
+
+fn show_main_window(app: &AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.unminimize();
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+}
+
+/// Tray icon showing current reclaimable space, with "Quick clean" and
+/// "Open DevCleaner" actions. A background thread keeps the figure fresh.
+pub fn init(app: &App) -> tauri::Result<()> {
+    let labels = labels();
+    let size_item = MenuItem::with_id(app, "footprint", &labels.scanning, false, None::<&str>)?;
+    let quick_item = MenuItem::with_id(app, "quick_clean", &labels.quick_clean, true, None::<&str>)?;
+    let open_item = MenuItem::with_id(app, "open", &labels.open, true, None::<&str>)?;
+    let quit_item = MenuItem::with_id(app, "quit", &labels.quit, true, None::<&str>)?;
+    let separator = PredefinedMenuItem::separator(app)?;
+    let menu = Menu::with_items(app, &[&size_item, &separator, &quick_item, &open_item, &quit_item])?;
+    app.manage(TrayMenu { size_item: size_item.clone(), quick_item, open_item, quit_item });
+
+    let mut builder = TrayIconBuilder::with_id(TRAY_ID).menu(&menu).tooltip("DevCleaner");
+    if let Some(icon) = app.default_window_icon() {
+        builder = builder.icon(icon.clone());
+    }
+
+    let menu_size_item = size_item.clone();
+    let tray = builder
+        .on_menu_event(move |app, event| match event.id().as_ref() {
+            "quick_clean" => {
+                if let Some(tray) = app.tray_by_id(TRAY_ID) {
+                    let (app, item) = (app.clone(), menu_size_item.clone());
+                    std::thread::spawn(move || quick_clean(&app, &item, &tray));
+                }
+            }
+            "open" => show_main_window(app),
+            "quit" => app.exit(0),
+            _ => {}
+        })
+        .build(app)?;
+
+    std::thread::spawn(move || loop {
+        if !QUICK_CLEAN_RUNNING.load(Ordering::SeqCst) {
+            refresh(&size_item, &tray);
+        }
+        std::thread::sleep(REFRESH_INTERVAL);
+    });
+
+    Ok(())
+}
@@ -1,6 +1,5 @@
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
-use std::process::Command;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VersionInstall {
@@ -23,6 +22,9 @@ pub struct InstalledProgram {
     pub ide_id: Option<String>,
     pub icon_base64: Option<String>,
     pub version_installs: Vec<VersionInstall>,
+    /// Set when the IDE came from Scoop/Chocolatey; its uninstall goes through that tool.
+    #[serde(default)]
+    pub package_manager: Option<crate::package_managers::PackageManager>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -31,6 +33,9 @@ pub struct UninstallOptions {
     pub keep_conversations: bool,
     pub keep_extensions: bool,
     pub keep_settings: bool,
+    /// Back up and remove Credential Manager entries (IDE sign-ins) too.
+    #[serde(default)]
+    pub remove_credentials: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -40,13 +45,45 @@ pub struct UninstallResult {
     pub uninstaller_ran: bool,
     pub residual_cleaned: bool,
     pub residual_freed_bytes: u64,
+    pub registry_keys_removed: Vec<String>,
+    pub credentials_removed: Vec<String>,
+    /// Backup holding the exported registry keys/credentials, if any were removed.
+    pub backup_id: Option<String>,
     pub errors: Vec<String>,
+    /// What a fresh detection pass still finds after the uninstall.
+    pub verification: UninstallVerification,
+    /// Residual folders that couldn't be removed.
+    #[serde(default)]
+    pub failed_paths: Vec<std::path::PathBuf>,
+    /// Set when `failed_paths` were recorded for `retry::retry_failed`.
+    #[serde(default)]
+    pub operation_id: Option<String>,
+    /// Installed programs before vs. after, when snapshots could be taken.
+    #[serde(default)]
+    pub programs_diff: Option<crate::program_snapshots::ProgramsDiff>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UninstallVerification {
+    /// True when nothing targeted by the uninstall is left behind.
+    pub clean: bool,
+    pub remaining: Vec<RemainingItem>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemainingItem {
+    /// "program", "path", "registry" or "credential"
+    pub kind: String,
+    pub item: String,
+    pub reason: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResidualInfo {
     pub paths: Vec<ResidualPath>,
     pub registry_keys: Vec<String>,
+    /// Credential Manager entries tied to the IDE (removed only with `remove_credentials`).
+    pub credentials: Vec<crate::credentials::CredentialEntry>,
     pub total_size: u64,
 }
 
@@ -57,12 +94,118 @@ pub struct ResidualPath {
     pub description: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct KeepOptionSizes {
     pub settings_size: u64,
     pub user_data_size: u64,
     pub conversations_size: u64,
     pub extensions_size: u64,
+    /// What each option protects, folder by folder (caches/logs under `Always`).
+    pub breakdown: Vec<KeepOptionBreakdown>,
+    /// One estimate per combination of the four keep options.
+    pub estimates: Vec<ReclaimEstimate>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeepOptionBreakdown {
+    pub group: KeepGroup,
+    /// Folders removed when the option is unchecked.
+    pub roots: Vec<PathBuf>,
+    /// Immediate children of `roots` (e.g. globalStorage/<extension>), largest first.
+    pub entries: Vec<ResidualPath>,
+    pub size: u64,
+}
+
+/// Bytes an uninstall removes / leaves behind for one set of keep options.
+/// Folders nest (VS Code's config dir holds User/globalStorage), so a file
+/// only counts as kept when no removed folder contains it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReclaimEstimate {
+    pub keep_user_data: bool,
+    pub keep_conversations: bool,
+    pub keep_extensions: bool,
+    pub keep_settings: bool,
+    pub removed_size: u64,
+    pub kept_size: u64,
+}
+
+/// Per-folder breakdown and reclaim estimates for every option combination,
+/// from a single walk over the residual candidates.
+fn keep_option_preview(ide: &crate::ide_detector::IdeInfo) -> (Vec<KeepOptionBreakdown>, Vec<ReclaimEstimate>) {
+    let candidates: Vec<(KeepGroup, PathBuf, &'static str)> =
+        residual_candidates(ide).into_iter().filter(|(_, p, _)| p.exists()).collect();
+
+    // Walk only outermost roots; each file gets the bits of every root containing it
+    let mut by_mask: std::collections::HashMap<u8, u64> = std::collections::HashMap::new();
+    let mut children: Vec<std::collections::HashMap<PathBuf, u64>> = vec![Default::default(); candidates.len()];
+    let outermost = candidates
+        .iter()
+        .filter(|(_, p, _)| !candidates.iter().any(|(_, other, _)| other != p && p.starts_with(other)));
+    let mut walked: Vec<&PathBuf> = Vec::new();
+    for (_, root, _) in outermost {
+        if walked.contains(&root) {
+            continue;
+        }
+        walked.push(root);
+        for entry in walkdir::WalkDir::new(root).into_iter().filter_map(|e| e.ok()).filter(|e| e.file_type().is_file()) {
+            let size = entry.metadata().map(|m| m.len()).unwrap_or(0);
+            let mut mask = 0;
+            for (i, (group, candidate, _)) in candidates.iter().enumerate() {
+                let Ok(rel) = entry.path().strip_prefix(candidate) else { continue };
+                mask |= group.bit();
+                if let Some(first) = rel.components().next() {
+                    *children[i].entry(candidate.join(first)).or_default() += size;
+                }
+            }
+            *by_mask.entry(mask).or_default() += size;
+        }
+    }
+
+    let breakdown = KeepGroup::ALL
+        .iter()
+        .filter_map(|&group| {
+            let indices: Vec<usize> = (0..candidates.len()).filter(|&i| candidates[i].0 == group).collect();
+            if indices.is_empty() {
+                return None;
+            }
+            let mut entries: Vec<ResidualPath> = Vec::new();
+            for &i in &indices {
+                entries.extend(children[i].iter().map(|(path, &size)| ResidualPath {
+                    path: path.clone(),
+                    size,
+                    description: candidates[i].2.into(),
+                }));
+            }
+            entries.sort_by(|a, b| b.size.cmp(&a.size));
+            let size = by_mask.iter().filter(|(m, _)| *m & group.bit() != 0).map(|(_, s)| s).sum();
+            Some(KeepOptionBreakdown { group, roots: indices.iter().map(|&i| candidates[i].1.clone()).collect(), entries, size })
+        })
+        .collect();
+
+    let total: u64 = by_mask.values().sum();
+    let estimates = (0..16u8)
+        .map(|combo| {
+            let options = UninstallOptions {
+                keep_settings: combo & 1 != 0,
+                keep_user_data: combo & 2 != 0,
+                keep_conversations: combo & 4 != 0,
+                keep_extensions: combo & 8 != 0,
+                remove_credentials: false,
+            };
+            let removed_mask: u8 = KeepGroup::ALL.iter().filter(|g| !g.kept(&options)).map(|g| g.bit()).sum();
+            let removed_size = by_mask.iter().filter(|(m, _)| *m & removed_mask != 0).map(|(_, s)| s).sum();
+            ReclaimEstimate {
+                keep_user_data: options.keep_user_data,
+                keep_conversations: options.keep_conversations,
+                keep_extensions: options.keep_extensions,
+                keep_settings: options.keep_settings,
+                removed_size,
+                kept_size: total - removed_size,
+            }
+        })
+        .collect();
+
+    (breakdown, estimates)
 }
 
 /// Calculate sizes for each keep option category of a given IDE
@@ -70,7 +213,7 @@ pub fn get_keep_option_sizes(ide_id: &str) -> KeepOptionSizes {
     let ides = crate::ide_detector::detect_installed_ides();
     let ide = match ides.iter().find(|i| i.id == ide_id && i.installed) {
         Some(i) => i,
-        None => return KeepOptionSizes { settings_size: 0, user_data_size: 0, conversations_size: 0, extensions_size: 0 },
+        None => return KeepOptionSizes::default(),
     };
 
     let mut settings_size: u64 = 0;
@@ -112,6 +255,65 @@ pub fn get_keep_option_sizes(ide_id: &str) -> KeepOptionSizes {
                 if ext.exists() { extensions_size = dir_size(ext); }
             }
         }
+        crate::ide_detector::IdeType::Zed => {
+            // Settings: settings.json, keymap.json, themes/, snippets/
+            if let Some(ref config) = ide.config_path {
+                if config.exists() { settings_size = dir_size(config); }
+            }
+
+            // Workspace data: db/ (open panes, recent projects)
+            if let Some(ref ws) = ide.workspace_storage_path {
+                if ws.exists() { user_data_size = dir_size(ws); }
+            }
+
+            // Conversations: threads/, conversations/, prompts/
+            for dir in crate::ide_detector::zed_conversation_dirs() {
+                conversations_size += dir_size(&dir);
+            }
+
+            if let Some(ref ext) = ide.extension_path {
+                if ext.exists() { extensions_size = dir_size(ext); }
+            }
+        }
+        crate::ide_detector::IdeType::VisualStudio => {
+            // Settings: %APPDATA%\Microsoft\VisualStudio\<instance> (CurrentSettings.vssettings, ActivityLog)
+            if let Some(ref config) = ide.config_path {
+                if config.exists() { settings_size = dir_size(config); }
+            }
+
+            // Extensions: per-instance Extensions folder
+            if let Some(ref ext) = ide.extension_path {
+                if ext.exists() { extensions_size = dir_size(ext); }
+            }
+        }
+        crate::ide_detector::IdeType::TerminalEditor => {
+            // Settings: init.lua / vimrc directory
+            if let Some(ref config) = ide.config_path {
+                if config.exists() { settings_size = dir_size(config); }
+            }
+
+            // Extensions: plugin manager checkouts
+            if let Some(ref ext) = ide.extension_path {
+                if ext.exists() { extensions_size = dir_size(ext); }
+            }
+        }
+        crate::ide_detector::IdeType::Xcode => {
+            // Settings: ~/Library/Developer/Xcode/UserData (key bindings, themes, snippets)
+            if let Some(ref config) = ide.config_path {
+                if config.exists() { settings_size = dir_size(config); }
+            }
+        }
+        crate::ide_detector::IdeType::Eclipse => {
+            // Settings: ~/.eclipse (per-user install configuration)
+            if let Some(ref config) = ide.config_path {
+                if config.exists() { settings_size = dir_size(config); }
+            }
+
+            // Extensions: ~/.p2 bundle pool
+            if let Some(ref ext) = ide.extension_path {
+                if ext.exists() { extensions_size = dir_size(ext); }
+            }
+        }
         crate::ide_detector::IdeType::JetBrains => {
             // Settings: config dir (minus plugins subfolder)
             if let Some(ref config) = ide.config_path {
@@ -133,18 +335,30 @@ pub fn get_keep_option_sizes(ide_id: &str) -> KeepOptionSizes {
         }
     }
 
+    let (breakdown, estimates) = keep_option_preview(ide);
     KeepOptionSizes {
         settings_size,
         user_data_size,
         conversations_size,
         extensions_size,
+        breakdown,
+        estimates,
     }
 }
 
 // IDE name patterns to match in registry DisplayName
 const IDE_PATTERNS: &[(&str, &str)] = &[
+    ("Eclipse IDE", "eclipse"),
+    ("Neovim", "neovim"),
+    ("Vim ", "vim"),
     ("Visual Studio Code", "vscode"),
     ("Microsoft Visual Studio Code", "vscode"),
+    ("Visual Studio Community 2022", "visualstudio_2022"),
+    ("Visual Studio Professional 2022", "visualstudio_2022"),
+    ("Visual Studio Enterprise 2022", "visualstudio_2022"),
+    ("Visual Studio Community 2019", "visualstudio_2019"),
+    ("Visual Studio Professional 2019", "visualstudio_2019"),
+    ("Visual Studio Enterprise 2019", "visualstudio_2019"),
     ("Cursor", "cursor"),
     ("Windsurf", "windsurf"),
     ("Kiro", "kiro"),
@@ -175,16 +389,34 @@ const IDE_PATTERNS: &[(&str, &str)] = &[
     ("Fleet", "fleet"),
 ];
 
-fn match_ide_id(display_name: &str) -> Option<String> {
+fn match_ide_id(display_name: &str, custom: &[(String, String)]) -> Option<String> {
     let lower = display_name.to_lowercase();
+    // "zed" is too short for a substring match ("Optimized", "Authorized", ...)
+    if lower == "zed" || lower.starts_with("zed ") {
+        return Some("zed".into());
+    }
     for (pattern, id) in IDE_PATTERNS {
         if lower.contains(&pattern.to_lowercase()) {
             return Some(id.to_string());
         }
     }
+    // User-defined IDEs (ide_definitions.json) match on their display name
+    for (name, id) in custom {
+        if lower.contains(&name.to_lowercase()) {
+            return Some(id.clone());
+        }
+    }
     None
 }
 
+/// (name, id) pairs of user-defined IDEs, for registry DisplayName matching.
+fn custom_ide_patterns() -> Vec<(String, String)> {
+    let defs = crate::ide_detector::load_ide_definitions();
+    defs.vscode.iter().filter(|d| d.custom).map(|d| (d.name.clone(), d.id.clone()))
+        .chain(defs.jetbrains.iter().filter(|p| p.custom).map(|p| (p.name.clone(), p.id.clone())))
+        .collect()
+}
+
 /// Scan Windows registry for installed programs matching dev tools
 pub fn scan_installed_programs() -> Vec<InstalledProgram> {
     let mut programs = Vec::new();
@@ -194,6 +426,7 @@ pub fn scan_installed_programs() -> Vec<InstalledProgram> {
         use winreg::enums::*;
         use winreg::RegKey;
 
+        let custom_patterns = custom_ide_patterns();
         let paths = [
             (HKEY_LOCAL_MACHINE, r"SOFTWARE\Microsoft\Windows\CurrentVersion\Uninstall"),
             (HKEY_LOCAL_MACHINE, r"SOFTWARE\WOW6432Node\Microsoft\Windows\CurrentVersion\Uninstall"),
@@ -211,7 +444,7 @@ pub fn scan_installed_programs() -> Vec<InstalledProgram> {
                         }
 
                         // Only include dev-tool-related programs
-                        let ide_id = match_ide_id(&display_name);
+                        let ide_id = match_ide_id(&display_name, &custom_patterns);
                         if ide_id.is_none() {
                             continue;
                         }
@@ -248,6 +481,7 @@ pub fn scan_installed_programs() -> Vec<InstalledProgram> {
                             ide_id,
                             icon_base64: None,
                             version_installs: vec![],
+                            package_manager: None,
                         });
                     }
                 }
@@ -255,6 +489,12 @@ pub fn scan_installed_programs() -> Vec<InstalledProgram> {
         }
     }
 
+    merge_package_installs(&mut programs);
+
+    // Programs of ignored IDEs stay out of the list entirely
+    let ignored = crate::config::load_config().ignored_ides;
+    programs.retain(|p| p.ide_id.as_ref().map(|id| !ignored.contains(id)).unwrap_or(true));
+
     // Merge IDEs detected by filesystem that have no registry entry (e.g. Toolbox-managed, or already uninstalled but residual data remains)
     let detected_ides = crate::ide_detector::detect_installed_ides();
     let registry_ide_ids: std::collections::HashSet<String> = programs.iter()
@@ -322,6 +562,7 @@ pub fn scan_installed_programs() -> Vec<InstalledProgram> {
             ide_id: Some(ide.id.clone()),
             icon_base64: None,
             version_installs: vi,
+            package_manager: None,
         });
     }
 
@@ -409,7 +650,7 @@ fn extract_icons_batch(programs: &mut [InstalledProgram]) {
         }
         script.push_str("$results | ConvertTo-Json -Compress\n");
 
-        if let Ok(output) = Command::new("powershell")
+        if let Ok(output) = crate::process::hidden_command("powershell")
             .args(["-NoProfile", "-NonInteractive", "-Command", &script])
             .output()
         {
@@ -431,107 +672,283 @@ fn extract_icons_batch(programs: &mut [InstalledProgram]) {
     }
 }
 
-/// Find residual files/folders after an IDE is uninstalled
-pub fn find_residual_data(ide_id: &str, options: &UninstallOptions) -> ResidualInfo {
-    let mut paths = Vec::new();
-    let mut total_size: u64 = 0;
+/// Minimal record of one Uninstall registry entry (every program, not just IDEs).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UninstallEntry {
+    pub registry_key: String,
+    pub display_name: String,
+    pub publisher: String,
+    pub display_version: String,
+}
 
-    let ides = crate::ide_detector::detect_installed_ides();
-    if let Some(ide) = ides.iter().find(|i| i.id == ide_id) {
-        // Cache paths — always clean
-        for cache_path in &ide.cache_paths {
-            if cache_path.exists() {
-                let size = dir_size(cache_path);
-                total_size += size;
-                paths.push(ResidualPath {
-                    path: cache_path.clone(),
-                    size,
-                    description: "Cache".into(),
-                });
-            }
-        }
+// ── Scoop / Chocolatey installs ──
 
-        // Log paths — always clean
-        for log_path in &ide.log_paths {
-            if log_path.exists() {
-                let size = dir_size(log_path);
-                total_size += size;
-                paths.push(ResidualPath {
-                    path: log_path.clone(),
-                    size,
-                    description: "Logs".into(),
-                });
-            }
+/// Add IDEs installed through Scoop/Chocolatey. A Chocolatey package usually
+/// wraps the vendor installer, so a matching registry entry is switched over
+/// to `choco uninstall` instead of being listed twice. Scoop never writes one.
+fn merge_package_installs(programs: &mut Vec<InstalledProgram>) {
+    use crate::package_managers::{package_ide_id, package_installs, package_key, PackageManager};
+
+    for install in package_installs() {
+        let Some(ide_id) = package_ide_id(&install.package) else { continue };
+        let command = install.uninstall_command();
+        let existing = programs.iter_mut().find(|p| p.ide_id.as_deref() == Some(ide_id) && p.package_manager.is_none());
+        if let Some(existing) = existing.filter(|_| install.manager == PackageManager::Chocolatey) {
+            existing.package_manager = Some(install.manager);
+            existing.uninstall_string = Some(command.clone());
+            existing.quiet_uninstall_string = Some(command);
+            continue;
         }
+        let manager_name = match install.manager {
+            PackageManager::Scoop => "Scoop",
+            PackageManager::Chocolatey => "Chocolatey",
+        };
+        programs.push(InstalledProgram {
+            display_name: format!("{} ({})", install.package, manager_name),
+            publisher: String::new(),
+            install_location: Some(install.install_dir.display().to_string()),
+            uninstall_string: Some(command.clone()),
+            quiet_uninstall_string: Some(command),
+            display_version: install.version.clone(),
+            estimated_size_kb: dir_size(&install.install_dir) / 1024,
+            registry_key: package_key(&install),
+            icon_path: None,
+            ide_id: Some(ide_id.to_string()),
+            icon_base64: None,
+            version_installs: vec![],
+            package_manager: Some(install.manager),
+        });
+    }
+}
 
-        // Config path (settings) — respect keep_settings
-        if !options.keep_settings {
-            if let Some(ref config) = ide.config_path {
-                if config.exists() {
-                    let size = dir_size(config);
-                    total_size += size;
-                    paths.push(ResidualPath {
-                        path: config.clone(),
-                        size,
-                        description: "Configuration".into(),
+/// Every entry shown in Programs and Features, without size/icon lookups.
+#[cfg(target_os = "windows")]
+pub fn list_uninstall_entries() -> Vec<UninstallEntry> {
+    use winreg::enums::*;
+    use winreg::RegKey;
+
+    let mut entries = Vec::new();
+    // The same subkey name can exist under both hives, so keys carry the hive
+    let paths = [
+        (HKEY_LOCAL_MACHINE, "HKEY_LOCAL_MACHINE", r"SOFTWARE\Microsoft\Windows\CurrentVersion\Uninstall"),
+        (HKEY_LOCAL_MACHINE, "HKEY_LOCAL_MACHINE", r"SOFTWARE\WOW6432Node\Microsoft\Windows\CurrentVersion\Uninstall"),
+        (HKEY_CURRENT_USER, "HKEY_CURRENT_USER", r"SOFTWARE\Microsoft\Windows\CurrentVersion\Uninstall"),
+    ];
+    for (hive, hive_name, path) in &paths {
+        if let Ok(uninstall_key) = RegKey::predef(*hive).open_subkey(path) {
+            for name in uninstall_key.enum_keys().filter_map(|k| k.ok()) {
+                if let Ok(subkey) = uninstall_key.open_subkey(&name) {
+                    let display_name: String = subkey.get_value("DisplayName").unwrap_or_default();
+                    if display_name.is_empty() { continue; }
+                    entries.push(UninstallEntry {
+                        registry_key: format!("{}\\{}\\{}", hive_name, path, name),
+                        display_name,
+                        publisher: subkey.get_value("Publisher").unwrap_or_default(),
+                        display_version: subkey.get_value("DisplayVersion").unwrap_or_default(),
                     });
                 }
             }
         }
+    }
+    entries
+}
 
-        // Extensions — respect keep_extensions
-        if !options.keep_extensions {
-            if let Some(ref ext) = ide.extension_path {
-                if ext.exists() {
-                    let size = dir_size(ext);
-                    total_size += size;
-                    paths.push(ResidualPath {
-                        path: ext.clone(),
-                        size,
-                        description: "Extensions".into(),
-                    });
-                }
-            }
+#[cfg(not(target_os = "windows"))]
+pub fn list_uninstall_entries() -> Vec<UninstallEntry> {
+    Vec::new()
+}
+
+/// Which keep option protects a residual folder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KeepGroup {
+    /// Caches and logs, removed whatever the options.
+    Always,
+    Settings,
+    UserData,
+    Conversations,
+    Extensions,
+}
+
+impl KeepGroup {
+    const ALL: [KeepGroup; 5] =
+        [KeepGroup::Always, KeepGroup::Settings, KeepGroup::UserData, KeepGroup::Conversations, KeepGroup::Extensions];
+
+    fn kept(self, options: &UninstallOptions) -> bool {
+        match self {
+            KeepGroup::Always => false,
+            KeepGroup::Settings => options.keep_settings,
+            KeepGroup::UserData => options.keep_user_data,
+            KeepGroup::Conversations => options.keep_conversations,
+            KeepGroup::Extensions => options.keep_extensions,
         }
+    }
 
-        // Workspace storage (user data) — respect keep_user_data
-        if !options.keep_user_data {
-            if let Some(ref ws) = ide.workspace_storage_path {
-                if ws.exists() {
-                    let size = dir_size(ws);
-                    total_size += size;
-                    paths.push(ResidualPath {
-                        path: ws.clone(),
-                        size,
-                        description: "Workspace Storage".into(),
-                    });
-                }
-            }
+    fn bit(self) -> u8 {
+        1 << KeepGroup::ALL.iter().position(|g| *g == self).unwrap_or(0)
+    }
+}
+
+/// Every folder an uninstall may remove, with the option protecting it, in removal order.
+fn residual_candidates(ide: &crate::ide_detector::IdeInfo) -> Vec<(KeepGroup, PathBuf, &'static str)> {
+    let mut candidates = Vec::new();
+
+    // Caches. JetBrains cache_paths are the pieces of each system dir
+    // (LocalHistory is split off), so remove the system dirs whole.
+    let cache_dirs: Vec<PathBuf> = if ide.ide_type == crate::ide_detector::IdeType::JetBrains {
+        ide.versioned_folders.iter().filter_map(|vf| vf.cache_path.clone()).collect()
+    } else {
+        ide.cache_paths.clone()
+    };
+    for cache_path in cache_dirs {
+        candidates.push((KeepGroup::Always, cache_path, "Cache"));
+    }
+    for log_path in &ide.log_paths {
+        candidates.push((KeepGroup::Always, log_path.clone(), "Logs"));
+    }
+    if let Some(ref config) = ide.config_path {
+        candidates.push((KeepGroup::Settings, config.clone(), "Configuration"));
+    }
+    if let Some(ref ext) = ide.extension_path {
+        candidates.push((KeepGroup::Extensions, ext.clone(), "Extensions"));
+    }
+    if let Some(ref ws) = ide.workspace_storage_path {
+        candidates.push((KeepGroup::UserData, ws.clone(), "Workspace Storage"));
+    }
+    if let Some(ref gs) = ide.global_storage_path {
+        candidates.push((KeepGroup::Conversations, gs.clone(), "Global Storage (conversations)"));
+    }
+    if ide.ide_type == crate::ide_detector::IdeType::Zed {
+        for dir in crate::ide_detector::zed_conversation_dirs() {
+            candidates.push((KeepGroup::Conversations, dir, "AI conversations"));
         }
+    }
+    candidates
+}
 
-        // Global storage (conversations) — respect keep_conversations
-        if !options.keep_conversations {
-            if let Some(ref gs) = ide.global_storage_path {
-                if gs.exists() {
-                    let size = dir_size(gs);
-                    total_size += size;
-                    paths.push(ResidualPath {
-                        path: gs.clone(),
-                        size,
-                        description: "Global Storage (conversations)".into(),
-                    });
-                }
+/// Find residual files/folders after an IDE is uninstalled
+pub fn find_residual_data(ide_id: &str, options: &UninstallOptions) -> ResidualInfo {
+    let mut paths = Vec::new();
+    let mut registry_keys = Vec::new();
+    let mut credentials = Vec::new();
+    let mut total_size: u64 = 0;
+
+    let ides = crate::ide_detector::detect_installed_ides();
+    if let Some(ide) = ides.iter().find(|i| i.id == ide_id) {
+        registry_keys = find_residual_registry_keys(ide);
+        // Shared JetBrains targets belong to every JetBrains IDE, not just this one
+        credentials = crate::credentials::find_ide_credentials(std::slice::from_ref(ide))
+            .into_iter()
+            .filter(|c| !c.shared)
+            .collect();
+
+        for (group, path, description) in residual_candidates(ide) {
+            if group.kept(options) || !path.exists() {
+                continue;
             }
+            let size = dir_size(&path);
+            total_size += size;
+            paths.push(ResidualPath { path, size, description: description.into() });
         }
     }
 
     ResidualInfo {
         paths,
-        registry_keys: vec![],
+        registry_keys,
+        credentials,
         total_size,
     }
 }
 
+/// Registry keys an IDE leaves behind: vendor keys, Explorer context-menu verbs
+/// and the `Applications\<exe>` registration. Only existing keys are returned.
+#[cfg(target_os = "windows")]
+fn find_residual_registry_keys(ide: &crate::ide_detector::IdeInfo) -> Vec<String> {
+    use winreg::enums::*;
+    use winreg::RegKey;
+
+    // Portable copies don't register themselves
+    if ide.portable_root.is_some() {
+        return Vec::new();
+    }
+
+    // Trae and Trae CN both register `Applications\Trae.exe`; while the other
+    // product is installed the key may be pointing at it, so it stays
+    let shared: Vec<String> = crate::ide_detector::detect_all_ides()
+        .iter()
+        .filter(|other| other.installed && other.id != ide.id)
+        .flat_map(|other| crate::ide_detector::get_process_names(&other.id))
+        .collect();
+    let mut candidates: Vec<String> = Vec::new();
+    for exe in crate::ide_detector::get_process_names(&ide.id) {
+        if shared.iter().any(|s| s.eq_ignore_ascii_case(&exe)) {
+            continue;
+        }
+        candidates.push(format!(r"Software\Classes\Applications\{}", exe));
+    }
+    match ide.ide_type {
+        crate::ide_detector::IdeType::VscodeBased => {
+            let verb = if ide.id == "vscode" { "VSCode".to_string() } else { ide.name.replace(' ', "") };
+            for shell in [
+                r"Software\Classes\*\shell",
+                r"Software\Classes\directory\shell",
+                r"Software\Classes\directory\background\shell",
+                r"Software\Classes\Drive\shell",
+            ] {
+                candidates.push(format!(r"{}\{}", shell, verb));
+            }
+        }
+        crate::ide_detector::IdeType::JetBrains => {
+            candidates.push(format!(r"Software\JetBrains\{}", ide.name));
+        }
+        crate::ide_detector::IdeType::Zed => {
+            candidates.push(r"Software\Classes\zed".to_string());
+        }
+        crate::ide_detector::IdeType::Eclipse
+        | crate::ide_detector::IdeType::TerminalEditor
+        | crate::ide_detector::IdeType::Xcode => {}
+        crate::ide_detector::IdeType::VisualStudio => {
+            for vf in &ide.versioned_folders {
+                candidates.push(format!(r"Software\Microsoft\VisualStudio\{}", vf.version));
+                candidates.push(format!(r"Software\Microsoft\VisualStudio\{}_Config", vf.version));
+            }
+        }
+    }
+
+    let hkcu = RegKey::predef(HKEY_CURRENT_USER);
+    candidates
+        .into_iter()
+        .filter(|k| hkcu.open_subkey(k).is_ok())
+        .map(|k| format!(r"HKEY_CURRENT_USER\{}", k))
+        .collect()
+}
+
+#[cfg(not(target_os = "windows"))]
+fn find_residual_registry_keys(_ide: &crate::ide_detector::IdeInfo) -> Vec<String> {
+    Vec::new()
+}
+
+#[cfg(target_os = "windows")]
+fn delete_registry_key(key: &str) -> Result<(), String> {
+    use winreg::enums::*;
+    use winreg::RegKey;
+
+    let (hive, path) = if let Some(p) = key.strip_prefix(r"HKEY_CURRENT_USER\") {
+        (HKEY_CURRENT_USER, p)
+    } else if let Some(p) = key.strip_prefix(r"HKEY_LOCAL_MACHINE\") {
+        (HKEY_LOCAL_MACHINE, p)
+    } else {
+        return Err(format!("Unsupported registry hive: {}", key));
+    };
+    RegKey::predef(hive)
+        .delete_subkey_all(path)
+        .map_err(|e| format!("{}: {}", key, e))
+}
+
+#[cfg(not(target_os = "windows"))]
+fn delete_registry_key(key: &str) -> Result<(), String> {
+    Err(format!("Registry is only available on Windows ({})", key))
+}
+
 /// Run uninstaller for a program and clean residuals
 pub fn uninstall_program(
     program: &InstalledProgram,
@@ -540,6 +957,26 @@ pub fn uninstall_program(
     let mut errors = Vec::new();
     let mut uninstaller_ran = false;
 
+    if let Some(ref ide_id) = program.ide_id {
+        if crate::config::load_config().ignored_ides.contains(ide_id) {
+            return UninstallResult {
+                success: false,
+                program_name: program.display_name.clone(),
+                uninstaller_ran: false,
+                residual_cleaned: false,
+                residual_freed_bytes: 0,
+                registry_keys_removed: Vec::new(),
+                credentials_removed: Vec::new(),
+                backup_id: None,
+                errors: vec![format!("'{}' is on the ignore list", program.display_name)],
+                verification: UninstallVerification::default(),
+                failed_paths: Vec::new(),
+                operation_id: None,
+                programs_diff: None,
+            };
+        }
+    }
+
     // Try quiet uninstall first, then normal
     let uninstall_cmd = program
         .quiet_uninstall_string
@@ -556,16 +993,77 @@ pub fn uninstall_program(
 
     // Find and clean residual data
     let mut residual_freed: u64 = 0;
+    let mut registry_keys_removed = Vec::new();
+    let mut credentials_removed = Vec::new();
+    let mut backup_id = None;
+    let mut failed_paths = Vec::new();
+    // Item → error, so verification can say why something is still there
+    let mut failures: std::collections::HashMap<String, String> = std::collections::HashMap::new();
     let residual_cleaned;
 
     if let Some(ref ide_id) = program.ide_id {
         let residual = find_residual_data(ide_id, options);
-        residual_cleaned = !residual.paths.is_empty();
+        residual_cleaned = !residual.paths.is_empty()
+            || !residual.registry_keys.is_empty()
+            || (options.remove_credentials && !residual.credentials.is_empty());
         for rp in &residual.paths {
             if rp.path.exists() {
-                match std::fs::remove_dir_all(&rp.path) {
+                crate::db_pool::close_under(&rp.path);
+                match crate::cleaner::remove_tree(&rp.path) {
                     Ok(_) => residual_freed += rp.size,
-                    Err(e) => errors.push(format!("{}: {}", rp.path.display(), e)),
+                    Err(e) => {
+                        failed_paths.push(rp.path.clone());
+                        failures.insert(rp.path.display().to_string(), e.to_string());
+                        errors.push(format!("{}: {}", rp.path.display(), e));
+                    }
+                }
+            }
+        }
+
+        // Registry keys and credentials are only removed once they've been saved into a backup
+        let credentials: &[crate::credentials::CredentialEntry] =
+            if options.remove_credentials { &residual.credentials } else { &[] };
+        let saved_credentials = match crate::credentials::read_credentials(credentials) {
+            Ok(saved) => saved,
+            Err(e) => {
+                errors.push(format!("Credential backup failed, credentials left in place: {}", e));
+                Vec::new()
+            }
+        };
+        if !residual.registry_keys.is_empty() || !saved_credentials.is_empty() {
+            match crate::backup::create_backup_with_extras(
+                ide_id,
+                &program.display_name,
+                &[],
+                &residual.registry_keys,
+                &saved_credentials,
+            ) {
+                Ok(info) => {
+                    backup_id = Some(info.id);
+                    for key in &residual.registry_keys {
+                        match delete_registry_key(key) {
+                            Ok(()) => registry_keys_removed.push(key.clone()),
+                            Err(e) => {
+                                failures.insert(key.clone(), e.clone());
+                                errors.push(e);
+                            }
+                        }
+                    }
+                    for cred in &saved_credentials {
+                        match crate::credentials::delete_credential(&cred.target_name, cred.cred_type) {
+                            Ok(()) => credentials_removed.push(cred.target_name.clone()),
+                            Err(e) => {
+                                failures.insert(cred.target_name.clone(), e.clone());
+                                errors.push(e);
+                            }
+                        }
+                    }
+                }
+                Err(e) => {
+                    for item in residual.registry_keys.iter().chain(saved_credentials.iter().map(|c| &c.target_name)) {
+                        failures.insert(item.clone(), format!("Not removed because the backup failed: {}", e));
+                    }
+                    errors.push(format!("Backup failed, registry keys and credentials left in place: {}", e));
                 }
             }
         }
@@ -575,11 +1073,12 @@ pub fn uninstall_program(
 
     // For detected-only entries (no uninstaller), success = residual cleaned without errors
     // For registry entries, success = uninstaller ran + no errors
+    let verification = verify_uninstall(program, options, uninstaller_ran, &failures);
     let success = if has_uninstaller {
         uninstaller_ran && errors.is_empty()
     } else {
         residual_cleaned && errors.is_empty()
-    };
+    } && verification.clean;
 
     UninstallResult {
         success,
@@ -587,14 +1086,101 @@ pub fn uninstall_program(
         uninstaller_ran,
         residual_cleaned,
         residual_freed_bytes: residual_freed,
+        registry_keys_removed,
+        credentials_removed,
+        backup_id,
         errors,
+        verification,
+        failed_paths,
+        operation_id: None,
+        programs_diff: None,
+    }
+}
+
+/// Re-run detection and the residual scan after an uninstall and report
+/// whatever still exists, with the removal error when there was one.
+fn verify_uninstall(
+    program: &InstalledProgram,
+    options: &UninstallOptions,
+    uninstaller_ran: bool,
+    failures: &std::collections::HashMap<String, String>,
+) -> UninstallVerification {
+    let reason = |item: &str, fallback: &str| {
+        failures.get(item).cloned().unwrap_or_else(|| fallback.to_string())
+    };
+    let mut remaining = Vec::new();
+
+    if uninstaller_ran && uninstall_entry_exists(&program.registry_key) {
+        remaining.push(RemainingItem {
+            kind: "program".into(),
+            item: program.display_name.clone(),
+            reason: "Still registered in Programs and Features (the uninstaller may have been cancelled or still be running)".into(),
+        });
+    } else if uninstaller_ran && crate::package_managers::find_by_key(&program.registry_key).is_some() {
+        remaining.push(RemainingItem {
+            kind: "program".into(),
+            item: program.display_name.clone(),
+            reason: "Still installed according to the package manager".into(),
+        });
+    }
+
+    if let Some(ref ide_id) = program.ide_id {
+        let residual = find_residual_data(ide_id, options);
+        for rp in &residual.paths {
+            let item = rp.path.display().to_string();
+            remaining.push(RemainingItem {
+                kind: "path".into(),
+                reason: reason(&item, "Still present after cleanup (recreated or in use)"),
+                item,
+            });
+        }
+        for key in &residual.registry_keys {
+            remaining.push(RemainingItem {
+                kind: "registry".into(),
+                item: key.clone(),
+                reason: reason(key, "Still present after cleanup"),
+            });
+        }
+        if options.remove_credentials {
+            for cred in &residual.credentials {
+                remaining.push(RemainingItem {
+                    kind: "credential".into(),
+                    item: cred.target_name.clone(),
+                    reason: reason(&cred.target_name, "Still present after cleanup"),
+                });
+            }
+        }
+    }
+
+    UninstallVerification {
+        clean: remaining.is_empty(),
+        remaining,
     }
 }
 
+/// Whether an Uninstall registry entry ("SOFTWARE\...\Uninstall\<name>") still exists.
+#[cfg(target_os = "windows")]
+fn uninstall_entry_exists(registry_key: &str) -> bool {
+    use winreg::enums::*;
+    use winreg::RegKey;
+
+    if registry_key.starts_with("detected:") {
+        return false;
+    }
+    [HKEY_LOCAL_MACHINE, HKEY_CURRENT_USER]
+        .iter()
+        .any(|hive| RegKey::predef(*hive).open_subkey(registry_key).is_ok())
+}
+
+#[cfg(not(target_os = "windows"))]
+fn uninstall_entry_exists(_registry_key: &str) -> bool {
+    false
+}
+
 fn run_uninstaller(cmd: &str) -> Result<(), String> {
     #[cfg(target_os = "windows")]
     {
-        let output = Command::new("cmd")
+        let output = crate::process::hidden_command("cmd")
             .args(["/C", cmd])
             .output()
             .map_err(|e| format!("Failed to start uninstaller: {}", e))?;
@@ -0,0 +1,270 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use crate::batch::{BatchResult, ItemResult};
+
+/// Remote server folders VS Code-family editors install in the Linux home dir.
+const SERVER_DIRS: &[(&str, &str)] = &[
+    (".vscode-server", "VS Code"),
+    (".vscode-server-insiders", "VS Code Insiders"),
+    (".cursor-server", "Cursor"),
+    (".windsurf-server", "Windsurf"),
+    (".vscodium-server", "VSCodium"),
+    (".trae-server", "Trae"),
+    (".kiro-server", "Kiro"),
+];
+
+/// A server build touched this recently may still be running, so it's never stale.
+const RECENT_USE: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Docker Desktop's internal distros have no user homes worth scanning.
+const IGNORED_DISTROS: &[&str] = &["docker-desktop", "docker-desktop-data"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum WslItemKind {
+    /// `bin/<commit>` or `cli/servers/<quality>-<commit>`.
+    ServerBuild,
+    /// `extensions/<publisher>.<name>-<version>`.
+    Extension,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WslServerItem {
+    pub kind: WslItemKind,
+    pub name: String,
+    pub path: PathBuf,
+    pub size: u64,
+    /// Superseded build, older extension version or one marked obsolete.
+    pub stale: bool,
+}
+
+/// One `~/.<product>-server` folder inside one distro.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WslServerDir {
+    pub distro: String,
+    pub user: String,
+    pub product: String,
+    pub path: PathBuf,
+    pub size: u64,
+    pub items: Vec<WslServerItem>,
+    pub stale_size: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WslReport {
+    pub distros: Vec<String>,
+    pub servers: Vec<WslServerDir>,
+}
+
+// ── Distros ──
+
+/// `wsl.exe -l -q` prints UTF-16LE; older builds may print the ANSI code page.
+fn decode_wsl_output(bytes: &[u8]) -> String {
+    if bytes.len() >= 2 && bytes.iter().skip(1).step_by(2).all(|&b| b == 0) {
+        let units: Vec<u16> = bytes.chunks_exact(2).map(|c| u16::from_le_bytes([c[0], c[1]])).collect();
+        String::from_utf16_lossy(&units)
+    } else {
+        String::from_utf8_lossy(bytes).into_owned()
+    }
+}
+
+/// Installed WSL distros (empty when WSL isn't installed or off Windows).
+pub fn list_distros() -> Vec<String> {
+    let Some(stdout) = wsl_list_output() else { return Vec::new() };
+    decode_wsl_output(&stdout)
+        .lines()
+        .map(|l| l.trim_matches(|c: char| c.is_whitespace() || c == '\u{feff}').to_string())
+        .filter(|l| !l.is_empty() && !IGNORED_DISTROS.iter().any(|d| d.eq_ignore_ascii_case(l)))
+        .collect()
+}
+
+#[cfg(target_os = "windows")]
+fn wsl_list_output() -> Option<Vec<u8>> {
+    let output = crate::process::hidden_command("wsl.exe").args(["-l", "-q"]).output().ok()?;
+    output.status.success().then_some(output.stdout)
+}
+
+#[cfg(not(target_os = "windows"))]
+fn wsl_list_output() -> Option<Vec<u8>> {
+    None
+}
+
+fn distro_root(distro: &str) -> PathBuf {
+    PathBuf::from(format!(r"\\wsl$\{}", distro))
+}
+
+/// `/root` plus every `/home/<user>` of a distro, as (user, home) pairs.
+fn distro_homes(root: &Path) -> Vec<(String, PathBuf)> {
+    let mut homes: Vec<(String, PathBuf)> = fs::read_dir(root.join("home"))
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .filter(|e| e.path().is_dir())
+                .map(|e| (e.file_name().to_string_lossy().to_string(), e.path()))
+                .collect()
+        })
+        .unwrap_or_default();
+    let root_home = root.join("root");
+    if root_home.is_dir() {
+        homes.push(("root".into(), root_home));
+    }
+    homes
+}
+
+// ── Server folders ──
+
+fn path_size(path: &Path) -> u64 {
+    walkdir::WalkDir::new(path)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| e.metadata().ok())
+        .map(|m| m.len())
+        .sum()
+}
+
+fn child_dirs(dir: &Path) -> Vec<PathBuf> {
+    fs::read_dir(dir)
+        .map(|entries| entries.filter_map(|e| e.ok()).map(|e| e.path()).filter(|p| p.is_dir()).collect())
+        .unwrap_or_default()
+}
+
+/// Newest mtime of anything inside `path`. A build folder's own mtime is set at
+/// install and doesn't move while the server runs; its logs and data files do.
+fn last_used(path: &Path) -> SystemTime {
+    walkdir::WalkDir::new(path)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter_map(|e| e.metadata().ok())
+        .filter_map(|m| m.modified().ok())
+        .max()
+        .unwrap_or(SystemTime::UNIX_EPOCH)
+}
+
+fn item(kind: WslItemKind, path: PathBuf, stale: bool) -> WslServerItem {
+    WslServerItem {
+        kind,
+        name: path.file_name().unwrap_or_default().to_string_lossy().to_string(),
+        size: path_size(&path),
+        path,
+        stale,
+    }
+}
+
+/// Server builds of one layout folder; all but the most recently used are stale,
+/// unless they were used within `RECENT_USE` (a second window may still be on one).
+fn server_builds(dir: &Path) -> Vec<WslServerItem> {
+    let now = SystemTime::now();
+    let mut builds: Vec<(PathBuf, SystemTime)> = child_dirs(dir).into_iter().map(|b| (b.clone(), last_used(&b))).collect();
+    builds.sort_by_key(|(_, used)| std::cmp::Reverse(*used));
+    builds
+        .into_iter()
+        .enumerate()
+        .map(|(i, (b, used))| {
+            let recent = now.duration_since(used).map(|age| age < RECENT_USE).unwrap_or(true);
+            item(WslItemKind::ServerBuild, b, i > 0 && !recent)
+        })
+        .collect()
+}
+
+/// Extension copies: every version but the newest of each extension is stale,
+/// as is anything the server listed in `extensions/.obsolete`.
+fn server_extensions(dir: &Path) -> Vec<WslServerItem> {
+    let obsolete: HashMap<String, bool> = fs::read_to_string(dir.join(".obsolete"))
+        .ok()
+        .and_then(|data| serde_json::from_str(&data).ok())
+        .unwrap_or_default();
+
+    let mut newest: HashMap<String, Vec<u64>> = HashMap::new();
+    let folders: Vec<(PathBuf, Option<crate::extension_folder::ExtensionVersion>)> = child_dirs(dir)
+        .into_iter()
+        .map(|p| {
+            let parsed = crate::extension_folder::parse_extension_folder(&p.file_name().unwrap_or_default().to_string_lossy());
+            if let Some((id, version)) = &parsed {
+                let entry = newest.entry(id.clone()).or_default();
+                if version > entry {
+                    *entry = version.clone();
+                }
+            }
+            (p, parsed)
+        })
+        .collect();
+
+    folders
+        .into_iter()
+        .map(|(path, parsed)| {
+            let name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+            let superseded = parsed.map(|(id, version)| newest.get(&id) != Some(&version)).unwrap_or(false);
+            item(WslItemKind::Extension, path, superseded || obsolete.get(&name).copied().unwrap_or(false))
+        })
+        .collect()
+}
+
+fn scan_server_dir(distro: &str, user: &str, product: &str, path: PathBuf) -> WslServerDir {
+    let mut items = server_builds(&path.join("bin"));
+    items.extend(server_builds(&path.join("cli").join("servers")));
+    items.extend(server_extensions(&path.join("extensions")));
+    items.sort_by(|a, b| b.size.cmp(&a.size));
+    WslServerDir {
+        distro: distro.to_string(),
+        user: user.to_string(),
+        product: product.to_string(),
+        size: path_size(&path),
+        stale_size: items.iter().filter(|i| i.stale).map(|i| i.size).sum(),
+        items,
+        path,
+    }
+}
+
+/// Every VS Code-family server folder in every WSL distro, largest first.
+/// Reading `\\wsl$\<distro>` starts a stopped distro.
+pub fn scan_wsl_servers() -> WslReport {
+    let distros = list_distros();
+    let mut servers = Vec::new();
+    for distro in &distros {
+        for (user, home) in distro_homes(&distro_root(distro)) {
+            for (folder, product) in SERVER_DIRS {
+                let path = home.join(folder);
+                if path.is_dir() {
+                    servers.push(scan_server_dir(distro, &user, product, path));
+                }
+            }
+        }
+    }
+    servers.sort_by(|a, b| b.size.cmp(&a.size));
+    WslReport { distros, servers }
+}
+
+/// Delete stale server builds / extension copies. Only paths a fresh scan
+/// still reports as stale are removed, so the build in use is never touched.
+pub fn clean_wsl_items(paths: &[PathBuf]) -> BatchResult {
+    let report = scan_wsl_servers();
+    let stale: Vec<&WslServerItem> = report.servers.iter().flat_map(|s| s.items.iter()).filter(|i| i.stale).collect();
+
+    let mut failed_paths = Vec::new();
+    let items = paths
+        .iter()
+        .map(|path| {
+            let id = path.display().to_string();
+            let Some(found) = stale.iter().find(|i| i.path == *path) else {
+                return if path.exists() {
+                    ItemResult::skipped(id, "Not a stale WSL server build or extension")
+                } else {
+                    ItemResult::not_found(id)
+                };
+            };
+            let (freed, _, errors) = crate::cleaner::clean_directory(&found.path);
+            let _ = fs::remove_dir(&found.path);
+            if !errors.is_empty() {
+                failed_paths.push(found.path.clone());
+            }
+            ItemResult::from_errors(id, freed, errors)
+        })
+        .collect();
+    let mut result = BatchResult::from_items(items);
+    result.failed_paths = failed_paths;
+    result
+}
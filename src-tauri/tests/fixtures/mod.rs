@@ -0,0 +1,157 @@
+//! Synthetic IDE data trees for the integration tests.
+//!
+//! A `Fixture` points `HOME` (and clears the XDG overrides) at a fresh temp
+//! directory, so every path `dirs` resolves — and with it IDE detection, the
+//! app's own config and the backup folder — lands inside the fixture.
+//! Shared by the integration tests and the benches, which each use a subset.
+#![allow(dead_code)]
+
+use rusqlite::Connection;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+
+/// The environment is process-wide: fixtures are used one at a time.
+static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+pub struct Fixture {
+    pub root: PathBuf,
+    _guard: MutexGuard<'static, ()>,
+}
+
+/// A Cursor composer written into `cursorDiskKV`.
+pub struct FakeConversation {
+    pub id: &'static str,
+    pub title: &'static str,
+    pub messages: usize,
+}
+
+impl Fixture {
+    pub fn new(name: &str) -> Fixture {
+        let guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let root = std::env::temp_dir().join(format!("devcleaner-it-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        std::env::set_var("HOME", &root);
+        for var in ["XDG_CONFIG_HOME", "XDG_CACHE_HOME", "XDG_DATA_HOME", "GRADLE_USER_HOME", "CARGO_HOME", "CLAUDE_CONFIG_DIR", "CODEX_HOME", "OLLAMA_MODELS", "HF_HOME", "HF_HUB_CACHE"] {
+            std::env::remove_var(var);
+        }
+        Fixture { root, _guard: guard }
+    }
+
+    pub fn config_dir(&self) -> PathBuf {
+        dirs::config_dir().unwrap()
+    }
+
+    pub fn cache_dir(&self) -> PathBuf {
+        dirs::cache_dir().unwrap()
+    }
+
+    pub fn data_local_dir(&self) -> PathBuf {
+        dirs::data_local_dir().unwrap()
+    }
+
+    /// Cursor's user data dir with caches, logs and a globalStorage
+    /// `state.vscdb` holding `conversations`. Returns the user data dir.
+    pub fn cursor(&self, conversations: &[FakeConversation]) -> PathBuf {
+        let base = self.config_dir().join("Cursor");
+        write_file(&base.join("Cache").join("data_0"), 4096);
+        write_file(&base.join("Cache").join("data_1"), 8192);
+        write_file(&base.join("GPUCache").join("index"), 1024);
+        write_file(&base.join("logs").join("20260101T000000").join("main.log"), 2048);
+        fs::create_dir_all(base.join("User").join("workspaceStorage")).unwrap();
+
+        let global_storage = base.join("User").join("globalStorage");
+        fs::create_dir_all(&global_storage).unwrap();
+        write_cursor_db(&global_storage.join("state.vscdb"), conversations);
+        base
+    }
+
+    /// JetBrains config + system dirs for each `<prefix><version>`, the way
+    /// %APPDATA%/%LOCALAPPDATA% (~/.config, ~/.cache here) lay them out.
+    pub fn jetbrains(&self, prefix: &str, versions: &[&str]) {
+        for version in versions {
+            let name = format!("{}{}", prefix, version);
+            let config = self.config_dir().join("JetBrains").join(&name);
+            write_text(&config.join("options").join("ide.general.xml"), "<application/>");
+            let system = self.cache_dir().join("JetBrains").join(&name);
+            write_file(&system.join("caches").join("content.dat"), 16 * 1024);
+            write_file(&system.join("index").join("stubs.dat"), 8 * 1024);
+            write_file(&system.join("log").join("idea.log"), 1024);
+        }
+    }
+}
+
+impl Drop for Fixture {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.root);
+    }
+}
+
+/// A file of `size` bytes with recognisable content.
+pub fn write_file(path: &Path, size: usize) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    let content: Vec<u8> = (0..size).map(|i| (i % 251) as u8).collect();
+    fs::write(path, content).unwrap();
+}
+
+/// `dirs` × `files_per_dir` files of `size` bytes under `root`, like a busy cache.
+pub fn file_tree(root: &Path, dirs: usize, files_per_dir: usize, size: usize) {
+    for d in 0..dirs {
+        for f in 0..files_per_dir {
+            write_file(&root.join(format!("{:02x}", d)).join(format!("f_{}", f)), size);
+        }
+    }
+}
+
+pub fn write_text(path: &Path, text: &str) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, text).unwrap();
+}
+
+/// Cursor v2 layout: `ItemTable` plus `cursorDiskKV` with one `composerData:<id>`
+/// row per conversation and one `bubbleId:<id>:<n>` row per message.
+fn write_cursor_db(path: &Path, conversations: &[FakeConversation]) {
+    let conn = Connection::open(path).unwrap();
+    conn.execute_batch(
+        "CREATE TABLE ItemTable (key TEXT UNIQUE ON CONFLICT REPLACE, value BLOB);
+         CREATE TABLE cursorDiskKV (key TEXT UNIQUE ON CONFLICT REPLACE, value BLOB);",
+    )
+    .unwrap();
+    for conv in conversations {
+        let headers: Vec<serde_json::Value> = (0..conv.messages)
+            .map(|i| serde_json::json!({ "bubbleId": format!("b{}", i), "type": if i % 2 == 0 { 1 } else { 2 } }))
+            .collect();
+        let composer = serde_json::json!({
+            "composerId": conv.id,
+            "name": conv.title,
+            "createdAt": 1_767_225_600_000_i64,
+            "fullConversationHeadersOnly": headers,
+        });
+        conn.execute(
+            "INSERT INTO cursorDiskKV (key, value) VALUES (?1, ?2)",
+            [format!("composerData:{}", conv.id), composer.to_string()],
+        )
+        .unwrap();
+        for i in 0..conv.messages {
+            let bubble = serde_json::json!({ "type": if i % 2 == 0 { 1 } else { 2 }, "text": format!("message {} of {}", i, conv.title) });
+            conn.execute(
+                "INSERT INTO cursorDiskKV (key, value) VALUES (?1, ?2)",
+                [format!("bubbleId:{}:b{}", conv.id, i), bubble.to_string()],
+            )
+            .unwrap();
+        }
+    }
+}
+
+/// Every file under `dir` (relative path → bytes), for before/after comparisons.
+pub fn snapshot(dir: &Path) -> Vec<(PathBuf, Vec<u8>)> {
+    let mut files: Vec<(PathBuf, Vec<u8>)> = walkdir::WalkDir::new(dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .map(|e| (e.path().strip_prefix(dir).unwrap().to_path_buf(), fs::read(e.path()).unwrap()))
+        .collect();
+    files.sort();
+    files
+}
@@ -1,19 +1,54 @@
-import { useState } from "react";
+import { useEffect, useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import { useTranslation } from "react-i18next";
+import { ShieldAlert } from "lucide-react";
 import Sidebar from "./components/Sidebar";
 import TitleBar from "./components/TitleBar";
 import ScanCleanPage from "./components/ScanCleanPage";
+import FootprintPage from "./components/FootprintPage";
 import ConversationsPage from "./components/ConversationsPage";
 import UninstallPage from "./components/UninstallPage";
 import SettingsPage from "./components/SettingsPage";
-import type { Page } from "./types";
+import type { Page, PolicyStatus } from "./types";
 
 function App() {
+  const { t, i18n } = useTranslation();
   const [currentPage, setCurrentPage] = useState<Page>("scan");
+  const [policy, setPolicy] = useState<PolicyStatus | null>(null);
+
+  // The tray lives in the backend; hand it this language's text, with {{size}} left for it to fill
+  useEffect(() => {
+    const size = { size: "{{size}}" };
+    invoke("set_tray_labels", {
+      labels: {
+        reclaimable: t("tray.reclaimable", size),
+        scanning: t("tray.scanning"),
+        cleaning: t("tray.cleaning"),
+        quick_clean: t("tray.quickClean"),
+        open: t("tray.open"),
+        quit: t("tray.quit"),
+        confirm_title: t("tray.confirmTitle"),
+        confirm_message: t("tray.confirmMessage", size),
+        confirm_clean: t("tray.confirmClean"),
+        confirm_cancel: t("tray.confirmCancel"),
+      },
+    }).catch(() => {});
+  }, [t, i18n.language]);
+
+  // Administrator kill-switch: the backend refuses destructive commands, this only explains why
+  useEffect(() => {
+    invoke<PolicyStatus>("get_policy_status").then(setPolicy).catch(() => {});
+    const unlisten = listen<PolicyStatus>("policy-changed", (e) => setPolicy(e.payload));
+    return () => { unlisten.then((f) => f()); };
+  }, []);
 
   const renderPage = () => {
     switch (currentPage) {
       case "scan":
         return <ScanCleanPage />;
+      case "footprint":
+        return <FootprintPage />;
       case "conversations":
         return <ConversationsPage />;
       case "uninstall":
@@ -28,8 +63,17 @@ function App() {
       <TitleBar />
       <div className="flex min-h-0 flex-1 overflow-hidden">
         <Sidebar currentPage={currentPage} onNavigate={setCurrentPage} />
-        <main className="app-shell-main relative flex-1 min-w-0 overflow-hidden bg-background/70">
-          {renderPage()}
+        <main className="app-shell-main relative flex-1 min-w-0 overflow-hidden bg-background/70 flex flex-col">
+          {policy?.destructive_disabled && (
+            <div className="flex items-start gap-2 px-4 py-2 text-xs bg-destructive/10 text-destructive border-b border-destructive/20" title={policy.source ?? undefined}>
+              <ShieldAlert size={14} className="shrink-0 mt-0.5" />
+              <span>
+                {t("app.policyDisabled")}
+                {policy.reason && <> — {policy.reason}</>}
+              </span>
+            </div>
+          )}
+          <div className="flex-1 min-h-0">{renderPage()}</div>
         </main>
       </div>
     </div>
@@ -1,6 +1,6 @@
 import { useEffect, useState } from "react";
 import { invoke } from "@tauri-apps/api/core";
-import { Archive, Trash2, RefreshCw, AlertCircle, HardDrive } from "lucide-react";
+import { Archive, Trash2, RefreshCw, AlertCircle, HardDrive, RotateCcw } from "lucide-react";
 import { useTranslation } from "react-i18next";
 import type { BackupListResult, BackupInfo, Page } from "../types";
 import { formatBytes, formatNumber } from "../utils/formatters";
@@ -170,6 +170,16 @@ function BackupCard({
 }) {
   const { t } = useTranslation();
   const [confirming, setConfirming] = useState(false);
+  const [restoreMessage, setRestoreMessage] = useState<string | null>(null);
+
+  async function handleRestoreRegistry() {
+    try {
+      const restored = await invoke<string[]>("restore_registry", { backupId: backup.id });
+      setRestoreMessage(t("backups.registryRestored", { count: restored.length }));
+    } catch (e) {
+      setRestoreMessage(String(e));
+    }
+  }
 
   return (
     <Card className="hover:border-primary/30 transition-colors group">
@@ -189,10 +199,17 @@ function BackupCard({
               <span>•</span>
               <span>{formatNumber(backup.file_count)} {t("backups.files")}</span>
             </div>
+            {restoreMessage && <p className="text-xs text-muted-foreground mt-1.5">{restoreMessage}</p>}
           </div>
         </div>
         
         <div className="flex items-center gap-2">
+          {backup.registry_files.length > 0 && !confirming && (
+            <Button onClick={handleRestoreRegistry} variant="outline" size="sm" className="h-8">
+              <RotateCcw size={14} className="mr-1.5" />
+              {t("backups.restoreRegistry")}
+            </Button>
+          )}
           {confirming ? (
             <div className="flex items-center gap-2 animate-in slide-in-from-right-4 fade-in duration-200">
               <span className="text-xs font-semibold text-warning mr-2 hidden sm:inline">{t("backups.confirmDelete")}</span>
@@ -50,7 +50,7 @@ export default function CleanPage() {
       case "Recommended":
         return new Set(["Cache", "Log", "WorkspaceStorage", "CrashReport"]);
       case "Aggressive":
-        return new Set(["Cache", "Log", "WorkspaceStorage", "CrashReport", "Extension"]);
+        return new Set(["Cache", "Log", "WorkspaceStorage", "CrashReport", "Extension", "WebStorage", "BuildCache"]);
     }
   }
 
@@ -1,5 +1,7 @@
 import { useEffect, useState, useCallback } from "react";
 import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import { save } from "@tauri-apps/plugin-dialog";
 import {
   MessageSquare,
   Loader2,
@@ -21,11 +23,25 @@ import {
   Square,
   ChevronsUp,
   ChevronsDown,
+  Hash,
+  ArrowDownAZ,
+  FileDown,
+  Archive,
+  RotateCcw,
+  Search,
+  Copy,
+  Lock,
+  LockOpen,
+  Tag,
+  StickyNote,
+  KeyRound,
+  Shrink,
 } from "lucide-react";
 import { useTranslation } from "react-i18next";
 import { createPortal } from "react-dom";
-import type { IdeInfo, ConversationListResult, ConversationInfo, ConversationContent, DbFileInfo } from "../types";
+import type { IdeInfo, ConversationPage, ConversationQuery, ConversationInfo, ConversationAnnotation, ConversationSecrets, SecretFinding, ConversationContent, DbFileInfo, ConversationSort, ConversationSortField, BatchResult, ExportFormat, ArchivedConversation, SearchHit, SearchIndexStats, VacuumProgress, VacuumResult } from "../types";
 import { formatBytes } from "../utils/formatters";
+import RetryFailedButton from "./RetryFailedButton";
 import { useIdeIcons } from "../hooks/useIdeIcons";
 import IdeIcon from "./IdeIcon";
 import { Card, CardContent } from "./ui/card";
@@ -33,15 +49,38 @@ import { Button } from "./ui/button";
 import { Badge } from "./ui/badge";
 import { Separator } from "./ui/separator";
 
-type SortMode = "time" | "size";
-type SortDir = "asc" | "desc";
+/** Conversation id inside an aggregated key: the part after `<ide>:<key>:`. */
+function conversationIdOf(conv: ConversationInfo): string {
+  const parts = conv.id.split(":");
+  return parts.length >= 3 ? parts.slice(2).join(":") : "";
+}
+
+const EXPORT_EXTENSIONS: Record<ExportFormat, string> = { Html: "html", Jsonl: "jsonl" };
+
+/** Conversations fetched per page; the rest stay in Rust until "Load more". */
+const PAGE_SIZE = 200;
+
+const SIZE_FILTERS = [0, 100 * 1024, 1024 * 1024, 10 * 1024 * 1024];
+
+/** Source id of Claude Code and other CLI agent sessions (conversation::CLI_AGENTS_ID). */
+const CLI_AGENTS_ID = "cli_agents";
+
+/** An IDE, or the CLI agents pseudo source, as far as this page needs one. */
+type ConversationSource = Pick<IdeInfo, "id" | "name">;
+
+const SORT_OPTIONS: { field: ConversationSortField; labelKey: string; icon: typeof Clock }[] = [
+  { field: "Date", labelKey: "conversations.sortByTime", icon: Clock },
+  { field: "Size", labelKey: "conversations.sortBySize", icon: HardDriveDownload },
+  { field: "MessageCount", labelKey: "conversations.sortByMessages", icon: Hash },
+  { field: "Title", labelKey: "conversations.sortByTitle", icon: ArrowDownAZ },
+];
 
 export default function ConversationsPage() {
   const { t } = useTranslation();
-  const [ides, setIdes] = useState<IdeInfo[]>([]);
+  const [ides, setIdes] = useState<ConversationSource[]>([]);
   const [loading, setLoading] = useState(true);
   const [expandedIde, setExpandedIde] = useState<string | null>(null);
-  const [conversations, setConversations] = useState<Map<string, ConversationListResult>>(new Map());
+  const [conversations, setConversations] = useState<Map<string, ConversationPage>>(new Map());
   const [loadingIde, setLoadingIde] = useState<string | null>(null);
   const [deleting, setDeleting] = useState<string | null>(null);
   const [error, setError] = useState<string | null>(null);
@@ -49,26 +88,57 @@ export default function ConversationsPage() {
   const [viewingConv, setViewingConv] = useState<ConversationInfo | null>(null);
   const [convContent, setConvContent] = useState<ConversationContent | null>(null);
   const [loadingContent, setLoadingContent] = useState(false);
+  // Rows the last batch delete could not remove: conversation id → error
+  const [failedIds, setFailedIds] = useState<Map<string, string>>(new Map());
+  const [retryOperation, setRetryOperation] = useState<{ ideId: string; id: string } | null>(null);
 
   // Sort & selection state
-  const [sortMode, setSortMode] = useState<SortMode>("time");
-  const [sortDir, setSortDir] = useState<SortDir>("desc");
+  const [sort, setSort] = useState<ConversationSort>({ field: "Date", descending: true });
   const [selectedIds, setSelectedIds] = useState<Set<string>>(new Set());
   const [batchDeleting, setBatchDeleting] = useState(false);
+  const [batchExporting, setBatchExporting] = useState(false);
+  const [batchArchiving, setBatchArchiving] = useState(false);
+  // Bumped after archiving so the archive list reloads
+  const [archiveVersion, setArchiveVersion] = useState(0);
+  const [viewingArchived, setViewingArchived] = useState(false);
+  const [batchFormat, setBatchFormat] = useState<ExportFormat>("Html");
+  const [langFilter, setLangFilter] = useState<string>("all");
+  const [tagFilter, setTagFilter] = useState<string>("all");
+  const [redactExport, setRedactExport] = useState(false);
+  // Conversation id → credentials found in it, per IDE, from the last secret scan
+  const [secretFindings, setSecretFindings] = useState<Map<string, Map<string, SecretFinding[]>>>(new Map());
+  const [scanningSecrets, setScanningSecrets] = useState(false);
+  const [titleFilter, setTitleFilter] = useState("");
+  const [minSize, setMinSize] = useState(0);
+  const [loadingMore, setLoadingMore] = useState(false);
+  // Databases with deletes whose VACUUM was deferred, and the one being compacted
+  const [pendingCompact, setPendingCompact] = useState<Set<string>>(new Set());
+  const [compacting, setCompacting] = useState<VacuumProgress | null>(null);
+  const [compacted, setCompacted] = useState<Map<string, number>>(new Map());
 
   useEffect(() => {
     loadIdes();
   }, []);
 
-  // Clear selection when IDE changes
-  useEffect(() => { setSelectedIds(new Set()); }, [expandedIde]);
+  useEffect(() => {
+    const unlisten = listen<VacuumProgress>("vacuum-progress", (e) => setCompacting(e.payload.done ? null : e.payload));
+    return () => { unlisten.then((f) => f()); };
+  }, []);
+
+  // Clear selection and filters when IDE changes
+  useEffect(() => { setSelectedIds(new Set()); setLangFilter("all"); setTagFilter("all"); setTitleFilter(""); setMinSize(0); }, [expandedIde]);
+  useEffect(() => { setSelectedIds(new Set()); }, [langFilter, tagFilter, minSize]);
 
   async function loadIdes() {
     setLoading(true);
     try {
       const result = await invoke<IdeInfo[]>("detect_ides");
-      const vsIdes = result.filter((i) => i.installed && i.ide_type === "VscodeBased");
-      setIdes(vsIdes);
+      const vsIdes: ConversationSource[] = result.filter((i) => i.installed && (i.ide_type === "VscodeBased" || i.ide_type === "Zed" || i.ide_type === "JetBrains"));
+      // CLI agents aren't IDEs; they only get a row when they have sessions
+      const cliAgents = await invoke<ConversationPage>("query_conversations", { ideId: CLI_AGENTS_ID, query: pageQuery(CLI_AGENTS_ID, {}), rescan: true });
+      const sources = cliAgents.total_conversations > 0 ? [...vsIdes, { id: CLI_AGENTS_ID, name: t("conversations.cliAgents") }] : vsIdes;
+      setIdes(sources);
+      setConversations((prev) => new Map(prev).set(CLI_AGENTS_ID, cliAgents));
       for (const ide of vsIdes) {
         loadConversations(ide.id);
       }
@@ -87,16 +157,41 @@ export default function ConversationsPage() {
     setExpandedIde(ideId);
     if (!conversations.has(ideId)) {
       await loadConversations(ideId);
+    } else {
+      // Filters are reset on expand; page 0 again from Rust's last scan
+      await reloadPage(ideId, { title: null, language: null, min_size: null });
     }
   }
 
+  // Filtering, sorting and paging all happen in Rust; the webview holds the loaded pages only
+  function pageQuery(ideId: string, overrides: Partial<ConversationQuery>): ConversationQuery {
+    const filtered = ideId === expandedIde;
+    return {
+      sort,
+      offset: 0,
+      limit: PAGE_SIZE,
+      title: filtered && titleFilter.trim() ? titleFilter.trim() : null,
+      min_size: filtered && minSize > 0 ? minSize : null,
+      max_size: null,
+      language: filtered && langFilter !== "all" ? langFilter : null,
+      tag: filtered && tagFilter !== "all" ? tagFilter : null,
+      ...overrides,
+    };
+  }
+
   async function loadConversations(ideId: string) {
     setLoadingIde(ideId);
     setError(null);
     try {
-      const convResult = await invoke<ConversationListResult>("scan_conversations", { ideId });
-      setConversations((prev) => new Map(prev).set(ideId, convResult));
+      const page = await invoke<ConversationPage>("query_conversations", { ideId, query: pageQuery(ideId, {}), rescan: true });
+      setConversations((prev) => new Map(prev).set(ideId, page));
       setSelectedIds(new Set());
+      // A rescan can drop or rename conversations; findings are per scan
+      setSecretFindings((prev) => {
+        const next = new Map(prev);
+        next.delete(ideId);
+        return next;
+      });
     } catch (e) {
       setError(String(e));
     } finally {
@@ -104,6 +199,33 @@ export default function ConversationsPage() {
     }
   }
 
+  /** First page again without rescanning, after the sort or a filter changed. */
+  async function reloadPage(ideId: string, overrides: Partial<ConversationQuery>) {
+    try {
+      const page = await invoke<ConversationPage>("query_conversations", { ideId, query: pageQuery(ideId, overrides) });
+      setConversations((prev) => new Map(prev).set(ideId, page));
+    } catch (e) {
+      setError(String(e));
+    }
+  }
+
+  async function loadMore(ideId: string) {
+    const current = conversations.get(ideId);
+    if (!current) return;
+    setLoadingMore(true);
+    try {
+      const page = await invoke<ConversationPage>("query_conversations", {
+        ideId,
+        query: pageQuery(ideId, { offset: current.conversations.length }),
+      });
+      setConversations((prev) => new Map(prev).set(ideId, { ...page, offset: 0, conversations: [...current.conversations, ...page.conversations] }));
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setLoadingMore(false);
+    }
+  }
+
   async function handleDeleteConversation(conv: ConversationInfo, ideId: string) {
     setDeleting(conv.id);
     try {
@@ -112,6 +234,7 @@ export default function ConversationsPage() {
         sourceKey: conv.source_key,
       });
       setSelectedIds((prev) => { const n = new Set(prev); n.delete(conv.id); return n; });
+      setFailedIds((prev) => { const n = new Map(prev); n.delete(conv.id); return n; });
       await loadConversations(ideId);
     } catch (e) {
       setError(String(e));
@@ -120,6 +243,86 @@ export default function ConversationsPage() {
     }
   }
 
+  async function handleBatchExport(ideId: string, convs: ConversationInfo[]) {
+    const selected = convs.filter((c) => selectedIds.has(c.id));
+    if (selected.length === 0) return;
+    const targetZip = await save({ defaultPath: `${ideId}-conversations.zip`, filters: [{ name: "ZIP", extensions: ["zip"] }] });
+    if (!targetZip) return;
+
+    setBatchExporting(true);
+    try {
+      const result = await invoke<BatchResult>("export_conversations_batch", {
+        items: selected.map((c) => ({ id: c.id, source_db: c.source_db, source_key: c.source_key })),
+        format: batchFormat,
+        targetZip,
+        redact: redactExport,
+      });
+      const failures = result.items.filter((i) => i.status === "Failed");
+      setFailedIds(new Map(failures.map((i) => [i.id, i.error ?? ""])));
+      if (failures.length > 0) {
+        setError(t("conversations.exportPartialFailure", { failed: failures.length, total: selected.length }));
+      }
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setBatchExporting(false);
+    }
+  }
+
+  async function handleBatchArchive(ideId: string, convs: ConversationInfo[]) {
+    const selected = convs.filter((c) => selectedIds.has(c.id));
+    if (selected.length === 0) return;
+    if (!window.confirm(t("conversations.archiveConfirm", { count: selected.length }))) return;
+
+    setBatchArchiving(true);
+    try {
+      const result = await invoke<BatchResult>("archive_conversations", { ideId, conversations: selected });
+      const failures = result.items.filter((i) => i.status === "Failed");
+      const skipped = result.items.filter((i) => i.status === "Skipped").length;
+      setFailedIds(new Map(failures.map((i) => [i.id, i.error ?? ""])));
+      await loadConversations(ideId);
+      setSelectedIds(new Set(failures.map((i) => i.id)));
+      setArchiveVersion((v) => v + 1);
+      if (failures.length > 0) {
+        setError(t("conversations.archivePartialFailure", { failed: failures.length, total: selected.length }));
+      } else if (skipped > 0) {
+        setError(t("conversations.protectedSkipped", { count: skipped }));
+      }
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setBatchArchiving(false);
+    }
+  }
+
+  async function handleViewArchived(entry: ArchivedConversation) {
+    setViewingConv({
+      id: entry.conversation_id,
+      title: entry.title,
+      source_db: entry.source_db,
+      source_key: entry.source_key,
+      message_count: entry.message_count,
+      size_bytes: entry.size_bytes,
+      last_modified: entry.last_modified,
+      last_modified_local: null,
+      language: "",
+      duplicates: [],
+      protected: false,
+      tags: [],
+      note: "",
+    });
+    setViewingArchived(true);
+    setConvContent(null);
+    setLoadingContent(true);
+    try {
+      setConvContent(await invoke<ConversationContent>("get_archived_conversation", { id: entry.id }));
+    } catch {
+      setConvContent({ title: entry.title, messages: [] });
+    } finally {
+      setLoadingContent(false);
+    }
+  }
+
   async function handleBatchDelete(ideId: string, convs: ConversationInfo[]) {
     const selected = convs.filter((c) => selectedIds.has(c.id));
     if (selected.length === 0) return;
@@ -128,11 +331,116 @@ export default function ConversationsPage() {
 
     setBatchDeleting(true);
     try {
-      await invoke("delete_conversations_batch", {
-        items: selected.map((c) => ({ source_db: c.source_db, source_key: c.source_key })),
+      // Compacting after every batch is slow on multi-GB databases; it's offered once afterwards
+      const result = await invoke<BatchResult>("delete_conversations_batch", {
+        items: selected.map((c) => ({ id: c.id, source_db: c.source_db, source_key: c.source_key })),
+        ideId,
+        vacuum: false,
       });
-      setSelectedIds(new Set());
+      const deletedFrom = selected.filter((c) => result.items.some((i) => i.id === c.id && i.status === "Done")).map((c) => c.source_db);
+      setPendingCompact((prev) => new Set([...prev, ...deletedFrom]));
+      const failures = result.items.filter((i) => i.status === "Failed");
+      const skipped = result.items.filter((i) => i.status === "Skipped").length;
+      setFailedIds(new Map(failures.map((i) => [i.id, i.error ?? ""])));
+      setRetryOperation(result.operation_id ? { ideId, id: result.operation_id } : null);
       await loadConversations(ideId);
+      // Failed rows stay selected, so deleting again retries exactly those
+      setSelectedIds(new Set(failures.map((i) => i.id)));
+      if (failures.length > 0) {
+        setError(t("conversations.batchPartialFailure", { failed: failures.length, total: selected.length }));
+      } else if (skipped > 0) {
+        setError(t("conversations.protectedSkipped", { count: skipped }));
+      }
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setBatchDeleting(false);
+    }
+  }
+
+  async function handleCompact(ideId: string, path: string) {
+    setCompacting({ path, size_before: 0, pages_done: 0, pages_total: 0, elapsed_ms: 0, done: false });
+    try {
+      const result = await invoke<VacuumResult>("vacuum_database", { ideId, path });
+      setCompacted((prev) => new Map(prev).set(path, result.freed_bytes));
+      setPendingCompact((prev) => { const n = new Set(prev); n.delete(path); return n; });
+      await loadConversations(ideId);
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setCompacting(null);
+    }
+  }
+
+  async function handleToggleProtected(ideId: string, conv: ConversationInfo) {
+    try {
+      await invoke("set_conversation_protected", { conversation: conv, protected: !conv.protected });
+      setConversations((prev) => {
+        const page = prev.get(ideId);
+        if (!page) return prev;
+        const updated = page.conversations.map((c) => (c.id === conv.id ? { ...c, protected: !conv.protected } : c));
+        return new Map(prev).set(ideId, { ...page, conversations: updated });
+      });
+    } catch (e) {
+      setError(String(e));
+    }
+  }
+
+  async function handleAnnotate(ideId: string, conv: ConversationInfo, tags: string[], note: string) {
+    try {
+      const saved = await invoke<ConversationAnnotation>("annotate_conversation", { id: conv.id, tags, note });
+      setConversations((prev) => {
+        const page = prev.get(ideId);
+        if (!page) return prev;
+        const updated = page.conversations.map((c) => (c.id === conv.id ? { ...c, ...saved } : c));
+        const allTags = [...new Set([...page.tags, ...saved.tags])].sort();
+        return new Map(prev).set(ideId, { ...page, conversations: updated, tags: allTags });
+      });
+    } catch (e) {
+      setError(String(e));
+    }
+  }
+
+  async function handleScanSecrets(ideId: string) {
+    setScanningSecrets(true);
+    try {
+      const flagged = await invoke<ConversationSecrets[]>("scan_conversation_secrets", { ideId });
+      setSecretFindings((prev) => new Map(prev).set(ideId, new Map(flagged.map((f) => [f.id, f.findings]))));
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setScanningSecrets(false);
+    }
+  }
+
+  function selectLeaking(ideId: string) {
+    setSelectedIds(new Set(secretFindings.get(ideId)?.keys() ?? []));
+  }
+
+  async function handleDeleteDuplicates(ideId: string, page: ConversationPage) {
+    if (!window.confirm(t("conversations.duplicatesConfirm", { count: page.duplicate_copies, size: formatBytes(page.duplicate_size) }))) return;
+    setBatchDeleting(true);
+    try {
+      const result = await invoke<BatchResult>("delete_duplicate_conversations", { ideId });
+      await loadConversations(ideId);
+      if (result.failed > 0) {
+        setError(t("conversations.duplicatesPartialFailure", { failed: result.failed, total: result.items.length }));
+      }
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setBatchDeleting(false);
+    }
+  }
+
+  async function handleDeleteStrayFiles(ideId: string) {
+    setBatchDeleting(true);
+    try {
+      const result = await invoke<BatchResult>("delete_stray_database_files", { ideId });
+      await loadConversations(ideId);
+      if (result.failed > 0) {
+        setError(t("conversations.strayFilesPartialFailure", { failed: result.failed, total: result.items.length }));
+      }
     } catch (e) {
       setError(String(e));
     } finally {
@@ -142,23 +450,21 @@ export default function ConversationsPage() {
 
   async function handleViewConversation(conv: ConversationInfo) {
     setViewingConv(conv);
+    setViewingArchived(false);
     setConvContent(null);
     setLoadingContent(true);
     try {
-      if (conv.id.startsWith("pb:")) {
-        setConvContent({ title: conv.title, messages: [{ role: "system", content: t("conversations.binaryContent") }] });
-        return;
-      }
-      const parts = conv.id.split(":");
-      const conversationId = parts.length >= 3 ? parts.slice(2).join(":") : "";
       const result = await invoke<ConversationContent>("get_conversation_content", {
         sourceDb: conv.source_db,
         sourceKey: conv.source_key,
-        conversationId,
+        conversationId: conversationIdOf(conv),
       });
       setConvContent(result);
-    } catch (e) {
-      setConvContent({ title: conv.title, messages: [] });
+    } catch {
+      // Cascade files Windsurf has encrypted can't be decoded
+      setConvContent(conv.id.startsWith("pb:")
+        ? { title: conv.title, messages: [{ role: "system", content: t("conversations.binaryContent"), timestamp: null }] }
+        : { title: conv.title, messages: [] });
     } finally {
       setLoadingContent(false);
     }
@@ -168,23 +474,15 @@ export default function ConversationsPage() {
     try { await invoke("open_path", { path }); } catch { /* ignore */ }
   }
 
-  const handleSortToggle = useCallback((mode: SortMode) => {
-    if (sortMode === mode) {
-      setSortDir((d) => (d === "desc" ? "asc" : "desc"));
-    } else {
-      setSortMode(mode);
-      setSortDir("desc");
-    }
-  }, [sortMode]);
-
-  // Sort conversations client-side
-  const sortConversations = useCallback((convs: ConversationInfo[]): ConversationInfo[] => {
-    return [...convs].sort((a, b) => {
-      const mul = sortDir === "desc" ? 1 : -1;
-      if (sortMode === "size") return mul * (b.size_bytes - a.size_bytes);
-      return mul * ((b.last_modified ?? 0) - (a.last_modified ?? 0));
-    });
-  }, [sortMode, sortDir]);
+  // Sorting happens in Rust on the last scan; the list is only replaced here
+  async function handleSortToggle(field: ConversationSortField) {
+    const next: ConversationSort = sort.field === field
+      ? { field, descending: !sort.descending }
+      : { field, descending: field !== "Title" };
+    setSort(next);
+    if (!expandedIde || !conversations.has(expandedIde)) return;
+    await reloadPage(expandedIde, { sort: next });
+  }
 
   // Selection helpers
   const toggleSelect = useCallback((id: string) => {
@@ -211,7 +509,6 @@ export default function ConversationsPage() {
     setSelectedIds(new Set(sorted.slice(idx).map((c) => c.id)));
   }, []);
 
-  const vsCodeIdes = ides.filter((i) => i.ide_type === "VscodeBased");
 
   return (
     <div className="h-full flex flex-col overflow-hidden">
@@ -230,11 +527,32 @@ export default function ConversationsPage() {
           </Button>
         </div>
 
+        {ides.length > 0 && <ConversationSearchSection ides={ides} onView={handleViewConversation} />}
+
         {error && (
           <Card className="border-destructive/50 bg-destructive/10">
             <CardContent className="flex items-center gap-3 p-4 text-destructive">
               <AlertTriangle size={18} />
               <span className="text-sm font-medium">{error}</span>
+              {retryOperation && (
+                <div className="ml-auto">
+                  <RetryFailedButton
+                    key={retryOperation.id}
+                    operationId={retryOperation.id}
+                    onRetried={async (result) => {
+                      const failures = result.items.filter((i) => i.status === "Failed");
+                      await loadConversations(retryOperation.ideId);
+                      setFailedIds(new Map(failures.map((i) => [i.id, i.error ?? ""])));
+                      setSelectedIds(new Set(failures.map((i) => i.id)));
+                      if (result.operation_id) {
+                        setError(t("conversations.batchPartialFailure", { failed: failures.length, total: result.items.length }));
+                      } else {
+                        setRetryOperation(null);
+                      }
+                    }}
+                  />
+                </div>
+              )}
             </CardContent>
           </Card>
         )}
@@ -244,7 +562,7 @@ export default function ConversationsPage() {
             <Loader2 size={48} className="text-primary animate-spin" />
             <p className="text-muted-foreground">{t("dashboard.scanning")}</p>
           </div>
-        ) : vsCodeIdes.length === 0 ? (
+        ) : ides.length === 0 ? (
           <Card className="border-dashed border-2 bg-transparent">
             <CardContent className="flex flex-col items-center text-center p-10">
               <MessageSquare size={48} className="text-muted-foreground/40 mb-4" />
@@ -253,11 +571,14 @@ export default function ConversationsPage() {
           </Card>
         ) : (
           <div className="space-y-3">
-            {vsCodeIdes.map((ide) => {
+            {ides.map((ide) => {
               const isExpanded = expandedIde === ide.id;
               const convData = conversations.get(ide.id);
               const isLoading = loadingIde === ide.id;
-              const sorted = convData ? sortConversations(convData.conversations) : [];
+              const languages = convData?.languages ?? [];
+              const tags = convData?.tags ?? [];
+              const leaking = secretFindings.get(ide.id);
+              const sorted = convData?.conversations ?? [];
               const allSelected = sorted.length > 0 && sorted.every((c) => selectedIds.has(c.id));
               const someSelected = sorted.some((c) => selectedIds.has(c.id));
               const selectedCount = sorted.filter((c) => selectedIds.has(c.id)).length;
@@ -275,7 +596,7 @@ export default function ConversationsPage() {
                         <span className="font-bold text-sm">{ide.name}</span>
                         {convData && (
                           <p className="text-xs text-muted-foreground">
-                            {convData.conversations.length} {t("conversations.chats")} · {formatBytes(convData.total_size)}
+                            {convData.total_conversations} {t("conversations.chats")} · {formatBytes(convData.total_size)}
                           </p>
                         )}
                       </div>
@@ -302,7 +623,7 @@ export default function ConversationsPage() {
                       ) : (
                         <div className="space-y-3">
                           {/* Database files overview */}
-                          {convData && convData.db_files && convData.db_files.length > 0 && (
+                          {convData && (convData.db_files.length > 0 || convData.stray_files.length > 0) && (
                             <div>
                               <h4 className="text-xs font-semibold text-muted-foreground uppercase tracking-wider mb-2 flex items-center gap-2">
                                 <HardDrive size={12} />
@@ -316,48 +637,144 @@ export default function ConversationsPage() {
                                       <span className="text-xs truncate">{df.name}</span>
                                     </div>
                                     <div className="flex items-center gap-2 shrink-0 ml-2">
+                                      {compacted.has(df.path) && (
+                                        <span className="text-xs text-success">{t("conversations.compactFreed", { size: formatBytes(compacted.get(df.path) ?? 0) })}</span>
+                                      )}
                                       <Badge variant="secondary" className="text-xs font-mono">
                                         {formatBytes(df.size)}
                                       </Badge>
+                                      {!df.name.endsWith("/") && (
+                                        <Button
+                                          size="sm"
+                                          variant={pendingCompact.has(df.path) ? "secondary" : "ghost"}
+                                          className="h-6 px-1.5 gap-1 text-xs"
+                                          disabled={compacting !== null}
+                                          onClick={() => handleCompact(ide.id, df.path)}
+                                          title={t("conversations.compactHint")}
+                                        >
+                                          {compacting?.path === df.path ? (
+                                            <>
+                                              <Loader2 size={12} className="animate-spin" />
+                                              {t("conversations.compacting", {
+                                                percent: compacting.pages_total > 0 ? Math.floor((compacting.pages_done / compacting.pages_total) * 100) : 0,
+                                                seconds: Math.floor(compacting.elapsed_ms / 1000),
+                                              })}
+                                            </>
+                                          ) : (
+                                            <>
+                                              <Shrink size={12} />
+                                              {pendingCompact.has(df.path) && t("conversations.compact")}
+                                            </>
+                                          )}
+                                        </Button>
+                                      )}
                                       <Button size="sm" variant="ghost" className="h-6 w-6 p-0" onClick={() => handleOpenPath(df.path)}>
                                         <FolderOpen size={12} />
                                       </Button>
                                     </div>
                                   </div>
                                 ))}
+                                {convData.stray_files.length > 0 && (
+                                  <div className="flex items-center justify-between p-2 rounded-lg border border-warning/40 bg-warning/5">
+                                    <div className="flex items-center gap-2 min-w-0 flex-1">
+                                      <AlertTriangle size={13} className="text-warning shrink-0" />
+                                      <span className="text-xs truncate" title={convData.stray_files.map((f) => f.name).join("\n")}>
+                                        {t("conversations.strayFiles", { count: convData.stray_files.length })}
+                                      </span>
+                                    </div>
+                                    <div className="flex items-center gap-2 shrink-0 ml-2">
+                                      <Badge variant="secondary" className="text-xs font-mono">
+                                        {formatBytes(convData.stray_files.reduce((sum, f) => sum + f.size, 0))}
+                                      </Badge>
+                                      <Button size="sm" variant="outline" className="h-6 text-[10px] px-2" disabled={batchDeleting} onClick={() => handleDeleteStrayFiles(ide.id)}>
+                                        <Trash2 size={10} className="mr-1" />
+                                        {t("conversations.removeStrayFiles")}
+                                      </Button>
+                                    </div>
+                                  </div>
+                                )}
                               </div>
                             </div>
                           )}
 
                           {/* Conversations list */}
-                          {sorted.length > 0 && (
+                          {convData && convData.total_conversations > 0 && (
                             <div>
                               {/* Toolbar: sort + select all + batch delete */}
                               <div className="flex items-center justify-between mb-2">
                                 <div className="flex items-center gap-2">
                                   <h4 className="text-xs font-semibold text-muted-foreground uppercase tracking-wider flex items-center gap-2">
                                     <MessageSquare size={12} />
-                                    {t("conversations.chatHistory")} ({sorted.length})
+                                    {t("conversations.chatHistory")} ({convData.matched})
                                   </h4>
                                   {/* Sort toggle */}
                                   <div className="flex items-center bg-muted/40 rounded-md p-0.5 ml-2">
-                                    <button
-                                      onClick={() => handleSortToggle("time")}
-                                      className={`flex items-center gap-1 px-2 py-0.5 rounded text-[10px] font-medium transition-colors ${sortMode === "time" ? "bg-background text-foreground shadow-sm" : "text-muted-foreground hover:text-foreground"}`}
+                                    {SORT_OPTIONS.map(({ field, labelKey, icon: Icon }) => (
+                                      <button
+                                        key={field}
+                                        onClick={() => handleSortToggle(field)}
+                                        className={`flex items-center gap-1 px-2 py-0.5 rounded text-[10px] font-medium transition-colors ${sort.field === field ? "bg-background text-foreground shadow-sm" : "text-muted-foreground hover:text-foreground"}`}
+                                      >
+                                        <Icon size={10} />
+                                        {t(labelKey)}
+                                        {sort.field === field && <span className="text-[9px] opacity-60">{sort.descending ? "↓" : "↑"}</span>}
+                                      </button>
+                                    ))}
+                                  </div>
+                                  {/* Language filter */}
+                                  {languages.length > 1 && (
+                                    <select
+                                      value={langFilter}
+                                      onChange={(e) => {
+                                        setLangFilter(e.target.value);
+                                        reloadPage(ide.id, { language: e.target.value === "all" ? null : e.target.value });
+                                      }}
+                                      className="bg-muted/40 rounded-md px-1.5 py-0.5 text-[10px] font-medium text-muted-foreground border-none outline-none"
                                     >
-                                      <Clock size={10} />
-                                      {t("conversations.sortByTime")}
-                                      {sortMode === "time" && <span className="text-[9px] opacity-60">{sortDir === "desc" ? "↓" : "↑"}</span>}
-                                    </button>
-                                    <button
-                                      onClick={() => handleSortToggle("size")}
-                                      className={`flex items-center gap-1 px-2 py-0.5 rounded text-[10px] font-medium transition-colors ${sortMode === "size" ? "bg-background text-foreground shadow-sm" : "text-muted-foreground hover:text-foreground"}`}
+                                      <option value="all">{t("conversations.allLanguages")}</option>
+                                      {languages.map((lang) => (
+                                        <option key={lang} value={lang}>{t(`conversations.languages.${lang}`, lang)}</option>
+                                      ))}
+                                    </select>
+                                  )}
+                                  {/* Tag filter */}
+                                  {tags.length > 0 && (
+                                    <select
+                                      value={tagFilter}
+                                      onChange={(e) => {
+                                        setTagFilter(e.target.value);
+                                        reloadPage(ide.id, { tag: e.target.value === "all" ? null : e.target.value });
+                                      }}
+                                      className="bg-muted/40 rounded-md px-1.5 py-0.5 text-[10px] font-medium text-muted-foreground border-none outline-none"
                                     >
-                                      <HardDriveDownload size={10} />
-                                      {t("conversations.sortBySize")}
-                                      {sortMode === "size" && <span className="text-[9px] opacity-60">{sortDir === "desc" ? "↓" : "↑"}</span>}
-                                    </button>
-                                  </div>
+                                      <option value="all">{t("conversations.allTags")}</option>
+                                      {tags.map((tag) => <option key={tag} value={tag}>#{tag}</option>)}
+                                    </select>
+                                  )}
+                                  {/* Size filter */}
+                                  <select
+                                    value={minSize}
+                                    onChange={(e) => {
+                                      const min = Number(e.target.value);
+                                      setMinSize(min);
+                                      reloadPage(ide.id, { min_size: min > 0 ? min : null });
+                                    }}
+                                    className="bg-muted/40 rounded-md px-1.5 py-0.5 text-[10px] font-medium text-muted-foreground border-none outline-none"
+                                  >
+                                    {SIZE_FILTERS.map((min) => (
+                                      <option key={min} value={min}>{min === 0 ? t("conversations.anySize") : `≥ ${formatBytes(min)}`}</option>
+                                    ))}
+                                  </select>
+                                  {/* Title filter */}
+                                  <input
+                                    type="text"
+                                    value={titleFilter}
+                                    placeholder={t("conversations.filterTitle")}
+                                    onChange={(e) => setTitleFilter(e.target.value)}
+                                    onKeyDown={(e) => e.key === "Enter" && reloadPage(ide.id, {})}
+                                    onBlur={() => reloadPage(ide.id, {})}
+                                    className="w-32 bg-muted/40 rounded-md px-1.5 py-0.5 text-[10px] text-foreground border-none outline-none"
+                                  />
                                 </div>
                                 <div className="flex items-center gap-2">
                                   {/* Select all */}
@@ -368,6 +785,81 @@ export default function ConversationsPage() {
                                     {allSelected ? <CheckSquare size={12} /> : <Square size={12} />}
                                     {allSelected ? t("conversations.deselectAll") : t("conversations.selectAll")}
                                   </button>
+                                  {/* Credentials pasted into chats */}
+                                  {leaking && leaking.size > 0 ? (
+                                    <Button
+                                      size="sm"
+                                      variant="outline"
+                                      className="h-6 text-[10px] px-2 text-destructive"
+                                      onClick={() => selectLeaking(ide.id)}
+                                    >
+                                      <KeyRound size={10} className="mr-1" />
+                                      {t("conversations.selectLeaking", { count: leaking.size })}
+                                    </Button>
+                                  ) : (
+                                    <Button
+                                      size="sm"
+                                      variant="outline"
+                                      className="h-6 text-[10px] px-2"
+                                      disabled={scanningSecrets}
+                                      onClick={() => handleScanSecrets(ide.id)}
+                                    >
+                                      {scanningSecrets ? <Loader2 size={10} className="animate-spin mr-1" /> : <KeyRound size={10} className="mr-1" />}
+                                      {leaking ? t("conversations.noSecrets") : t("conversations.scanSecrets")}
+                                    </Button>
+                                  )}
+                                  {/* Redundant copies of chats also stored elsewhere */}
+                                  {convData.duplicate_copies > 0 && (
+                                    <Button
+                                      size="sm"
+                                      variant="outline"
+                                      className="h-6 text-[10px] px-2"
+                                      disabled={batchDeleting}
+                                      onClick={() => handleDeleteDuplicates(ide.id, convData)}
+                                    >
+                                      <Copy size={10} className="mr-1" />
+                                      {t("conversations.removeDuplicates", { count: convData.duplicate_copies, size: formatBytes(convData.duplicate_size) })}
+                                    </Button>
+                                  )}
+                                  {/* Batch export */}
+                                  {someSelected && (
+                                    <div className="flex items-center gap-1">
+                                      <select
+                                        value={batchFormat}
+                                        onChange={(e) => setBatchFormat(e.target.value as ExportFormat)}
+                                        className="bg-muted/40 rounded-md px-1.5 py-0.5 text-[10px] font-medium text-muted-foreground border-none outline-none"
+                                      >
+                                        {(Object.keys(EXPORT_EXTENSIONS) as ExportFormat[]).map((f) => <option key={f} value={f}>{f.toUpperCase()}</option>)}
+                                      </select>
+                                      <label className="flex items-center gap-1 text-[10px] text-muted-foreground cursor-pointer">
+                                        <input type="checkbox" checked={redactExport} onChange={(e) => setRedactExport(e.target.checked)} />
+                                        {t("conversations.redactSecrets")}
+                                      </label>
+                                      <Button
+                                        size="sm"
+                                        variant="outline"
+                                        className="h-6 text-[10px] px-2"
+                                        disabled={batchExporting}
+                                        onClick={() => handleBatchExport(ide.id, sorted)}
+                                      >
+                                        {batchExporting ? <Loader2 size={10} className="animate-spin mr-1" /> : <FileDown size={10} className="mr-1" />}
+                                        {t("conversations.exportSelected", { count: selectedCount })}
+                                      </Button>
+                                    </div>
+                                  )}
+                                  {/* Batch archive */}
+                                  {someSelected && (
+                                    <Button
+                                      size="sm"
+                                      variant="outline"
+                                      className="h-6 text-[10px] px-2"
+                                      disabled={batchArchiving}
+                                      onClick={() => handleBatchArchive(ide.id, sorted)}
+                                    >
+                                      {batchArchiving ? <Loader2 size={10} className="animate-spin mr-1" /> : <Archive size={10} className="mr-1" />}
+                                      {t("conversations.archiveSelected", { count: selectedCount })}
+                                    </Button>
+                                  )}
                                   {/* Batch delete */}
                                   {someSelected && (
                                     <Button
@@ -399,20 +891,32 @@ export default function ConversationsPage() {
                                     key={conv.id}
                                     conv={conv}
                                     selected={selectedIds.has(conv.id)}
+                                    error={failedIds.get(conv.id)}
                                     deleting={deleting === conv.id}
                                     onToggleSelect={() => toggleSelect(conv.id)}
                                     onDelete={() => handleDeleteConversation(conv, ide.id)}
+                                    onToggleProtected={() => handleToggleProtected(ide.id, conv)}
+                                    onAnnotate={(tags, note) => handleAnnotate(ide.id, conv, tags, note)}
+                                    secrets={leaking?.get(conv.id)}
                                     onView={() => handleViewConversation(conv)}
                                     onSelectBefore={() => selectBefore(sorted, idx)}
                                     onSelectAfter={() => selectAfter(sorted, idx)}
                                   />
                                 ))}
                               </div>
+                              {sorted.length < convData.matched && (
+                                <div className="flex justify-center pt-2">
+                                  <Button size="sm" variant="ghost" className="h-6 text-[10px]" disabled={loadingMore} onClick={() => loadMore(ide.id)}>
+                                    {loadingMore && <Loader2 size={10} className="animate-spin mr-1" />}
+                                    {t("conversations.loadMore", { shown: sorted.length, total: convData.matched })}
+                                  </Button>
+                                </div>
+                              )}
                             </div>
                           )}
 
                           {/* Empty state */}
-                          {(!convData || (convData.conversations.length === 0 && (!convData.db_files || convData.db_files.length === 0))) && (
+                          {(!convData || (convData.total_conversations === 0 && convData.db_files.length === 0 && convData.stray_files.length === 0)) && (
                             <div className="text-center py-6 text-muted-foreground">
                               <MessageSquare size={28} className="mx-auto mb-2 opacity-40" />
                               <p className="text-sm">{t("conversations.noData")}</p>
@@ -427,6 +931,12 @@ export default function ConversationsPage() {
             })}
           </div>
         )}
+
+        <ArchivedConversationsSection
+          version={archiveVersion}
+          onView={handleViewArchived}
+          onRestored={(entry) => { if (conversations.has(entry.ide_id)) loadConversations(entry.ide_id); }}
+        />
       </div>
       {/* Conversation Content Viewer */}
       {viewingConv && createPortal(
@@ -434,6 +944,7 @@ export default function ConversationsPage() {
           conv={viewingConv}
           content={convContent}
           loading={loadingContent}
+          archived={viewingArchived}
           onClose={() => { setViewingConv(null); setConvContent(null); }}
           t={t}
         />,
@@ -443,83 +954,375 @@ export default function ConversationsPage() {
   );
 }
 
+/* ── Full-text search over every IDE's conversation titles and messages ── */
+function ConversationSearchSection({ ides, onView }: { ides: ConversationSource[]; onView: (conv: ConversationInfo) => void }) {
+  const { t } = useTranslation();
+  const [query, setQuery] = useState("");
+  const [ideFilter, setIdeFilter] = useState("");
+  const [hits, setHits] = useState<SearchHit[] | null>(null);
+  const [busy, setBusy] = useState(false);
+  const [indexing, setIndexing] = useState(false);
+  const [message, setMessage] = useState<string | null>(null);
+  // The index is brought up to date once per visit, on the first search
+  const [indexed, setIndexed] = useState(false);
+
+  async function handleSearch() {
+    if (!query.trim()) {
+      setHits(null);
+      return;
+    }
+    setBusy(true);
+    setMessage(null);
+    try {
+      if (!indexed) {
+        setIndexing(true);
+        await invoke<SearchIndexStats>("update_search_index");
+        setIndexed(true);
+      }
+      setHits(await invoke<SearchHit[]>("search_conversations", { query, ideFilter: ideFilter || null }));
+    } catch (e) {
+      setMessage(String(e));
+    }
+    setIndexing(false);
+    setBusy(false);
+  }
+
+  const ideName = (id: string) => ides.find((i) => i.id === id)?.name ?? id;
+
+  return (
+    <Card className="border-border/50">
+      <div className="flex flex-col gap-2 p-3">
+        <div className="flex items-center gap-2">
+          <input
+            type="text"
+            value={query}
+            placeholder={t("conversations.searchPlaceholder")}
+            onChange={(e) => setQuery(e.target.value)}
+            onKeyDown={(e) => e.key === "Enter" && handleSearch()}
+            className="flex-1 bg-muted border border-input rounded-md px-3 py-1.5 text-sm text-foreground outline-none focus:ring-2 focus:ring-ring focus:ring-offset-2"
+          />
+          <select
+            value={ideFilter}
+            onChange={(e) => setIdeFilter(e.target.value)}
+            className="h-8 rounded-md border border-border/50 bg-background px-2 text-xs"
+          >
+            <option value="">{t("conversations.searchAllIdes")}</option>
+            {ides.map((ide) => <option key={ide.id} value={ide.id}>{ide.name}</option>)}
+          </select>
+          <Button variant="outline" size="sm" disabled={busy} onClick={handleSearch}>
+            {busy ? <Loader2 size={14} className="mr-1.5 animate-spin" /> : <Search size={14} className="mr-1.5" />}
+            {indexing ? t("conversations.searchIndexing") : t("conversations.search")}
+          </Button>
+        </div>
+        {message && <p className="text-xs text-destructive">{message}</p>}
+        {hits && hits.length === 0 && <p className="text-xs text-muted-foreground">{t("conversations.searchNoHits")}</p>}
+        {hits && hits.length > 0 && (
+          <div className="space-y-1 max-h-[320px] overflow-y-auto">
+            {hits.map((hit) => (
+              <button
+                key={`${hit.ide_id}:${hit.conversation.id}`}
+                onClick={() => onView(hit.conversation)}
+                className="w-full text-left p-2 rounded-lg hover:bg-muted/40 transition-colors"
+              >
+                <p className="text-sm font-medium truncate">{hit.conversation.title}</p>
+                <p className="text-[11px] text-muted-foreground line-clamp-2">
+                  <span className="text-muted-foreground/60">{ideName(hit.ide_id)} · </span>
+                  {hit.snippet.split("\u0002").map((part, i) => {
+                    if (i === 0) return <span key={i}>{part}</span>;
+                    const [match, rest] = part.split("\u0003");
+                    return <span key={i}><mark className="bg-primary/20 text-foreground rounded-sm">{match}</mark>{rest}</span>;
+                  })}
+                </p>
+              </button>
+            ))}
+          </div>
+        )}
+      </div>
+    </Card>
+  );
+}
+
+/* ── Conversations archived out of their IDE into DevCleaner's own database ── */
+function ArchivedConversationsSection({
+  version, onView, onRestored,
+}: {
+  version: number;
+  onView: (entry: ArchivedConversation) => void;
+  onRestored: (entry: ArchivedConversation) => void;
+}) {
+  const { t } = useTranslation();
+  const [entries, setEntries] = useState<ArchivedConversation[]>([]);
+  const [expanded, setExpanded] = useState(false);
+  const [deleting, setDeleting] = useState<number | null>(null);
+  const [restoring, setRestoring] = useState<number | null>(null);
+  const [restoreError, setRestoreError] = useState<string | null>(null);
+
+  const load = useCallback(async () => {
+    try {
+      setEntries(await invoke<ArchivedConversation[]>("list_archived_conversations"));
+    } catch {
+      setEntries([]);
+    }
+  }, []);
+
+  useEffect(() => { load(); }, [load, version]);
+
+  async function handleDelete(entry: ArchivedConversation) {
+    if (!window.confirm(t("conversations.archiveDeleteConfirm", { title: entry.title }))) return;
+    setDeleting(entry.id);
+    try {
+      await invoke<BatchResult>("delete_archived_conversations", { ids: [entry.id] });
+      await load();
+    } catch {
+      // ignore
+    } finally {
+      setDeleting(null);
+    }
+  }
+
+  async function handleRestore(entry: ArchivedConversation) {
+    setRestoring(entry.id);
+    setRestoreError(null);
+    try {
+      await invoke<ArchivedConversation>("restore_conversation", { archiveId: entry.id });
+      await load();
+      onRestored(entry);
+    } catch (e) {
+      setRestoreError(t("conversations.restoreFailed", { title: entry.title, error: String(e) }));
+    } finally {
+      setRestoring(null);
+    }
+  }
+
+  if (entries.length === 0) return null;
+  const total = entries.reduce((s, e) => s + e.size_bytes, 0);
+
+  return (
+    <Card className="overflow-hidden">
+      <button
+        onClick={() => setExpanded(!expanded)}
+        className="w-full flex items-center justify-between p-4 hover:bg-muted/30 transition-colors"
+      >
+        <div className="flex items-center gap-3">
+          {expanded ? <ChevronDown size={16} className="text-muted-foreground" /> : <ChevronRight size={16} className="text-muted-foreground" />}
+          <Archive size={18} className="text-primary/70" />
+          <span className="font-semibold text-sm">{t("conversations.archived")}</span>
+          <Badge variant="secondary" className="text-[10px]">{entries.length}</Badge>
+        </div>
+        <span className="text-xs font-mono text-muted-foreground">{formatBytes(total)}</span>
+      </button>
+      {expanded && (
+        <div className="px-4 pb-4 space-y-1.5 max-h-[400px] overflow-y-auto">
+          {restoreError && <p className="text-xs text-destructive">{restoreError}</p>}
+          {entries.map((entry) => (
+            <div key={entry.id} className="flex items-center justify-between p-2.5 rounded-lg border border-border/50 bg-muted/20 hover:bg-muted/40 transition-colors group">
+              <button onClick={() => onView(entry)} className="flex items-center gap-2.5 min-w-0 flex-1 text-left">
+                <MessageSquare size={14} className="text-primary/60 shrink-0" />
+                <div className="min-w-0">
+                  <p className="text-sm font-medium truncate">{entry.title}</p>
+                  <p className="text-[10px] text-muted-foreground">
+                    {entry.ide_id} · {t("conversations.archivedOn", { date: new Date(entry.archived_at).toLocaleString() })}
+                  </p>
+                </div>
+              </button>
+              <div className="flex items-center gap-2 shrink-0 ml-2">
+                <span className="text-[10px] font-mono text-muted-foreground">{formatBytes(entry.size_bytes)}</span>
+                <Button
+                  size="sm"
+                  variant="ghost"
+                  className="h-6 w-6 p-0 opacity-0 group-hover:opacity-100 text-muted-foreground hover:text-primary"
+                  disabled={restoring === entry.id || deleting === entry.id}
+                  onClick={() => handleRestore(entry)}
+                  title={t("conversations.restore")}
+                >
+                  {restoring === entry.id ? <Loader2 size={12} className="animate-spin" /> : <RotateCcw size={12} />}
+                </Button>
+                <Button
+                  size="sm"
+                  variant="ghost"
+                  className="h-6 w-6 p-0 opacity-0 group-hover:opacity-100 text-muted-foreground hover:text-destructive"
+                  disabled={deleting === entry.id || restoring === entry.id}
+                  onClick={() => handleDelete(entry)}
+                  title={t("conversations.archiveDelete")}
+                >
+                  {deleting === entry.id ? <Loader2 size={12} className="animate-spin" /> : <Trash2 size={12} />}
+                </Button>
+              </div>
+            </div>
+          ))}
+        </div>
+      )}
+    </Card>
+  );
+}
+
 function ConversationItem({
-  conv, selected, deleting, onToggleSelect, onDelete, onView, onSelectBefore, onSelectAfter,
+  conv, selected, error, secrets, deleting, onToggleSelect, onDelete, onToggleProtected, onAnnotate, onView, onSelectBefore, onSelectAfter,
 }: {
   conv: ConversationInfo;
+  /** Credentials the last secret scan found in this conversation. */
+  secrets?: SecretFinding[];
   selected: boolean;
+  /** Why the last batch delete failed for this row, if it did. */
+  error?: string;
   deleting: boolean;
   onToggleSelect: () => void;
   onDelete: () => void;
+  onToggleProtected: () => void;
+  onAnnotate: (tags: string[], note: string) => void;
   onView: () => void;
   onSelectBefore: () => void;
   onSelectAfter: () => void;
 }) {
   const { t } = useTranslation();
-  const modified = conv.last_modified
-    ? new Date(conv.last_modified * 1000).toLocaleDateString()
-    : null;
+  const modified = conv.last_modified_local;
+  const [editing, setEditing] = useState(false);
+  const [tagsDraft, setTagsDraft] = useState("");
+  const [noteDraft, setNoteDraft] = useState("");
 
-  return (
-    <div className={`flex items-center justify-between p-2.5 rounded-lg border transition-colors group ${
-      selected ? "border-primary/40 bg-primary/5" : "border-border/50 bg-muted/20 hover:bg-muted/40"
-    }`}>
-      {/* Checkbox */}
-      <button
-        onClick={onToggleSelect}
-        className="shrink-0 mr-2 text-muted-foreground hover:text-primary transition-colors"
-      >
-        {selected ? <CheckSquare size={14} className="text-primary" /> : <Square size={14} />}
-      </button>
+  function startEditing() {
+    setTagsDraft(conv.tags.join(", "));
+    setNoteDraft(conv.note);
+    setEditing(true);
+  }
 
-      {/* Content (clickable to view) */}
-      <button
-        onClick={onView}
-        className="flex items-center gap-2.5 min-w-0 flex-1 cursor-pointer text-left"
-      >
-        <MessageSquare size={14} className="text-primary/60 shrink-0" />
-        <div className="min-w-0">
-          <p className="text-sm font-medium truncate">{conv.title}</p>
-          <div className="flex items-center gap-2 text-[10px] text-muted-foreground">
-            {conv.message_count > 0 && <span>{conv.message_count} messages</span>}
-            {modified && <span>· {modified}</span>}
-            <span>· {formatBytes(conv.size_bytes)}</span>
-          </div>
-        </div>
-      </button>
+  function saveAnnotation() {
+    onAnnotate(tagsDraft.split(","), noteDraft);
+    setEditing(false);
+  }
 
-      {/* Actions */}
-      <div className="flex items-center gap-0.5 shrink-0 ml-2">
-        <Button
-          size="sm" variant="ghost" title={t("conversations.selectBefore")}
-          className="h-6 w-6 p-0 text-muted-foreground/40 hover:text-primary opacity-0 group-hover:opacity-100 transition-opacity"
-          onClick={onSelectBefore}
-        >
-          <ChevronsUp size={12} />
-        </Button>
-        <Button
-          size="sm" variant="ghost" title={t("conversations.selectAfter")}
-          className="h-6 w-6 p-0 text-muted-foreground/40 hover:text-primary opacity-0 group-hover:opacity-100 transition-opacity"
-          onClick={onSelectAfter}
+  return (
+    <div>
+      <div className={`flex items-center justify-between p-2.5 rounded-lg border transition-colors group ${
+        error !== undefined ? "border-destructive/50 bg-destructive/5"
+          : selected ? "border-primary/40 bg-primary/5" : "border-border/50 bg-muted/20 hover:bg-muted/40"
+      }`}>
+        {/* Checkbox */}
+        <button
+          onClick={onToggleSelect}
+          className="shrink-0 mr-2 text-muted-foreground hover:text-primary transition-colors"
         >
-          <ChevronsDown size={12} />
-        </Button>
-        <Button
-          size="sm" variant="ghost"
-          className="h-6 w-6 p-0 text-primary/60 hover:text-primary opacity-0 group-hover:opacity-100 transition-opacity"
+          {selected ? <CheckSquare size={14} className="text-primary" /> : <Square size={14} />}
+        </button>
+
+        {/* Content (clickable to view) */}
+        <button
           onClick={onView}
+          className="flex items-center gap-2.5 min-w-0 flex-1 cursor-pointer text-left"
         >
-          <Eye size={12} />
-        </Button>
-        <Button
-          size="sm" variant="ghost"
-          className="h-6 w-6 p-0 text-destructive/60 hover:text-destructive opacity-0 group-hover:opacity-100 transition-opacity"
-          disabled={deleting}
-          onClick={onDelete}
-        >
-          {deleting ? <Loader2 size={12} className="animate-spin" /> : <Trash2 size={12} />}
-        </Button>
+          <MessageSquare size={14} className="text-primary/60 shrink-0" />
+          <div className="min-w-0">
+            <p className="text-sm font-medium truncate flex items-center gap-1">
+              {error !== undefined && (
+                <span title={error || t("conversations.deleteFailed")} className="shrink-0">
+                  <AlertTriangle size={11} className="text-destructive" />
+                </span>
+              )}
+              {secrets && secrets.length > 0 && (
+                <span
+                  title={secrets.map((f) => `${t(`conversations.secretKinds.${f.kind}`)}: ${f.preview}`).join("\n")}
+                  className="shrink-0"
+                >
+                  <KeyRound size={11} className="text-destructive" />
+                </span>
+              )}
+              {conv.protected && <Lock size={11} className="text-primary shrink-0" />}
+              <span className="truncate">{conv.title}</span>
+            </p>
+            <div className="flex items-center gap-2 text-[10px] text-muted-foreground">
+              {conv.message_count > 0 && <span>{conv.message_count} messages</span>}
+              {modified && <span>· {modified}</span>}
+              <span>· {formatBytes(conv.size_bytes)}</span>
+              {conv.duplicates.length > 0 && (
+                <span title={conv.duplicates.map((d) => d.source_db).join("\n")}>
+                  · {t("conversations.copies", { count: conv.duplicates.length })}
+                </span>
+              )}
+              {conv.tags.map((tag) => (
+                <span key={tag} className="px-1 rounded bg-primary/10 text-primary/80">#{tag}</span>
+              ))}
+              {conv.note && (
+                <span title={conv.note} className="shrink-0">
+                  <StickyNote size={10} className="text-muted-foreground/70" />
+                </span>
+              )}
+            </div>
+          </div>
+        </button>
+
+        {/* Actions */}
+        <div className="flex items-center gap-0.5 shrink-0 ml-2">
+          <Button
+            size="sm" variant="ghost" title={t("conversations.selectBefore")}
+            className="h-6 w-6 p-0 text-muted-foreground/40 hover:text-primary opacity-0 group-hover:opacity-100 transition-opacity"
+            onClick={onSelectBefore}
+          >
+            <ChevronsUp size={12} />
+          </Button>
+          <Button
+            size="sm" variant="ghost" title={t("conversations.selectAfter")}
+            className="h-6 w-6 p-0 text-muted-foreground/40 hover:text-primary opacity-0 group-hover:opacity-100 transition-opacity"
+            onClick={onSelectAfter}
+          >
+            <ChevronsDown size={12} />
+          </Button>
+          <Button
+            size="sm" variant="ghost"
+            className="h-6 w-6 p-0 text-primary/60 hover:text-primary opacity-0 group-hover:opacity-100 transition-opacity"
+            onClick={onView}
+          >
+            <Eye size={12} />
+          </Button>
+          <Button
+            size="sm" variant="ghost" title={conv.protected ? t("conversations.unprotect") : t("conversations.protect")}
+            className={`h-6 w-6 p-0 hover:text-primary transition-opacity ${
+              conv.protected ? "text-primary/60" : "text-muted-foreground/40 opacity-0 group-hover:opacity-100"
+            }`}
+            onClick={onToggleProtected}
+          >
+            {conv.protected ? <Lock size={12} /> : <LockOpen size={12} />}
+          </Button>
+          <Button
+            size="sm" variant="ghost" title={t("conversations.editNotes")}
+            className="h-6 w-6 p-0 text-muted-foreground/40 hover:text-primary opacity-0 group-hover:opacity-100 transition-opacity"
+            onClick={() => (editing ? setEditing(false) : startEditing())}
+          >
+            <Tag size={12} />
+          </Button>
+          <Button
+            size="sm" variant="ghost"
+            className="h-6 w-6 p-0 text-destructive/60 hover:text-destructive opacity-0 group-hover:opacity-100 transition-opacity"
+            title={conv.protected ? t("conversations.unprotectToDelete") : undefined}
+            disabled={deleting || conv.protected}
+            onClick={onDelete}
+          >
+            {deleting ? <Loader2 size={12} className="animate-spin" /> : <Trash2 size={12} />}
+          </Button>
+        </div>
       </div>
+      {editing && (
+        <div className="flex items-center gap-1.5 mt-1 ml-6">
+          <input
+            type="text"
+            value={tagsDraft}
+            placeholder={t("conversations.tagsPlaceholder")}
+            onChange={(e) => setTagsDraft(e.target.value)}
+            onKeyDown={(e) => e.key === "Enter" && saveAnnotation()}
+            className="w-40 bg-muted/40 rounded-md px-1.5 py-0.5 text-[10px] text-foreground border-none outline-none"
+          />
+          <input
+            type="text"
+            value={noteDraft}
+            placeholder={t("conversations.notePlaceholder")}
+            onChange={(e) => setNoteDraft(e.target.value)}
+            onKeyDown={(e) => e.key === "Enter" && saveAnnotation()}
+            className="flex-1 bg-muted/40 rounded-md px-1.5 py-0.5 text-[10px] text-foreground border-none outline-none"
+          />
+          <Button size="sm" variant="outline" className="h-6 text-[10px] px-2" onClick={saveAnnotation}>
+            {t("conversations.saveNotes")}
+          </Button>
+        </div>
+      )}
     </div>
   );
 }
@@ -529,15 +1332,46 @@ function ConversationViewer({
   conv,
   content,
   loading,
+  archived,
   onClose,
   t,
 }: {
   conv: ConversationInfo;
   content: ConversationContent | null;
   loading: boolean;
+  /** Content comes from DevCleaner's archive; the IDE no longer has it to export. */
+  archived: boolean;
   onClose: () => void;
   t: (key: string) => string;
 }) {
+  const [exporting, setExporting] = useState(false);
+  const [exportError, setExportError] = useState<string | null>(null);
+  const [redact, setRedact] = useState(false);
+
+  async function handleExport(format: ExportFormat) {
+    const extension = EXPORT_EXTENSIONS[format];
+    const name = (content?.title || conv.title).replace(/[\\/:*?"<>|]+/g, "_").slice(0, 80) || "conversation";
+    const path = await save({ defaultPath: `${name}.${extension}`, filters: [{ name: format.toUpperCase(), extensions: [extension] }] });
+    if (!path) return;
+    setExporting(true);
+    setExportError(null);
+    try {
+      await invoke("export_conversation", {
+        sourceDb: conv.source_db,
+        sourceKey: conv.source_key,
+        conversationId: conversationIdOf(conv),
+        format,
+        path,
+        redact,
+      });
+    } catch (e) {
+      setExportError(String(e));
+    }
+    setExporting(false);
+  }
+
+  const canExport = !archived && !loading && !conv.id.startsWith("pb:") && (content?.messages.length ?? 0) > 0;
+
   return (
     <div
       className="fixed inset-0 z-[9999] flex items-center justify-center bg-black/60 backdrop-blur-sm p-4 animate-in fade-in duration-200"
@@ -551,9 +1385,21 @@ function ConversationViewer({
             <p className="text-[10px] text-muted-foreground mt-0.5">
               {conv.message_count > 0 && <>{conv.message_count} {t("conversations.messagesCount")} · </>}
               {formatBytes(conv.size_bytes)}
+              {exportError && <span className="text-destructive ml-2" title={exportError}>{t("conversations.exportFailed")}</span>}
             </p>
           </div>
-          <Button size="sm" variant="ghost" className="h-7 w-7 p-0 shrink-0 ml-3" onClick={onClose}>
+          <label className="flex items-center gap-1 shrink-0 ml-3 text-[10px] text-muted-foreground cursor-pointer">
+            <input type="checkbox" checked={redact} disabled={!canExport} onChange={(e) => setRedact(e.target.checked)} />
+            {t("conversations.redactSecrets")}
+          </label>
+          <Button size="sm" variant="ghost" className="h-7 px-2 shrink-0 ml-1 text-xs" disabled={!canExport || exporting} onClick={() => handleExport("Html")}>
+            {exporting ? <Loader2 size={14} className="mr-1 animate-spin" /> : <FileDown size={14} className="mr-1" />}
+            {t("conversations.exportHtml")}
+          </Button>
+          <Button size="sm" variant="ghost" className="h-7 px-2 shrink-0 text-xs" disabled={!canExport || exporting} onClick={() => handleExport("Jsonl")}>
+            {t("conversations.exportJsonl")}
+          </Button>
+          <Button size="sm" variant="ghost" className="h-7 w-7 p-0 shrink-0 ml-1" onClick={onClose}>
             <X size={16} />
           </Button>
         </div>
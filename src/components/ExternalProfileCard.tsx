@@ -0,0 +1,76 @@
+import { useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { open } from "@tauri-apps/plugin-dialog";
+import { FolderSearch, Loader2, UserSearch } from "lucide-react";
+import { useTranslation } from "react-i18next";
+import type { ExternalProfileReport } from "../types";
+import { formatBytes } from "../utils/formatters";
+import { Card, CardContent, CardHeader, CardTitle } from "./ui/card";
+import { Button } from "./ui/button";
+
+/* ── Read-only analysis of another user's profile or a mounted disk image ── */
+export default function ExternalProfileCard() {
+  const { t } = useTranslation();
+  const [busy, setBusy] = useState(false);
+  const [report, setReport] = useState<ExternalProfileReport | null>(null);
+  const [message, setMessage] = useState<string | null>(null);
+
+  async function handlePick() {
+    const path = await open({ directory: true, multiple: false });
+    if (typeof path !== "string") return;
+    setBusy(true);
+    setMessage(null);
+    try {
+      setReport(await invoke<ExternalProfileReport>("analyze_external_profile", { path }));
+    } catch (e) {
+      setReport(null);
+      setMessage(String(e));
+    }
+    setBusy(false);
+  }
+
+  return (
+    <Card>
+      <CardHeader>
+        <div className="flex items-center gap-3">
+          <div className="p-2 bg-primary/10 rounded-lg text-primary">
+            <UserSearch size={20} />
+          </div>
+          <CardTitle>{t("externalProfile.title")}</CardTitle>
+        </div>
+      </CardHeader>
+      <CardContent className="space-y-4">
+        <p className="text-sm text-muted-foreground">{t("externalProfile.desc")}</p>
+        <div className="flex justify-end">
+          <Button variant="outline" size="sm" disabled={busy} onClick={handlePick}>
+            {busy ? <Loader2 size={14} className="mr-1.5 animate-spin" /> : <FolderSearch size={14} className="mr-1.5" />}
+            {busy ? t("externalProfile.analyzing") : t("externalProfile.pick")}
+          </Button>
+        </div>
+
+        {report && report.profiles.map((profile) => (
+          <div key={profile.dirs.home} className="space-y-1 text-xs">
+            <p>
+              <span className="font-medium">{profile.name}</span>
+              <span className="text-muted-foreground ml-2">
+                {profile.dirs.layout} · {profile.scan.results.length > 0
+                  ? t("externalProfile.ides", { count: profile.scan.results.length, size: formatBytes(profile.scan.grand_total_size) })
+                  : t("externalProfile.noIdes")}
+              </span>
+            </p>
+            {[...profile.scan.results]
+              .sort((a, b) => b.total_size - a.total_size)
+              .map((r) => (
+                <p key={r.ide_id} className="flex justify-between pl-3 text-muted-foreground">
+                  <span>{r.ide_name}</span>
+                  <span className="font-mono">{formatBytes(r.total_size)}</span>
+                </p>
+              ))}
+          </div>
+        ))}
+
+        {message && <p className="text-xs text-destructive">{message}</p>}
+      </CardContent>
+    </Card>
+  );
+}
@@ -0,0 +1,134 @@
+import { useEffect, useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { Loader2, RefreshCw, FolderOpen } from "lucide-react";
+import { useTranslation } from "react-i18next";
+import type { DeveloperFootprint, FootprintFamily } from "../types";
+import { formatBytes, formatDuration } from "../utils/formatters";
+import { Card, CardContent } from "./ui/card";
+import { Button } from "./ui/button";
+import { Badge } from "./ui/badge";
+
+let cachedFootprint: DeveloperFootprint | null = null;
+
+const FAMILY_KEYS: Record<FootprintFamily, string> = {
+  Ide: "footprint.families.ide",
+  DevCache: "footprint.families.devCache",
+  ToolboxBuild: "footprint.families.toolboxBuild",
+  ProjectArtifact: "footprint.families.projectArtifact",
+  DevContainer: "footprint.families.devContainer",
+  LocalModel: "footprint.families.localModel",
+  WslServer: "footprint.families.wslServer",
+  AiCliSession: "footprint.families.aiCliSession",
+};
+
+export default function FootprintPage() {
+  const { t } = useTranslation();
+  const [footprint, setFootprint] = useState<DeveloperFootprint | null>(cachedFootprint);
+  const [loading, setLoading] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    if (!cachedFootprint) load();
+  }, []);
+
+  async function load() {
+    setLoading(true);
+    setError(null);
+    try {
+      const result = await invoke<DeveloperFootprint>("get_developer_footprint");
+      cachedFootprint = result;
+      setFootprint(result);
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setLoading(false);
+    }
+  }
+
+  async function openPath(path: string) {
+    try { await invoke("open_path", { path }); } catch { /* ignore */ }
+  }
+
+  return (
+    <div className="h-full flex flex-col overflow-hidden">
+      <div className="flex-1 overflow-y-auto p-6 space-y-6">
+        <div className="flex items-end justify-between">
+          <div>
+            <h2 className="text-2xl font-bold tracking-tight text-[var(--color-text)]">{t("footprint.title")}</h2>
+            <p className="text-muted-foreground mt-1">{t("footprint.subtitle")}</p>
+          </div>
+          <Button onClick={load} disabled={loading} variant="outline" className="shadow-sm">
+            <RefreshCw size={16} className={`mr-2 ${loading ? "animate-spin" : ""}`} />
+            {loading ? t("dashboard.scanning") : t("scan.rescan")}
+          </Button>
+        </div>
+
+        {error && <p className="text-sm text-destructive">{error}</p>}
+
+        {!footprint && loading && (
+          <div className="flex items-center justify-center gap-3 pt-16 text-muted-foreground">
+            <Loader2 size={24} className="animate-spin" />
+            <span>{t("dashboard.scanning")}</span>
+          </div>
+        )}
+
+        {footprint && (
+          <>
+            <div className="grid grid-cols-1 sm:grid-cols-2 lg:grid-cols-4 gap-4">
+              <Card className="border-primary/40">
+                <CardContent className="p-4">
+                  <p className="text-xs text-muted-foreground uppercase tracking-wider">{t("footprint.total")}</p>
+                  <p className="text-2xl font-bold mt-1">{formatBytes(footprint.grand_total)}</p>
+                  <p className="text-[10px] text-muted-foreground mt-1">{formatDuration(footprint.scan_duration_ms)}</p>
+                </CardContent>
+              </Card>
+              {footprint.families.map((f) => (
+                <Card key={f.family}>
+                  <CardContent className="p-4">
+                    <p className="text-xs text-muted-foreground uppercase tracking-wider">{t(FAMILY_KEYS[f.family])}</p>
+                    <p className="text-2xl font-bold mt-1">{formatBytes(f.total_size)}</p>
+                    <p className="text-[10px] text-muted-foreground mt-1">{t("footprint.items", { count: f.item_count })}</p>
+                  </CardContent>
+                </Card>
+              ))}
+            </div>
+
+            <div className="space-y-2">
+              <h3 className="text-sm font-medium text-muted-foreground uppercase tracking-wider">{t("footprint.ranked")}</h3>
+              {footprint.items.map((item) => {
+                const pct = footprint.grand_total > 0 ? (item.size / footprint.grand_total) * 100 : 0;
+                return (
+                  <div key={item.id} className="flex flex-col gap-1.5 p-3 rounded-xl bg-muted/30 border border-transparent hover:bg-muted/60 hover:border-border/50 transition-all">
+                    <div className="flex items-center justify-between gap-3">
+                      <div className="flex items-center gap-2 min-w-0">
+                        <Badge variant="secondary" className="shrink-0 text-[10px]">{t(FAMILY_KEYS[item.family])}</Badge>
+                        <span className="text-sm font-medium truncate">{item.name}</span>
+                        <span className="text-xs text-muted-foreground truncate">{item.detail}</span>
+                      </div>
+                      <div className="flex items-center gap-2 shrink-0">
+                        <span className="text-[10px] text-muted-foreground/60 font-mono">{pct.toFixed(1)}%</span>
+                        <span className="text-xs font-mono">{formatBytes(item.size)}</span>
+                        {item.paths[0] && (
+                          <button
+                            onClick={() => openPath(item.paths[0])}
+                            className="p-0.5 rounded text-muted-foreground/60 hover:text-primary transition-colors"
+                            title={item.paths.join("\n")}
+                          >
+                            <FolderOpen size={12} />
+                          </button>
+                        )}
+                      </div>
+                    </div>
+                    <div className="h-1 rounded-full bg-muted/60 overflow-hidden">
+                      <div className="h-full rounded-full bg-primary/50" style={{ width: `${Math.max(pct, 0.5)}%` }} />
+                    </div>
+                  </div>
+                );
+              })}
+            </div>
+          </>
+        )}
+      </div>
+    </div>
+  );
+}
@@ -0,0 +1,199 @@
+import { useEffect, useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { open, save } from "@tauri-apps/plugin-dialog";
+import { Download, Loader2, Truck, Upload } from "lucide-react";
+import { useTranslation } from "react-i18next";
+import type { IdeInfo, MigrationExportSummary, MigrationImportSummary, MigrationManifest } from "../types";
+import { formatBytes } from "../utils/formatters";
+import { Card, CardContent, CardHeader, CardTitle } from "./ui/card";
+import { Button } from "./ui/button";
+import { Checkbox } from "./ui/checkbox";
+import { Separator } from "./ui/separator";
+
+/* ── Migrate to a new machine: settings, extension lists and conversations in one archive ── */
+export default function MigrationCard() {
+  const { t } = useTranslation();
+  const [ides, setIdes] = useState<IdeInfo[]>([]);
+  const [selected, setSelected] = useState<Set<string>>(new Set());
+  const [includeConversations, setIncludeConversations] = useState(true);
+  const [busy, setBusy] = useState(false);
+  const [message, setMessage] = useState<string | null>(null);
+
+  const [importPath, setImportPath] = useState<string | null>(null);
+  const [manifest, setManifest] = useState<MigrationManifest | null>(null);
+  const [overwriteSettings, setOverwriteSettings] = useState(false);
+  const [installExtensions, setInstallExtensions] = useState(true);
+  const [importSummary, setImportSummary] = useState<MigrationImportSummary | null>(null);
+
+  useEffect(() => {
+    invoke<IdeInfo[]>("detect_ides")
+      .then((list) => {
+        const installed = list.filter((i) => i.installed);
+        setIdes(installed);
+        setSelected(new Set(installed.map((i) => i.id)));
+      })
+      .catch(() => {});
+  }, []);
+
+  function toggle(id: string) {
+    setSelected((prev) => {
+      const next = new Set(prev);
+      if (next.has(id)) next.delete(id); else next.add(id);
+      return next;
+    });
+  }
+
+  async function handleExport() {
+    const path = await save({ defaultPath: "devcleaner-migration.zip", filters: [{ name: "ZIP", extensions: ["zip"] }] });
+    if (!path) return;
+    setBusy(true);
+    try {
+      const summary = await invoke<MigrationExportSummary>("export_migration", {
+        path,
+        ideIds: Array.from(selected),
+        includeConversations,
+      });
+      setMessage(t("migration.exported", { ides: summary.manifest.ides.length, size: formatBytes(summary.size) }));
+    } catch (e) {
+      setMessage(String(e));
+    }
+    setBusy(false);
+  }
+
+  async function handlePickArchive() {
+    const path = await open({ multiple: false, filters: [{ name: "ZIP", extensions: ["zip"] }] });
+    if (typeof path !== "string") return;
+    setImportSummary(null);
+    try {
+      setManifest(await invoke<MigrationManifest>("read_migration_manifest", { path }));
+      setImportPath(path);
+      setMessage(null);
+    } catch (e) {
+      setManifest(null);
+      setMessage(String(e));
+    }
+  }
+
+  async function handleImport() {
+    if (!importPath) return;
+    setBusy(true);
+    try {
+      setImportSummary(await invoke<MigrationImportSummary>("import_migration", {
+        path: importPath,
+        options: { ide_ids: [], overwrite_settings: overwriteSettings, install_extensions: installExtensions },
+      }));
+      setManifest(null);
+    } catch (e) {
+      setMessage(String(e));
+    }
+    setBusy(false);
+  }
+
+  return (
+    <Card>
+      <CardHeader>
+        <div className="flex items-center gap-3">
+          <div className="p-2 bg-primary/10 rounded-lg text-primary">
+            <Truck size={20} />
+          </div>
+          <CardTitle>{t("migration.title")}</CardTitle>
+        </div>
+      </CardHeader>
+      <CardContent className="space-y-4">
+        <p className="text-sm text-muted-foreground">{t("migration.desc")}</p>
+
+        <div className="flex flex-wrap gap-x-4 gap-y-2">
+          {ides.map((ide) => (
+            <label key={ide.id} className="flex items-center gap-2 text-xs cursor-pointer">
+              <Checkbox checked={selected.has(ide.id)} onCheckedChange={() => toggle(ide.id)} />
+              {ide.name}
+            </label>
+          ))}
+        </div>
+        <label className="flex items-center gap-2 text-xs text-muted-foreground cursor-pointer">
+          <Checkbox checked={includeConversations} onCheckedChange={(v) => setIncludeConversations(v === true)} />
+          {t("migration.includeConversations")}
+        </label>
+        <div className="flex items-center gap-2 justify-end">
+          <Button variant="outline" size="sm" disabled={busy || selected.size === 0} onClick={handleExport}>
+            {busy ? <Loader2 size={14} className="mr-1.5 animate-spin" /> : <Download size={14} className="mr-1.5" />}
+            {t("migration.export")}
+          </Button>
+          <Button variant="outline" size="sm" disabled={busy} onClick={handlePickArchive}>
+            <Upload size={14} className="mr-1.5" />
+            {t("migration.import")}
+          </Button>
+        </div>
+
+        {manifest && (
+          <>
+            <Separator />
+            <p className="text-xs text-muted-foreground">
+              {t("migration.archiveFrom", { date: manifest.created_at.slice(0, 10), os: manifest.source_os })}
+            </p>
+            <div className="space-y-1">
+              {manifest.ides.map((ide) => (
+                <p key={ide.id} className="text-xs">
+                  <span className="font-medium">{ide.name}</span>
+                  <span className="text-muted-foreground ml-2">
+                    {t("migration.ideContents", { settings: ide.settings_files.length, extensions: ide.extensions.length, conversations: ide.conversations })}
+                  </span>
+                </p>
+              ))}
+            </div>
+            <label className="flex items-center gap-2 text-xs text-muted-foreground cursor-pointer">
+              <Checkbox checked={overwriteSettings} onCheckedChange={(v) => setOverwriteSettings(v === true)} />
+              {t("migration.overwriteSettings")}
+            </label>
+            <label className="flex items-center gap-2 text-xs text-muted-foreground cursor-pointer">
+              <Checkbox checked={installExtensions} onCheckedChange={(v) => setInstallExtensions(v === true)} />
+              {t("migration.installExtensions")}
+            </label>
+            <div className="flex justify-end">
+              <Button size="sm" disabled={busy} onClick={handleImport}>
+                {busy && <Loader2 size={14} className="mr-1.5 animate-spin" />}
+                {t("migration.restore")}
+              </Button>
+            </div>
+          </>
+        )}
+
+        {importSummary && (
+          <div className="space-y-1 text-xs">
+            {importSummary.ides.map((r) => (
+              <div key={r.id}>
+                <p>
+                  <span className="font-medium">{r.id}</span>
+                  <span className="text-muted-foreground ml-2">
+                    {t("migration.restored", { files: r.restored_files, skipped: r.skipped_files, installed: r.installed_extensions.length })}
+                  </span>
+                </p>
+                {r.pending_extensions.length > 0 && (
+                  <p className="text-muted-foreground/70 truncate" title={r.pending_extensions.join("\n")}>
+                    {t("migration.pendingExtensions", { count: r.pending_extensions.length })}
+                  </p>
+                )}
+                {r.conversations_dir && (
+                  <p className="text-muted-foreground/70 truncate" title={r.conversations_dir}>
+                    {t("migration.conversationsSaved", { path: r.conversations_dir })}
+                  </p>
+                )}
+                {r.errors.length > 0 && (
+                  <p className="text-destructive" title={r.errors.join("\n")}>{r.errors.length} {t("clean.errors")}</p>
+                )}
+              </div>
+            ))}
+            {importSummary.added_annotations > 0 && (
+              <p className="text-muted-foreground">{t("migration.annotationsAdded", { count: importSummary.added_annotations })}</p>
+            )}
+            {importSummary.skipped_ides.length > 0 && (
+              <p className="text-warning">{t("migration.skippedIdes", { ides: importSummary.skipped_ides.join(", ") })}</p>
+            )}
+          </div>
+        )}
+
+        {message && <p className="text-xs text-muted-foreground">{message}</p>}
+      </CardContent>
+    </Card>
+  );
+}
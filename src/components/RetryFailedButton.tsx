@@ -0,0 +1,62 @@
+import { useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { Loader2, RotateCcw } from "lucide-react";
+import { useTranslation } from "react-i18next";
+import type { BatchResult } from "../types";
+import { formatBytes } from "../utils/formatters";
+import { Button } from "./ui/button";
+import { Checkbox } from "./ui/checkbox";
+
+interface RetryFailedButtonProps {
+  operationId: string;
+  /** Called after each retry; `result.operation_id` is null once nothing is left. */
+  onRetried?: (result: BatchResult) => void;
+}
+
+/** Retries only the items a clean/delete/uninstall failed on, optionally closing the IDE first. */
+export default function RetryFailedButton({ operationId, onRetried }: RetryFailedButtonProps) {
+  const { t } = useTranslation();
+  const [closeIde, setCloseIde] = useState(false);
+  const [retrying, setRetrying] = useState(false);
+  const [last, setLast] = useState<BatchResult | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  async function handleRetry() {
+    setRetrying(true);
+    setError(null);
+    try {
+      const result = await invoke<BatchResult>("retry_failed", { operationId, closeIde });
+      setLast(result);
+      onRetried?.(result);
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setRetrying(false);
+    }
+  }
+
+  if (last && !last.operation_id) {
+    return (
+      <p className="text-xs text-success">
+        {t("retry.allDone", { size: formatBytes(last.freed_bytes) })}
+      </p>
+    );
+  }
+
+  return (
+    <div className="flex flex-wrap items-center gap-3">
+      <Button variant="outline" size="sm" disabled={retrying} onClick={handleRetry}>
+        {retrying ? <Loader2 size={14} className="mr-1.5 animate-spin" /> : <RotateCcw size={14} className="mr-1.5" />}
+        {t("retry.retryFailed")}
+      </Button>
+      <label className="flex items-center gap-2 text-xs text-muted-foreground cursor-pointer">
+        <Checkbox checked={closeIde} onCheckedChange={(v) => setCloseIde(v === true)} />
+        {t("retry.closeIdeFirst")}
+      </label>
+      {last && (
+        <span className="text-xs text-warning">{t("retry.stillFailing", { count: last.failed })}</span>
+      )}
+      {error && <span className="text-xs text-destructive">{error}</span>}
+    </div>
+  );
+}
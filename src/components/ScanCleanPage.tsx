@@ -1,6 +1,8 @@
 import { useEffect, useState, useCallback } from "react";
 import { createPortal } from "react-dom";
 import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import { open, save } from "@tauri-apps/plugin-dialog";
 import {
   Trash2,
   Shield,
@@ -18,13 +20,20 @@ import {
   ArrowRight,
   FolderOpen,
   Globe,
+  Package,
+  Terminal,
+  Download,
+  Upload,
+  History,
 } from "lucide-react";
 import { Treemap, ResponsiveContainer, Tooltip } from "recharts";
 import { useTranslation } from "react-i18next";
-import type { ScanSummary, CleanMode, CleanResult, IdeScanResult, CategoryType, IdeInfo, ScanCategory, StorageEntry } from "../types";
+import type { ScanSummary, CleanMode, CleanResult, IdeScanResult, CategoryType, IdeInfo, IdeType, FamilyCleanResult, FamilyCleanProgress, ScanCategory, StorageEntry, DevCache, DevCacheEntry, RustTarget, MavenRepoReport, ExtensionReport, ExtensionGroup, ExtensionUninstallResult, ExtensionList, ExtensionInstallResult, WslReport, WslServerDir, BatchResult, SshTarget, RemoteServerReport, DevContainerReport, DevContainerItem, AiCliReport, AiCliSession, AiCliSessionSummary, PythonProject, PythonArtifactGroup, LocalModel, LocalModelReport } from "../types";
+import { invokeChunked } from "../utils/chunked";
 import { formatBytes, formatNumber, formatDuration, getIdeColor } from "../utils/formatters";
 import { useIdeIcons } from "../hooks/useIdeIcons";
 import IdeIcon from "./IdeIcon";
+import RetryFailedButton from "./RetryFailedButton";
 import { loadSettings } from "../utils/storage";
 import { Card, CardContent, CardHeader, CardTitle } from "./ui/card";
 import { Button } from "./ui/button";
@@ -58,8 +67,15 @@ export default function ScanCleanPage() {
   const [confirmStep, setConfirmStep] = useState<"idle" | "warning" | "confirm">("idle");
   const [runningIdes, setRunningIdes] = useState<string[]>([]);
   const [installedIdes, setInstalledIdes] = useState<IdeInfo[]>([]);
+  const [familyProgress, setFamilyProgress] = useState<FamilyCleanProgress | null>(null);
+  const [familyOperation, setFamilyOperation] = useState<string | null>(null);
   const ideIcons = useIdeIcons();
 
+  useEffect(() => {
+    const unlisten = listen<FamilyCleanProgress>("family-clean-progress", (e) => setFamilyProgress(e.payload.ide_id ? e.payload : null));
+    return () => { unlisten.then((f) => f()); };
+  }, []);
+
   useEffect(() => {
     if (cachedScanResult) {
       applyModeSelection(cachedScanResult, cleanMode);
@@ -83,7 +99,7 @@ export default function ScanCleanPage() {
       case "Recommended":
         return new Set(["Cache", "Log", "WorkspaceStorage", "CrashReport"]);
       case "Aggressive":
-        return new Set(["Cache", "Log", "WorkspaceStorage", "CrashReport", "Extension", "GlobalStorage"]);
+        return new Set(["Cache", "Log", "WorkspaceStorage", "CrashReport", "Extension", "GlobalStorage", "WebStorage", "BuildCache"]);
     }
   }
 
@@ -116,7 +132,8 @@ export default function ScanCleanPage() {
     setError(null);
     setCleanResults([]);
     try {
-      const result = await invoke<ScanSummary>("scan_all_ides");
+      // Each IDE's results are shown as soon as they arrive
+      const result = await invokeChunked<ScanSummary>("scan_all_ides_chunked", undefined, setScanResult);
       setScanResult(result);
       cachedScanResult = result;
       applyModeSelection(result, cleanMode);
@@ -208,12 +225,53 @@ export default function ScanCleanPage() {
         results.push(result);
       }
       setCleanResults(results);
-      const newScan = await invoke<ScanSummary>("scan_all_ides");
+      const newScan = await invokeChunked<ScanSummary>("scan_all_ides_chunked");
+      setScanResult(newScan);
+      cachedScanResult = newScan;
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setCleaning(false);
+    }
+  }
+
+  /** Families with more than one installed IDE, with the categories the current mode allows in them. */
+  function cleanableFamilies(): { ideType: IdeType; ides: IdeInfo[]; categories: string[]; size: number }[] {
+    if (!scanResult) return [];
+    const allowTypes = getAllowedCategoryTypes(cleanMode);
+    const families = new Map<IdeType, IdeInfo[]>();
+    for (const ide of installedIdes) families.set(ide.ide_type, [...(families.get(ide.ide_type) || []), ide]);
+    return Array.from(families.entries())
+      .filter(([, ides]) => ides.length > 1)
+      .map(([ideType, ides]) => {
+        const ids = new Set(ides.map((i) => i.id));
+        const cats = scanResult.results
+          .filter((r) => ids.has(r.ide_id))
+          .flatMap((r) => r.categories)
+          .filter((c) => allowTypes.has(c.category_type) && c.total_size > 0);
+        return { ideType, ides, categories: Array.from(new Set(cats.map((c) => c.name))), size: cats.reduce((sum, c) => sum + c.total_size, 0) };
+      })
+      .filter((f) => f.size > 0);
+  }
+
+  async function executeFamilyClean(ideType: IdeType, ides: IdeInfo[], categories: string[], size: number) {
+    const names = ides.map((i) => i.name).join(", ");
+    if (!window.confirm(t("clean.familyConfirm", { names, size: formatBytes(size) }))) return;
+    setCleaning(true);
+    setCleanResults([]);
+    setFamilyOperation(null);
+    setError(null);
+    try {
+      const result = await invoke<FamilyCleanResult>("clean_family", { ideType, categories, mode: cleanMode, createBackup });
+      setCleanResults(result.results);
+      setFamilyOperation(result.operation_id);
+      const newScan = await invokeChunked<ScanSummary>("scan_all_ides_chunked");
       setScanResult(newScan);
       cachedScanResult = newScan;
     } catch (e) {
       setError(String(e));
     } finally {
+      setFamilyProgress(null);
       setCleaning(false);
     }
   }
@@ -253,6 +311,9 @@ export default function ScanCleanPage() {
       case "Extension": return <Puzzle size={14} className="text-purple-400" />;
       case "CrashReport": return <AlertTriangle size={14} className="text-red-400" />;
       case "GlobalStorage": return <Globe size={14} className="text-cyan-400" />;
+      case "WebStorage": return <Globe size={14} className="text-orange-400" />;
+      case "BuildCache": return <Database size={14} className="text-indigo-400" />;
+      case "LocalHistory": return <History size={14} className="text-teal-400" />;
       default: return <FileText size={14} />;
     }
   };
@@ -310,15 +371,18 @@ export default function ScanCleanPage() {
             <CardContent>
               <div className="grid grid-cols-1 md:grid-cols-2 gap-4">
                 {cleanResults.map((r) => (
-                  <div key={r.ide_id} className="flex items-center justify-between p-3 bg-success/5 rounded-xl border border-success/10">
-                    <span className="font-medium">{r.ide_id}</span>
-                    <div className="text-sm">
-                      <span className="text-success">{t("clean.freed")} {formatBytes(r.freed_bytes)}</span>
-                      <span className="text-muted-foreground text-xs ml-2">({formatNumber(r.deleted_files)} {t("dashboard.files")})</span>
-                      {r.errors.length > 0 && (
-                        <span className="text-warning ml-2">({r.errors.length} {t("clean.errors")})</span>
-                      )}
+                  <div key={r.ide_id} className="p-3 bg-success/5 rounded-xl border border-success/10 space-y-2">
+                    <div className="flex items-center justify-between">
+                      <span className="font-medium">{r.ide_id}</span>
+                      <div className="text-sm">
+                        <span className="text-success">{t("clean.freed")} {formatBytes(r.freed_bytes)}</span>
+                        <span className="text-muted-foreground text-xs ml-2">({formatNumber(r.deleted_files)} {t("dashboard.files")})</span>
+                        {r.errors.length > 0 && (
+                          <span className="text-warning ml-2" title={r.errors.join("\n")}>({r.errors.length} {t("clean.errors")})</span>
+                        )}
+                      </div>
                     </div>
+                    {r.operation_id && <RetryFailedButton operationId={r.operation_id} />}
                   </div>
                 ))}
               </div>
@@ -437,6 +501,38 @@ export default function ScanCleanPage() {
               </div>
             </div>
 
+            {/* Whole-family clean: every IDE of one type in one call */}
+            {cleanableFamilies().length > 0 && (
+              <div className="space-y-3">
+                <h3 className="text-sm font-medium text-muted-foreground uppercase tracking-wider">{t("clean.byFamily")}</h3>
+                <div className="flex flex-wrap gap-2">
+                  {cleanableFamilies().map((f) => (
+                    <Button
+                      key={f.ideType}
+                      variant="outline" size="sm"
+                      disabled={cleaning}
+                      onClick={() => executeFamilyClean(f.ideType, f.ides, f.categories, f.size)}
+                    >
+                      <Trash2 size={14} className="mr-1.5" />
+                      {t(`clean.families.${f.ideType}`, { count: f.ides.length })} · {formatBytes(f.size)}
+                    </Button>
+                  ))}
+                </div>
+                {familyProgress && (
+                  <p className="text-xs text-muted-foreground flex items-center gap-1.5">
+                    <Loader2 size={12} className="animate-spin" />
+                    {t("clean.familyProgress", {
+                      name: familyProgress.ide_name,
+                      done: familyProgress.done + 1,
+                      total: familyProgress.total,
+                      size: formatBytes(familyProgress.freed_bytes),
+                    })}
+                  </p>
+                )}
+                {familyOperation && <RetryFailedButton operationId={familyOperation} />}
+              </div>
+            )}
+
             {/* IDE & Category Selection */}
             <div className="space-y-4 pb-4">
               <h3 className="text-sm font-medium text-muted-foreground uppercase tracking-wider">{t("clean.selectItems")}</h3>
@@ -455,6 +551,17 @@ export default function ScanCleanPage() {
                   />
                 ))}
             </div>
+
+            <DevCachesSection scanResult={scanResult} />
+            <RustTargetsSection scanResult={scanResult} />
+            <PythonArtifactsSection scanResult={scanResult} />
+            <MavenRepoSection scanResult={scanResult} />
+            <AiCliSection scanResult={scanResult} />
+            <LocalModelsSection scanResult={scanResult} />
+            <ExtensionsSection scanResult={scanResult} />
+            <WslServersSection scanResult={scanResult} />
+            <DevContainersSection scanResult={scanResult} />
+            <RemoteServersSection />
           </div>
         ) : (
           /* Empty State / Scanning State */
@@ -669,6 +776,7 @@ function IdeCleanCard({
                 onToggle={() => onToggleCategory(cat.name)}
                 icon={categoryIcon(cat.category_type)}
                 ideTotal={result.total_size}
+                ideId={result.ide_id}
               />
             ))}
           </div>
@@ -678,6 +786,1189 @@ function IdeCleanCard({
   );
 }
 
+/* ── Dev tool caches (npm, ...) outside any IDE, cleaned one at a time ── */
+function DevCachesSection({ scanResult }: { scanResult: ScanSummary }) {
+  const { t } = useTranslation();
+  const [caches, setCaches] = useState<DevCache[]>([]);
+  const [loading, setLoading] = useState(false);
+  const [cleaningId, setCleaningId] = useState<string | null>(null);
+  const [lastResult, setLastResult] = useState<{ id: string; freed_bytes: number; errors: string[]; operation_id: string | null } | null>(null);
+
+  const load = useCallback(async () => {
+    setLoading(true);
+    try {
+      setCaches(await invoke<DevCache[]>("scan_dev_caches"));
+    } catch { /* ignore */ }
+    setLoading(false);
+  }, []);
+
+  useEffect(() => { load(); }, [load, scanResult]);
+
+  const handleClean = useCallback(async (cache: DevCache, useTool: boolean, only?: DevCacheEntry) => {
+    const msg = t("devCaches.confirm", {
+      name: only ? `${cache.name} ${only.name}` : cache.name,
+      size: formatBytes(only ? only.size : cache.size),
+    });
+    if (!window.confirm(msg)) return;
+    setCleaningId(cache.id);
+    try {
+      const result = await invoke<BatchResult>("clean_dev_cache", { id: cache.id, useTool, only: only?.path ?? null });
+      setLastResult({
+        id: cache.id,
+        freed_bytes: result.freed_bytes,
+        errors: result.items.filter((i) => i.status === "Failed").map((i) => `${i.id}: ${i.error ?? ""}`),
+        operation_id: result.operation_id ?? null,
+      });
+      await load();
+    } catch (e) {
+      setLastResult({ id: cache.id, freed_bytes: 0, errors: [String(e)], operation_id: null });
+    }
+    setCleaningId(null);
+  }, [load, t]);
+
+  if (!loading && caches.length === 0) return null;
+
+  return (
+    <div className="space-y-4 pb-4">
+      <h3 className="text-sm font-medium text-muted-foreground uppercase tracking-wider">{t("devCaches.title")}</h3>
+      {loading && caches.length === 0 ? (
+        <div className="flex items-center gap-2 text-muted-foreground/60">
+          <Loader2 size={14} className="animate-spin" />
+          <span className="text-xs">{t("dashboard.scanning")}</span>
+        </div>
+      ) : (
+        caches.map((cache) => (
+          <Card key={cache.id} className="border-border/50">
+            <div className="flex items-center justify-between gap-4 p-4">
+              <div className="flex items-center gap-3 min-w-0">
+                <Package size={20} className="shrink-0 text-muted-foreground" />
+                <div className="min-w-0">
+                  <div className="font-bold text-sm">{cache.name}</div>
+                  <button
+                    onClick={() => cache.paths[0] && openPath(cache.paths[0])}
+                    className="flex items-center gap-1.5 text-[10px] text-muted-foreground/70 hover:text-primary transition-colors truncate text-left"
+                    title={cache.paths.join("\n")}
+                  >
+                    <FolderOpen size={10} className="shrink-0 opacity-60" />
+                    <span className="truncate">{cache.paths[0]}</span>
+                  </button>
+                </div>
+              </div>
+              <div className="flex items-center gap-2 shrink-0">
+                <span className="text-xs font-mono font-medium mr-2">{formatBytes(cache.size)}</span>
+                {cache.clean_command && cache.tool_available && (
+                  <Button
+                    variant="outline"
+                    size="sm"
+                    disabled={cleaningId !== null}
+                    onClick={() => handleClean(cache, true)}
+                    title={cache.clean_command}
+                  >
+                    <Terminal size={14} className="mr-1.5" />
+                    {t("devCaches.runTool", { command: cache.clean_command })}
+                  </Button>
+                )}
+                <Button
+                  variant="destructive"
+                  size="sm"
+                  disabled={cleaningId !== null || cache.size === 0}
+                  onClick={() => handleClean(cache, false)}
+                >
+                  {cleaningId === cache.id ? <Loader2 size={14} className="animate-spin mr-1.5" /> : <Trash2 size={14} className="mr-1.5" />}
+                  {t("devCaches.delete")}
+                </Button>
+              </div>
+            </div>
+            {cache.breakdown.length > 0 && (
+              <div className="ml-12 mr-4 mb-3 flex flex-col gap-1">
+                {cache.breakdown.map((entry) => (
+                  <div key={entry.path} className="flex items-center justify-between gap-2 py-1 px-2 rounded-lg hover:bg-muted/40 group/sub transition-colors">
+                    <span className="text-[11px] truncate text-muted-foreground" title={entry.path}>{entry.name}</span>
+                    <div className="flex items-center gap-2 shrink-0">
+                      <span className="text-[10px] font-mono text-muted-foreground/60">{formatBytes(entry.size)}</span>
+                      <button
+                        onClick={() => handleClean(cache, false, entry)}
+                        disabled={cleaningId !== null || entry.size === 0}
+                        className="opacity-0 group-hover/sub:opacity-100 p-0.5 rounded text-muted-foreground/50 hover:text-destructive hover:bg-destructive/10 transition-all disabled:opacity-50"
+                        title={t("scan.deleteItem", "删除此项")}
+                      >
+                        <Trash2 size={11} />
+                      </button>
+                    </div>
+                  </div>
+                ))}
+              </div>
+            )}
+            {lastResult?.id === cache.id && (
+              <div className="px-4 pb-3 text-xs text-muted-foreground">
+                {t("clean.freed")}: <strong className="text-foreground">{formatBytes(lastResult.freed_bytes)}</strong>
+                {lastResult.errors.length > 0 && (
+                  <span className="text-destructive ml-2" title={lastResult.errors.join("\n")}>
+                    {lastResult.errors.length} {t("clean.errors")}
+                  </span>
+                )}
+                {lastResult.operation_id && <RetryFailedButton operationId={lastResult.operation_id} onRetried={load} />}
+              </div>
+            )}
+          </Card>
+        ))
+      )}
+    </div>
+  );
+}
+
+const STALE_DAY_OPTIONS = [14, 30, 90, 180];
+const MS_PER_DAY = 86_400_000;
+
+/* ── Cargo target/ folders under the project roots set in Settings ── */
+function RustTargetsSection({ scanResult }: { scanResult: ScanSummary }) {
+  const { t } = useTranslation();
+  const [targets, setTargets] = useState<RustTarget[]>([]);
+  const [staleDays, setStaleDays] = useState(90);
+  const [deleting, setDeleting] = useState<Set<string>>(new Set());
+  const [errors, setErrors] = useState<string[]>([]);
+  const [retryOperation, setRetryOperation] = useState<string | null>(null);
+
+  useEffect(() => {
+    invoke<RustTarget[]>("find_rust_targets").then(setTargets).catch(() => {});
+  }, [scanResult]);
+
+  const isStale = (tg: RustTarget) => tg.last_modified !== null && Date.now() - tg.last_modified * 1000 > staleDays * MS_PER_DAY;
+
+  const handleDeleteStale = useCallback(async () => {
+    const stale = targets.filter(isStale);
+    if (stale.length === 0) return;
+    const size = stale.reduce((sum, tg) => sum + tg.size, 0);
+    if (!window.confirm(t("rustTargets.confirm", { count: stale.length, size: formatBytes(size) }))) return;
+    setDeleting(new Set(stale.map((tg) => tg.target_path)));
+    try {
+      const result = await invoke<BatchResult>("clean_stale_rust_targets", { olderThanDays: staleDays });
+      const gone = new Set(result.items.filter((i) => i.status === "Done" || i.status === "NotFound").map((i) => i.id));
+      setTargets((prev) => prev.filter((tg) => !gone.has(tg.target_path)));
+      setErrors(result.items.filter((i) => i.status === "Failed").map((i) => `${i.id}: ${i.error ?? ""}`));
+      setRetryOperation(result.operation_id ?? null);
+    } catch (e) {
+      setErrors([String(e)]);
+    }
+    setDeleting(new Set());
+  }, [targets, staleDays, t]);
+
+  const handleDelete = useCallback(async (paths: string[]) => {
+    const size = targets.filter((tg) => paths.includes(tg.target_path)).reduce((sum, tg) => sum + tg.size, 0);
+    if (!window.confirm(t("rustTargets.confirm", { count: paths.length, size: formatBytes(size) }))) return;
+    setDeleting(new Set(paths));
+    try {
+      const result = await invoke<BatchResult>("clean_rust_targets", { paths });
+      const gone = new Set(result.items.filter((i) => i.status === "Done" || i.status === "NotFound").map((i) => i.id));
+      setTargets((prev) => prev.filter((tg) => !gone.has(tg.target_path)));
+      setErrors(result.items.filter((i) => i.status === "Failed").map((i) => `${i.id}: ${i.error ?? ""}`));
+      setRetryOperation(result.operation_id ?? null);
+    } catch (e) {
+      setErrors([String(e)]);
+    }
+    setDeleting(new Set());
+  }, [targets, t]);
+
+  if (targets.length === 0) return null;
+  const total = targets.reduce((sum, tg) => sum + tg.size, 0);
+  const staleSize = targets.filter(isStale).reduce((sum, tg) => sum + tg.size, 0);
+
+  return (
+    <div className="space-y-4 pb-4">
+      <div className="flex items-center justify-between">
+        <h3 className="text-sm font-medium text-muted-foreground uppercase tracking-wider">
+          {t("rustTargets.title")} · {formatBytes(total)}
+        </h3>
+        <div className="flex items-center gap-2">
+          <select
+            value={staleDays}
+            onChange={(e) => setStaleDays(Number(e.target.value))}
+            className="h-8 rounded-md border border-border/50 bg-background px-2 text-xs"
+          >
+            {STALE_DAY_OPTIONS.map((d) => (
+              <option key={d} value={d}>{t("rustTargets.notBuiltFor", { count: d })}</option>
+            ))}
+          </select>
+          <Button variant="outline" size="sm" disabled={deleting.size > 0 || staleSize === 0} onClick={handleDeleteStale}>
+            <Trash2 size={14} className="mr-1.5" />
+            {t("rustTargets.deleteStale", { size: formatBytes(staleSize) })}
+          </Button>
+          <Button
+            variant="destructive"
+            size="sm"
+            disabled={deleting.size > 0}
+            onClick={() => handleDelete(targets.map((tg) => tg.target_path))}
+          >
+            <Trash2 size={14} className="mr-1.5" />
+            {t("rustTargets.deleteAll")}
+          </Button>
+        </div>
+      </div>
+      <Card className="border-border/50">
+        <div className="flex flex-col gap-1 p-3 max-h-72 overflow-y-auto">
+          {targets.map((tg) => (
+            <div key={tg.target_path} className="flex items-center justify-between gap-2 py-1 px-2 rounded-lg hover:bg-muted/40 group/sub transition-colors">
+              <button
+                onClick={() => openPath(tg.project_path)}
+                className="flex items-center gap-2 min-w-0 text-left"
+                title={tg.target_path}
+              >
+                <Folder size={11} className="shrink-0 text-muted-foreground/50" />
+                <span className="text-[11px] font-medium truncate">{tg.crate_name}</span>
+                <span className="text-[10px] text-muted-foreground/60 truncate">{tg.project_path}</span>
+              </button>
+              <div className="flex items-center gap-2 shrink-0">
+                {tg.last_modified && (
+                  <span className={`text-[10px] ${isStale(tg) ? "text-warning" : "text-muted-foreground/60"}`} title={new Date(tg.last_modified * 1000).toLocaleString()}>
+                    {t("rustTargets.daysAgo", { count: Math.floor((Date.now() - tg.last_modified * 1000) / MS_PER_DAY) })}
+                  </span>
+                )}
+                <span className="text-[10px] font-mono text-muted-foreground/60">{formatBytes(tg.size)}</span>
+                <button
+                  onClick={() => handleDelete([tg.target_path])}
+                  disabled={deleting.size > 0}
+                  className="opacity-0 group-hover/sub:opacity-100 p-0.5 rounded text-muted-foreground/50 hover:text-destructive hover:bg-destructive/10 transition-all disabled:opacity-50"
+                  title={t("scan.deleteItem", "删除此项")}
+                >
+                  {deleting.has(tg.target_path) ? <Loader2 size={11} className="animate-spin" /> : <Trash2 size={11} />}
+                </button>
+              </div>
+            </div>
+          ))}
+        </div>
+      </Card>
+      {errors.length > 0 && (
+        <p className="text-xs text-destructive" title={errors.join("\n")}>{errors.length} {t("clean.errors")}</p>
+      )}
+      {retryOperation && (
+        <RetryFailedButton
+          operationId={retryOperation}
+          onRetried={async () => setTargets(await invoke<RustTarget[]>("find_rust_targets"))}
+        />
+      )}
+    </div>
+  );
+}
+
+/* ── Python caches and virtualenvs under the project roots, grouped per project ── */
+function PythonArtifactsSection({ scanResult }: { scanResult: ScanSummary }) {
+  const { t } = useTranslation();
+  const [staleDays, setStaleDays] = useState(90);
+  const [projects, setProjects] = useState<PythonProject[]>([]);
+  const [selected, setSelected] = useState<Set<string>>(new Set());
+  const [deleting, setDeleting] = useState(false);
+  const [errors, setErrors] = useState<string[]>([]);
+  const [retryOperation, setRetryOperation] = useState<string | null>(null);
+
+  const groupKey = (p: PythonProject, g: PythonArtifactGroup) => `${p.project_path}|${g.kind}`;
+
+  const load = useCallback(async (days: number) => {
+    const result = await invoke<PythonProject[]>("find_python_artifacts", { staleDays: days });
+    setProjects(result);
+    // Caches are always safe to drop; virtualenvs only preselected once stale
+    setSelected(new Set(result.flatMap((p) => p.groups.filter((g) => g.kind !== "Venv" || g.stale).map((g) => groupKey(p, g)))));
+  }, []);
+
+  useEffect(() => {
+    load(staleDays).catch(() => {});
+  }, [scanResult, staleDays, load]);
+
+  const toggle = (key: string) => {
+    setSelected((prev) => {
+      const next = new Set(prev);
+      if (next.has(key)) next.delete(key); else next.add(key);
+      return next;
+    });
+  };
+
+  const chosen = projects.flatMap((p) => p.groups.filter((g) => selected.has(groupKey(p, g))));
+  const chosenSize = chosen.reduce((sum, g) => sum + g.size, 0);
+
+  const handleDelete = useCallback(async () => {
+    const paths = chosen.flatMap((g) => g.paths);
+    if (paths.length === 0) return;
+    if (!window.confirm(t("python.confirm", { count: paths.length, size: formatBytes(chosenSize) }))) return;
+    setDeleting(true);
+    try {
+      const result = await invoke<BatchResult>("clean_python_artifacts", { paths });
+      setErrors(result.items.filter((i) => i.status === "Failed").map((i) => `${i.id}: ${i.error ?? ""}`));
+      setRetryOperation(result.operation_id ?? null);
+      await load(staleDays);
+    } catch (e) {
+      setErrors([String(e)]);
+    }
+    setDeleting(false);
+  }, [chosen, chosenSize, staleDays, load, t]);
+
+  if (projects.length === 0) return null;
+  const total = projects.reduce((sum, p) => sum + p.size, 0);
+
+  return (
+    <div className="space-y-4 pb-4">
+      <div className="flex items-center justify-between">
+        <h3 className="text-sm font-medium text-muted-foreground uppercase tracking-wider">
+          {t("python.title")} · {formatBytes(total)}
+        </h3>
+        <div className="flex items-center gap-2">
+          <select
+            value={staleDays}
+            onChange={(e) => setStaleDays(Number(e.target.value))}
+            className="h-8 rounded-md border border-border/50 bg-background px-2 text-xs"
+          >
+            {STALE_DAY_OPTIONS.map((d) => (
+              <option key={d} value={d}>{t("python.venvUnusedFor", { count: d })}</option>
+            ))}
+          </select>
+          <Button variant="destructive" size="sm" disabled={deleting || chosen.length === 0} onClick={handleDelete}>
+            {deleting ? <Loader2 size={14} className="mr-1.5 animate-spin" /> : <Trash2 size={14} className="mr-1.5" />}
+            {t("python.deleteSelected", { size: formatBytes(chosenSize) })}
+          </Button>
+        </div>
+      </div>
+      <Card className="border-border/50">
+        <div className="flex flex-col gap-2 p-3 max-h-96 overflow-y-auto">
+          {projects.map((p) => (
+            <div key={p.project_path}>
+              <button
+                onClick={() => openPath(p.project_path)}
+                className="flex items-center gap-2 min-w-0 text-left px-2"
+                title={p.project_path}
+              >
+                <Folder size={11} className="shrink-0 text-muted-foreground/50" />
+                <span className="text-[11px] font-medium truncate">{p.name}</span>
+                <span className="text-[10px] text-muted-foreground/60 truncate">{p.project_path}</span>
+              </button>
+              {p.groups.map((g) => (
+                <label
+                  key={g.kind}
+                  className="ml-5 flex items-center justify-between gap-2 py-0.5 px-2 rounded hover:bg-muted/40 cursor-pointer transition-colors"
+                  title={g.paths.join("\n")}
+                >
+                  <div className="flex items-center gap-2 min-w-0">
+                    <Checkbox checked={selected.has(groupKey(p, g))} onCheckedChange={() => toggle(groupKey(p, g))} />
+                    <span className="text-[11px]">{t(`python.kinds.${g.kind}`)}</span>
+                    {g.paths.length > 1 && <span className="text-[10px] text-muted-foreground/60">×{g.paths.length}</span>}
+                    {g.stale && <Badge variant="secondary">{t("python.stale")}</Badge>}
+                  </div>
+                  <div className="flex items-center gap-2 shrink-0">
+                    {g.last_modified && (
+                      <span className="text-[10px] text-muted-foreground/60">{new Date(g.last_modified * 1000).toLocaleDateString()}</span>
+                    )}
+                    <span className="text-[10px] font-mono text-muted-foreground/60">{formatBytes(g.size)}</span>
+                  </div>
+                </label>
+              ))}
+            </div>
+          ))}
+        </div>
+      </Card>
+      {errors.length > 0 && (
+        <p className="text-xs text-destructive" title={errors.join("\n")}>{errors.length} {t("clean.errors")}</p>
+      )}
+      {retryOperation && (
+        <RetryFailedButton
+          operationId={retryOperation}
+          onRetried={() => load(staleDays)}
+        />
+      )}
+    </div>
+  );
+}
+
+const STALE_MONTH_OPTIONS = [3, 6, 12, 24];
+
+function MavenRepoSection({ scanResult }: { scanResult: ScanSummary }) {
+  const { t } = useTranslation();
+  const [staleMonths, setStaleMonths] = useState(6);
+  const [report, setReport] = useState<MavenRepoReport | null>(null);
+  const [deleting, setDeleting] = useState(false);
+  const [errors, setErrors] = useState<string[]>([]);
+  const [retryOperation, setRetryOperation] = useState<string | null>(null);
+
+  useEffect(() => {
+    invoke<MavenRepoReport | null>("analyze_maven_repo", { staleMonths }).then(setReport).catch(() => {});
+  }, [scanResult, staleMonths]);
+
+  const handleDeleteStale = useCallback(async () => {
+    if (!report || report.stale.length === 0) return;
+    if (!window.confirm(t("maven.confirm", { count: report.stale.length, size: formatBytes(report.stale_size) }))) return;
+    setDeleting(true);
+    try {
+      const result = await invoke<BatchResult>("clean_maven_artifacts", { paths: report.stale.map((a) => a.path) });
+      setErrors(result.items.filter((i) => i.status === "Failed").map((i) => `${i.id}: ${i.error ?? ""}`));
+      setRetryOperation(result.operation_id ?? null);
+      setReport(await invoke<MavenRepoReport | null>("analyze_maven_repo", { staleMonths }));
+    } catch (e) {
+      setErrors([String(e)]);
+    }
+    setDeleting(false);
+  }, [report, staleMonths, t]);
+
+  if (!report || report.artifact_count === 0) return null;
+
+  return (
+    <div className="space-y-4 pb-4">
+      <div className="flex items-center justify-between">
+        <h3 className="text-sm font-medium text-muted-foreground uppercase tracking-wider">
+          {t("maven.title")} · {formatBytes(report.total_size)}
+        </h3>
+        <div className="flex items-center gap-2">
+          <select
+            value={staleMonths}
+            onChange={(e) => setStaleMonths(Number(e.target.value))}
+            className="h-8 rounded-md border border-border/50 bg-background px-2 text-xs"
+          >
+            {STALE_MONTH_OPTIONS.map((m) => (
+              <option key={m} value={m}>{t("maven.unusedFor", { count: m })}</option>
+            ))}
+          </select>
+          <Button variant="destructive" size="sm" disabled={deleting || report.stale.length === 0} onClick={handleDeleteStale}>
+            {deleting ? <Loader2 size={14} className="mr-1.5 animate-spin" /> : <Trash2 size={14} className="mr-1.5" />}
+            {t("maven.deleteStale", { size: formatBytes(report.stale_size) })}
+          </Button>
+        </div>
+      </div>
+      <Card className="border-border/50">
+        <div className="flex flex-col gap-1 p-3 max-h-72 overflow-y-auto">
+          <p className="text-[10px] text-muted-foreground uppercase tracking-wider px-2 pb-1">{t("maven.largestGroups")}</p>
+          {report.groups.map((g) => (
+            <div key={g.group_id} className="flex items-center justify-between gap-2 py-1 px-2 rounded-lg hover:bg-muted/40 transition-colors">
+              <span className="text-[11px] font-medium truncate">{g.group_id}</span>
+              <div className="flex items-center gap-2 shrink-0">
+                <span className="text-[10px] text-muted-foreground/60">{t("maven.versions", { count: g.artifact_count })}</span>
+                <span className="text-[10px] font-mono text-muted-foreground/60">{formatBytes(g.size)}</span>
+              </div>
+            </div>
+          ))}
+        </div>
+      </Card>
+      {errors.length > 0 && (
+        <p className="text-xs text-destructive" title={errors.join("\n")}>{errors.length} {t("clean.errors")}</p>
+      )}
+      {retryOperation && (
+        <RetryFailedButton
+          operationId={retryOperation}
+          onRetried={async () => setReport(await invoke<MavenRepoReport | null>("analyze_maven_repo", { staleMonths }))}
+        />
+      )}
+    </div>
+  );
+}
+
+/* ── VS Code-family extensions: outdated duplicate versions, .obsolete leftovers and uninstall ── */
+function ExtensionsSection({ scanResult }: { scanResult: ScanSummary }) {
+  const { t } = useTranslation();
+  const [reports, setReports] = useState<ExtensionReport[]>([]);
+  const [expanded, setExpanded] = useState<string | null>(null);
+  const [cleaning, setCleaning] = useState<string | null>(null);
+  const [clearData, setClearData] = useState(false);
+  const [exactVersions, setExactVersions] = useState(false);
+  const [message, setMessage] = useState<string | null>(null);
+  const [errors, setErrors] = useState<string[]>([]);
+  const [retryOperation, setRetryOperation] = useState<string | null>(null);
+
+  const load = useCallback(async () => {
+    const ideIds = scanResult.results
+      .filter((r) => r.categories.some((c) => c.category_type === "Extension"))
+      .map((r) => r.ide_id);
+    const loaded = await Promise.all(
+      ideIds.map((ideId) => invoke<ExtensionReport>("analyze_extensions", { ideId }).catch(() => null))
+    );
+    setReports(loaded.filter((r): r is ExtensionReport => r !== null));
+  }, [scanResult]);
+
+  useEffect(() => { load(); }, [load]);
+
+  const handleClean = useCallback(async (report: ExtensionReport) => {
+    const stale = report.groups.flatMap((g) => g.copies).filter((c) => c.stale);
+    const ideName = scanResult.results.find((r) => r.ide_id === report.ide_id)?.ide_name ?? report.ide_id;
+    if (!window.confirm(t("extensions.confirm", { count: stale.length, size: formatBytes(report.stale_size), ide: ideName }))) return;
+    setCleaning(report.ide_id);
+    try {
+      const result = await invoke<BatchResult>("clean_stale_extensions", { ideId: report.ide_id, paths: stale.map((c) => c.path) });
+      setErrors(result.items.filter((i) => i.status === "Failed").map((i) => `${i.id}: ${i.error ?? ""}`));
+      setRetryOperation(result.operation_id ?? null);
+      await load();
+    } catch (e) {
+      setErrors([String(e)]);
+    }
+    setCleaning(null);
+  }, [t, scanResult, load]);
+
+  const handleUninstall = useCallback(async (report: ExtensionReport, group: ExtensionGroup) => {
+    if (!window.confirm(t("extensions.uninstallConfirm", { id: group.id, size: formatBytes(group.size) }))) return;
+    setCleaning(`${report.ide_id}/${group.id}`);
+    try {
+      const result = await invoke<ExtensionUninstallResult>("uninstall_extension", {
+        ideId: report.ide_id,
+        extensionId: group.id,
+        clearGlobalStorage: clearData,
+      });
+      setErrors(result.errors);
+      setRetryOperation(result.operation_id ?? null);
+      await load();
+    } catch (e) {
+      setErrors([String(e)]);
+    }
+    setCleaning(null);
+  }, [t, clearData, load]);
+
+  const handleExport = useCallback(async (report: ExtensionReport) => {
+    const path = await save({ defaultPath: `${report.ide_id}-extensions.json`, filters: [{ name: "JSON", extensions: ["json"] }] });
+    if (!path) return;
+    try {
+      const list = await invoke<ExtensionList>("export_extension_list", { ideId: report.ide_id, path });
+      setMessage(t("extensions.exported", { count: list.extensions.length, path }));
+    } catch (e) {
+      setErrors([String(e)]);
+    }
+  }, [t]);
+
+  const handleImport = useCallback(async (report: ExtensionReport, ideName: string) => {
+    const path = await open({ multiple: false, filters: [{ name: "JSON", extensions: ["json"] }] });
+    if (typeof path !== "string") return;
+    try {
+      const list = await invoke<ExtensionList>("read_extension_list", { path });
+      if (!window.confirm(t("extensions.importConfirm", { count: list.extensions.length, source: list.source_ide, ide: ideName }))) return;
+      setCleaning(`${report.ide_id}/import`);
+      const result = await invoke<ExtensionInstallResult>("import_extension_list", { ideId: report.ide_id, path, exactVersions });
+      setMessage(t("extensions.imported", { installed: result.installed.length, skipped: result.skipped.length }));
+      setErrors(result.failed.map((spec) => `${spec}: ${t("extensions.installFailed")}`));
+      await load();
+    } catch (e) {
+      setErrors([String(e)]);
+    }
+    setCleaning(null);
+  }, [t, exactVersions, load]);
+
+  if (reports.length === 0) return null;
+  const total = reports.reduce((s, r) => s + r.total_size, 0);
+
+  return (
+    <div className="space-y-4 pb-4">
+      <h3 className="text-sm font-medium text-muted-foreground uppercase tracking-wider">
+        {t("extensions.title")} · {formatBytes(total)}
+      </h3>
+      <Card className="border-border/50">
+        <div className="flex flex-col gap-1 p-3">
+          {reports.map((report) => {
+            const ideName = scanResult.results.find((r) => r.ide_id === report.ide_id)?.ide_name ?? report.ide_id;
+            return (
+              <div key={report.ide_id} className="flex flex-col gap-1">
+                <div className="flex items-center justify-between gap-2 py-1.5 px-2 rounded-lg hover:bg-muted/40 transition-colors">
+                  <button
+                    onClick={() => setExpanded(expanded === report.ide_id ? null : report.ide_id)}
+                    className="flex flex-col min-w-0 text-left"
+                    title={report.extension_path}
+                  >
+                    <span className="text-xs font-medium truncate">{ideName}</span>
+                    <span className="text-[10px] text-muted-foreground/70">
+                      {t("extensions.installedSummary", { installed: report.groups.length })}
+                      {report.stale_count > 0 && ` · ${t("extensions.staleSummary", { count: report.stale_count })}`}
+                    </span>
+                  </button>
+                  <div className="flex items-center gap-2 shrink-0">
+                    <span className="text-[10px] font-mono text-muted-foreground/60">{formatBytes(report.total_size)}</span>
+                    <Button variant="destructive" size="sm" disabled={cleaning !== null || report.stale_count === 0} onClick={() => handleClean(report)}>
+                      {cleaning === report.ide_id ? <Loader2 size={14} className="mr-1.5 animate-spin" /> : <Trash2 size={14} className="mr-1.5" />}
+                      {t("extensions.deleteStale", { size: formatBytes(report.stale_size) })}
+                    </Button>
+                  </div>
+                </div>
+                {expanded === report.ide_id && (
+                  <div className="ml-4 flex flex-col gap-0.5">
+                    <div className="flex items-center justify-between gap-2 px-2 py-1">
+                      <label className="flex items-center gap-2 text-[10px] text-muted-foreground cursor-pointer">
+                        <Checkbox checked={clearData} onCheckedChange={(v) => setClearData(v === true)} className="w-3 h-3" />
+                        {t("extensions.clearData")}
+                      </label>
+                      <div className="flex items-center gap-2 shrink-0">
+                        <label className="flex items-center gap-1.5 text-[10px] text-muted-foreground cursor-pointer">
+                          <Checkbox checked={exactVersions} onCheckedChange={(v) => setExactVersions(v === true)} className="w-3 h-3" />
+                          {t("extensions.exactVersions")}
+                        </label>
+                        <Button variant="ghost" size="sm" className="h-6 px-2 text-[10px]" disabled={cleaning !== null} onClick={() => handleExport(report)}>
+                          <Download size={11} className="mr-1" />
+                          {t("extensions.exportList")}
+                        </Button>
+                        <Button variant="ghost" size="sm" className="h-6 px-2 text-[10px]" disabled={cleaning !== null} onClick={() => handleImport(report, ideName)}>
+                          {cleaning === `${report.ide_id}/import` ? <Loader2 size={11} className="mr-1 animate-spin" /> : <Upload size={11} className="mr-1" />}
+                          {t("extensions.importList")}
+                        </Button>
+                      </div>
+                    </div>
+                    <div className="flex flex-col gap-0.5 max-h-64 overflow-y-auto pr-1">
+                      {[...report.groups].sort((a, b) => b.size - a.size).map((group) => (
+                        <div key={group.id} className="flex items-center justify-between gap-2 px-2 py-0.5 rounded hover:bg-muted/40 group/ext text-[11px] text-muted-foreground">
+                          <span className="truncate" title={group.copies.map((c) => c.folder).join("\n")}>
+                            {group.id}
+                            <span className="ml-2 text-muted-foreground/60">{group.copies.map((c) => c.version).join(", ")}</span>
+                            {group.copies.some((c) => c.obsolete) && <span className="ml-2 text-warning/80">{t("extensions.obsolete")}</span>}
+                          </span>
+                          <div className="flex items-center gap-2 shrink-0">
+                            <span className="font-mono text-[10px] text-muted-foreground/60">{formatBytes(group.size)}</span>
+                            <button
+                              onClick={() => handleUninstall(report, group)}
+                              disabled={cleaning !== null}
+                              className="opacity-0 group-hover/ext:opacity-100 p-0.5 rounded text-muted-foreground/50 hover:text-destructive hover:bg-destructive/10 transition-all disabled:opacity-50"
+                              title={t("extensions.uninstall")}
+                            >
+                              {cleaning === `${report.ide_id}/${group.id}` ? <Loader2 size={11} className="animate-spin" /> : <Trash2 size={11} />}
+                            </button>
+                          </div>
+                        </div>
+                      ))}
+                    </div>
+                  </div>
+                )}
+              </div>
+            );
+          })}
+        </div>
+      </Card>
+      {message && <p className="text-xs text-muted-foreground truncate" title={message}>{message}</p>}
+      {errors.length > 0 && (
+        <p className="text-xs text-destructive" title={errors.join("\n")}>{errors.length} {t("clean.errors")}</p>
+      )}
+      {retryOperation && <RetryFailedButton operationId={retryOperation} onRetried={load} />}
+    </div>
+  );
+}
+
+/* ── AI coding CLIs: per-project session transcripts (Claude Code, Gemini CLI) ── */
+/* ── Local LLM runtimes: model weights are often the largest thing on disk ── */
+function LocalModelsSection({ scanResult }: { scanResult: ScanSummary }) {
+  const { t } = useTranslation();
+  const [report, setReport] = useState<LocalModelReport | null>(null);
+  const [selected, setSelected] = useState<Set<string>>(new Set());
+  const [deleting, setDeleting] = useState(false);
+  const [errors, setErrors] = useState<string[]>([]);
+  const [retryOperation, setRetryOperation] = useState<string | null>(null);
+
+  useEffect(() => {
+    invoke<LocalModelReport>("analyze_local_models").then(setReport).catch(() => {});
+    setSelected(new Set());
+  }, [scanResult]);
+
+  const toggle = useCallback((path: string) => {
+    setSelected((prev) => {
+      const next = new Set(prev);
+      if (next.has(path)) next.delete(path); else next.add(path);
+      return next;
+    });
+  }, []);
+
+  const chosen = report?.models.filter((m) => selected.has(m.path)) ?? [];
+  const chosenSize = chosen.reduce((sum, m) => sum + m.unique_size, 0);
+
+  const handleDelete = useCallback(async () => {
+    if (chosen.length === 0) return;
+    if (!window.confirm(t("localModels.confirm", { count: chosen.length, size: formatBytes(chosenSize) }))) return;
+    setDeleting(true);
+    try {
+      const result = await invoke<BatchResult>("delete_local_models", { paths: chosen.map((m) => m.path) });
+      setErrors(result.items.filter((i) => i.status === "Failed").map((i) => `${i.id}: ${i.error ?? ""}`));
+      setRetryOperation(result.operation_id ?? null);
+      setSelected(new Set());
+      setReport(await invoke<LocalModelReport>("analyze_local_models"));
+    } catch (e) {
+      setErrors([String(e)]);
+    }
+    setDeleting(false);
+  }, [chosen, chosenSize, t]);
+
+  const handlePrune = useCallback(async (model: LocalModel) => {
+    const size = model.revisions.filter((r) => !r.is_latest).reduce((sum, r) => sum + r.unique_size, 0);
+    if (!window.confirm(t("localModels.confirmPrune", { name: model.name, size: formatBytes(size) }))) return;
+    setDeleting(true);
+    try {
+      const result = await invoke<BatchResult>("prune_model_revisions", { paths: [model.path] });
+      setErrors(result.items.filter((i) => i.status === "Failed").map((i) => `${i.id}: ${i.error ?? ""}`));
+      setRetryOperation(result.operation_id ?? null);
+      setReport(await invoke<LocalModelReport>("analyze_local_models"));
+    } catch (e) {
+      setErrors([String(e)]);
+    }
+    setDeleting(false);
+  }, [t]);
+
+  if (!report || report.models.length === 0) return null;
+
+  return (
+    <div className="space-y-4 pb-4">
+      <div className="flex items-center justify-between">
+        <h3 className="text-sm font-medium text-muted-foreground uppercase tracking-wider">
+          {t("localModels.title")} · {formatBytes(report.total_size)}
+        </h3>
+        <Button variant="destructive" size="sm" disabled={deleting || chosen.length === 0} onClick={handleDelete}>
+          {deleting ? <Loader2 size={14} className="mr-1.5 animate-spin" /> : <Trash2 size={14} className="mr-1.5" />}
+          {t("localModels.deleteSelected", { size: formatBytes(chosenSize) })}
+        </Button>
+      </div>
+      <Card className="border-border/50">
+        <div className="flex flex-col gap-1 p-3 max-h-96 overflow-y-auto">
+          {report.models.map((m) => (
+            <div key={m.path}>
+              <label
+                className="flex items-center justify-between gap-2 py-1 px-2 rounded hover:bg-muted/40 cursor-pointer transition-colors"
+                title={m.path}
+              >
+                <div className="flex items-center gap-2 min-w-0">
+                  <Checkbox checked={selected.has(m.path)} onCheckedChange={() => toggle(m.path)} />
+                  <span className="text-xs font-medium truncate">{m.name}</span>
+                  <span className="text-[10px] text-muted-foreground/60 shrink-0">{t(`localModels.runtimes.${m.runtime}`)}</span>
+                </div>
+                <div className="flex items-center gap-2 shrink-0">
+                  {m.unique_size < m.size && (
+                    <span className="text-[10px] text-muted-foreground/60">{t("localModels.shared", { size: formatBytes(m.size - m.unique_size) })}</span>
+                  )}
+                  {m.last_modified && (
+                    <span className="text-[10px] text-muted-foreground/60">{new Date(m.last_modified).toLocaleDateString()}</span>
+                  )}
+                  <span className="text-[10px] font-mono text-muted-foreground/60">{formatBytes(m.size)}</span>
+                </div>
+              </label>
+              {m.revisions.length > 1 && (
+                <div className="ml-8 mb-1 flex flex-col gap-0.5">
+                  {m.revisions.map((r) => (
+                    <div key={r.commit} className="flex items-center justify-between gap-2 px-2 text-[10px] text-muted-foreground/60">
+                      <span className="font-mono truncate">
+                        {r.commit.slice(0, 10)}{r.refs.length > 0 && ` (${r.refs.join(", ")})`}
+                      </span>
+                      <span className="font-mono shrink-0">{formatBytes(r.unique_size)}</span>
+                    </div>
+                  ))}
+                  <Button variant="outline" size="sm" className="self-start h-6 text-[10px]" disabled={deleting} onClick={() => handlePrune(m)}>
+                    {t("localModels.pruneRevisions", {
+                      count: m.revisions.length - 1,
+                      size: formatBytes(m.revisions.filter((r) => !r.is_latest).reduce((sum, r) => sum + r.unique_size, 0)),
+                    })}
+                  </Button>
+                </div>
+              )}
+            </div>
+          ))}
+        </div>
+      </Card>
+      {errors.length > 0 && (
+        <p className="text-xs text-destructive" title={errors.join("\n")}>{errors.length} {t("clean.errors")}</p>
+      )}
+      {retryOperation && (
+        <RetryFailedButton
+          operationId={retryOperation}
+          onRetried={async () => setReport(await invoke<LocalModelReport>("analyze_local_models"))}
+        />
+      )}
+    </div>
+  );
+}
+
+function AiCliSection({ scanResult }: { scanResult: ScanSummary }) {
+  const { t } = useTranslation();
+  const [staleDays, setStaleDays] = useState(30);
+  const [report, setReport] = useState<AiCliReport | null>(null);
+  const [expanded, setExpanded] = useState<string | null>(null);
+  const [summary, setSummary] = useState<AiCliSessionSummary | null>(null);
+  const [deleting, setDeleting] = useState(false);
+  const [errors, setErrors] = useState<string[]>([]);
+  const [retryOperation, setRetryOperation] = useState<string | null>(null);
+
+  useEffect(() => {
+    invoke<AiCliReport>("analyze_ai_cli", { staleDays }).then(setReport).catch(() => {});
+  }, [scanResult, staleDays]);
+
+  const handleShowSummary = useCallback(async (path: string) => {
+    if (summary?.path === path) {
+      setSummary(null);
+      return;
+    }
+    try {
+      setSummary(await invoke<AiCliSessionSummary>("get_ai_cli_session_summary", { path }));
+    } catch (e) {
+      setErrors([String(e)]);
+    }
+  }, [summary]);
+
+  const handlePrune = useCallback(async (sessions: AiCliSession[]) => {
+    if (sessions.length === 0) return;
+    const size = sessions.reduce((sum, s) => sum + s.size, 0);
+    if (!window.confirm(t("aiCli.confirm", { count: sessions.length, size: formatBytes(size) }))) return;
+    setDeleting(true);
+    try {
+      const result = await invoke<BatchResult>("prune_ai_cli_sessions", { paths: sessions.map((s) => s.path) });
+      setErrors(result.items.filter((i) => i.status === "Failed").map((i) => `${i.id}: ${i.error ?? ""}`));
+      setRetryOperation(result.operation_id ?? null);
+      setSummary(null);
+      setReport(await invoke<AiCliReport>("analyze_ai_cli", { staleDays }));
+    } catch (e) {
+      setErrors([String(e)]);
+    }
+    setDeleting(false);
+  }, [staleDays, t]);
+
+  if (!report || report.projects.length === 0) return null;
+  const stale = report.projects.flatMap((p) => p.sessions.filter((s) => s.stale));
+
+  return (
+    <div className="space-y-4 pb-4">
+      <div className="flex items-center justify-between">
+        <h3 className="text-sm font-medium text-muted-foreground uppercase tracking-wider">
+          {t("aiCli.title")} · {formatBytes(report.total_size)}
+        </h3>
+        <div className="flex items-center gap-2">
+          <select
+            value={staleDays}
+            onChange={(e) => setStaleDays(Number(e.target.value))}
+            className="h-8 rounded-md border border-border/50 bg-background px-2 text-xs"
+          >
+            {STALE_DAY_OPTIONS.map((d) => (
+              <option key={d} value={d}>{t("aiCli.untouchedFor", { count: d })}</option>
+            ))}
+          </select>
+          <Button variant="destructive" size="sm" disabled={deleting || stale.length === 0} onClick={() => handlePrune(stale)}>
+            {deleting ? <Loader2 size={14} className="mr-1.5 animate-spin" /> : <Trash2 size={14} className="mr-1.5" />}
+            {t("aiCli.pruneStale", { size: formatBytes(report.stale_size) })}
+          </Button>
+        </div>
+      </div>
+      <Card className="border-border/50">
+        <div className="flex flex-col gap-1 p-3 max-h-96 overflow-y-auto">
+          {report.projects.map((p) => (
+            <div key={p.storage_path}>
+              <button
+                onClick={() => setExpanded(expanded === p.storage_path ? null : p.storage_path)}
+                className="w-full flex items-center justify-between gap-2 py-1 px-2 rounded-lg hover:bg-muted/40 transition-colors text-left"
+                title={p.storage_path}
+              >
+                <div className="flex items-center gap-2 min-w-0">
+                  <Terminal size={11} className="shrink-0 text-muted-foreground/50" />
+                  <span className="text-[11px] font-medium truncate">{p.project}</span>
+                  <span className="text-[10px] text-muted-foreground/60 shrink-0">{t(`aiCli.tools.${p.tool}`)}</span>
+                </div>
+                <div className="flex items-center gap-2 shrink-0">
+                  <span className="text-[10px] text-muted-foreground/60">{t("aiCli.sessions", { count: p.sessions.length })}</span>
+                  {p.last_modified && (
+                    <span className="text-[10px] text-muted-foreground/60">{new Date(p.last_modified).toLocaleDateString()}</span>
+                  )}
+                  <span className="text-[10px] font-mono text-muted-foreground/60">{formatBytes(p.size)}</span>
+                </div>
+              </button>
+              {expanded === p.storage_path && (
+                <div className="ml-5 flex flex-col gap-0.5 border-l border-border/40 pl-2">
+                  {p.sessions.map((s) => (
+                    <div key={s.path}>
+                      <div className="flex items-center justify-between gap-2 py-0.5 px-2 rounded hover:bg-muted/40 group/sub transition-colors">
+                        <button onClick={() => handleShowSummary(s.path)} className="min-w-0 text-left" title={s.path}>
+                          <span className={`text-[11px] truncate ${s.stale ? "text-muted-foreground/60" : ""}`}>{s.title || t("aiCli.untitled")}</span>
+                        </button>
+                        <div className="flex items-center gap-2 shrink-0">
+                          {s.last_modified && (
+                            <span className="text-[10px] text-muted-foreground/60">{new Date(s.last_modified).toLocaleDateString()}</span>
+                          )}
+                          <span className="text-[10px] font-mono text-muted-foreground/60">{formatBytes(s.size)}</span>
+                          <button
+                            onClick={() => handlePrune([s])}
+                            disabled={deleting}
+                            className="opacity-0 group-hover/sub:opacity-100 p-0.5 rounded text-muted-foreground/50 hover:text-destructive hover:bg-destructive/10 transition-all disabled:opacity-50"
+                            title={t("scan.deleteItem", "删除此项")}
+                          >
+                            <Trash2 size={11} />
+                          </button>
+                        </div>
+                      </div>
+                      {summary?.path === s.path && (
+                        <div className="mx-2 my-1 p-2 rounded-md bg-muted/30 space-y-1 text-[10px] text-muted-foreground">
+                          <p>
+                            {t("aiCli.messages", { user: summary.user_messages, assistant: summary.assistant_messages })}
+                            {summary.models.length > 0 && ` · ${summary.models.join(", ")}`}
+                            {summary.started && ` · ${new Date(summary.started).toLocaleString()}`}
+                            {summary.last_active && ` – ${new Date(summary.last_active).toLocaleString()}`}
+                          </p>
+                          {summary.summaries.length > 0 && (
+                            <p><span className="font-medium">{t("aiCli.summaries")}:</span> {summary.summaries.join(" · ")}</p>
+                          )}
+                          {summary.first_prompt && (
+                            <p className="line-clamp-3"><span className="font-medium">{t("aiCli.firstPrompt")}:</span> {summary.first_prompt}</p>
+                          )}
+                          {summary.last_prompt && summary.last_prompt !== summary.first_prompt && (
+                            <p className="line-clamp-3"><span className="font-medium">{t("aiCli.lastPrompt")}:</span> {summary.last_prompt}</p>
+                          )}
+                        </div>
+                      )}
+                    </div>
+                  ))}
+                </div>
+              )}
+            </div>
+          ))}
+        </div>
+      </Card>
+      {errors.length > 0 && (
+        <p className="text-xs text-destructive" title={errors.join("\n")}>{errors.length} {t("clean.errors")}</p>
+      )}
+      {retryOperation && (
+        <RetryFailedButton
+          operationId={retryOperation}
+          onRetried={async () => setReport(await invoke<AiCliReport>("analyze_ai_cli", { staleDays }))}
+        />
+      )}
+    </div>
+  );
+}
+
+function WslServersSection({ scanResult }: { scanResult: ScanSummary }) {
+  const { t } = useTranslation();
+  const [report, setReport] = useState<WslReport | null>(null);
+  const [cleaning, setCleaning] = useState<string | null>(null);
+  const [errors, setErrors] = useState<string[]>([]);
+  const [retryOperation, setRetryOperation] = useState<string | null>(null);
+
+  useEffect(() => {
+    invoke<WslReport>("scan_wsl_servers").then(setReport).catch(() => {});
+  }, [scanResult]);
+
+  const handleClean = useCallback(async (server: WslServerDir) => {
+    const stale = server.items.filter((i) => i.stale);
+    if (stale.length === 0) return;
+    if (!window.confirm(t("wsl.confirm", { count: stale.length, size: formatBytes(server.stale_size), distro: server.distro }))) return;
+    setCleaning(server.path);
+    try {
+      const result = await invoke<BatchResult>("clean_wsl_items", { paths: stale.map((i) => i.path) });
+      setErrors(result.items.filter((i) => i.status === "Failed").map((i) => `${i.id}: ${i.error ?? ""}`));
+      setRetryOperation(result.operation_id ?? null);
+      setReport(await invoke<WslReport>("scan_wsl_servers"));
+    } catch (e) {
+      setErrors([String(e)]);
+    }
+    setCleaning(null);
+  }, [t]);
+
+  if (!report || report.servers.length === 0) return null;
+  const total = report.servers.reduce((s, d) => s + d.size, 0);
+
+  return (
+    <div className="space-y-4 pb-4">
+      <h3 className="text-sm font-medium text-muted-foreground uppercase tracking-wider">
+        {t("wsl.title")} · {formatBytes(total)}
+      </h3>
+      <Card className="border-border/50">
+        <div className="flex flex-col gap-1 p-3">
+          {report.servers.map((server) => {
+            const builds = server.items.filter((i) => i.stale && i.kind === "ServerBuild").length;
+            const extensions = server.items.filter((i) => i.stale && i.kind === "Extension").length;
+            return (
+              <div key={server.path} className="flex items-center justify-between gap-2 py-1.5 px-2 rounded-lg hover:bg-muted/40 transition-colors">
+                <button onClick={() => openPath(server.path)} className="flex flex-col min-w-0 text-left" title={server.path}>
+                  <span className="text-xs font-medium truncate">{server.product} · {server.distro} ({server.user})</span>
+                  <span className="text-[10px] text-muted-foreground/70">
+                    {t("wsl.staleSummary", { builds, extensions })}
+                  </span>
+                </button>
+                <div className="flex items-center gap-2 shrink-0">
+                  <span className="text-[10px] font-mono text-muted-foreground/60">{formatBytes(server.size)}</span>
+                  <Button
+                    variant="destructive" size="sm"
+                    disabled={cleaning !== null || server.stale_size === 0}
+                    onClick={() => handleClean(server)}
+                  >
+                    {cleaning === server.path ? <Loader2 size={14} className="mr-1.5 animate-spin" /> : <Trash2 size={14} className="mr-1.5" />}
+                    {t("wsl.deleteStale", { size: formatBytes(server.stale_size) })}
+                  </Button>
+                </div>
+              </div>
+            );
+          })}
+        </div>
+      </Card>
+      {errors.length > 0 && (
+        <p className="text-xs text-destructive" title={errors.join("\n")}>{errors.length} {t("clean.errors")}</p>
+      )}
+      {retryOperation && (
+        <RetryFailedButton
+          operationId={retryOperation}
+          onRetried={async () => setReport(await invoke<WslReport>("scan_wsl_servers"))}
+        />
+      )}
+    </div>
+  );
+}
+
+/* ── Dev Containers: containers, images and volumes they create, plus the CLI cache ── */
+function DevContainersSection({ scanResult }: { scanResult: ScanSummary }) {
+  const { t } = useTranslation();
+  const [report, setReport] = useState<DevContainerReport | null>(null);
+  const [removing, setRemoving] = useState<string | null>(null);
+  const [errors, setErrors] = useState<string[]>([]);
+  const [retryOperation, setRetryOperation] = useState<string | null>(null);
+
+  useEffect(() => {
+    invoke<DevContainerReport>("scan_devcontainers").then(setReport).catch(() => {});
+  }, [scanResult]);
+
+  const handleRemove = useCallback(async (item: DevContainerItem) => {
+    if (!window.confirm(t("devcontainers.confirm", { name: item.name, size: formatBytes(item.size) }))) return;
+    setRemoving(item.id);
+    try {
+      const result = await invoke<BatchResult>("remove_devcontainer_items", { items: [{ kind: item.kind, id: item.id }] });
+      setErrors(result.items.filter((i) => i.status === "Failed").map((i) => `${i.id}: ${i.error ?? ""}`));
+      setRetryOperation(result.operation_id ?? null);
+      setReport(await invoke<DevContainerReport>("scan_devcontainers"));
+    } catch (e) {
+      setErrors([String(e)]);
+    }
+    setRemoving(null);
+  }, [t]);
+
+  if (!report || report.items.length === 0) return null;
+
+  return (
+    <div className="space-y-4 pb-4">
+      <h3 className="text-sm font-medium text-muted-foreground uppercase tracking-wider">
+        {t("devcontainers.title")} · {formatBytes(report.total_size)}
+      </h3>
+      <Card className="border-border/50">
+        <div className="flex flex-col gap-1 p-3">
+          {!report.docker_available && (
+            <p className="text-[10px] text-muted-foreground/70 px-2">{t("devcontainers.dockerUnavailable")}</p>
+          )}
+          {report.items.map((item) => (
+            <div key={`${item.kind}:${item.id}`} className="flex items-center justify-between gap-2 py-1.5 px-2 rounded-lg hover:bg-muted/40 transition-colors">
+              <div className="flex flex-col min-w-0">
+                <span className="text-xs font-medium truncate" title={item.id}>{item.name}</span>
+                <span className="text-[10px] text-muted-foreground/70 truncate">
+                  {t(`devcontainers.kinds.${item.kind}`)}{item.local_folder ? ` · ${item.local_folder}` : ""}
+                </span>
+              </div>
+              <div className="flex items-center gap-2 shrink-0">
+                {item.in_use && <Badge variant="secondary">{t("remoteSsh.inUse")}</Badge>}
+                <span className="text-[10px] font-mono text-muted-foreground/60">{formatBytes(item.size)}</span>
+                <Button
+                  variant="ghost" size="sm"
+                  disabled={removing !== null || item.in_use}
+                  onClick={() => handleRemove(item)}
+                  title={t("devcontainers.remove")}
+                >
+                  {removing === item.id ? <Loader2 size={14} className="animate-spin" /> : <Trash2 size={14} />}
+                </Button>
+              </div>
+            </div>
+          ))}
+        </div>
+      </Card>
+      {errors.length > 0 && (
+        <p className="text-xs text-destructive" title={errors.join("\n")}>{errors.length === 1 ? errors[0] : `${errors.length} ${t("clean.errors")}`}</p>
+      )}
+      {retryOperation && (
+        <RetryFailedButton
+          operationId={retryOperation}
+          onRetried={async () => setReport(await invoke<DevContainerReport>("scan_devcontainers"))}
+        />
+      )}
+    </div>
+  );
+}
+
+/** "user@host:port" → SshTarget; user and port are optional. */
+function parseSshDestination(input: string): SshTarget | null {
+  const match = input.trim().match(/^(?:([^@\s]+)@)?([^@:\s]+)(?::(\d+))?$/);
+  if (!match) return null;
+  return { user: match[1] ?? null, host: match[2], port: match[3] ? Number(match[3]) : null, identity_file: null };
+}
+
+/* ── Remote SSH hosts: only contacted when the user enters one ── */
+function RemoteServersSection() {
+  const { t } = useTranslation();
+  const [destination, setDestination] = useState("");
+  const [target, setTarget] = useState<SshTarget | null>(null);
+  const [report, setReport] = useState<RemoteServerReport | null>(null);
+  const [busy, setBusy] = useState(false);
+  const [errors, setErrors] = useState<string[]>([]);
+
+  const handleScan = useCallback(async () => {
+    const parsed = parseSshDestination(destination);
+    if (!parsed) {
+      setErrors([t("remoteSsh.invalidDestination")]);
+      return;
+    }
+    setBusy(true);
+    setErrors([]);
+    try {
+      setReport(await invoke<RemoteServerReport>("scan_remote_servers", { target: parsed }));
+      setTarget(parsed);
+    } catch (e) {
+      setReport(null);
+      setErrors([String(e)]);
+    }
+    setBusy(false);
+  }, [destination, t]);
+
+  const handleClean = useCallback(async () => {
+    if (!report || !target) return;
+    const stale = report.builds.filter((b) => b.stale);
+    if (stale.length === 0) return;
+    if (!window.confirm(t("remoteSsh.confirm", { count: stale.length, size: formatBytes(report.stale_size), host: report.host }))) return;
+    setBusy(true);
+    try {
+      const result = await invoke<BatchResult>("clean_remote_servers", { target, paths: stale.map((b) => b.path) });
+      setErrors(result.items.filter((i) => i.status === "Failed").map((i) => `${i.id}: ${i.error ?? ""}`));
+      setReport(await invoke<RemoteServerReport>("scan_remote_servers", { target }));
+    } catch (e) {
+      setErrors([String(e)]);
+    }
+    setBusy(false);
+  }, [report, target, t]);
+
+  return (
+    <div className="space-y-4 pb-4">
+      <h3 className="text-sm font-medium text-muted-foreground uppercase tracking-wider">
+        {t("remoteSsh.title")}{report ? ` · ${formatBytes(report.total_size)}` : ""}
+      </h3>
+      <Card className="border-border/50">
+        <div className="flex flex-col gap-2 p-3">
+          <div className="flex items-center gap-2">
+            <input
+              type="text"
+              value={destination}
+              placeholder="user@host:22"
+              onChange={(e) => setDestination(e.target.value)}
+              onKeyDown={(e) => e.key === "Enter" && handleScan()}
+              className="flex-1 bg-muted border border-input rounded-md px-3 py-1.5 text-sm text-foreground outline-none focus:ring-2 focus:ring-ring focus:ring-offset-2"
+            />
+            <Button variant="outline" size="sm" disabled={busy || !destination.trim()} onClick={handleScan}>
+              {busy ? <Loader2 size={14} className="mr-1.5 animate-spin" /> : <Globe size={14} className="mr-1.5" />}
+              {t("remoteSsh.scan")}
+            </Button>
+          </div>
+          <p className="text-[10px] text-muted-foreground/70">{t("remoteSsh.hint")}</p>
+          {report && (
+            <>
+              {report.builds.map((build) => (
+                <div key={build.path} className="flex items-center justify-between gap-2 py-1.5 px-2 rounded-lg hover:bg-muted/40 transition-colors">
+                  <span className="text-xs font-mono truncate" title={build.path}>{build.path}</span>
+                  <div className="flex items-center gap-2 shrink-0">
+                    {build.in_use && <Badge variant="secondary">{t("remoteSsh.inUse")}</Badge>}
+                    {!build.in_use && !build.stale && <Badge variant="secondary">{t("remoteSsh.current")}</Badge>}
+                    <span className="text-[10px] font-mono text-muted-foreground/60">{formatBytes(build.size)}</span>
+                  </div>
+                </div>
+              ))}
+              <div className="flex justify-end">
+                <Button variant="destructive" size="sm" disabled={busy || report.stale_size === 0} onClick={handleClean}>
+                  <Trash2 size={14} className="mr-1.5" />
+                  {t("wsl.deleteStale", { size: formatBytes(report.stale_size) })}
+                </Button>
+              </div>
+            </>
+          )}
+        </div>
+      </Card>
+      {errors.length > 0 && (
+        <p className="text-xs text-destructive" title={errors.join("\n")}>{errors.length === 1 ? errors[0] : `${errors.length} ${t("clean.errors")}`}</p>
+      )}
+    </div>
+  );
+}
+
 /* ── Expandable category types ── */
 const EXPANDABLE_TYPES: Set<string> = new Set(["Extension", "WorkspaceStorage", "GlobalStorage"]);
 
@@ -688,6 +1979,7 @@ function CategoryItem({
   onToggle,
   icon,
   ideTotal,
+  ideId,
   onSizeChange,
 }: {
   cat: ScanCategory;
@@ -695,6 +1987,7 @@ function CategoryItem({
   onToggle: () => void;
   icon: React.ReactNode;
   ideTotal: number;
+  ideId?: string;
   onSizeChange?: () => void;
 }) {
   const { t } = useTranslation();
@@ -707,6 +2000,10 @@ function CategoryItem({
   const [subItems, setSubItems] = useState<StorageEntry[]>([]);
   const [loadingSub, setLoadingSub] = useState(false);
   const [deletingPaths, setDeletingPaths] = useState<Set<string>>(new Set());
+  const [purgeDays, setPurgeDays] = useState(30);
+  const [purging, setPurging] = useState(false);
+  const [purgeMessage, setPurgeMessage] = useState<string | null>(null);
+  const [purgeOperation, setPurgeOperation] = useState<string | null>(null);
 
   const handleOpenPath = useCallback(
     (e: React.MouseEvent) => {
@@ -718,13 +2015,18 @@ function CategoryItem({
 
   const loadSubItems = useCallback(async () => {
     if (!primaryPath) return;
+    // GlobalStorage already arrives broken down per extension
+    if (cat.entries.length > 0) {
+      setSubItems(cat.entries);
+      return;
+    }
     setLoadingSub(true);
     try {
-      const items = await invoke<StorageEntry[]>("list_storage_entries", { path: primaryPath });
+      const items = await invokeChunked<StorageEntry[]>("list_storage_entries_chunked", { path: primaryPath }, setSubItems);
       setSubItems(items);
     } catch { /* ignore */ }
     setLoadingSub(false);
-  }, [primaryPath]);
+  }, [primaryPath, cat.entries]);
 
   const handleExpand = useCallback((e: React.MouseEvent) => {
     e.stopPropagation();
@@ -743,6 +2045,25 @@ function CategoryItem({
     setDeletingPaths(prev => { const n = new Set(prev); n.delete(itemPath); return n; });
   }, [onSizeChange]);
 
+  const handlePurge = useCallback(async () => {
+    if (!ideId || !window.confirm(t("scan.crashPurgeConfirm", { name: cat.name, count: purgeDays }))) return;
+    setPurging(true);
+    try {
+      const result = await invoke<CleanResult>("purge_category_older_than", {
+        ideId,
+        category: cat.name,
+        olderThanDays: purgeDays,
+        createBackup: false,
+      });
+      setPurgeMessage(t("scan.crashPurged", { size: formatBytes(result.freed_bytes) }));
+      setPurgeOperation(result.operation_id ?? null);
+      onSizeChange?.();
+    } catch (e) {
+      setPurgeMessage(String(e));
+    }
+    setPurging(false);
+  }, [ideId, cat.name, purgeDays, onSizeChange, t]);
+
   return (
     <div
       className={`flex flex-col gap-1.5 p-3 rounded-xl transition-all select-none border ${
@@ -798,6 +2119,50 @@ function CategoryItem({
           <span className="truncate">{shortPath}</span>
         </button>
       )}
+      {/* Folders protected by a .devcleaner-ignore marker */}
+      {cat.excluded_by_marker.length > 0 && (
+        <span
+          className="flex items-center gap-1.5 ml-7 text-[10px] text-warning/80"
+          title={cat.excluded_by_marker.join("\n")}
+        >
+          <ShieldCheck size={10} className="shrink-0" />
+          {t("scan.excludedByMarker", { count: cat.excluded_by_marker.length })}
+        </span>
+      )}
+      {/* Age-based purge for crash dumps */}
+      {(cat.category_type === "CrashReport" || cat.category_type === "LocalHistory") && ideId && (
+        <div className="flex items-center gap-2 ml-7 text-[10px] text-muted-foreground">
+          <select
+            value={purgeDays}
+            onChange={(e) => setPurgeDays(Number(e.target.value))}
+            className="h-6 rounded-md border border-border/50 bg-background px-1 text-[10px]"
+          >
+            {STALE_DAY_OPTIONS.map((d) => (
+              <option key={d} value={d}>{t("scan.crashOlderThan", { count: d })}</option>
+            ))}
+          </select>
+          <button
+            onClick={handlePurge}
+            disabled={purging}
+            className="flex items-center gap-1 px-1.5 py-0.5 rounded hover:text-destructive hover:bg-destructive/10 transition-colors disabled:opacity-50"
+          >
+            {purging ? <Loader2 size={10} className="animate-spin" /> : <Trash2 size={10} />}
+            {t("scan.purgeCrashes")}
+          </button>
+          {purgeMessage && <span className="truncate" title={purgeMessage}>{purgeMessage}</span>}
+          {purgeOperation && <RetryFailedButton operationId={purgeOperation} onRetried={() => onSizeChange?.()} />}
+        </div>
+      )}
+      {/* Largest extensions, visible without expanding */}
+      {cat.entries.length > 0 && !expanded && (
+        <div className="flex flex-wrap gap-x-3 ml-7 text-[10px] text-muted-foreground/70">
+          {cat.entries.slice(0, 3).map((item) => (
+            <span key={item.path} className={`truncate ${item.is_ai_related ? "text-primary/80" : ""}`} title={item.name}>
+              {item.name}: <span className="font-mono">{formatBytes(item.size)}</span>
+            </span>
+          ))}
+        </div>
+      )}
       {/* Expandable sub-items */}
       {expanded && (
         <div className="ml-7 mt-1 flex flex-col gap-1 max-h-48 overflow-y-auto pr-1 animate-in fade-in slide-in-from-top-1 duration-150">
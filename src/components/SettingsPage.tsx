@@ -1,22 +1,36 @@
 import { useState, useCallback, useEffect } from "react";
 import { invoke } from "@tauri-apps/api/core";
-import { Settings, Info, Languages, Palette, Archive, FolderOpen, Trash2, Github, Mail } from "lucide-react";
+import { open, save } from "@tauri-apps/plugin-dialog";
+import { Settings, Info, Languages, Palette, Archive, FolderOpen, Trash2, Github, Mail, ArrowLeftRight, Download, Upload, FolderTree, Package, Plus, X } from "lucide-react";
 import { useTranslation } from "react-i18next";
 import { changeLanguage } from "../i18n";
 import { loadSettings, saveSettings } from "../utils/storage";
 import { formatBytes } from "../utils/formatters";
-import type { BackupListResult } from "../types";
+import type { BackupListResult, ReadLimits, CurationImportSummary } from "../types";
 import { Card, CardContent, CardHeader, CardTitle } from "./ui/card";
 import { Switch } from "./ui/switch";
 import { Button } from "./ui/button";
 import { Badge } from "./ui/badge";
 import { Separator } from "./ui/separator";
+import MigrationCard from "./MigrationCard";
+import SettingsTransferCard from "./SettingsTransferCard";
+import ExternalProfileCard from "./ExternalProfileCard";
+import SnapshotCard from "./SnapshotCard";
 
 export default function SettingsPage() {
   const { t, i18n } = useTranslation();
   const [settings, setSettings] = useState(loadSettings);
   const [backupInfo, setBackupInfo] = useState<{ path: string; count: number; size: number } | null>(null);
   const [clearing, setClearing] = useState(false);
+  const [readLimits, setReadLimits] = useState<ReadLimits | null>(null);
+  const [limitsError, setLimitsError] = useState<string | null>(null);
+  const [curationMessage, setCurationMessage] = useState<string | null>(null);
+  const [projectRoots, setProjectRoots] = useState<string[]>([]);
+  const [rootsError, setRootsError] = useState<string | null>(null);
+  const [portablePaths, setPortablePaths] = useState<string[]>([]);
+  const [portableError, setPortableError] = useState<string | null>(null);
+  const [secureDelete, setSecureDelete] = useState(false);
+  const [secureDeleteError, setSecureDeleteError] = useState<string | null>(null);
 
   const update = useCallback((patch: Partial<typeof settings>) => {
     setSettings((prev) => {
@@ -28,8 +42,96 @@ export default function SettingsPage() {
 
   useEffect(() => {
     loadBackupInfo();
+    invoke<ReadLimits>("get_conversation_read_limits").then(setReadLimits).catch(() => {});
+    invoke<string[]>("get_project_roots").then(setProjectRoots).catch(() => {});
+    invoke<string[]>("get_portable_paths").then(setPortablePaths).catch(() => {});
+    invoke<boolean>("get_secure_delete").then(setSecureDelete).catch(() => {});
   }, []);
 
+  async function handleSecureDeleteChange(enabled: boolean) {
+    try {
+      setSecureDelete(await invoke<boolean>("set_secure_delete", { enabled }));
+      setSecureDeleteError(null);
+    } catch (e) {
+      setSecureDeleteError(String(e));
+    }
+  }
+
+  async function saveReadLimits(limits: ReadLimits | null) {
+    try {
+      setReadLimits(await invoke<ReadLimits>("set_conversation_read_limits", { limits }));
+      setLimitsError(null);
+    } catch (e) {
+      setLimitsError(String(e));
+    }
+  }
+
+  async function handleAddProjectRoot() {
+    const path = await open({ directory: true, multiple: false });
+    if (typeof path !== "string") return;
+    try {
+      setProjectRoots(await invoke<string[]>("add_project_root", { path }));
+      setRootsError(null);
+    } catch (e) {
+      setRootsError(String(e));
+    }
+  }
+
+  async function handleRemoveProjectRoot(path: string) {
+    try {
+      setProjectRoots(await invoke<string[]>("remove_project_root", { path }));
+    } catch (e) {
+      setRootsError(String(e));
+    }
+  }
+
+  async function handleAddPortablePath() {
+    const path = await open({ directory: true, multiple: false });
+    if (typeof path !== "string") return;
+    try {
+      setPortablePaths(await invoke<string[]>("add_portable_path", { path }));
+      setPortableError(null);
+    } catch (e) {
+      setPortableError(String(e));
+    }
+  }
+
+  async function handleRemovePortablePath(path: string) {
+    try {
+      setPortablePaths(await invoke<string[]>("remove_portable_path", { path }));
+    } catch (e) {
+      setPortableError(String(e));
+    }
+  }
+
+  async function handleExportCuration() {
+    const path = await save({ defaultPath: "devcleaner-settings.json", filters: [{ name: "JSON", extensions: ["json"] }] });
+    if (!path) return;
+    try {
+      await invoke("export_curation", { path });
+      setCurationMessage(t("settings.curationExported"));
+    } catch (e) {
+      setCurationMessage(String(e));
+    }
+  }
+
+  async function handleImportCuration() {
+    const path = await open({ multiple: false, filters: [{ name: "JSON", extensions: ["json"] }] });
+    if (typeof path !== "string") return;
+    try {
+      const summary = await invoke<CurationImportSummary>("import_curation", { path });
+      setCurationMessage(t("settings.curationImported", {
+        ides: summary.added_ignored_ides.length,
+        paths: summary.added_portable_paths.length,
+        protected: summary.added_protected,
+        rules: summary.added_rules,
+        skipped: summary.skipped_portable_paths.length,
+      }));
+    } catch (e) {
+      setCurationMessage(String(e));
+    }
+  }
+
   async function loadBackupInfo() {
     try {
       const [dirPath, list] = await Promise.all([
@@ -93,6 +195,19 @@ export default function SettingsPage() {
                 onCheckedChange={(checked) => update({ autoBackup: checked })}
               />
             </div>
+
+            <Separator />
+
+            <div className="flex items-center justify-between gap-4">
+              <div className="space-y-1">
+                <p className="text-sm font-medium leading-none">{t("settings.secureDelete")}</p>
+                <p className="text-xs text-muted-foreground">
+                  {t("settings.secureDeleteDesc")}
+                </p>
+                {secureDeleteError && <p className="text-xs text-destructive">{secureDeleteError}</p>}
+              </div>
+              <Switch checked={secureDelete} onCheckedChange={handleSecureDeleteChange} />
+            </div>
             
             <Separator />
 
@@ -136,6 +251,57 @@ export default function SettingsPage() {
                 <option value="dark">{t("settings.themeDark")}</option>
               </select>
             </div>
+
+            {readLimits && (
+              <>
+                <Separator />
+
+                <div className="flex items-center justify-between gap-4">
+                  <div className="space-y-1">
+                    <p className="text-sm font-medium leading-none">{t("settings.previewLen")}</p>
+                    <p className="text-xs text-muted-foreground">
+                      {t("settings.previewLenDesc")}
+                    </p>
+                  </div>
+                  <input
+                    type="number"
+                    min={1000}
+                    max={1000000}
+                    step={1000}
+                    defaultValue={readLimits.preview_len}
+                    key={`preview-${readLimits.preview_len}`}
+                    onBlur={(e) => saveReadLimits({ ...readLimits, preview_len: Number(e.target.value) })}
+                    className="w-32 bg-muted border border-input rounded-md px-3 py-1.5 text-sm text-foreground outline-none focus:ring-2 focus:ring-ring focus:ring-offset-2"
+                  />
+                </div>
+
+                <div className="flex items-center justify-between gap-4">
+                  <div className="space-y-1">
+                    <p className="text-sm font-medium leading-none">{t("settings.maxFullRead")}</p>
+                    <p className="text-xs text-muted-foreground">
+                      {t("settings.maxFullReadDesc")}
+                    </p>
+                  </div>
+                  <input
+                    type="number"
+                    min={1}
+                    max={1000}
+                    defaultValue={Math.round(readLimits.max_full_read / 1_000_000)}
+                    key={`full-${readLimits.max_full_read}`}
+                    onBlur={(e) => saveReadLimits({ ...readLimits, max_full_read: Number(e.target.value) * 1_000_000 })}
+                    className="w-32 bg-muted border border-input rounded-md px-3 py-1.5 text-sm text-foreground outline-none focus:ring-2 focus:ring-ring focus:ring-offset-2"
+                  />
+                </div>
+
+                {limitsError && <p className="text-xs text-destructive">{limitsError}</p>}
+
+                <div className="flex justify-end">
+                  <Button variant="outline" size="sm" onClick={() => saveReadLimits(null)}>
+                    {t("settings.resetReadLimits")}
+                  </Button>
+                </div>
+              </>
+            )}
           </CardContent>
         </Card>
 
@@ -219,6 +385,104 @@ export default function SettingsPage() {
           </CardContent>
         </Card>
 
+        {/* Project roots */}
+        <Card>
+          <CardHeader>
+            <div className="flex items-center gap-3">
+              <div className="p-2 bg-primary/10 rounded-lg text-primary">
+                <FolderTree size={20} />
+              </div>
+              <CardTitle>{t("settings.projectRoots")}</CardTitle>
+            </div>
+          </CardHeader>
+          <CardContent className="space-y-3">
+            <p className="text-sm text-muted-foreground">{t("settings.projectRootsDesc")}</p>
+            {projectRoots.map((root) => (
+              <div key={root} className="flex items-center justify-between gap-2 text-sm">
+                <code className="text-xs bg-muted px-2 py-1 rounded truncate" title={root}>{root}</code>
+                <Button variant="ghost" size="sm" onClick={() => handleRemoveProjectRoot(root)}>
+                  <X size={14} />
+                </Button>
+              </div>
+            ))}
+            <div className="flex justify-end">
+              <Button variant="outline" size="sm" onClick={handleAddProjectRoot}>
+                <Plus size={14} className="mr-1.5" />
+                {t("settings.addProjectRoot")}
+              </Button>
+            </div>
+            {rootsError && <p className="text-xs text-destructive">{rootsError}</p>}
+          </CardContent>
+        </Card>
+
+        {/* Portable installs */}
+        <Card>
+          <CardHeader>
+            <div className="flex items-center gap-3">
+              <div className="p-2 bg-primary/10 rounded-lg text-primary">
+                <Package size={20} />
+              </div>
+              <CardTitle>{t("settings.portablePaths")}</CardTitle>
+            </div>
+          </CardHeader>
+          <CardContent className="space-y-3">
+            <p className="text-sm text-muted-foreground">{t("settings.portablePathsDesc")}</p>
+            {portablePaths.map((path) => (
+              <div key={path} className="flex items-center justify-between gap-2 text-sm">
+                <code className="text-xs bg-muted px-2 py-1 rounded truncate" title={path}>{path}</code>
+                <Button variant="ghost" size="sm" onClick={() => handleRemovePortablePath(path)}>
+                  <X size={14} />
+                </Button>
+              </div>
+            ))}
+            <div className="flex justify-end">
+              <Button variant="outline" size="sm" onClick={handleAddPortablePath}>
+                <Plus size={14} className="mr-1.5" />
+                {t("settings.addPortablePath")}
+              </Button>
+            </div>
+            {portableError && <p className="text-xs text-destructive">{portableError}</p>}
+          </CardContent>
+        </Card>
+
+        {/* Settings transfer */}
+        <Card>
+          <CardHeader>
+            <div className="flex items-center gap-3">
+              <div className="p-2 bg-primary/10 rounded-lg text-primary">
+                <ArrowLeftRight size={20} />
+              </div>
+              <CardTitle>{t("settings.curation")}</CardTitle>
+            </div>
+          </CardHeader>
+          <CardContent className="space-y-4">
+            <p className="text-sm text-muted-foreground">{t("settings.curationDesc")}</p>
+            <div className="flex items-center gap-2 justify-end">
+              <Button variant="outline" size="sm" onClick={handleExportCuration}>
+                <Download size={14} className="mr-1.5" />
+                {t("settings.exportCuration")}
+              </Button>
+              <Button variant="outline" size="sm" onClick={handleImportCuration}>
+                <Upload size={14} className="mr-1.5" />
+                {t("settings.importCuration")}
+              </Button>
+            </div>
+            {curationMessage && <p className="text-xs text-muted-foreground">{curationMessage}</p>}
+          </CardContent>
+        </Card>
+
+        {/* Migrate to a new machine */}
+        <MigrationCard />
+
+        {/* Copy settings between IDEs on this machine */}
+        <SettingsTransferCard />
+
+        {/* Portable scan snapshots */}
+        <SnapshotCard />
+
+        {/* Read-only analysis of another profile or disk image */}
+        <ExternalProfileCard />
+
         {/* About */}
         <Card>
           <CardHeader>
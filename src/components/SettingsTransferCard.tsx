@@ -0,0 +1,174 @@
+import { useEffect, useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { ArrowLeftRight, Copy, Loader2, Search } from "lucide-react";
+import { useTranslation } from "react-i18next";
+import type { IdeInfo, SettingsTransferPreview, SettingsTransferResult, TransferStatus } from "../types";
+import { formatBytes } from "../utils/formatters";
+import { Card, CardContent, CardHeader, CardTitle } from "./ui/card";
+import { Button } from "./ui/button";
+import { Checkbox } from "./ui/checkbox";
+import { Separator } from "./ui/separator";
+
+const STATUS_CLASS: Record<TransferStatus, string> = {
+  New: "text-success",
+  Identical: "text-muted-foreground/60",
+  Conflict: "text-warning",
+};
+
+/* ── Copy settings, keybindings, snippets and extensions from one VS Code-based IDE to another ── */
+export default function SettingsTransferCard() {
+  const { t } = useTranslation();
+  const [ides, setIdes] = useState<IdeInfo[]>([]);
+  const [fromId, setFromId] = useState("");
+  const [toId, setToId] = useState("");
+  const [preview, setPreview] = useState<SettingsTransferPreview | null>(null);
+  const [selected, setSelected] = useState<Set<string>>(new Set());
+  const [overwrite, setOverwrite] = useState(false);
+  const [installExtensions, setInstallExtensions] = useState(true);
+  const [busy, setBusy] = useState(false);
+  const [result, setResult] = useState<SettingsTransferResult | null>(null);
+  const [message, setMessage] = useState<string | null>(null);
+
+  useEffect(() => {
+    invoke<IdeInfo[]>("detect_ides")
+      .then((list) => {
+        const vscode = list.filter((i) => i.installed && i.ide_type === "VscodeBased");
+        setIdes(vscode);
+        setFromId(vscode[0]?.id ?? "");
+        setToId(vscode[1]?.id ?? "");
+      })
+      .catch(() => {});
+  }, []);
+
+  if (ides.length < 2) return null;
+
+  function toggle(path: string) {
+    setSelected((prev) => {
+      const next = new Set(prev);
+      if (next.has(path)) next.delete(path); else next.add(path);
+      return next;
+    });
+  }
+
+  async function handlePreview() {
+    setBusy(true);
+    setResult(null);
+    setMessage(null);
+    try {
+      const p = await invoke<SettingsTransferPreview>("preview_settings_transfer", { fromId, toId });
+      setPreview(p);
+      setSelected(new Set(p.files.filter((f) => f.status !== "Identical").map((f) => f.path)));
+    } catch (e) {
+      setPreview(null);
+      setMessage(String(e));
+    }
+    setBusy(false);
+  }
+
+  async function handleTransfer() {
+    if (!preview) return;
+    setBusy(true);
+    try {
+      setResult(await invoke<SettingsTransferResult>("transfer_settings", {
+        fromId: preview.from_id,
+        toId: preview.to_id,
+        options: { files: Array.from(selected), overwrite_conflicts: overwrite, install_extensions: installExtensions },
+      }));
+      setPreview(null);
+    } catch (e) {
+      setMessage(String(e));
+    }
+    setBusy(false);
+  }
+
+  const conflicts = preview?.files.filter((f) => f.status === "Conflict" && selected.has(f.path)).length ?? 0;
+
+  return (
+    <Card>
+      <CardHeader>
+        <div className="flex items-center gap-3">
+          <div className="p-2 bg-primary/10 rounded-lg text-primary">
+            <ArrowLeftRight size={20} />
+          </div>
+          <CardTitle>{t("settingsTransfer.title")}</CardTitle>
+        </div>
+      </CardHeader>
+      <CardContent className="space-y-4">
+        <p className="text-sm text-muted-foreground">{t("settingsTransfer.desc")}</p>
+        <div className="flex items-center gap-2 text-xs">
+          <select
+            value={fromId}
+            onChange={(e) => { setFromId(e.target.value); setPreview(null); }}
+            className="h-8 rounded-md border border-border/50 bg-background px-2 text-xs"
+          >
+            {ides.map((ide) => <option key={ide.id} value={ide.id}>{ide.name}</option>)}
+          </select>
+          <span className="text-muted-foreground">→</span>
+          <select
+            value={toId}
+            onChange={(e) => { setToId(e.target.value); setPreview(null); }}
+            className="h-8 rounded-md border border-border/50 bg-background px-2 text-xs"
+          >
+            {ides.map((ide) => <option key={ide.id} value={ide.id}>{ide.name}</option>)}
+          </select>
+          <Button variant="outline" size="sm" className="ml-auto" disabled={busy || !fromId || fromId === toId} onClick={handlePreview}>
+            {busy && !preview ? <Loader2 size={14} className="mr-1.5 animate-spin" /> : <Search size={14} className="mr-1.5" />}
+            {t("settingsTransfer.preview")}
+          </Button>
+        </div>
+
+        {preview && (
+          <>
+            <Separator />
+            <div className="flex flex-col gap-0.5 max-h-56 overflow-y-auto pr-1">
+              {preview.files.map((file) => (
+                <label key={file.path} className="flex items-center justify-between gap-2 text-xs cursor-pointer">
+                  <span className="flex items-center gap-2 min-w-0">
+                    <Checkbox checked={selected.has(file.path)} onCheckedChange={() => toggle(file.path)} disabled={file.status === "Identical"} />
+                    <span className="truncate">{file.path}</span>
+                  </span>
+                  <span className="flex items-center gap-2 shrink-0">
+                    <span className={STATUS_CLASS[file.status]}>{t(`settingsTransfer.status.${file.status}`)}</span>
+                    <span className="font-mono text-[10px] text-muted-foreground/60">{formatBytes(file.size)}</span>
+                  </span>
+                </label>
+              ))}
+            </div>
+            <label className="flex items-center gap-2 text-xs text-muted-foreground cursor-pointer">
+              <Checkbox checked={overwrite} onCheckedChange={(v) => setOverwrite(v === true)} />
+              {t("settingsTransfer.overwrite", { count: conflicts })}
+            </label>
+            <label className="flex items-center gap-2 text-xs text-muted-foreground cursor-pointer" title={preview.missing_extensions.join("\n")}>
+              <Checkbox checked={installExtensions} onCheckedChange={(v) => setInstallExtensions(v === true)} disabled={preview.missing_extensions.length === 0} />
+              {t("settingsTransfer.installExtensions", { count: preview.missing_extensions.length })}
+            </label>
+            <div className="flex justify-end">
+              <Button size="sm" disabled={busy || (selected.size === 0 && !(installExtensions && preview.missing_extensions.length > 0))} onClick={handleTransfer}>
+                {busy ? <Loader2 size={14} className="mr-1.5 animate-spin" /> : <Copy size={14} className="mr-1.5" />}
+                {t("settingsTransfer.copy")}
+              </Button>
+            </div>
+          </>
+        )}
+
+        {result && (
+          <div className="space-y-1 text-xs">
+            <p className="text-muted-foreground">
+              {t("settingsTransfer.done", { files: result.copied_files, skipped: result.skipped_files, installed: result.installed_extensions.length })}
+            </p>
+            {result.pending_extensions.length > 0 && (
+              <p className="text-muted-foreground/70 truncate" title={result.pending_extensions.join("\n")}>
+                {t("migration.pendingExtensions", { count: result.pending_extensions.length })}
+              </p>
+            )}
+            {result.errors.length > 0 && (
+              <p className="text-destructive" title={result.errors.join("\n")}>{result.errors.length} {t("clean.errors")}</p>
+            )}
+          </div>
+        )}
+
+        {message && <p className="text-xs text-destructive">{message}</p>}
+      </CardContent>
+    </Card>
+  );
+}
@@ -1,4 +1,4 @@
-import { Search, Settings, MessageSquare, Trash2 } from "lucide-react";
+import { Search, Settings, MessageSquare, Trash2, PieChart } from "lucide-react";
 import { useTranslation } from "react-i18next";
 import type { Page } from "../types";
 import { cn } from "../lib/utils";
@@ -13,6 +13,7 @@ export default function Sidebar({ currentPage, onNavigate }: SidebarProps) {
 
   const navItems: { page: Page; labelKey: string; icon: React.ReactNode }[] = [
     { page: "scan", labelKey: "nav.scan", icon: <Search size={20} /> },
+    { page: "footprint", labelKey: "nav.footprint", icon: <PieChart size={20} /> },
     { page: "conversations", labelKey: "nav.conversations", icon: <MessageSquare size={20} /> },
     { page: "uninstall", labelKey: "nav.uninstall", icon: <Trash2 size={20} /> },
     { page: "settings", labelKey: "nav.settings", icon: <Settings size={20} /> },
@@ -0,0 +1,121 @@
+import { useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { open, save } from "@tauri-apps/plugin-dialog";
+import { Camera, FolderOpen, GitCompare, Loader2, Save } from "lucide-react";
+import { useTranslation } from "react-i18next";
+import type { ScanSnapshot, ScanSummary } from "../types";
+import { formatBytes } from "../utils/formatters";
+import { invokeChunked } from "../utils/chunked";
+import { Card, CardContent, CardHeader, CardTitle } from "./ui/card";
+import { Button } from "./ui/button";
+
+/* ── Portable scan snapshots: save, reopen offline, compare with this machine ── */
+export default function SnapshotCard() {
+  const { t } = useTranslation();
+  const [busy, setBusy] = useState(false);
+  const [snapshot, setSnapshot] = useState<ScanSnapshot | null>(null);
+  const [current, setCurrent] = useState<ScanSummary | null>(null);
+  const [message, setMessage] = useState<string | null>(null);
+
+  async function handleSave() {
+    const path = await save({ defaultPath: "devcleaner-snapshot.json", filters: [{ name: "JSON", extensions: ["json"] }] });
+    if (!path) return;
+    setBusy(true);
+    try {
+      await invoke("save_scan_snapshot", { path });
+      setMessage(t("snapshot.saved", { path }));
+    } catch (e) {
+      setMessage(String(e));
+    }
+    setBusy(false);
+  }
+
+  async function handleOpen() {
+    const path = await open({ multiple: false, filters: [{ name: "JSON", extensions: ["json"] }] });
+    if (typeof path !== "string") return;
+    setCurrent(null);
+    try {
+      setSnapshot(await invoke<ScanSnapshot>("load_scan_snapshot", { path }));
+      setMessage(null);
+    } catch (e) {
+      setSnapshot(null);
+      setMessage(String(e));
+    }
+  }
+
+  async function handleCompare() {
+    setBusy(true);
+    try {
+      setCurrent(await invokeChunked<ScanSummary>("scan_all_ides_chunked"));
+    } catch (e) {
+      setMessage(String(e));
+    }
+    setBusy(false);
+  }
+
+  const conversationCount = snapshot?.conversations.reduce((n, c) => n + c.conversations.length, 0) ?? 0;
+
+  return (
+    <Card>
+      <CardHeader>
+        <div className="flex items-center gap-3">
+          <div className="p-2 bg-primary/10 rounded-lg text-primary">
+            <Camera size={20} />
+          </div>
+          <CardTitle>{t("snapshot.title")}</CardTitle>
+        </div>
+      </CardHeader>
+      <CardContent className="space-y-4">
+        <p className="text-sm text-muted-foreground">{t("snapshot.desc")}</p>
+        <div className="flex items-center gap-2 justify-end">
+          <Button variant="outline" size="sm" disabled={busy} onClick={handleSave}>
+            {busy ? <Loader2 size={14} className="mr-1.5 animate-spin" /> : <Save size={14} className="mr-1.5" />}
+            {t("snapshot.save")}
+          </Button>
+          <Button variant="outline" size="sm" disabled={busy} onClick={handleOpen}>
+            <FolderOpen size={14} className="mr-1.5" />
+            {t("snapshot.open")}
+          </Button>
+        </div>
+
+        {snapshot && (
+          <div className="space-y-1 text-xs">
+            <div className="flex items-center justify-between gap-2">
+              <p className="text-muted-foreground">
+                {t("snapshot.takenOn", {
+                  date: snapshot.created_at.slice(0, 16).replace("T", " "),
+                  os: snapshot.source_os,
+                  ides: snapshot.scan.results.length,
+                  size: formatBytes(snapshot.scan.grand_total_size),
+                  conversations: conversationCount,
+                })}
+              </p>
+              <Button variant="ghost" size="sm" disabled={busy} onClick={handleCompare}>
+                <GitCompare size={14} className="mr-1.5" />
+                {t("snapshot.compare")}
+              </Button>
+            </div>
+            {[...snapshot.scan.results]
+              .sort((a, b) => b.total_size - a.total_size)
+              .map((r) => {
+                const now = current?.results.find((c) => c.ide_id === r.ide_id);
+                return (
+                  <p key={r.ide_id} className="flex justify-between pl-3 text-muted-foreground">
+                    <span>{r.ide_name}</span>
+                    <span className="font-mono">
+                      {formatBytes(r.total_size)}
+                      {current && (
+                        <span className="ml-2 text-muted-foreground/70">{t("snapshot.now", { size: formatBytes(now?.total_size ?? 0) })}</span>
+                      )}
+                    </span>
+                  </p>
+                );
+              })}
+          </div>
+        )}
+
+        {message && <p className="text-xs text-muted-foreground truncate" title={message}>{message}</p>}
+      </CardContent>
+    </Card>
+  );
+}
@@ -15,13 +15,14 @@ import {
 } from "lucide-react";
 import { createPortal } from "react-dom";
 import { useTranslation } from "react-i18next";
-import type { InstalledProgram, UninstallOptions, UninstallResult, KeepOptionSizes, VersionInstall } from "../types";
+import type { InstalledProgram, UninstallOptions, UninstallResult, KeepOptionSizes, KeepGroup, VersionInstall } from "../types";
 import { formatBytes, getIdeColor } from "../utils/formatters";
 import { Card, CardContent, CardHeader, CardTitle } from "./ui/card";
 import { Button } from "./ui/button";
 import { Checkbox } from "./ui/checkbox";
 import { Badge } from "./ui/badge";
 import { Separator } from "./ui/separator";
+import RetryFailedButton from "./RetryFailedButton";
 
 export default function UninstallPage() {
   const { t } = useTranslation();
@@ -133,6 +134,21 @@ export default function UninstallPage() {
                   ))}
                 </div>
               )}
+              {result.programs_diff && result.programs_diff.leftover_related.length > 0 && (
+                <div className="mt-2 text-xs text-muted-foreground">
+                  <p className="font-medium">{t("uninstall.leftoverRelated")}</p>
+                  {result.programs_diff.leftover_related.map((p) => (
+                    <p key={p.registry_key}>
+                      {p.display_name} {p.display_version}
+                    </p>
+                  ))}
+                </div>
+              )}
+              {result.operation_id && (
+                <div className="mt-3">
+                  <RetryFailedButton operationId={result.operation_id} />
+                </div>
+              )}
             </CardContent>
           </Card>
         )}
@@ -190,6 +206,7 @@ export default function UninstallPage() {
                         <div className="flex items-center gap-2 text-xs text-muted-foreground">
                           {prog.publisher && <span>{prog.publisher}</span>}
                           {prog.display_version && <span>v{prog.display_version}</span>}
+                          {prog.package_manager && <span>{t("uninstall.viaPackageManager", { manager: prog.package_manager })}</span>}
                         </div>
                         {prog.version_installs.length > 0 ? (
                           <div className="flex flex-col gap-0.5 mt-0.5">
@@ -325,6 +342,8 @@ export default function UninstallPage() {
 }
 
 /* ── Keep Options Panel with size display ── */
+const BREAKDOWN_ROWS = 8;
+
 function KeepOptionsPanel({
   ideId,
   options,
@@ -342,10 +361,11 @@ function KeepOptionsPanel({
   loadingSizes: string | null;
   setKeepSizes: React.Dispatch<React.SetStateAction<Record<string, KeepOptionSizes>>>;
   setLoadingSizes: React.Dispatch<React.SetStateAction<string | null>>;
-  t: (key: string) => string;
+  t: (key: string, options?: Record<string, unknown>) => string;
 }) {
   const sizes = ideId ? keepSizes[ideId] : null;
   const isLoading = loadingSizes === ideId;
+  const [expanded, setExpanded] = useState<KeepGroup | null>(null);
 
   useEffect(() => {
     if (ideId && !keepSizes[ideId]) {
@@ -359,13 +379,32 @@ function KeepOptionsPanel({
     }
   }, [ideId]);
 
-  const items: { key: keyof UninstallOptions; optionKey: keyof KeepOptionSizes; icon: React.ReactNode; label: string }[] = [
-    { key: "keep_settings", optionKey: "settings_size", icon: <Settings size={14} className="text-muted-foreground" />, label: t("uninstall.keepSettings") },
-    { key: "keep_user_data", optionKey: "user_data_size", icon: <FolderOpen size={14} className="text-muted-foreground" />, label: t("uninstall.keepUserData") },
-    { key: "keep_conversations", optionKey: "conversations_size", icon: <MessageSquare size={14} className="text-muted-foreground" />, label: t("uninstall.keepConversations") },
-    { key: "keep_extensions", optionKey: "extensions_size", icon: <Puzzle size={14} className="text-muted-foreground" />, label: t("uninstall.keepExtensions") },
+  const items: { key: "keep_settings" | "keep_user_data" | "keep_conversations" | "keep_extensions"; optionKey: "settings_size" | "user_data_size" | "conversations_size" | "extensions_size"; group: KeepGroup; icon: React.ReactNode; label: string }[] = [
+    { key: "keep_settings", optionKey: "settings_size", group: "Settings", icon: <Settings size={14} className="text-muted-foreground" />, label: t("uninstall.keepSettings") },
+    { key: "keep_user_data", optionKey: "user_data_size", group: "UserData", icon: <FolderOpen size={14} className="text-muted-foreground" />, label: t("uninstall.keepUserData") },
+    { key: "keep_conversations", optionKey: "conversations_size", group: "Conversations", icon: <MessageSquare size={14} className="text-muted-foreground" />, label: t("uninstall.keepConversations") },
+    { key: "keep_extensions", optionKey: "extensions_size", group: "Extensions", icon: <Puzzle size={14} className="text-muted-foreground" />, label: t("uninstall.keepExtensions") },
   ];
 
+  const estimate = sizes?.estimates.find((e) =>
+    e.keep_settings === options.keep_settings &&
+    e.keep_user_data === options.keep_user_data &&
+    e.keep_conversations === options.keep_conversations &&
+    e.keep_extensions === options.keep_extensions
+  );
+  // A kept folder inside a removed one (VS Code's config dir holds User/globalStorage) goes anyway
+  const normalize = (p: string) => p.replace(/\\/g, "/").toLowerCase();
+  const removedRoots = items
+    .filter((i) => !options[i.key])
+    .flatMap((i) => sizes?.breakdown.find((b) => b.group === i.group)?.roots ?? [])
+    .map(normalize);
+  const overridden = items.filter((i) =>
+    options[i.key] &&
+    (sizes?.breakdown.find((b) => b.group === i.group)?.roots ?? []).some((root) =>
+      removedRoots.some((r) => normalize(root) !== r && normalize(root).startsWith(r + "/"))
+    )
+  );
+
   return (
     <div className="px-4 pb-4 animate-in slide-in-from-top-2 fade-in duration-200">
       <Separator className="mb-3" />
@@ -375,34 +414,73 @@ function KeepOptionsPanel({
       <div className="space-y-1.5">
         {items.map((item) => {
           const size = sizes?.[item.optionKey] ?? 0;
+          const breakdown = sizes?.breakdown.find((b) => b.group === item.group);
           // Hide options with 0 size (not applicable for this IDE type)
           if (!isLoading && sizes && size === 0) return null;
           return (
-            <label
-              key={item.key}
-              className="flex items-center justify-between py-1.5 px-2 rounded-md hover:bg-muted/30 cursor-pointer select-none transition-colors"
-            >
-              <div className="flex items-center gap-2.5">
-                <Checkbox
-                  checked={options[item.key]}
-                  onCheckedChange={(c) => setOptions((o) => ({ ...o, [item.key]: c === true }))}
-                />
-                {item.icon}
-                <span className="text-xs">{item.label}</span>
-              </div>
-              <div className="shrink-0 ml-3">
-                {isLoading ? (
-                  <Loader2 size={12} className="animate-spin text-muted-foreground" />
-                ) : size > 0 ? (
-                  <Badge variant="secondary" className="text-[10px] font-mono px-1.5 py-0">
-                    {formatBytes(size)}
-                  </Badge>
-                ) : null}
-              </div>
-            </label>
+            <div key={item.key}>
+              <label
+                className="flex items-center justify-between py-1.5 px-2 rounded-md hover:bg-muted/30 cursor-pointer select-none transition-colors"
+              >
+                <div className="flex items-center gap-2.5">
+                  <Checkbox
+                    checked={options[item.key]}
+                    onCheckedChange={(c) => setOptions((o) => ({ ...o, [item.key]: c === true }))}
+                  />
+                  {item.icon}
+                  <span className="text-xs">{item.label}</span>
+                </div>
+                <div className="shrink-0 ml-3 flex items-center gap-1">
+                  {isLoading ? (
+                    <Loader2 size={12} className="animate-spin text-muted-foreground" />
+                  ) : size > 0 ? (
+                    <Badge variant="secondary" className="text-[10px] font-mono px-1.5 py-0">
+                      {formatBytes(size)}
+                    </Badge>
+                  ) : null}
+                  {breakdown && breakdown.entries.length > 0 && (
+                    <button
+                      onClick={(e) => { e.preventDefault(); setExpanded(expanded === item.group ? null : item.group); }}
+                      className="p-0.5 rounded text-muted-foreground hover:text-foreground"
+                      title={t("uninstall.showBreakdown")}
+                    >
+                      <ChevronDown size={12} className={`transition-transform ${expanded === item.group ? "rotate-180" : ""}`} />
+                    </button>
+                  )}
+                </div>
+              </label>
+              {expanded === item.group && breakdown && (
+                <div className="ml-9 mb-1 space-y-0.5">
+                  {breakdown.entries.slice(0, BREAKDOWN_ROWS).map((entry) => (
+                    <div key={entry.path} className="flex items-center justify-between gap-2 text-[10px] text-muted-foreground/70">
+                      <span className="truncate" title={entry.path}>{entry.path}</span>
+                      <span className="font-mono shrink-0">{formatBytes(entry.size)}</span>
+                    </div>
+                  ))}
+                  {breakdown.entries.length > BREAKDOWN_ROWS && (
+                    <p className="text-[10px] text-muted-foreground/50">
+                      {t("uninstall.moreEntries", { count: breakdown.entries.length - BREAKDOWN_ROWS })}
+                    </p>
+                  )}
+                </div>
+              )}
+            </div>
           );
         })}
       </div>
+      {estimate && (
+        <div className="mt-3 px-2 space-y-1">
+          <div className="flex items-center justify-between text-xs">
+            <span className="text-destructive">{t("uninstall.willRemove", { size: formatBytes(estimate.removed_size) })}</span>
+            <span className="text-muted-foreground">{t("uninstall.willKeep", { size: formatBytes(estimate.kept_size) })}</span>
+          </div>
+          {overridden.length > 0 && (
+            <p className="text-[10px] text-warning">
+              {t("uninstall.keptInsideRemoved", { options: overridden.map((i) => i.label).join(", ") })}
+            </p>
+          )}
+        </div>
+      )}
     </div>
   );
 }
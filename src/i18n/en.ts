@@ -4,22 +4,176 @@ const en = {
       name: "DevCleaner",
       version: "v0.1.0",
       footer: "Built with Tauri + React",
+      policyDisabled: "Cleaning, deleting and uninstalling are disabled by your administrator.",
     },
     nav: {
       scan: "Scan & Clean",
+      footprint: "Footprint",
       conversations: "Conversations",
       uninstall: "Uninstall",
       settings: "Settings",
     },
+    tray: {
+      reclaimable: "Reclaimable: {{size}}",
+      scanning: "Reclaimable: scanning…",
+      cleaning: "Cleaning…",
+      quickClean: "Quick clean (caches & logs)",
+      open: "Open DevCleaner",
+      quit: "Quit",
+      confirmTitle: "DevCleaner quick clean",
+      confirmMessage: "Delete {{size}} of caches and logs from every IDE that isn't running? Settings, extensions and conversations are not touched.",
+      confirmClean: "Clean",
+      confirmCancel: "Cancel",
+    },
     scan: {
       title: "Scan & Clean",
       subtitle: "Scan dev tools cache usage and clean up in one step",
       rescan: "Rescan",
+      excludedByMarker: "{{count}} folder(s) protected by .devcleaner-ignore",
+      crashOlderThan: "Older than {{count}} days",
+      purgeCrashes: "Purge",
+      crashPurgeConfirm: "Delete {{name}} older than {{count}} days?",
+      crashPurged: "Freed {{size}}",
       readyTitle: "Ready to Scan",
       readyDesc: 'Click below to analyze cache usage across {{count}} installed dev tool(s)',
       startScan: "Start Scan",
       spaceMap: "Space Usage Map",
     },
+    devCaches: {
+      title: "Dev Tool Caches",
+      confirm: "Clean the {{name}} cache ({{size}})?",
+      runTool: "Run {{command}}",
+      delete: "Wipe",
+    },
+    rustTargets: {
+      title: "Rust target/ Directories",
+      confirm: "Delete {{count}} target folder(s) ({{size}})? Projects will rebuild from scratch.",
+      deleteAll: "Delete All",
+      notBuiltFor: "Not built for {{count}} days",
+      deleteStale: "Delete untouched ({{size}})",
+      daysAgo: "{{count}} day(s) ago",
+    },
+    python: {
+      title: "Python Caches & Virtualenvs",
+      venvUnusedFor: "Virtualenvs unused for {{count}} days",
+      deleteSelected: "Delete selected ({{size}})",
+      confirm: "Delete {{count}} folder(s) ({{size}})? Caches are rebuilt automatically; virtualenvs have to be recreated.",
+      stale: "Stale",
+      kinds: {
+        PyCache: "__pycache__",
+        PytestCache: ".pytest_cache",
+        MypyCache: ".mypy_cache",
+        Venv: "Virtualenv",
+      },
+    },
+    maven: {
+      title: "Maven Local Repository",
+      largestGroups: "Largest groupIds",
+      versions: "{{count}} version(s)",
+      unusedFor: "Unused for {{count}} months",
+      deleteStale: "Delete unused ({{size}})",
+      confirm: "Delete {{count}} artifact version(s) ({{size}})? Builds that need them will download them again.",
+    },
+    retry: {
+      retryFailed: "Retry failed items",
+      closeIdeFirst: "Close the IDE first",
+      stillFailing: "{{count}} item(s) still failing",
+      allDone: "All remaining items removed ({{size}} freed)",
+    },
+    extensions: {
+      title: "Extensions",
+      installedSummary: "{{installed}} extensions",
+      staleSummary: "{{count}} outdated or obsolete extension copies",
+      deleteStale: "Delete stale ({{size}})",
+      confirm: "Delete {{count}} outdated extension copies ({{size}}) from {{ide}}? The versions in use are kept.",
+      obsolete: "obsolete",
+      uninstall: "Uninstall",
+      uninstallConfirm: "Uninstall {{id}} ({{size}})? Close the IDE first so it doesn't reinstall it.",
+      clearData: "Also delete the extension's data (globalStorage)",
+      exportList: "Export list",
+      importList: "Import list",
+      exactVersions: "Exact versions",
+      exported: "Exported {{count}} extensions to {{path}}",
+      importConfirm: "Install {{count}} extensions exported from {{source}} into {{ide}}? Ones already installed are skipped.",
+      imported: "Installed {{installed}} extensions, {{skipped}} already present",
+      installFailed: "install failed, install it by hand",
+    },
+    wsl: {
+      title: "WSL Remote Servers",
+      staleSummary: "{{builds}} old server build(s), {{extensions}} outdated extension copies",
+      deleteStale: "Delete stale ({{size}})",
+      confirm: "Delete {{count}} old server build(s) and extension copies ({{size}}) in {{distro}}? The build currently in use is kept.",
+    },
+    devcontainers: {
+      title: "Dev Containers",
+      dockerUnavailable: "Docker isn't running or installed; only the devcontainers CLI cache is shown.",
+      remove: "Remove",
+      confirm: "Remove {{name}} ({{size}})?",
+      kinds: {
+        Container: "Container",
+        Image: "Image",
+        Volume: "Volume",
+        CliCache: "devcontainers CLI cache",
+      },
+    },
+    aiCli: {
+      title: "AI Coding CLI Sessions",
+      tools: {
+        ClaudeCode: "Claude Code",
+        GeminiCli: "Gemini CLI",
+        CodexCli: "Codex CLI",
+        Aider: "aider",
+      },
+      sessions: "{{count}} session(s)",
+      untouchedFor: "Untouched for {{count}} days",
+      pruneStale: "Prune old sessions ({{size}})",
+      confirm: "Delete {{count}} session transcript(s) ({{size}})? They can't be resumed afterwards.",
+      untitled: "Untitled session",
+      summaries: "Summaries",
+      firstPrompt: "First prompt",
+      lastPrompt: "Last prompt",
+      messages: "{{user}} prompt(s), {{assistant}} response(s)",
+    },
+    localModels: {
+      title: "Local AI Models",
+      runtimes: {
+        Ollama: "Ollama",
+        LmStudio: "LM Studio",
+        Gpt4All: "GPT4All",
+        HuggingFace: "Hugging Face",
+      },
+      deleteSelected: "Delete selected ({{size}})",
+      confirm: "Delete {{count}} model(s)? This frees {{size}}; they have to be downloaded again to be used.",
+      shared: "{{size}} shared",
+      pruneRevisions: "Prune {{count}} old revisions ({{size}})",
+      confirmPrune: "Delete every revision of {{name}} except the latest? This frees {{size}}.",
+    },
+    remoteSsh: {
+      title: "Remote SSH Hosts",
+      scan: "Scan host",
+      hint: "Uses your ssh client with key or agent authentication. Lists ~/.vscode-server (and Cursor, Windsurf…) server builds; the newest and any running build are kept.",
+      invalidDestination: "Enter a destination like user@host or user@host:port",
+      inUse: "In use",
+      current: "Current",
+      confirm: "Delete {{count}} old server build(s) ({{size}}) on {{host}}?",
+    },
+    footprint: {
+      title: "Developer Footprint",
+      subtitle: "Everything dev tools take up on this machine, ranked",
+      total: "Total",
+      ranked: "Largest items",
+      items: "{{count}} item(s)",
+      families: {
+        ide: "IDEs",
+        devCache: "Dev Tool Caches",
+        toolboxBuild: "Old Toolbox Builds",
+        projectArtifact: "Project Build Artifacts",
+        devContainer: "Dev Containers",
+        localModel: "Local AI Models",
+        wslServer: "WSL Remote Servers",
+        aiCliSession: "AI CLI Sessions",
+      },
+    },
     dashboard: {
       scanning: "Scanning...",
       totalSize: "Total Size",
@@ -36,6 +190,18 @@ const en = {
       cleanComplete: "Clean Complete",
       freed: "Freed",
       errors: "errors",
+      byFamily: "Clean a Whole Family",
+      families: {
+        VscodeBased: "All {{count}} VS Code-based IDEs",
+        JetBrains: "All {{count}} JetBrains IDEs",
+        Zed: "All {{count}} Zed builds",
+        VisualStudio: "All {{count}} Visual Studio versions",
+        Eclipse: "All {{count}} Eclipse installs",
+        TerminalEditor: "All {{count}} terminal editors",
+        Xcode: "All {{count}} Xcode versions",
+      },
+      familyConfirm: "Clean what the current mode selects in {{names}}? About {{size}} will be removed.",
+      familyProgress: "Cleaning {{name}} ({{done}}/{{total}}), {{size}} freed so far",
       ideRunning: "Process is Running",
       ideRunningDesc: "The following processes are still running. Cleaning while running may cause issues.",
       continueAnyway: "Continue Anyway",
@@ -57,18 +223,84 @@ const en = {
       chats: "chats",
       chatHistory: "Conversations",
       dbFiles: "Database Files",
+      compact: "Compact",
+      compactHint: "Compact the database (VACUUM) to give the space of deleted conversations back to the disk",
+      compacting: "Compacting… {{percent}}% ({{seconds}}s)",
+      compactFreed: "−{{size}}",
+      strayFiles: "{{count}} leftover WAL/journal files of deleted databases",
+      removeStrayFiles: "Remove",
+      strayFilesPartialFailure: "{{failed}} of {{total}} leftover files could not be deleted.",
       noIdes: "No supported IDEs detected",
       noData: "No conversation data found",
       deleteConversation: "Delete Conversation",
       deleteConfirm: "Are you sure you want to delete this conversation? This cannot be undone.",
       batchDeleteConfirm: "Are you sure you want to delete {{count}} selected conversations? This cannot be undone.",
+      batchPartialFailure: "{{failed}} of {{total}} conversations could not be deleted. They are still selected — close the IDE and delete again to retry.",
+      removeDuplicates: "Remove {{count}} duplicate copies ({{size}})",
+      duplicatesConfirm: "Delete {{count}} redundant copies ({{size}})? Each chat keeps its most complete copy.",
+      duplicatesPartialFailure: "{{failed}} of {{total}} copies could not be deleted. Copies stored together with other chats are kept.",
+      copies: "{{count}} more copies",
+      protect: "Protect from cleanup",
+      unprotect: "Remove protection",
+      protectedSkipped: "{{count}} protected conversations were skipped.",
+      unprotectToDelete: "Remove protection to delete",
+      editNotes: "Tags and note",
+      tagsPlaceholder: "Tags, comma-separated",
+      notePlaceholder: "Note",
+      saveNotes: "Save",
+      allTags: "All tags",
+      scanSecrets: "Scan for secrets",
+      noSecrets: "No secrets found",
+      selectLeaking: "Select {{count}} leaking",
+      redactSecrets: "Redact secrets",
+      secretKinds: {
+        AwsAccessKey: "AWS access key",
+        ApiKey: "API key",
+        GitHubToken: "GitHub token",
+        SlackToken: "Slack token",
+        PrivateKey: "Private key",
+      },
+      deleteFailed: "Could not be deleted",
       viewContent: "View Content",
+      exportHtml: "Export HTML",
+      exportJsonl: "JSONL",
+      exportSelected: "Export selected ({{count}})",
+      exportPartialFailure: "{{failed}} of {{total}} conversations could not be exported",
+      archiveSelected: "Archive selected ({{count}})",
+      archiveConfirm: "Archive {{count}} selected conversations? They are copied into DevCleaner's archive and then removed from the IDE.",
+      archivePartialFailure: "{{failed}} of {{total}} conversations could not be archived. They are still selected — close the IDE and archive again to retry.",
+      archived: "Archived Conversations",
+      archivedOn: "archived {{date}}",
+      archiveDelete: "Delete from archive",
+      archiveDeleteConfirm: "Permanently delete \"{{title}}\" from the archive?",
+      restore: "Restore to IDE",
+      restoreFailed: "Could not restore \"{{title}}\": {{error}}",
+      search: "Search",
+      searchPlaceholder: "Search titles and messages in every IDE…",
+      searchAllIdes: "All IDEs",
+      searchIndexing: "Indexing…",
+      searchNoHits: "No conversations match",
+      cliAgents: "CLI agents",
+      exportFailed: "Export failed",
       noMessages: "Could not parse message content",
-      binaryContent: "This conversation is stored in binary format and full content cannot be previewed",
+      binaryContent: "This conversation is encrypted or in a binary format that could not be decoded",
       messagesCount: "messages",
       close: "Close",
+      allLanguages: "All languages",
+      anySize: "Any size",
+      filterTitle: "Filter titles…",
+      loadMore: "Load more ({{shown}} of {{total}})",
+      languages: {
+        zh: "Chinese",
+        ja: "Japanese",
+        ko: "Korean",
+        ru: "Russian",
+        latin: "Latin script",
+      },
       sortByTime: "By time",
       sortBySize: "By size",
+      sortByMessages: "By messages",
+      sortByTitle: "By title",
       selectAll: "Select all",
       deselectAll: "Deselect all",
       selectBefore: "Select this & before",
@@ -89,8 +321,14 @@ const en = {
       keepUserData: "Keep workspace data",
       keepConversations: "Keep conversation history",
       keepExtensions: "Keep extensions",
+      showBreakdown: "Show what this covers",
+      moreEntries: "…and {{count}} more",
+      willRemove: "Will be removed: {{size}}",
+      willKeep: "Will be kept: {{size}}",
+      keptInsideRemoved: "{{options}} lives inside a folder that will be removed, so it is deleted too.",
       selectProgram: "Select a program to view uninstall options",
       unknownLocation: "Unknown install location",
+      viaPackageManager: "via {{manager}}",
       uninstallBtn: "Uninstall Program",
       uninstalling: "Uninstalling...",
       confirmTitle: "Confirm Uninstall",
@@ -98,9 +336,12 @@ const en = {
       confirmYes: "Yes, Uninstall",
       success: "Uninstall Successful",
       failed: "Uninstall Failed",
+      leftoverRelated: "Still installed from the same publisher:",
     },
     backups: {
       no: "Cancel",
+      restoreRegistry: "Restore registry keys",
+      registryRestored: "{{count}} registry keys restored",
     },
     settings: {
       title: "Settings",
@@ -108,6 +349,8 @@ const en = {
       general: "General",
       autoBackup: "Auto Backup",
       autoBackupDesc: "Automatically create backups before cleaning",
+      secureDelete: "Secure Delete",
+      secureDeleteDesc: "Overwrite conversation files, transcripts and logs before deleting them and zero deleted conversations in IDE databases. Slower, and less reliable on SSDs",
       defaultCleanMode: "Default Clean Mode",
       defaultCleanModeDesc: "Default mode when opening the clean page",
       theme: "Theme",
@@ -115,8 +358,25 @@ const en = {
       themeSystem: "Follow System",
       themeLight: "Light",
       themeDark: "Dark",
+      previewLen: "Conversation Preview Length",
+      previewLenDesc: "Characters read per chat for quick titles and counts",
+      maxFullRead: "Full-Read Ceiling (MB)",
+      maxFullReadDesc: "Chats up to this size are parsed in full for accurate counts; larger ones are scanned in chunks",
+      resetReadLimits: "Reset to Defaults",
       language: "Language",
       languageDesc: "Select display language",
+      projectRoots: "Project Roots",
+      projectRootsDesc: "Folders searched for project build artifacts such as Rust target/ directories.",
+      addProjectRoot: "Add Folder",
+      portablePaths: "Portable Installs",
+      portablePathsDesc: "Folders holding portable IDE copies (with a data/ folder next to the executable) that aren't found automatically.",
+      addPortablePath: "Add Folder",
+      curation: "Settings Transfer",
+      curationDesc: "Export hidden IDEs, portable install paths, protected conversations and conversation key rules, and merge them into another installation. Importing only adds entries, it never removes existing ones.",
+      exportCuration: "Export",
+      importCuration: "Import",
+      curationExported: "Settings exported",
+      curationImported: "Imported {{ides}} hidden IDE(s), {{paths}} portable path(s), {{protected}} protected conversation(s), {{rules}} rule(s); {{skipped}} path(s) not found here",
       backupManagement: "Backup Management",
       backupLocation: "Storage Location",
       backupCount: "Backups",
@@ -129,6 +389,56 @@ const en = {
       license: "License",
       author: "Author",
     },
+    migration: {
+      title: "Migrate to a New Machine",
+      desc: "Bundle the selected IDEs' settings, extension lists and conversations together with your DevCleaner settings, tags and notes into one archive, then restore it on the new machine. Conversations come back as readable copies; they aren't put back into the IDE.",
+      includeConversations: "Include conversations",
+      export: "Export Bundle",
+      import: "Open Bundle",
+      exported: "Exported {{ides}} IDE(s), {{size}}",
+      archiveFrom: "Bundle created {{date}} on {{os}}",
+      ideContents: "{{settings}} settings file(s), {{extensions}} extension(s), {{conversations}} conversation(s)",
+      overwriteSettings: "Overwrite existing settings (originals kept as .pre-migration)",
+      installExtensions: "Install extensions with the IDE's command line",
+      restore: "Restore",
+      restored: "{{files}} file(s) restored, {{skipped}} skipped, {{installed}} extension(s) installed",
+      pendingExtensions: "{{count}} extension(s) still to install",
+      conversationsSaved: "Conversations saved to {{path}}",
+      skippedIdes: "Not installed here: {{ides}}",
+      annotationsAdded: "Tags and notes added to {{count}} conversation(s)",
+    },
+    settingsTransfer: {
+      title: "Copy Settings Between IDEs",
+      desc: "Copy settings, keybindings, snippets and profiles from one VS Code-based IDE to another, e.g. Cursor to Windsurf, and install the extensions it's missing. Preview first to see what would change.",
+      preview: "Preview",
+      status: {
+        New: "new",
+        Identical: "identical",
+        Conflict: "differs",
+      },
+      overwrite: "Overwrite {{count}} differing file(s) (originals kept as .pre-migration)",
+      installExtensions: "Install {{count}} missing extension(s) with the IDE's command line",
+      copy: "Copy",
+      done: "{{files}} file(s) copied, {{skipped}} skipped, {{installed}} extension(s) installed",
+    },
+    snapshot: {
+      title: "Scan Snapshots",
+      desc: "Save the full detection, scan and conversation inventory to one file to compare machines, share with support or review changes later without re-scanning.",
+      save: "Save Snapshot",
+      open: "Open Snapshot",
+      compare: "Compare with Now",
+      saved: "Snapshot saved to {{path}}",
+      takenOn: "Taken {{date}} on {{os}}: {{ides}} IDE(s), {{size}}, {{conversations}} conversation(s)",
+      now: "now {{size}}",
+    },
+    externalProfile: {
+      title: "Analyze Another Profile",
+      desc: "Pick another user's profile folder or the root of a mounted disk image to see which IDEs it holds and how much they take up. Nothing is modified.",
+      pick: "Choose Folder",
+      analyzing: "Analyzing...",
+      noIdes: "No IDEs found",
+      ides: "{{count}} IDE(s), {{size}}",
+    },
     titlebar: {
       minimize: "Minimize",
       maximize: "Maximize",
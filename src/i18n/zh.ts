@@ -4,22 +4,176 @@ const zh = {
       name: "DevCleaner",
       version: "v0.1.0",
       footer: "基于 Tauri + React 构建",
+      policyDisabled: "管理员已禁用清理、删除和卸载操作。",
     },
     nav: {
       scan: "扫描清理",
+      footprint: "空间占用",
       conversations: "对话管理",
       uninstall: "卸载管理",
       settings: "设置",
     },
+    tray: {
+      reclaimable: "可释放：{{size}}",
+      scanning: "可释放：扫描中…",
+      cleaning: "清理中…",
+      quickClean: "快速清理（缓存和日志）",
+      open: "打开 DevCleaner",
+      quit: "退出",
+      confirmTitle: "DevCleaner 快速清理",
+      confirmMessage: "从所有未运行的 IDE 中删除 {{size}} 的缓存和日志？设置、扩展和对话不会受影响。",
+      confirmClean: "清理",
+      confirmCancel: "取消",
+    },
     scan: {
       title: "扫描清理",
       subtitle: "扫描开发工具缓存占用并一键清理",
       rescan: "重新扫描",
+      excludedByMarker: "{{count}} 个文件夹受 .devcleaner-ignore 保护",
+      crashOlderThan: "超过 {{count}} 天",
+      purgeCrashes: "清除",
+      crashPurgeConfirm: "删除超过 {{count}} 天的 {{name}}？",
+      crashPurged: "已释放 {{size}}",
       readyTitle: "准备扫描",
       readyDesc: '点击下方按钮分析 {{count}} 个已安装开发工具的缓存占用',
       startScan: "开始扫描",
       spaceMap: "空间占用分布",
     },
+    devCaches: {
+      title: "开发工具缓存",
+      confirm: "清理 {{name}} 缓存（{{size}}）？",
+      runTool: "运行 {{command}}",
+      delete: "清空",
+    },
+    rustTargets: {
+      title: "Rust target/ 目录",
+      confirm: "删除 {{count}} 个 target 文件夹（{{size}}）？项目将需要完整重新编译。",
+      deleteAll: "全部删除",
+      notBuiltFor: "{{count}} 天未编译",
+      deleteStale: "删除未使用的（{{size}}）",
+      daysAgo: "{{count}} 天前",
+    },
+    python: {
+      title: "Python 缓存与虚拟环境",
+      venvUnusedFor: "虚拟环境 {{count}} 天未使用",
+      deleteSelected: "删除所选（{{size}}）",
+      confirm: "删除 {{count}} 个文件夹（{{size}}）？缓存会自动重建，虚拟环境需要重新创建。",
+      stale: "已过期",
+      kinds: {
+        PyCache: "__pycache__",
+        PytestCache: ".pytest_cache",
+        MypyCache: ".mypy_cache",
+        Venv: "虚拟环境",
+      },
+    },
+    maven: {
+      title: "Maven 本地仓库",
+      largestGroups: "占用最大的 groupId",
+      versions: "{{count}} 个版本",
+      unusedFor: "{{count}} 个月未使用",
+      deleteStale: "删除未使用项 ({{size}})",
+      confirm: "删除 {{count}} 个构件版本（{{size}}）？需要它们的构建会重新下载。",
+    },
+    retry: {
+      retryFailed: "重试失败项",
+      closeIdeFirst: "先关闭 IDE",
+      stillFailing: "仍有 {{count}} 项失败",
+      allDone: "剩余项已全部删除（释放 {{size}}）",
+    },
+    extensions: {
+      title: "扩展",
+      installedSummary: "{{installed}} 个扩展",
+      staleSummary: "{{count}} 个过期或已废弃的扩展副本",
+      deleteStale: "删除过期项 ({{size}})",
+      confirm: "从 {{ide}} 删除 {{count}} 个过期扩展副本（{{size}}）？正在使用的版本会保留。",
+      obsolete: "已废弃",
+      uninstall: "卸载",
+      uninstallConfirm: "卸载 {{id}}（{{size}}）？请先关闭 IDE，以免其重新安装。",
+      clearData: "同时删除扩展数据（globalStorage）",
+      exportList: "导出列表",
+      importList: "导入列表",
+      exactVersions: "精确版本",
+      exported: "已导出 {{count}} 个扩展到 {{path}}",
+      importConfirm: "将从 {{source}} 导出的 {{count}} 个扩展安装到 {{ide}}？已安装的会被跳过。",
+      imported: "已安装 {{installed}} 个扩展，{{skipped}} 个已存在",
+      installFailed: "安装失败，请手动安装",
+    },
+    wsl: {
+      title: "WSL 远程服务端",
+      staleSummary: "{{builds}} 个旧服务端版本，{{extensions}} 个过期扩展副本",
+      deleteStale: "删除过期项 ({{size}})",
+      confirm: "删除 {{distro}} 中的 {{count}} 个旧服务端版本和扩展副本（{{size}}）？当前使用的版本会保留。",
+    },
+    devcontainers: {
+      title: "开发容器",
+      dockerUnavailable: "Docker 未运行或未安装，仅显示 devcontainers CLI 缓存。",
+      remove: "删除",
+      confirm: "删除 {{name}}（{{size}}）？",
+      kinds: {
+        Container: "容器",
+        Image: "镜像",
+        Volume: "卷",
+        CliCache: "devcontainers CLI 缓存",
+      },
+    },
+    aiCli: {
+      title: "AI 编程 CLI 会话",
+      tools: {
+        ClaudeCode: "Claude Code",
+        GeminiCli: "Gemini CLI",
+        CodexCli: "Codex CLI",
+        Aider: "aider",
+      },
+      sessions: "{{count}} 个会话",
+      untouchedFor: "{{count}} 天未使用",
+      pruneStale: "清理旧会话（{{size}}）",
+      confirm: "删除 {{count}} 个会话记录（{{size}}）？删除后将无法继续这些会话。",
+      untitled: "未命名会话",
+      summaries: "摘要",
+      firstPrompt: "首条提示",
+      lastPrompt: "最后一条提示",
+      messages: "{{user}} 条提示，{{assistant}} 条回复",
+    },
+    localModels: {
+      title: "本地 AI 模型",
+      runtimes: {
+        Ollama: "Ollama",
+        LmStudio: "LM Studio",
+        Gpt4All: "GPT4All",
+        HuggingFace: "Hugging Face",
+      },
+      deleteSelected: "删除所选（{{size}}）",
+      confirm: "删除 {{count}} 个模型？将释放 {{size}}，再次使用需要重新下载。",
+      shared: "{{size}} 与其他模型共享",
+      pruneRevisions: "清理 {{count}} 个旧版本（{{size}}）",
+      confirmPrune: "删除 {{name}} 除最新版本外的所有版本？将释放 {{size}}。",
+    },
+    remoteSsh: {
+      title: "远程 SSH 主机",
+      scan: "扫描主机",
+      hint: "使用本机 ssh 客户端（密钥或 agent 认证）。列出 ~/.vscode-server（及 Cursor、Windsurf 等）的服务端版本；最新版本和正在运行的版本会保留。",
+      invalidDestination: "请输入 user@host 或 user@host:port 格式的地址",
+      inUse: "使用中",
+      current: "当前",
+      confirm: "删除 {{host}} 上的 {{count}} 个旧服务端版本（{{size}}）？",
+    },
+    footprint: {
+      title: "开发者空间占用",
+      subtitle: "本机所有开发工具占用的空间，按大小排序",
+      total: "总计",
+      ranked: "占用最大的项目",
+      items: "{{count}} 项",
+      families: {
+        ide: "IDE",
+        devCache: "开发工具缓存",
+        toolboxBuild: "旧版 Toolbox 构建",
+        projectArtifact: "项目构建产物",
+        devContainer: "开发容器",
+        localModel: "本地 AI 模型",
+        wslServer: "WSL 远程服务器",
+        aiCliSession: "AI CLI 会话",
+      },
+    },
     dashboard: {
       scanning: "扫描中...",
       totalSize: "总占用",
@@ -36,6 +190,18 @@ const zh = {
       cleanComplete: "清理完成",
       freed: "已释放",
       errors: "个错误",
+      byFamily: "按系列清理",
+      families: {
+        VscodeBased: "全部 {{count}} 个 VS Code 系 IDE",
+        JetBrains: "全部 {{count}} 个 JetBrains IDE",
+        Zed: "全部 {{count}} 个 Zed 版本",
+        VisualStudio: "全部 {{count}} 个 Visual Studio 版本",
+        Eclipse: "全部 {{count}} 个 Eclipse 安装",
+        TerminalEditor: "全部 {{count}} 个终端编辑器",
+        Xcode: "全部 {{count}} 个 Xcode 版本",
+      },
+      familyConfirm: "按当前模式清理 {{names}}？将删除约 {{size}}。",
+      familyProgress: "正在清理 {{name}}（{{done}}/{{total}}），已释放 {{size}}",
       ideRunning: "进程正在运行",
       ideRunningDesc: "以下进程仍在运行中，运行时清理可能导致问题。",
       continueAnyway: "继续清理",
@@ -57,18 +223,84 @@ const zh = {
       chats: "个对话",
       chatHistory: "对话列表",
       dbFiles: "数据库文件",
+      compact: "压缩",
+      compactHint: "压缩数据库（VACUUM），将已删除对话占用的空间归还给磁盘",
+      compacting: "正在压缩… {{percent}}%（{{seconds}} 秒）",
+      compactFreed: "−{{size}}",
+      strayFiles: "已删除数据库遗留的 {{count}} 个 WAL/日志文件",
+      removeStrayFiles: "移除",
+      strayFilesPartialFailure: "{{total}} 个遗留文件中有 {{failed}} 个未能删除。",
       noIdes: "未检测到支持的开发工具",
       noData: "未发现对话数据",
       deleteConversation: "删除对话",
       deleteConfirm: "确定要删除这条对话吗？此操作不可撤销。",
       batchDeleteConfirm: "确定要删除选中的 {{count}} 条对话吗？此操作不可撤销。",
+      batchPartialFailure: "{{total}} 条对话中有 {{failed}} 条未能删除，已保持选中——关闭 IDE 后再次删除即可重试。",
+      removeDuplicates: "移除 {{count}} 个重复副本（{{size}}）",
+      duplicatesConfirm: "删除 {{count}} 个冗余副本（{{size}}）？每条对话会保留最完整的一份。",
+      duplicatesPartialFailure: "{{total}} 个副本中有 {{failed}} 个未能删除；与其他对话存放在一起的副本会被保留。",
+      copies: "另有 {{count}} 个副本",
+      protect: "保护，不被清理",
+      unprotect: "取消保护",
+      protectedSkipped: "已跳过 {{count}} 条受保护的对话。",
+      unprotectToDelete: "取消保护后才能删除",
+      editNotes: "标签和备注",
+      tagsPlaceholder: "标签，用逗号分隔",
+      notePlaceholder: "备注",
+      saveNotes: "保存",
+      allTags: "全部标签",
+      scanSecrets: "扫描密钥泄露",
+      noSecrets: "未发现密钥",
+      selectLeaking: "选中 {{count}} 条泄露对话",
+      redactSecrets: "隐去密钥",
+      secretKinds: {
+        AwsAccessKey: "AWS 访问密钥",
+        ApiKey: "API 密钥",
+        GitHubToken: "GitHub 令牌",
+        SlackToken: "Slack 令牌",
+        PrivateKey: "私钥",
+      },
+      deleteFailed: "删除失败",
       viewContent: "查看对话内容",
+      exportHtml: "导出 HTML",
+      exportJsonl: "JSONL",
+      exportSelected: "导出选中 ({{count}})",
+      exportPartialFailure: "{{total}} 条对话中有 {{failed}} 条未能导出",
+      archiveSelected: "归档选中 ({{count}})",
+      archiveConfirm: "归档选中的 {{count}} 条对话？对话会先复制到 DevCleaner 的归档中，再从 IDE 中删除。",
+      archivePartialFailure: "{{total}} 条对话中有 {{failed}} 条未能归档，已保持选中——关闭 IDE 后再次归档即可重试。",
+      archived: "已归档的对话",
+      archivedOn: "归档于 {{date}}",
+      archiveDelete: "从归档中删除",
+      archiveDeleteConfirm: "从归档中永久删除“{{title}}”？",
+      restore: "恢复到 IDE",
+      restoreFailed: "无法恢复“{{title}}”：{{error}}",
+      search: "搜索",
+      searchPlaceholder: "搜索所有 IDE 的对话标题和消息…",
+      searchAllIdes: "全部 IDE",
+      searchIndexing: "正在建立索引…",
+      searchNoHits: "没有匹配的对话",
+      cliAgents: "命令行智能体",
+      exportFailed: "导出失败",
       noMessages: "未能解析出消息内容",
-      binaryContent: "此对话以二进制格式存储，暂不支持预览完整内容",
+      binaryContent: "此对话已加密或为无法解码的二进制格式",
       messagesCount: "条消息",
       close: "关闭",
+      allLanguages: "全部语言",
+      anySize: "任意大小",
+      filterTitle: "筛选标题…",
+      loadMore: "加载更多（{{shown}} / {{total}}）",
+      languages: {
+        zh: "中文",
+        ja: "日文",
+        ko: "韩文",
+        ru: "俄文",
+        latin: "拉丁文字",
+      },
       sortByTime: "按时间",
       sortBySize: "按大小",
+      sortByMessages: "按消息数",
+      sortByTitle: "按标题",
       selectAll: "全选",
       deselectAll: "取消全选",
       selectBefore: "选择此项及之前",
@@ -89,8 +321,14 @@ const zh = {
       keepUserData: "保留工作区数据",
       keepConversations: "保留对话历史",
       keepExtensions: "保留扩展",
+      showBreakdown: "查看包含的内容",
+      moreEntries: "…以及另外 {{count}} 项",
+      willRemove: "将删除：{{size}}",
+      willKeep: "将保留：{{size}}",
+      keptInsideRemoved: "{{options}} 位于将被删除的文件夹内，也会一并删除。",
       selectProgram: "选择左侧程序以查看卸载选项",
       unknownLocation: "安装位置未知",
+      viaPackageManager: "通过 {{manager}} 安装",
       uninstallBtn: "卸载程序",
       uninstalling: "卸载中...",
       confirmTitle: "确认卸载",
@@ -98,9 +336,12 @@ const zh = {
       confirmYes: "确认卸载",
       success: "卸载成功",
       failed: "卸载失败",
+      leftoverRelated: "同一发布者的以下程序仍已安装：",
     },
     backups: {
       no: "取消",
+      restoreRegistry: "恢复注册表项",
+      registryRestored: "已恢复 {{count}} 个注册表项",
     },
     settings: {
       title: "设置",
@@ -108,6 +349,8 @@ const zh = {
       general: "通用",
       autoBackup: "自动备份",
       autoBackupDesc: "清理前自动创建备份",
+      secureDelete: "安全删除",
+      secureDeleteDesc: "删除对话文件、记录和日志前先覆盖其内容，并清零 IDE 数据库中已删除的对话。速度较慢，在 SSD 上不够可靠",
       defaultCleanMode: "默认清理模式",
       defaultCleanModeDesc: "打开清理页面时的默认模式",
       theme: "主题",
@@ -115,8 +358,25 @@ const zh = {
       themeSystem: "跟随系统",
       themeLight: "浅色",
       themeDark: "深色",
+      previewLen: "对话预览长度",
+      previewLenDesc: "快速读取标题和消息数时每个对话读取的字符数",
+      maxFullRead: "完整读取上限 (MB)",
+      maxFullReadDesc: "不超过此大小的对话会完整解析以获得准确计数，更大的对话将分块扫描",
+      resetReadLimits: "恢复默认",
       language: "语言",
       languageDesc: "选择显示语言",
+      projectRoots: "项目根目录",
+      projectRootsDesc: "在这些文件夹中查找项目构建产物，例如 Rust 的 target/ 目录。",
+      addProjectRoot: "添加文件夹",
+      portablePaths: "便携版安装",
+      portablePathsDesc: "存放便携版 IDE（可执行文件旁有 data/ 文件夹）且未被自动发现的文件夹。",
+      addPortablePath: "添加文件夹",
+      curation: "设置迁移",
+      curationDesc: "导出隐藏的 IDE、便携版路径、受保护的对话和对话键规则，并合并到其他安装中。导入只会新增条目，不会删除现有设置。",
+      exportCuration: "导出",
+      importCuration: "导入",
+      curationExported: "设置已导出",
+      curationImported: "已导入 {{ides}} 个隐藏 IDE、{{paths}} 个便携路径、{{protected}} 条受保护的对话、{{rules}} 条规则；{{skipped}} 个路径在本机不存在",
       backupManagement: "备份管理",
       backupLocation: "存储位置",
       backupCount: "备份数量",
@@ -129,6 +389,56 @@ const zh = {
       license: "许可证",
       author: "开发者",
     },
+    migration: {
+      title: "迁移到新电脑",
+      desc: "将所选 IDE 的设置、扩展列表和对话连同 DevCleaner 设置、标签和备注打包为一个归档，然后在新电脑上恢复。对话以可读副本的形式恢复，不会写回 IDE。",
+      includeConversations: "包含对话",
+      export: "导出迁移包",
+      import: "打开迁移包",
+      exported: "已导出 {{ides}} 个 IDE，共 {{size}}",
+      archiveFrom: "迁移包创建于 {{date}}（{{os}}）",
+      ideContents: "{{settings}} 个设置文件、{{extensions}} 个扩展、{{conversations}} 个对话",
+      overwriteSettings: "覆盖现有设置（原文件保留为 .pre-migration）",
+      installExtensions: "通过 IDE 命令行安装扩展",
+      restore: "恢复",
+      restored: "已恢复 {{files}} 个文件，跳过 {{skipped}} 个，安装 {{installed}} 个扩展",
+      pendingExtensions: "还有 {{count}} 个扩展待安装",
+      conversationsSaved: "对话已保存到 {{path}}",
+      skippedIdes: "本机未安装：{{ides}}",
+      annotationsAdded: "已为 {{count}} 个对话添加标签和备注",
+    },
+    settingsTransfer: {
+      title: "在 IDE 之间复制设置",
+      desc: "将设置、快捷键、代码片段和配置文件从一个 VS Code 系 IDE 复制到另一个（如 Cursor 到 Windsurf），并安装缺少的扩展。可先预览将发生的变更。",
+      preview: "预览",
+      status: {
+        New: "新增",
+        Identical: "相同",
+        Conflict: "不同",
+      },
+      overwrite: "覆盖 {{count}} 个不同的文件（原文件保留为 .pre-migration）",
+      installExtensions: "通过 IDE 命令行安装缺少的 {{count}} 个扩展",
+      copy: "复制",
+      done: "已复制 {{files}} 个文件，跳过 {{skipped}} 个，安装 {{installed}} 个扩展",
+    },
+    snapshot: {
+      title: "扫描快照",
+      desc: "将完整的检测、扫描和对话清单保存为一个文件，便于对比不同电脑、发给技术支持，或稍后无需重新扫描即可查看变化。",
+      save: "保存快照",
+      open: "打开快照",
+      compare: "与当前对比",
+      saved: "快照已保存到 {{path}}",
+      takenOn: "{{date}} 于 {{os}} 生成：{{ides}} 个 IDE，共 {{size}}，{{conversations}} 个对话",
+      now: "当前 {{size}}",
+    },
+    externalProfile: {
+      title: "分析其他用户配置",
+      desc: "选择其他用户的配置文件夹或已挂载磁盘映像的根目录，查看其中有哪些 IDE 及其占用空间。不会做任何修改。",
+      pick: "选择文件夹",
+      analyzing: "分析中...",
+      noIdes: "未发现 IDE",
+      ides: "{{count}} 个 IDE，共 {{size}}",
+    },
     titlebar: {
       minimize: "最小化",
       maximize: "最大化",
@@ -1,4 +1,4 @@
-export type IdeType = "VscodeBased" | "JetBrains";
+export type IdeType = "VscodeBased" | "JetBrains" | "Zed" | "VisualStudio" | "Eclipse" | "TerminalEditor" | "Xcode";
 
 export interface IdeInfo {
   name: string;
@@ -12,6 +12,14 @@ export interface IdeInfo {
   workspace_storage_path: string | null;
   global_storage_path: string | null;
   versioned_folders: VersionedFolder[];
+  portable_root: string | null;
+  extra_categories: ExtraCategory[];
+}
+
+export interface ExtraCategory {
+  name: string;
+  category_type: CategoryType;
+  paths: string[];
 }
 
 export interface VersionedFolder {
@@ -31,7 +39,11 @@ export type CategoryType =
   | "WorkspaceStorage"
   | "Extension"
   | "CrashReport"
-  | "GlobalStorage";
+  | "GlobalStorage"
+  | "Installer"
+  | "WebStorage"
+  | "BuildCache"
+  | "LocalHistory";
 
 export interface ScanCategory {
   name: string;
@@ -39,6 +51,10 @@ export interface ScanCategory {
   total_size: number;
   file_count: number;
   paths: string[];
+  /** GlobalStorage only: per-extension folders, largest first. */
+  entries: StorageEntry[];
+  /** Folders holding a .devcleaner-ignore marker: not counted, never cleaned. */
+  excluded_by_marker: string[];
 }
 
 export interface IdeScanResult {
@@ -63,6 +79,25 @@ export interface CleanResult {
   freed_bytes: number;
   deleted_files: number;
   errors: string[];
+  failed_paths: string[];
+  operation_id: string | null;
+}
+
+export interface FamilyCleanProgress {
+  ide_id: string | null;
+  ide_name: string | null;
+  done: number;
+  total: number;
+  freed_bytes: number;
+}
+
+export interface FamilyCleanResult {
+  ide_type: IdeType;
+  results: CleanResult[];
+  freed_bytes: number;
+  deleted_files: number;
+  errors: string[];
+  operation_id: string | null;
 }
 
 export interface BackupInfo {
@@ -73,6 +108,9 @@ export interface BackupInfo {
   file_path: string;
   size: number;
   file_count: number;
+  registry_keys: string[];
+  registry_files: string[];
+  credential_targets: string[];
 }
 
 export interface BackupListResult {
@@ -86,6 +124,8 @@ export interface StorageEntry {
   size: number;
   file_count: number;
   modified: number | null;
+  /** workspaceStorage only: last real use from state.vscdb / hot-exit backups. */
+  last_activity: number | null;
   is_ai_related: boolean;
 }
 
@@ -96,7 +136,45 @@ export interface ConversationInfo {
   source_key: string;
   message_count: number;
   size_bytes: number;
+  /** UTC epoch milliseconds. */
   last_modified: number | null;
+  last_modified_local: string | null;
+  /** Dominant script of the title: "zh" | "ja" | "ko" | "ru" | "latin", or "" if undetermined. */
+  language: string;
+  /** Other places the same conversation is stored, merged into this entry. */
+  duplicates: ConversationCopy[];
+  /** Pinned by the user; batch deletes and bulk cleanup skip it. */
+  protected: boolean;
+  /** The user's tags and note from DevCleaner's own notes database. */
+  tags: string[];
+  note: string;
+}
+
+export type SecretKind = "AwsAccessKey" | "ApiKey" | "GitHubToken" | "SlackToken" | "PrivateKey";
+
+export interface SecretFinding {
+  kind: SecretKind;
+  /** Masked; the secret itself never leaves the backend. */
+  preview: string;
+  /** Index into the conversation's messages; null for the title. */
+  message_index: number | null;
+}
+
+export interface ConversationSecrets {
+  id: string;
+  title: string;
+  findings: SecretFinding[];
+}
+
+export interface ConversationAnnotation {
+  tags: string[];
+  note: string;
+}
+
+export interface ConversationCopy {
+  source_db: string;
+  source_key: string;
+  size_bytes: number;
 }
 
 export interface DbFileInfo {
@@ -111,6 +189,96 @@ export interface ConversationListResult {
   conversations: ConversationInfo[];
   db_files: DbFileInfo[];
   total_size: number;
+  /** -wal/-shm/-journal files whose database is gone. */
+  stray_files: DbFileInfo[];
+}
+
+export type ConversationSortField = "Date" | "Size" | "MessageCount" | "Title" | "Source";
+
+export type ItemStatus = "Done" | "NotFound" | "Failed" | "Skipped";
+
+export interface ItemResult {
+  id: string;
+  status: ItemStatus;
+  bytes_freed: number;
+  error: string | null;
+}
+
+export interface BatchResult {
+  items: ItemResult[];
+  freed_bytes: number;
+  succeeded: number;
+  failed: number;
+  operation_id: string | null;
+  /** What failed items left on disk, recorded for retry. */
+  failed_paths: string[];
+}
+
+/** Emitted as "vacuum-progress" while a database is compacted. */
+export interface VacuumProgress {
+  path: string;
+  size_before: number;
+  pages_done: number;
+  pages_total: number;
+  elapsed_ms: number;
+  done: boolean;
+}
+
+export interface VacuumResult {
+  path: string;
+  size_before: number;
+  size_after: number;
+  freed_bytes: number;
+  elapsed_ms: number;
+}
+
+export type OperationKind = "Clean" | "DeleteConversations" | "Uninstall";
+
+export type FailedItem =
+  | { type: "Path"; path: string }
+  | { type: "Conversation"; request: { id: string; source_db: string; source_key: string } };
+
+export interface FailedOperation {
+  id: string;
+  kind: OperationKind;
+  ide_ids: string[];
+  created_at: string;
+  items: FailedItem[];
+}
+
+export interface ConversationSort {
+  field: ConversationSortField;
+  descending: boolean;
+}
+
+export interface ConversationQuery {
+  sort: ConversationSort;
+  offset: number;
+  limit: number | null;
+  title: string | null;
+  min_size: number | null;
+  max_size: number | null;
+  language: string | null;
+  /** One of the user's tags, case-insensitive. */
+  tag: string | null;
+}
+
+export interface ConversationPage {
+  ide_id: string;
+  conversations: ConversationInfo[];
+  offset: number;
+  matched: number;
+  matched_size: number;
+  total_conversations: number;
+  db_files: DbFileInfo[];
+  total_size: number;
+  languages: string[];
+  /** Distinct tags among all conversations, for the filter picker. */
+  tags: string[];
+  /** Redundant copies merged into the IDE's conversations, and their size. */
+  duplicate_copies: number;
+  duplicate_size: number;
+  stray_files: DbFileInfo[];
 }
 
 export interface VersionInstall {
@@ -132,13 +300,17 @@ export interface InstalledProgram {
   ide_id: string | null;
   icon_base64: string | null;
   version_installs: VersionInstall[];
+  package_manager: PackageManager | null;
 }
 
+export type PackageManager = "Scoop" | "Chocolatey";
+
 export interface UninstallOptions {
   keep_user_data: boolean;
   keep_conversations: boolean;
   keep_extensions: boolean;
   keep_settings: boolean;
+  remove_credentials?: boolean;
 }
 
 export interface UninstallResult {
@@ -147,15 +319,75 @@ export interface UninstallResult {
   uninstaller_ran: boolean;
   residual_cleaned: boolean;
   residual_freed_bytes: number;
+  registry_keys_removed: string[];
+  credentials_removed: string[];
+  backup_id: string | null;
   errors: string[];
+  verification: UninstallVerification;
+  failed_paths: string[];
+  operation_id: string | null;
+  programs_diff: ProgramsDiff | null;
+}
+
+export interface UninstallVerification {
+  clean: boolean;
+  remaining: RemainingItem[];
+}
+
+export interface RemainingItem {
+  kind: "program" | "path" | "registry" | "credential";
+  item: string;
+  reason: string;
+}
+
+export interface UninstallEntry {
+  registry_key: string;
+  display_name: string;
+  publisher: string;
+  display_version: string;
+}
+
+export interface ProgramsSnapshot {
+  id: string;
+  label: string;
+  timestamp: string;
+  programs: UninstallEntry[];
+}
+
+export interface ProgramsSnapshotInfo {
+  id: string;
+  label: string;
+  timestamp: string;
+  program_count: number;
+}
+
+export interface ProgramsDiff {
+  before_id: string;
+  after_id: string;
+  removed: UninstallEntry[];
+  added: UninstallEntry[];
+  leftover_related: UninstallEntry[];
 }
 
 export interface ResidualInfo {
   paths: ResidualPath[];
   registry_keys: string[];
+  credentials: CredentialEntry[];
   total_size: number;
 }
 
+export interface CredentialEntry {
+  target_name: string;
+  user_name: string;
+  comment: string;
+  cred_type: number;
+  persist: number;
+  blob_size: number;
+  last_written: number | null;
+  ide_ids: string[];
+  shared: boolean;
+}
+
 export interface ResidualPath {
   path: string;
   size: number;
@@ -165,6 +397,7 @@ export interface ResidualPath {
 export interface ConversationMessage {
   role: string;
   content: string;
+  timestamp: number | null;
 }
 
 export interface ConversationContent {
@@ -172,11 +405,565 @@ export interface ConversationContent {
   messages: ConversationMessage[];
 }
 
+export type ExportFormat = "Html" | "Jsonl";
+
+export interface ArchivedConversation {
+  id: number;
+  ide_id: string;
+  conversation_id: string;
+  title: string;
+  source_db: string;
+  source_key: string;
+  message_count: number;
+  size_bytes: number;
+  last_modified: number | null;
+  archived_at: number;
+}
+
+export interface SearchIndexStats {
+  indexed: number;
+  removed: number;
+  total: number;
+}
+
+export interface SearchHit {
+  ide_id: string;
+  conversation: ConversationInfo;
+  /** Matched terms are wrapped in \u0002 … \u0003. */
+  snippet: string;
+  score: number;
+}
+
+export type KeepGroup = "Always" | "Settings" | "UserData" | "Conversations" | "Extensions";
+
+export interface KeepOptionBreakdown {
+  group: KeepGroup;
+  roots: string[];
+  entries: ResidualPath[];
+  size: number;
+}
+
+export interface ReclaimEstimate {
+  keep_user_data: boolean;
+  keep_conversations: boolean;
+  keep_extensions: boolean;
+  keep_settings: boolean;
+  removed_size: number;
+  kept_size: number;
+}
+
 export interface KeepOptionSizes {
   settings_size: number;
   user_data_size: number;
   conversations_size: number;
   extensions_size: number;
+  breakdown: KeepOptionBreakdown[];
+  estimates: ReclaimEstimate[];
+}
+
+export interface VscodeIdeDefinition {
+  name: string;
+  id: string;
+  appdata_folder: string;
+  home_dot_folder: string;
+  process_names: string[];
+  custom: boolean;
+}
+
+export interface JetBrainsIdeDefinition {
+  name: string;
+  id: string;
+  folder_prefixes: string[];
+  process_names: string[];
+  custom: boolean;
+}
+
+export interface IdeDefinitions {
+  path: string;
+  vscode: VscodeIdeDefinition[];
+  jetbrains: JetBrainsIdeDefinition[];
+  error: string | null;
+}
+
+export type RuleSource = "Builtin" | "RulesPack";
+
+export interface KeyRule {
+  pattern: string;
+  source: RuleSource;
+}
+
+export interface ConversationRules {
+  chat_data_keys: KeyRule[];
+  item_table_like: KeyRule[];
+  ignored_keys: KeyRule[];
+}
+
+export interface RulesDiagnostics {
+  pack_path: string;
+  pack_loaded: boolean;
+  pack_version: string | null;
+  error: string | null;
+  rules: ConversationRules;
+}
+
+export type Page = "scan" | "footprint" | "conversations" | "uninstall" | "settings";
+
+export interface StaleVersion {
+  ide_id: string;
+  ide_name: string;
+  version: string;
+  latest_version: string;
+  config_path: string | null;
+  cache_path: string | null;
+  size: number;
+  still_installed: boolean;
+}
+
+export interface StaleVersionRequest {
+  ide_id: string;
+  version: string;
+}
+
+export interface ToolboxLeftover {
+  id: string;
+  product: string;
+  channel: string;
+  build: string;
+  current_build: string;
+  paths: string[];
+  size: number;
+}
+
+export interface OrphanedStorage {
+  ide_id: string;
+  ide_name: string;
+  extension_id: string;
+  category_type: CategoryType;
+  path: string;
+  size: number;
+}
+
+export interface DevCache {
+  id: string;
+  name: string;
+  paths: string[];
+  size: number;
+  file_count: number;
+  breakdown: DevCacheEntry[];
+  clean_command: string | null;
+  tool_available: boolean;
+}
+
+export interface DevCacheEntry {
+  name: string;
+  path: string;
+  size: number;
+}
+
+export type FootprintFamily =
+  | "Ide"
+  | "DevCache"
+  | "ToolboxBuild"
+  | "ProjectArtifact"
+  | "DevContainer"
+  | "LocalModel"
+  | "WslServer"
+  | "AiCliSession";
+
+export interface FootprintItem {
+  family: FootprintFamily;
+  id: string;
+  name: string;
+  detail: string;
+  size: number;
+  paths: string[];
+}
+
+export interface FamilyTotal {
+  family: FootprintFamily;
+  total_size: number;
+  item_count: number;
 }
 
-export type Page = "scan" | "conversations" | "uninstall" | "settings";
+export interface DeveloperFootprint {
+  items: FootprintItem[];
+  families: FamilyTotal[];
+  grand_total: number;
+  scan_duration_ms: number;
+}
+
+export interface CurationImportSummary {
+  added_ignored_ides: string[];
+  added_portable_paths: string[];
+  skipped_portable_paths: string[];
+  added_protected: number;
+  added_rules: number;
+}
+
+export interface MigratedIde {
+  id: string;
+  name: string;
+  ide_type: IdeType;
+  settings_files: string[];
+  extensions: string[];
+  conversations: number;
+}
+
+export interface MigrationManifest {
+  format_version: number;
+  created_at: string;
+  source_os: string;
+  ides: MigratedIde[];
+}
+
+export interface MigrationExportSummary {
+  path: string;
+  size: number;
+  manifest: MigrationManifest;
+}
+
+export interface IdeImportResult {
+  id: string;
+  restored_files: number;
+  skipped_files: number;
+  installed_extensions: string[];
+  pending_extensions: string[];
+  conversations_dir: string | null;
+  errors: string[];
+}
+
+export interface MigrationImportSummary {
+  ides: IdeImportResult[];
+  skipped_ides: string[];
+  curation: CurationImportSummary | null;
+  added_annotations: number;
+}
+
+export type TransferStatus = "New" | "Identical" | "Conflict";
+
+export interface TransferFile {
+  path: string;
+  size: number;
+  status: TransferStatus;
+}
+
+export interface SettingsTransferPreview {
+  from_id: string;
+  to_id: string;
+  files: TransferFile[];
+  missing_extensions: string[];
+}
+
+export interface SettingsTransferResult {
+  copied_files: number;
+  skipped_files: number;
+  installed_extensions: string[];
+  pending_extensions: string[];
+  errors: string[];
+}
+
+export interface ScanSnapshot {
+  format_version: number;
+  created_at: string;
+  source_os: string;
+  ides: IdeInfo[];
+  scan: ScanSummary;
+  conversations: ConversationListResult[];
+}
+
+export type ProfileLayout = "Windows" | "MacOs" | "Linux";
+
+export interface ProfileDirs {
+  home: string;
+  roaming: string;
+  local: string;
+  data_local: string;
+  layout: ProfileLayout;
+}
+
+export interface ProfileReport {
+  name: string;
+  dirs: ProfileDirs;
+  ides: IdeInfo[];
+  scan: ScanSummary;
+}
+
+export interface ExternalProfileReport {
+  root: string;
+  profiles: ProfileReport[];
+}
+
+export interface RustTarget {
+  crate_name: string;
+  project_path: string;
+  target_path: string;
+  size: number;
+  last_modified: number | null;
+}
+
+export interface ReadLimits {
+  preview_len: number;
+  max_full_read: number;
+}
+
+export interface ChunkedHandle {
+  handle: string;
+  /** The result with its item list emptied; items come from `fetch_chunk`. */
+  shell: unknown;
+  items_field: string | null;
+  item_count: number;
+  chunk_count: number;
+}
+
+export interface PolicyStatus {
+  destructive_disabled: boolean;
+  reason: string | null;
+  source: string | null;
+}
+
+export interface MavenArtifact {
+  group_id: string;
+  artifact_id: string;
+  version: string;
+  path: string;
+  size: number;
+  last_used: number | null;
+}
+
+export interface MavenGroup {
+  group_id: string;
+  size: number;
+  artifact_count: number;
+}
+
+export type PythonArtifactKind = "PyCache" | "PytestCache" | "MypyCache" | "Venv";
+
+export interface PythonArtifactGroup {
+  kind: PythonArtifactKind;
+  paths: string[];
+  size: number;
+  last_modified: number | null;
+  stale: boolean;
+}
+
+export interface PythonProject {
+  name: string;
+  project_path: string;
+  groups: PythonArtifactGroup[];
+  size: number;
+}
+
+export interface MavenRepoReport {
+  path: string;
+  total_size: number;
+  artifact_count: number;
+  groups: MavenGroup[];
+  stale: MavenArtifact[];
+  stale_size: number;
+  stale_months: number;
+}
+
+export type AiCliTool = "ClaudeCode" | "GeminiCli" | "CodexCli" | "Aider";
+
+export interface AiCliRoot {
+  tool: AiCliTool;
+  path: string;
+  size: number;
+}
+
+export interface AiCliSession {
+  id: string;
+  path: string;
+  size: number;
+  last_modified: number | null;
+  title: string;
+  stale: boolean;
+}
+
+export interface AiCliProject {
+  tool: AiCliTool;
+  project: string;
+  storage_path: string;
+  sessions: AiCliSession[];
+  size: number;
+  last_modified: number | null;
+}
+
+export interface AiCliReport {
+  roots: AiCliRoot[];
+  projects: AiCliProject[];
+  total_size: number;
+  stale_count: number;
+  stale_size: number;
+  stale_days: number;
+}
+
+export interface AiCliSessionSummary {
+  tool: AiCliTool;
+  id: string;
+  path: string;
+  project: string | null;
+  title: string;
+  summaries: string[];
+  first_prompt: string | null;
+  last_prompt: string | null;
+  user_messages: number;
+  assistant_messages: number;
+  models: string[];
+  started: number | null;
+  last_active: number | null;
+  size: number;
+}
+
+export type ModelRuntime = "Ollama" | "LmStudio" | "Gpt4All" | "HuggingFace";
+
+export interface ModelStore {
+  runtime: ModelRuntime;
+  path: string;
+  size: number;
+}
+
+export interface LocalModel {
+  runtime: ModelRuntime;
+  name: string;
+  path: string;
+  size: number;
+  /** What deleting frees; blobs shared with other models stay. */
+  unique_size: number;
+  last_modified: number | null;
+  /** Hugging Face snapshots, latest first. */
+  revisions: ModelRevision[];
+}
+
+export interface ModelRevision {
+  commit: string;
+  refs: string[];
+  size: number;
+  unique_size: number;
+  last_modified: number | null;
+  is_latest: boolean;
+}
+
+export interface LocalModelReport {
+  stores: ModelStore[];
+  models: LocalModel[];
+  total_size: number;
+}
+
+export type WslItemKind = "ServerBuild" | "Extension";
+
+export interface WslServerItem {
+  kind: WslItemKind;
+  name: string;
+  path: string;
+  size: number;
+  stale: boolean;
+}
+
+export interface WslServerDir {
+  distro: string;
+  user: string;
+  product: string;
+  path: string;
+  size: number;
+  items: WslServerItem[];
+  stale_size: number;
+}
+
+export interface ExtensionCopy {
+  folder: string;
+  path: string;
+  version: string;
+  size: number;
+  in_use: boolean;
+  obsolete: boolean;
+  stale: boolean;
+}
+
+export interface ExtensionGroup {
+  id: string;
+  copies: ExtensionCopy[];
+  size: number;
+  stale_size: number;
+}
+
+export interface ExtensionReport {
+  ide_id: string;
+  extension_path: string;
+  groups: ExtensionGroup[];
+  total_size: number;
+  stale_size: number;
+  stale_count: number;
+}
+
+export interface ExtensionUninstallResult {
+  extension_id: string;
+  removed: string[];
+  freed_bytes: number;
+  errors: string[];
+  failed_paths: string[];
+  operation_id: string | null;
+}
+
+export interface ExtensionListEntry {
+  id: string;
+  version: string;
+}
+
+export interface ExtensionList {
+  format_version: number;
+  created_at: string;
+  source_ide: string;
+  extensions: ExtensionListEntry[];
+}
+
+export interface ExtensionInstallResult {
+  ide_id: string;
+  installed: string[];
+  skipped: string[];
+  failed: string[];
+}
+
+export interface WslReport {
+  distros: string[];
+  servers: WslServerDir[];
+}
+
+export type DevContainerKind = "Container" | "Image" | "Volume" | "CliCache";
+
+export interface DevContainerItem {
+  kind: DevContainerKind;
+  id: string;
+  name: string;
+  size: number;
+  in_use: boolean;
+  local_folder: string | null;
+}
+
+export interface DevContainerReport {
+  docker_available: boolean;
+  items: DevContainerItem[];
+  total_size: number;
+}
+
+export interface SshTarget {
+  host: string;
+  user: string | null;
+  port: number | null;
+  identity_file: string | null;
+}
+
+export interface RemoteServerBuild {
+  path: string;
+  size: number;
+  modified: number;
+  in_use: boolean;
+  stale: boolean;
+}
+
+export interface RemoteServerReport {
+  host: string;
+  builds: RemoteServerBuild[];
+  total_size: number;
+  stale_size: number;
+}
@@ -0,0 +1,28 @@
+import { invoke } from "@tauri-apps/api/core";
+import type { ChunkedHandle } from "../types";
+
+/**
+ * Invoke a `*_chunked` command and fill its result from `fetch_chunk` pieces.
+ * `onChunk` gets the partial result after each chunk so it can be rendered
+ * before the rest arrives.
+ */
+export async function invokeChunked<T>(
+  command: string,
+  args?: Record<string, unknown>,
+  onChunk?: (partial: T) => void,
+): Promise<T> {
+  const { handle, shell, items_field, chunk_count } = await invoke<ChunkedHandle>(command, args);
+  const items: unknown[] = [];
+  const assemble = () =>
+    (items_field ? { ...(shell as Record<string, unknown>), [items_field]: [...items] } : [...items]) as T;
+  try {
+    for (let index = 0; index < chunk_count; index++) {
+      items.push(...(await invoke<unknown[]>("fetch_chunk", { handle, index })));
+      if (onChunk && index < chunk_count - 1) onChunk(assemble());
+    }
+  } catch (e) {
+    await invoke("release_chunked", { handle }).catch(() => {});
+    throw e;
+  }
+  return assemble();
+}
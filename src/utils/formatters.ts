@@ -44,6 +44,7 @@ export function getIdeColor(ideId: string): string {
     positron: "#4E7FBF",
     vscodium: "#2F80ED",
     void: "#8B5CF6",
+    cli_agents: "#D97757",
   };
   return colors[ideId] || "#6366F1";
 }
@@ -77,6 +78,7 @@ export function getIdeIcon(ideId: string): string {
     positron: "Po",
     vscodium: "VC",
     void: "Vo",
+    cli_agents: ">_",
   };
   return icons[ideId] || ideId.slice(0, 2).toUpperCase();
 }